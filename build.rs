@@ -38,13 +38,21 @@ fn main() {
                 "PacketDriver",
             ],
         );
+        // Type names let us find the descriptor of each message for reflection
+        let mut prost_config = tonic_prost_build::Config::new();
+        prost_config.enable_type_names();
+
         let res = bld
             .type_attribute(".", "#[derive(::serde::Deserialize, ::serde::Serialize)]")
             .build_server(true)
+            // New RPCs answer UNIMPLEMENTED instead of breaking existing servers,
+            // like embedding UnimplementedConfigServiceServer in Go
+            .generate_default_stubs(true)
             .build_client(true)
             .compile_well_known_types(true)
             .out_dir("src/generated")
-            .compile_protos(&[proto], &["proto"]);
+            .file_descriptor_set_path("src/generated/descriptor.bin")
+            .compile_with_config(prost_config, &[proto], &["proto"]);
 
         match res {
             Ok(_) => println!("Protobuf compiled successfully!"),
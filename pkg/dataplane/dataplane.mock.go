@@ -69,3 +69,27 @@ func (m *MockConfigServiceServer) UpdateConfig(_ context.Context, req *UpdateCon
 		Error: Error_ERROR_NONE,
 	}, nil
 }
+
+func (m *MockConfigServiceServer) ListNatSessions(context.Context, *ListNatSessionsRequest) (*ListNatSessionsResponse, error) {
+	if m.log {
+		slog.Info("ListNatSessions called")
+	}
+
+	return &ListNatSessionsResponse{}, nil
+}
+
+func (m *MockConfigServiceServer) GetRoutes(_ context.Context, req *GetRoutesRequest) (*GetRoutesResponse, error) {
+	if m.log {
+		slog.Info("GetRoutes called", "vrf", req.GetVrf(), "vpc", req.GetVpc())
+	}
+
+	return &GetRoutesResponse{}, nil
+}
+
+func (m *MockConfigServiceServer) LookupRoute(_ context.Context, req *LookupRouteRequest) (*LookupRouteResponse, error) {
+	if m.log {
+		slog.Info("LookupRoute called", "vrf", req.GetVrf(), "vpc", req.GetVpc(), "address", req.GetAddress())
+	}
+
+	return &LookupRouteResponse{}, nil
+}
@@ -13,6 +13,7 @@ import (
 	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
 	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
 	durationpb "google.golang.org/protobuf/types/known/durationpb"
+	timestamppb "google.golang.org/protobuf/types/known/timestamppb"
 	reflect "reflect"
 	sync "sync"
 	unsafe "unsafe"
@@ -231,8 +232,6 @@ func (InterfaceAdminStatusType) EnumDescriptor() ([]byte, []int) {
 	return file_proto_dataplane_proto_rawDescGZIP(), []int{3}
 }
 
-// TODO: Consider adding additional states such as FRR_STATUS_STARTING, FRR_STATUS_STOPPING,
-// or other states that might be relevant for tracking the lifecycle of FRR processes.
 type ZebraStatusType int32
 
 const (
@@ -325,6 +324,65 @@ func (FrrAgentStatusType) EnumDescriptor() ([]byte, []int) {
 	return file_proto_dataplane_proto_rawDescGZIP(), []int{5}
 }
 
+// Lifecycle of the FRR processes, UNKNOWN for dataplanes that don't report it
+type FrrLifecycleState int32
+
+const (
+	FrrLifecycleState_FRR_STATE_UNKNOWN   FrrLifecycleState = 0
+	FrrLifecycleState_FRR_STATE_STARTING  FrrLifecycleState = 1
+	FrrLifecycleState_FRR_STATE_RUNNING   FrrLifecycleState = 2
+	FrrLifecycleState_FRR_STATE_RELOADING FrrLifecycleState = 3
+	FrrLifecycleState_FRR_STATE_STOPPING  FrrLifecycleState = 4
+	FrrLifecycleState_FRR_STATE_CRASHED   FrrLifecycleState = 5
+)
+
+// Enum value maps for FrrLifecycleState.
+var (
+	FrrLifecycleState_name = map[int32]string{
+		0: "FRR_STATE_UNKNOWN",
+		1: "FRR_STATE_STARTING",
+		2: "FRR_STATE_RUNNING",
+		3: "FRR_STATE_RELOADING",
+		4: "FRR_STATE_STOPPING",
+		5: "FRR_STATE_CRASHED",
+	}
+	FrrLifecycleState_value = map[string]int32{
+		"FRR_STATE_UNKNOWN":   0,
+		"FRR_STATE_STARTING":  1,
+		"FRR_STATE_RUNNING":   2,
+		"FRR_STATE_RELOADING": 3,
+		"FRR_STATE_STOPPING":  4,
+		"FRR_STATE_CRASHED":   5,
+	}
+)
+
+func (x FrrLifecycleState) Enum() *FrrLifecycleState {
+	p := new(FrrLifecycleState)
+	*p = x
+	return p
+}
+
+func (x FrrLifecycleState) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (FrrLifecycleState) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[6].Descriptor()
+}
+
+func (FrrLifecycleState) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[6]
+}
+
+func (x FrrLifecycleState) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use FrrLifecycleState.Descriptor instead.
+func (FrrLifecycleState) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{6}
+}
+
 type DataplaneStatusType int32
 
 const (
@@ -361,11 +419,11 @@ func (x DataplaneStatusType) String() string {
 }
 
 func (DataplaneStatusType) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[6].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[7].Descriptor()
 }
 
 func (DataplaneStatusType) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[6]
+	return &file_proto_dataplane_proto_enumTypes[7]
 }
 
 func (x DataplaneStatusType) Number() protoreflect.EnumNumber {
@@ -374,7 +432,7 @@ func (x DataplaneStatusType) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use DataplaneStatusType.Descriptor instead.
 func (DataplaneStatusType) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{6}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{7}
 }
 
 type BgpNeighborSessionState int32
@@ -419,11 +477,11 @@ func (x BgpNeighborSessionState) String() string {
 }
 
 func (BgpNeighborSessionState) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[7].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[8].Descriptor()
 }
 
 func (BgpNeighborSessionState) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[7]
+	return &file_proto_dataplane_proto_enumTypes[8]
 }
 
 func (x BgpNeighborSessionState) Number() protoreflect.EnumNumber {
@@ -432,7 +490,166 @@ func (x BgpNeighborSessionState) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use BgpNeighborSessionState.Descriptor instead.
 func (BgpNeighborSessionState) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{7}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{8}
+}
+
+type VtepReachability int32
+
+const (
+	VtepReachability_VTEP_REACHABILITY_UNKNOWN     VtepReachability = 0
+	VtepReachability_VTEP_REACHABILITY_REACHABLE   VtepReachability = 1
+	VtepReachability_VTEP_REACHABILITY_UNREACHABLE VtepReachability = 2
+)
+
+// Enum value maps for VtepReachability.
+var (
+	VtepReachability_name = map[int32]string{
+		0: "VTEP_REACHABILITY_UNKNOWN",
+		1: "VTEP_REACHABILITY_REACHABLE",
+		2: "VTEP_REACHABILITY_UNREACHABLE",
+	}
+	VtepReachability_value = map[string]int32{
+		"VTEP_REACHABILITY_UNKNOWN":     0,
+		"VTEP_REACHABILITY_REACHABLE":   1,
+		"VTEP_REACHABILITY_UNREACHABLE": 2,
+	}
+)
+
+func (x VtepReachability) Enum() *VtepReachability {
+	p := new(VtepReachability)
+	*p = x
+	return p
+}
+
+func (x VtepReachability) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (VtepReachability) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[9].Descriptor()
+}
+
+func (VtepReachability) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[9]
+}
+
+func (x VtepReachability) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use VtepReachability.Descriptor instead.
+func (VtepReachability) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{9}
+}
+
+type IpProtocol int32
+
+const (
+	IpProtocol_IP_PROTOCOL_UNSPECIFIED IpProtocol = 0
+	IpProtocol_IP_PROTOCOL_TCP         IpProtocol = 1
+	IpProtocol_IP_PROTOCOL_UDP         IpProtocol = 2
+	IpProtocol_IP_PROTOCOL_ICMP        IpProtocol = 3
+	IpProtocol_IP_PROTOCOL_ICMPV6      IpProtocol = 4
+)
+
+// Enum value maps for IpProtocol.
+var (
+	IpProtocol_name = map[int32]string{
+		0: "IP_PROTOCOL_UNSPECIFIED",
+		1: "IP_PROTOCOL_TCP",
+		2: "IP_PROTOCOL_UDP",
+		3: "IP_PROTOCOL_ICMP",
+		4: "IP_PROTOCOL_ICMPV6",
+	}
+	IpProtocol_value = map[string]int32{
+		"IP_PROTOCOL_UNSPECIFIED": 0,
+		"IP_PROTOCOL_TCP":         1,
+		"IP_PROTOCOL_UDP":         2,
+		"IP_PROTOCOL_ICMP":        3,
+		"IP_PROTOCOL_ICMPV6":      4,
+	}
+)
+
+func (x IpProtocol) Enum() *IpProtocol {
+	p := new(IpProtocol)
+	*p = x
+	return p
+}
+
+func (x IpProtocol) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (IpProtocol) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[10].Descriptor()
+}
+
+func (IpProtocol) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[10]
+}
+
+func (x IpProtocol) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use IpProtocol.Descriptor instead.
+func (IpProtocol) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{10}
+}
+
+type RouteProtocol int32
+
+const (
+	RouteProtocol_ROUTE_PROTOCOL_UNSPECIFIED RouteProtocol = 0
+	RouteProtocol_ROUTE_PROTOCOL_CONNECTED   RouteProtocol = 1
+	RouteProtocol_ROUTE_PROTOCOL_STATIC      RouteProtocol = 2
+	RouteProtocol_ROUTE_PROTOCOL_BGP         RouteProtocol = 3
+	RouteProtocol_ROUTE_PROTOCOL_OSPF        RouteProtocol = 4
+)
+
+// Enum value maps for RouteProtocol.
+var (
+	RouteProtocol_name = map[int32]string{
+		0: "ROUTE_PROTOCOL_UNSPECIFIED",
+		1: "ROUTE_PROTOCOL_CONNECTED",
+		2: "ROUTE_PROTOCOL_STATIC",
+		3: "ROUTE_PROTOCOL_BGP",
+		4: "ROUTE_PROTOCOL_OSPF",
+	}
+	RouteProtocol_value = map[string]int32{
+		"ROUTE_PROTOCOL_UNSPECIFIED": 0,
+		"ROUTE_PROTOCOL_CONNECTED":   1,
+		"ROUTE_PROTOCOL_STATIC":      2,
+		"ROUTE_PROTOCOL_BGP":         3,
+		"ROUTE_PROTOCOL_OSPF":        4,
+	}
+)
+
+func (x RouteProtocol) Enum() *RouteProtocol {
+	p := new(RouteProtocol)
+	*p = x
+	return p
+}
+
+func (x RouteProtocol) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (RouteProtocol) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[11].Descriptor()
+}
+
+func (RouteProtocol) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[11]
+}
+
+func (x RouteProtocol) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use RouteProtocol.Descriptor instead.
+func (RouteProtocol) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{11}
 }
 
 // Defines interface representation on the Gateway
@@ -472,11 +689,11 @@ func (x IfType) String() string {
 }
 
 func (IfType) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[8].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[12].Descriptor()
 }
 
 func (IfType) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[8]
+	return &file_proto_dataplane_proto_enumTypes[12]
 }
 
 func (x IfType) Number() protoreflect.EnumNumber {
@@ -485,7 +702,7 @@ func (x IfType) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use IfType.Descriptor instead.
 func (IfType) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{8}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{12}
 }
 
 // For physical interface - fabric-facing or external-facing
@@ -519,11 +736,11 @@ func (x IfRole) String() string {
 }
 
 func (IfRole) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[9].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[13].Descriptor()
 }
 
 func (IfRole) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[9]
+	return &file_proto_dataplane_proto_enumTypes[13]
 }
 
 func (x IfRole) Number() protoreflect.EnumNumber {
@@ -532,7 +749,7 @@ func (x IfRole) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use IfRole.Descriptor instead.
 func (IfRole) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{9}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{13}
 }
 
 // AFIs supported for BGP peering
@@ -569,11 +786,11 @@ func (x BgpAF) String() string {
 }
 
 func (BgpAF) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[10].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[14].Descriptor()
 }
 
 func (BgpAF) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[10]
+	return &file_proto_dataplane_proto_enumTypes[14]
 }
 
 func (x BgpAF) Number() protoreflect.EnumNumber {
@@ -582,7 +799,7 @@ func (x BgpAF) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use BgpAF.Descriptor instead.
 func (BgpAF) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{10}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{14}
 }
 
 // Log-level for dataplane and DPDK
@@ -628,11 +845,11 @@ func (x LogLevel) String() string {
 }
 
 func (LogLevel) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[11].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[15].Descriptor()
 }
 
 func (LogLevel) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[11]
+	return &file_proto_dataplane_proto_enumTypes[15]
 }
 
 func (x LogLevel) Number() protoreflect.EnumNumber {
@@ -641,7 +858,7 @@ func (x LogLevel) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use LogLevel.Descriptor instead.
 func (LogLevel) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{11}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{15}
 }
 
 type GetConfigRequest struct {
@@ -1014,12 +1231,12 @@ func (*GetDataplaneStatusRequest) Descriptor() ([]byte, []int) {
 	return file_proto_dataplane_proto_rawDescGZIP(), []int{7}
 }
 
-// TODO: Consider adding an optional string field to InterfaceStatus for additional error messages or descriptions.
 type InterfaceStatus struct {
 	state         protoimpl.MessageState   `protogen:"open.v1"`
 	Ifname        string                   `protobuf:"bytes,1,opt,name=ifname,proto3" json:"ifname,omitempty"`
 	OperStatus    InterfaceOperStatusType  `protobuf:"varint,2,opt,name=oper_status,json=operStatus,proto3,enum=config.InterfaceOperStatusType" json:"oper_status,omitempty"`
 	AdminStatus   InterfaceAdminStatusType `protobuf:"varint,3,opt,name=admin_status,json=adminStatus,proto3,enum=config.InterfaceAdminStatusType" json:"admin_status,omitempty"`
+	ErrorReason   string                   `protobuf:"bytes,4,opt,name=error_reason,json=errorReason,proto3" json:"error_reason,omitempty"` // why the interface is down or in error, empty otherwise
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -1075,16 +1292,29 @@ func (x *InterfaceStatus) GetAdminStatus() InterfaceAdminStatusType {
 	return InterfaceAdminStatusType_INTERFACE_ADMIN_STATUS_UNKNOWN
 }
 
+func (x *InterfaceStatus) GetErrorReason() string {
+	if x != nil {
+		return x.ErrorReason
+	}
+	return ""
+}
+
 type FrrStatus struct {
-	state            protoimpl.MessageState `protogen:"open.v1"`
-	ZebraStatus      ZebraStatusType        `protobuf:"varint,1,opt,name=zebra_status,json=zebraStatus,proto3,enum=config.ZebraStatusType" json:"zebra_status,omitempty"`
-	FrrAgentStatus   FrrAgentStatusType     `protobuf:"varint,2,opt,name=frr_agent_status,json=frrAgentStatus,proto3,enum=config.FrrAgentStatusType" json:"frr_agent_status,omitempty"`
-	AppliedConfigGen int64                  `protobuf:"varint,3,opt,name=applied_config_gen,json=appliedConfigGen,proto3" json:"applied_config_gen,omitempty"`
-	Restarts         uint32                 `protobuf:"varint,4,opt,name=restarts,proto3" json:"restarts,omitempty"`
-	AppliedConfigs   uint32                 `protobuf:"varint,5,opt,name=applied_configs,json=appliedConfigs,proto3" json:"applied_configs,omitempty"`
-	FailedConfigs    uint32                 `protobuf:"varint,6,opt,name=failed_configs,json=failedConfigs,proto3" json:"failed_configs,omitempty"`
-	unknownFields    protoimpl.UnknownFields
-	sizeCache        protoimpl.SizeCache
+	state               protoimpl.MessageState `protogen:"open.v1"`
+	ZebraStatus         ZebraStatusType        `protobuf:"varint,1,opt,name=zebra_status,json=zebraStatus,proto3,enum=config.ZebraStatusType" json:"zebra_status,omitempty"`
+	FrrAgentStatus      FrrAgentStatusType     `protobuf:"varint,2,opt,name=frr_agent_status,json=frrAgentStatus,proto3,enum=config.FrrAgentStatusType" json:"frr_agent_status,omitempty"`
+	AppliedConfigGen    int64                  `protobuf:"varint,3,opt,name=applied_config_gen,json=appliedConfigGen,proto3" json:"applied_config_gen,omitempty"`
+	Restarts            uint32                 `protobuf:"varint,4,opt,name=restarts,proto3" json:"restarts,omitempty"`
+	AppliedConfigs      uint32                 `protobuf:"varint,5,opt,name=applied_configs,json=appliedConfigs,proto3" json:"applied_configs,omitempty"`
+	FailedConfigs       uint32                 `protobuf:"varint,6,opt,name=failed_configs,json=failedConfigs,proto3" json:"failed_configs,omitempty"`
+	State               FrrLifecycleState      `protobuf:"varint,7,opt,name=state,proto3,enum=config.FrrLifecycleState" json:"state,omitempty"`
+	StateSince          *timestamppb.Timestamp `protobuf:"bytes,8,opt,name=state_since,json=stateSince,proto3" json:"state_since,omitempty"`                                 // last transition of state
+	ZebraStatusSince    *timestamppb.Timestamp `protobuf:"bytes,9,opt,name=zebra_status_since,json=zebraStatusSince,proto3" json:"zebra_status_since,omitempty"`             // last transition of zebra_status
+	FrrAgentStatusSince *timestamppb.Timestamp `protobuf:"bytes,10,opt,name=frr_agent_status_since,json=frrAgentStatusSince,proto3" json:"frr_agent_status_since,omitempty"` // last transition of frr_agent_status
+	LastApplyError      string                 `protobuf:"bytes,11,opt,name=last_apply_error,json=lastApplyError,proto3" json:"last_apply_error,omitempty"`                  // why the last config apply failed, empty if it succeeded
+	PendingConfigGen    int64                  `protobuf:"varint,12,opt,name=pending_config_gen,json=pendingConfigGen,proto3" json:"pending_config_gen,omitempty"`           // generation being applied, 0 if none
+	unknownFields       protoimpl.UnknownFields
+	sizeCache           protoimpl.SizeCache
 }
 
 func (x *FrrStatus) Reset() {
@@ -1159,6 +1389,48 @@ func (x *FrrStatus) GetFailedConfigs() uint32 {
 	return 0
 }
 
+func (x *FrrStatus) GetState() FrrLifecycleState {
+	if x != nil {
+		return x.State
+	}
+	return FrrLifecycleState_FRR_STATE_UNKNOWN
+}
+
+func (x *FrrStatus) GetStateSince() *timestamppb.Timestamp {
+	if x != nil {
+		return x.StateSince
+	}
+	return nil
+}
+
+func (x *FrrStatus) GetZebraStatusSince() *timestamppb.Timestamp {
+	if x != nil {
+		return x.ZebraStatusSince
+	}
+	return nil
+}
+
+func (x *FrrStatus) GetFrrAgentStatusSince() *timestamppb.Timestamp {
+	if x != nil {
+		return x.FrrAgentStatusSince
+	}
+	return nil
+}
+
+func (x *FrrStatus) GetLastApplyError() string {
+	if x != nil {
+		return x.LastApplyError
+	}
+	return ""
+}
+
+func (x *FrrStatus) GetPendingConfigGen() int64 {
+	if x != nil {
+		return x.PendingConfigGen
+	}
+	return 0
+}
+
 type DataplaneStatusInfo struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	Status        DataplaneStatusType    `protobuf:"varint,1,opt,name=status,proto3,enum=config.DataplaneStatusType" json:"status,omitempty"`
@@ -1203,32 +1475,30 @@ func (x *DataplaneStatusInfo) GetStatus() DataplaneStatusType {
 	return DataplaneStatusType_DATAPLANE_STATUS_UNKNOWN
 }
 
-type InterfaceCounters struct {
+type InterfaceQueueCounters struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
-	TxBits        uint64                 `protobuf:"varint,1,opt,name=tx_bits,json=txBits,proto3" json:"tx_bits,omitempty"`
-	TxBps         float64                `protobuf:"fixed64,2,opt,name=tx_bps,json=txBps,proto3" json:"tx_bps,omitempty"`
-	TxErrors      uint64                 `protobuf:"varint,3,opt,name=tx_errors,json=txErrors,proto3" json:"tx_errors,omitempty"`
-	RxBits        uint64                 `protobuf:"varint,4,opt,name=rx_bits,json=rxBits,proto3" json:"rx_bits,omitempty"`
-	RxBps         float64                `protobuf:"fixed64,5,opt,name=rx_bps,json=rxBps,proto3" json:"rx_bps,omitempty"`
-	RxErrors      uint64                 `protobuf:"varint,6,opt,name=rx_errors,json=rxErrors,proto3" json:"rx_errors,omitempty"`
+	Queue         uint32                 `protobuf:"varint,1,opt,name=queue,proto3" json:"queue,omitempty"`
+	Packets       uint64                 `protobuf:"varint,2,opt,name=packets,proto3" json:"packets,omitempty"`
+	Bytes         uint64                 `protobuf:"varint,3,opt,name=bytes,proto3" json:"bytes,omitempty"`
+	Drops         uint64                 `protobuf:"varint,4,opt,name=drops,proto3" json:"drops,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *InterfaceCounters) Reset() {
-	*x = InterfaceCounters{}
+func (x *InterfaceQueueCounters) Reset() {
+	*x = InterfaceQueueCounters{}
 	mi := &file_proto_dataplane_proto_msgTypes[11]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *InterfaceCounters) String() string {
+func (x *InterfaceQueueCounters) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*InterfaceCounters) ProtoMessage() {}
+func (*InterfaceQueueCounters) ProtoMessage() {}
 
-func (x *InterfaceCounters) ProtoReflect() protoreflect.Message {
+func (x *InterfaceQueueCounters) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_dataplane_proto_msgTypes[11]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1240,78 +1510,75 @@ func (x *InterfaceCounters) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use InterfaceCounters.ProtoReflect.Descriptor instead.
-func (*InterfaceCounters) Descriptor() ([]byte, []int) {
+// Deprecated: Use InterfaceQueueCounters.ProtoReflect.Descriptor instead.
+func (*InterfaceQueueCounters) Descriptor() ([]byte, []int) {
 	return file_proto_dataplane_proto_rawDescGZIP(), []int{11}
 }
 
-func (x *InterfaceCounters) GetTxBits() uint64 {
-	if x != nil {
-		return x.TxBits
-	}
-	return 0
-}
-
-func (x *InterfaceCounters) GetTxBps() float64 {
-	if x != nil {
-		return x.TxBps
-	}
-	return 0
-}
-
-func (x *InterfaceCounters) GetTxErrors() uint64 {
+func (x *InterfaceQueueCounters) GetQueue() uint32 {
 	if x != nil {
-		return x.TxErrors
+		return x.Queue
 	}
 	return 0
 }
 
-func (x *InterfaceCounters) GetRxBits() uint64 {
+func (x *InterfaceQueueCounters) GetPackets() uint64 {
 	if x != nil {
-		return x.RxBits
+		return x.Packets
 	}
 	return 0
 }
 
-func (x *InterfaceCounters) GetRxBps() float64 {
+func (x *InterfaceQueueCounters) GetBytes() uint64 {
 	if x != nil {
-		return x.RxBps
+		return x.Bytes
 	}
 	return 0
 }
 
-func (x *InterfaceCounters) GetRxErrors() uint64 {
+func (x *InterfaceQueueCounters) GetDrops() uint64 {
 	if x != nil {
-		return x.RxErrors
+		return x.Drops
 	}
 	return 0
 }
 
-type InterfaceRuntimeStatus struct {
-	state         protoimpl.MessageState   `protogen:"open.v1"`
-	AdminStatus   InterfaceAdminStatusType `protobuf:"varint,1,opt,name=admin_status,json=adminStatus,proto3,enum=config.InterfaceAdminStatusType" json:"admin_status,omitempty"`
-	OperStatus    InterfaceOperStatusType  `protobuf:"varint,2,opt,name=oper_status,json=operStatus,proto3,enum=config.InterfaceOperStatusType" json:"oper_status,omitempty"`
-	Mac           string                   `protobuf:"bytes,3,opt,name=mac,proto3" json:"mac,omitempty"`
-	Mtu           uint32                   `protobuf:"varint,4,opt,name=mtu,proto3" json:"mtu,omitempty"`
-	Counters      *InterfaceCounters       `protobuf:"bytes,5,opt,name=counters,proto3" json:"counters,omitempty"`
+type InterfaceCounters struct {
+	state         protoimpl.MessageState    `protogen:"open.v1"`
+	TxBits        uint64                    `protobuf:"varint,1,opt,name=tx_bits,json=txBits,proto3" json:"tx_bits,omitempty"`
+	TxBps         float64                   `protobuf:"fixed64,2,opt,name=tx_bps,json=txBps,proto3" json:"tx_bps,omitempty"`
+	TxErrors      uint64                    `protobuf:"varint,3,opt,name=tx_errors,json=txErrors,proto3" json:"tx_errors,omitempty"`
+	RxBits        uint64                    `protobuf:"varint,4,opt,name=rx_bits,json=rxBits,proto3" json:"rx_bits,omitempty"`
+	RxBps         float64                   `protobuf:"fixed64,5,opt,name=rx_bps,json=rxBps,proto3" json:"rx_bps,omitempty"`
+	RxErrors      uint64                    `protobuf:"varint,6,opt,name=rx_errors,json=rxErrors,proto3" json:"rx_errors,omitempty"`
+	TxPackets     uint64                    `protobuf:"varint,7,opt,name=tx_packets,json=txPackets,proto3" json:"tx_packets,omitempty"`
+	TxDrops       uint64                    `protobuf:"varint,8,opt,name=tx_drops,json=txDrops,proto3" json:"tx_drops,omitempty"`
+	TxMulticast   uint64                    `protobuf:"varint,9,opt,name=tx_multicast,json=txMulticast,proto3" json:"tx_multicast,omitempty"`  // packets, included in tx_packets
+	TxBroadcast   uint64                    `protobuf:"varint,10,opt,name=tx_broadcast,json=txBroadcast,proto3" json:"tx_broadcast,omitempty"` // packets, included in tx_packets
+	RxPackets     uint64                    `protobuf:"varint,11,opt,name=rx_packets,json=rxPackets,proto3" json:"rx_packets,omitempty"`
+	RxDrops       uint64                    `protobuf:"varint,12,opt,name=rx_drops,json=rxDrops,proto3" json:"rx_drops,omitempty"`
+	RxMulticast   uint64                    `protobuf:"varint,13,opt,name=rx_multicast,json=rxMulticast,proto3" json:"rx_multicast,omitempty"` // packets, included in rx_packets
+	RxBroadcast   uint64                    `protobuf:"varint,14,opt,name=rx_broadcast,json=rxBroadcast,proto3" json:"rx_broadcast,omitempty"` // packets, included in rx_packets
+	RxQueues      []*InterfaceQueueCounters `protobuf:"bytes,15,rep,name=rx_queues,json=rxQueues,proto3" json:"rx_queues,omitempty"`
+	TxQueues      []*InterfaceQueueCounters `protobuf:"bytes,16,rep,name=tx_queues,json=txQueues,proto3" json:"tx_queues,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *InterfaceRuntimeStatus) Reset() {
-	*x = InterfaceRuntimeStatus{}
+func (x *InterfaceCounters) Reset() {
+	*x = InterfaceCounters{}
 	mi := &file_proto_dataplane_proto_msgTypes[12]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *InterfaceRuntimeStatus) String() string {
+func (x *InterfaceCounters) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*InterfaceRuntimeStatus) ProtoMessage() {}
+func (*InterfaceCounters) ProtoMessage() {}
 
-func (x *InterfaceRuntimeStatus) ProtoReflect() protoreflect.Message {
+func (x *InterfaceCounters) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_dataplane_proto_msgTypes[12]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1323,46 +1590,207 @@ func (x *InterfaceRuntimeStatus) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use InterfaceRuntimeStatus.ProtoReflect.Descriptor instead.
-func (*InterfaceRuntimeStatus) Descriptor() ([]byte, []int) {
+// Deprecated: Use InterfaceCounters.ProtoReflect.Descriptor instead.
+func (*InterfaceCounters) Descriptor() ([]byte, []int) {
 	return file_proto_dataplane_proto_rawDescGZIP(), []int{12}
 }
 
-func (x *InterfaceRuntimeStatus) GetAdminStatus() InterfaceAdminStatusType {
+func (x *InterfaceCounters) GetTxBits() uint64 {
 	if x != nil {
-		return x.AdminStatus
+		return x.TxBits
 	}
-	return InterfaceAdminStatusType_INTERFACE_ADMIN_STATUS_UNKNOWN
+	return 0
 }
 
-func (x *InterfaceRuntimeStatus) GetOperStatus() InterfaceOperStatusType {
+func (x *InterfaceCounters) GetTxBps() float64 {
 	if x != nil {
-		return x.OperStatus
+		return x.TxBps
 	}
-	return InterfaceOperStatusType_INTERFACE_STATUS_UNKNOWN
+	return 0
 }
 
-func (x *InterfaceRuntimeStatus) GetMac() string {
+func (x *InterfaceCounters) GetTxErrors() uint64 {
 	if x != nil {
-		return x.Mac
+		return x.TxErrors
 	}
-	return ""
+	return 0
 }
 
-func (x *InterfaceRuntimeStatus) GetMtu() uint32 {
+func (x *InterfaceCounters) GetRxBits() uint64 {
 	if x != nil {
-		return x.Mtu
+		return x.RxBits
 	}
 	return 0
 }
 
-func (x *InterfaceRuntimeStatus) GetCounters() *InterfaceCounters {
+func (x *InterfaceCounters) GetRxBps() float64 {
 	if x != nil {
-		return x.Counters
+		return x.RxBps
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetRxErrors() uint64 {
+	if x != nil {
+		return x.RxErrors
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetTxPackets() uint64 {
+	if x != nil {
+		return x.TxPackets
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetTxDrops() uint64 {
+	if x != nil {
+		return x.TxDrops
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetTxMulticast() uint64 {
+	if x != nil {
+		return x.TxMulticast
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetTxBroadcast() uint64 {
+	if x != nil {
+		return x.TxBroadcast
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetRxPackets() uint64 {
+	if x != nil {
+		return x.RxPackets
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetRxDrops() uint64 {
+	if x != nil {
+		return x.RxDrops
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetRxMulticast() uint64 {
+	if x != nil {
+		return x.RxMulticast
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetRxBroadcast() uint64 {
+	if x != nil {
+		return x.RxBroadcast
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetRxQueues() []*InterfaceQueueCounters {
+	if x != nil {
+		return x.RxQueues
+	}
+	return nil
+}
+
+func (x *InterfaceCounters) GetTxQueues() []*InterfaceQueueCounters {
+	if x != nil {
+		return x.TxQueues
+	}
+	return nil
+}
+
+type InterfaceRuntimeStatus struct {
+	state         protoimpl.MessageState   `protogen:"open.v1"`
+	AdminStatus   InterfaceAdminStatusType `protobuf:"varint,1,opt,name=admin_status,json=adminStatus,proto3,enum=config.InterfaceAdminStatusType" json:"admin_status,omitempty"`
+	OperStatus    InterfaceOperStatusType  `protobuf:"varint,2,opt,name=oper_status,json=operStatus,proto3,enum=config.InterfaceOperStatusType" json:"oper_status,omitempty"`
+	Mac           string                   `protobuf:"bytes,3,opt,name=mac,proto3" json:"mac,omitempty"`
+	Mtu           uint32                   `protobuf:"varint,4,opt,name=mtu,proto3" json:"mtu,omitempty"`
+	Counters      *InterfaceCounters       `protobuf:"bytes,5,opt,name=counters,proto3" json:"counters,omitempty"`
+	ErrorReason   string                   `protobuf:"bytes,6,opt,name=error_reason,json=errorReason,proto3" json:"error_reason,omitempty"` // why the interface is down or in error, empty otherwise
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *InterfaceRuntimeStatus) Reset() {
+	*x = InterfaceRuntimeStatus{}
+	mi := &file_proto_dataplane_proto_msgTypes[13]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *InterfaceRuntimeStatus) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*InterfaceRuntimeStatus) ProtoMessage() {}
+
+func (x *InterfaceRuntimeStatus) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[13]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use InterfaceRuntimeStatus.ProtoReflect.Descriptor instead.
+func (*InterfaceRuntimeStatus) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{13}
+}
+
+func (x *InterfaceRuntimeStatus) GetAdminStatus() InterfaceAdminStatusType {
+	if x != nil {
+		return x.AdminStatus
+	}
+	return InterfaceAdminStatusType_INTERFACE_ADMIN_STATUS_UNKNOWN
+}
+
+func (x *InterfaceRuntimeStatus) GetOperStatus() InterfaceOperStatusType {
+	if x != nil {
+		return x.OperStatus
+	}
+	return InterfaceOperStatusType_INTERFACE_STATUS_UNKNOWN
+}
+
+func (x *InterfaceRuntimeStatus) GetMac() string {
+	if x != nil {
+		return x.Mac
+	}
+	return ""
+}
+
+func (x *InterfaceRuntimeStatus) GetMtu() uint32 {
+	if x != nil {
+		return x.Mtu
+	}
+	return 0
+}
+
+func (x *InterfaceRuntimeStatus) GetCounters() *InterfaceCounters {
+	if x != nil {
+		return x.Counters
 	}
 	return nil
 }
 
+func (x *InterfaceRuntimeStatus) GetErrorReason() string {
+	if x != nil {
+		return x.ErrorReason
+	}
+	return ""
+}
+
 type BgpMessageCounters struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	Capability    uint64                 `protobuf:"varint,1,opt,name=capability,proto3" json:"capability,omitempty"`
@@ -1377,7 +1805,7 @@ type BgpMessageCounters struct {
 
 func (x *BgpMessageCounters) Reset() {
 	*x = BgpMessageCounters{}
-	mi := &file_proto_dataplane_proto_msgTypes[13]
+	mi := &file_proto_dataplane_proto_msgTypes[14]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1389,7 +1817,7 @@ func (x *BgpMessageCounters) String() string {
 func (*BgpMessageCounters) ProtoMessage() {}
 
 func (x *BgpMessageCounters) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[13]
+	mi := &file_proto_dataplane_proto_msgTypes[14]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1402,7 +1830,7 @@ func (x *BgpMessageCounters) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpMessageCounters.ProtoReflect.Descriptor instead.
 func (*BgpMessageCounters) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{13}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{14}
 }
 
 func (x *BgpMessageCounters) GetCapability() uint64 {
@@ -1457,7 +1885,7 @@ type BgpMessages struct {
 
 func (x *BgpMessages) Reset() {
 	*x = BgpMessages{}
-	mi := &file_proto_dataplane_proto_msgTypes[14]
+	mi := &file_proto_dataplane_proto_msgTypes[15]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1469,7 +1897,7 @@ func (x *BgpMessages) String() string {
 func (*BgpMessages) ProtoMessage() {}
 
 func (x *BgpMessages) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[14]
+	mi := &file_proto_dataplane_proto_msgTypes[15]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1482,7 +1910,7 @@ func (x *BgpMessages) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpMessages.ProtoReflect.Descriptor instead.
 func (*BgpMessages) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{14}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{15}
 }
 
 func (x *BgpMessages) GetReceived() *BgpMessageCounters {
@@ -1510,7 +1938,7 @@ type BgpNeighborPrefixes struct {
 
 func (x *BgpNeighborPrefixes) Reset() {
 	*x = BgpNeighborPrefixes{}
-	mi := &file_proto_dataplane_proto_msgTypes[15]
+	mi := &file_proto_dataplane_proto_msgTypes[16]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1522,7 +1950,7 @@ func (x *BgpNeighborPrefixes) String() string {
 func (*BgpNeighborPrefixes) ProtoMessage() {}
 
 func (x *BgpNeighborPrefixes) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[15]
+	mi := &file_proto_dataplane_proto_msgTypes[16]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1535,7 +1963,7 @@ func (x *BgpNeighborPrefixes) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpNeighborPrefixes.ProtoReflect.Descriptor instead.
 func (*BgpNeighborPrefixes) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{15}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{16}
 }
 
 func (x *BgpNeighborPrefixes) GetReceived() uint32 {
@@ -1581,7 +2009,7 @@ type BgpNeighborStatus struct {
 
 func (x *BgpNeighborStatus) Reset() {
 	*x = BgpNeighborStatus{}
-	mi := &file_proto_dataplane_proto_msgTypes[16]
+	mi := &file_proto_dataplane_proto_msgTypes[17]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1593,7 +2021,7 @@ func (x *BgpNeighborStatus) String() string {
 func (*BgpNeighborStatus) ProtoMessage() {}
 
 func (x *BgpNeighborStatus) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[16]
+	mi := &file_proto_dataplane_proto_msgTypes[17]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1606,7 +2034,7 @@ func (x *BgpNeighborStatus) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpNeighborStatus.ProtoReflect.Descriptor instead.
 func (*BgpNeighborStatus) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{16}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{17}
 }
 
 func (x *BgpNeighborStatus) GetEnabled() bool {
@@ -1717,7 +2145,7 @@ type BgpVrfStatus struct {
 
 func (x *BgpVrfStatus) Reset() {
 	*x = BgpVrfStatus{}
-	mi := &file_proto_dataplane_proto_msgTypes[17]
+	mi := &file_proto_dataplane_proto_msgTypes[18]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1729,7 +2157,7 @@ func (x *BgpVrfStatus) String() string {
 func (*BgpVrfStatus) ProtoMessage() {}
 
 func (x *BgpVrfStatus) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[17]
+	mi := &file_proto_dataplane_proto_msgTypes[18]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1742,7 +2170,7 @@ func (x *BgpVrfStatus) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpVrfStatus.ProtoReflect.Descriptor instead.
 func (*BgpVrfStatus) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{17}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{18}
 }
 
 func (x *BgpVrfStatus) GetNeighbors() map[string]*BgpNeighborStatus {
@@ -1762,7 +2190,7 @@ type BgpStatus struct {
 
 func (x *BgpStatus) Reset() {
 	*x = BgpStatus{}
-	mi := &file_proto_dataplane_proto_msgTypes[18]
+	mi := &file_proto_dataplane_proto_msgTypes[19]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1774,7 +2202,7 @@ func (x *BgpStatus) String() string {
 func (*BgpStatus) ProtoMessage() {}
 
 func (x *BgpStatus) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[18]
+	mi := &file_proto_dataplane_proto_msgTypes[19]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1787,7 +2215,7 @@ func (x *BgpStatus) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpStatus.ProtoReflect.Descriptor instead.
 func (*BgpStatus) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{18}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{19}
 }
 
 func (x *BgpStatus) GetVrfs() map[string]*BgpVrfStatus {
@@ -1808,7 +2236,7 @@ type VpcInterfaceStatus struct {
 
 func (x *VpcInterfaceStatus) Reset() {
 	*x = VpcInterfaceStatus{}
-	mi := &file_proto_dataplane_proto_msgTypes[19]
+	mi := &file_proto_dataplane_proto_msgTypes[20]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1820,7 +2248,7 @@ func (x *VpcInterfaceStatus) String() string {
 func (*VpcInterfaceStatus) ProtoMessage() {}
 
 func (x *VpcInterfaceStatus) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[19]
+	mi := &file_proto_dataplane_proto_msgTypes[20]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1833,7 +2261,7 @@ func (x *VpcInterfaceStatus) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VpcInterfaceStatus.ProtoReflect.Descriptor instead.
 func (*VpcInterfaceStatus) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{19}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{20}
 }
 
 func (x *VpcInterfaceStatus) GetIfname() string {
@@ -1859,10 +2287,10 @@ func (x *VpcInterfaceStatus) GetOperStatus() InterfaceOperStatusType {
 
 type VpcStatus struct {
 	state      protoimpl.MessageState `protogen:"open.v1"`
-	Id         string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`     // matches VPC.id
-	Name       string                 `protobuf:"bytes,2,opt,name=name,proto3" json:"name,omitempty"` // matches VPC.name
-	Vni        uint32                 `protobuf:"varint,3,opt,name=vni,proto3" json:"vni,omitempty"`
-	RouteCount uint32                 `protobuf:"varint,4,opt,name=route_count,json=routeCount,proto3" json:"route_count,omitempty"` // routes inside AF_UNICAST, TODO: add more later
+	Id         string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`                                    // matches VPC.id
+	Name       string                 `protobuf:"bytes,2,opt,name=name,proto3" json:"name,omitempty"`                                // matches VPC.name
+	Vni        uint32                 `protobuf:"varint,3,opt,name=vni,proto3" json:"vni,omitempty"`                                 // key of GetDataplaneStatusResponse.vnis
+	RouteCount uint32                 `protobuf:"varint,4,opt,name=route_count,json=routeCount,proto3" json:"route_count,omitempty"` // routes inside AF_UNICAST, GetRoutes lists them
 	// key: interface name
 	Interfaces    map[string]*VpcInterfaceStatus `protobuf:"bytes,7,rep,name=interfaces,proto3" json:"interfaces,omitempty" protobuf_key:"bytes,1,opt,name=key" protobuf_val:"bytes,2,opt,name=value"`
 	unknownFields protoimpl.UnknownFields
@@ -1871,7 +2299,7 @@ type VpcStatus struct {
 
 func (x *VpcStatus) Reset() {
 	*x = VpcStatus{}
-	mi := &file_proto_dataplane_proto_msgTypes[20]
+	mi := &file_proto_dataplane_proto_msgTypes[21]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1883,7 +2311,7 @@ func (x *VpcStatus) String() string {
 func (*VpcStatus) ProtoMessage() {}
 
 func (x *VpcStatus) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[20]
+	mi := &file_proto_dataplane_proto_msgTypes[21]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1896,7 +2324,7 @@ func (x *VpcStatus) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VpcStatus.ProtoReflect.Descriptor instead.
 func (*VpcStatus) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{20}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{21}
 }
 
 func (x *VpcStatus) GetId() string {
@@ -1910,59 +2338,1149 @@ func (x *VpcStatus) GetName() string {
 	if x != nil {
 		return x.Name
 	}
-	return ""
+	return ""
+}
+
+func (x *VpcStatus) GetVni() uint32 {
+	if x != nil {
+		return x.Vni
+	}
+	return 0
+}
+
+func (x *VpcStatus) GetRouteCount() uint32 {
+	if x != nil {
+		return x.RouteCount
+	}
+	return 0
+}
+
+func (x *VpcStatus) GetInterfaces() map[string]*VpcInterfaceStatus {
+	if x != nil {
+		return x.Interfaces
+	}
+	return nil
+}
+
+type RemoteVtepStatus struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Address       string                 `protobuf:"bytes,1,opt,name=address,proto3" json:"address,omitempty"` // underlay address of the remote VTEP
+	Reachability  VtepReachability       `protobuf:"varint,2,opt,name=reachability,proto3,enum=config.VtepReachability" json:"reachability,omitempty"`
+	Vnis          []uint32               `protobuf:"varint,3,rep,packed,name=vnis,proto3" json:"vnis,omitempty"` // VNIs learned from the remote VTEP through EVPN
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RemoteVtepStatus) Reset() {
+	*x = RemoteVtepStatus{}
+	mi := &file_proto_dataplane_proto_msgTypes[22]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RemoteVtepStatus) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RemoteVtepStatus) ProtoMessage() {}
+
+func (x *RemoteVtepStatus) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[22]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RemoteVtepStatus.ProtoReflect.Descriptor instead.
+func (*RemoteVtepStatus) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{22}
+}
+
+func (x *RemoteVtepStatus) GetAddress() string {
+	if x != nil {
+		return x.Address
+	}
+	return ""
+}
+
+func (x *RemoteVtepStatus) GetReachability() VtepReachability {
+	if x != nil {
+		return x.Reachability
+	}
+	return VtepReachability_VTEP_REACHABILITY_UNKNOWN
+}
+
+func (x *RemoteVtepStatus) GetVnis() []uint32 {
+	if x != nil {
+		return x.Vnis
+	}
+	return nil
+}
+
+type VniStatus struct {
+	state           protoimpl.MessageState `protogen:"open.v1"`
+	Vni             uint32                 `protobuf:"varint,1,opt,name=vni,proto3" json:"vni,omitempty"`                                                  // matches VpcStatus.vni
+	MacCount        uint32                 `protobuf:"varint,2,opt,name=mac_count,json=macCount,proto3" json:"mac_count,omitempty"`                        // MACs learned through EVPN Type-2 routes
+	RouteCount      uint32                 `protobuf:"varint,3,opt,name=route_count,json=routeCount,proto3" json:"route_count,omitempty"`                  // EVPN routes of all types
+	Type5RouteCount uint32                 `protobuf:"varint,4,opt,name=type5_route_count,json=type5RouteCount,proto3" json:"type5_route_count,omitempty"` // EVPN Type-5 (IP prefix) routes, included in route_count
+	unknownFields   protoimpl.UnknownFields
+	sizeCache       protoimpl.SizeCache
+}
+
+func (x *VniStatus) Reset() {
+	*x = VniStatus{}
+	mi := &file_proto_dataplane_proto_msgTypes[23]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *VniStatus) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*VniStatus) ProtoMessage() {}
+
+func (x *VniStatus) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[23]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use VniStatus.ProtoReflect.Descriptor instead.
+func (*VniStatus) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{23}
+}
+
+func (x *VniStatus) GetVni() uint32 {
+	if x != nil {
+		return x.Vni
+	}
+	return 0
+}
+
+func (x *VniStatus) GetMacCount() uint32 {
+	if x != nil {
+		return x.MacCount
+	}
+	return 0
+}
+
+func (x *VniStatus) GetRouteCount() uint32 {
+	if x != nil {
+		return x.RouteCount
+	}
+	return 0
+}
+
+func (x *VniStatus) GetType5RouteCount() uint32 {
+	if x != nil {
+		return x.Type5RouteCount
+	}
+	return 0
+}
+
+type VpcPeeringCounters struct {
+	state   protoimpl.MessageState `protogen:"open.v1"`
+	Name    string                 `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	SrcVpc  string                 `protobuf:"bytes,2,opt,name=src_vpc,json=srcVpc,proto3" json:"src_vpc,omitempty"`
+	DstVpc  string                 `protobuf:"bytes,3,opt,name=dst_vpc,json=dstVpc,proto3" json:"dst_vpc,omitempty"`
+	Packets uint64                 `protobuf:"varint,4,opt,name=packets,proto3" json:"packets,omitempty"`
+	Bytes   uint64                 `protobuf:"varint,5,opt,name=bytes,proto3" json:"bytes,omitempty"`
+	Drops   uint64                 `protobuf:"varint,6,opt,name=drops,proto3" json:"drops,omitempty"`
+	Pps     float64                `protobuf:"fixed64,7,opt,name=pps,proto3" json:"pps,omitempty"`
+	Bps     float64                `protobuf:"fixed64,8,opt,name=bps,proto3" json:"bps,omitempty"`
+	// key: drop reason, values sum up to drops
+	DropReasons   map[string]uint64 `protobuf:"bytes,9,rep,name=drop_reasons,json=dropReasons,proto3" json:"drop_reasons,omitempty" protobuf_key:"bytes,1,opt,name=key" protobuf_val:"varint,2,opt,name=value"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *VpcPeeringCounters) Reset() {
+	*x = VpcPeeringCounters{}
+	mi := &file_proto_dataplane_proto_msgTypes[24]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *VpcPeeringCounters) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*VpcPeeringCounters) ProtoMessage() {}
+
+func (x *VpcPeeringCounters) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[24]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use VpcPeeringCounters.ProtoReflect.Descriptor instead.
+func (*VpcPeeringCounters) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{24}
+}
+
+func (x *VpcPeeringCounters) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *VpcPeeringCounters) GetSrcVpc() string {
+	if x != nil {
+		return x.SrcVpc
+	}
+	return ""
+}
+
+func (x *VpcPeeringCounters) GetDstVpc() string {
+	if x != nil {
+		return x.DstVpc
+	}
+	return ""
+}
+
+func (x *VpcPeeringCounters) GetPackets() uint64 {
+	if x != nil {
+		return x.Packets
+	}
+	return 0
+}
+
+func (x *VpcPeeringCounters) GetBytes() uint64 {
+	if x != nil {
+		return x.Bytes
+	}
+	return 0
+}
+
+func (x *VpcPeeringCounters) GetDrops() uint64 {
+	if x != nil {
+		return x.Drops
+	}
+	return 0
+}
+
+func (x *VpcPeeringCounters) GetPps() float64 {
+	if x != nil {
+		return x.Pps
+	}
+	return 0
+}
+
+func (x *VpcPeeringCounters) GetBps() float64 {
+	if x != nil {
+		return x.Bps
+	}
+	return 0
+}
+
+func (x *VpcPeeringCounters) GetDropReasons() map[string]uint64 {
+	if x != nil {
+		return x.DropReasons
+	}
+	return nil
+}
+
+type VpcCounters struct {
+	state   protoimpl.MessageState `protogen:"open.v1"`
+	Name    string                 `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	Packets uint64                 `protobuf:"varint,2,opt,name=packets,proto3" json:"packets,omitempty"`
+	Drops   uint64                 `protobuf:"varint,3,opt,name=drops,proto3" json:"drops,omitempty"`
+	Bytes   uint64                 `protobuf:"varint,4,opt,name=bytes,proto3" json:"bytes,omitempty"`
+	// key: drop reason, values sum up to drops
+	DropReasons   map[string]uint64 `protobuf:"bytes,5,rep,name=drop_reasons,json=dropReasons,proto3" json:"drop_reasons,omitempty" protobuf_key:"bytes,1,opt,name=key" protobuf_val:"varint,2,opt,name=value"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *VpcCounters) Reset() {
+	*x = VpcCounters{}
+	mi := &file_proto_dataplane_proto_msgTypes[25]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *VpcCounters) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*VpcCounters) ProtoMessage() {}
+
+func (x *VpcCounters) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[25]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use VpcCounters.ProtoReflect.Descriptor instead.
+func (*VpcCounters) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{25}
+}
+
+func (x *VpcCounters) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *VpcCounters) GetPackets() uint64 {
+	if x != nil {
+		return x.Packets
+	}
+	return 0
+}
+
+func (x *VpcCounters) GetDrops() uint64 {
+	if x != nil {
+		return x.Drops
+	}
+	return 0
+}
+
+func (x *VpcCounters) GetBytes() uint64 {
+	if x != nil {
+		return x.Bytes
+	}
+	return 0
+}
+
+func (x *VpcCounters) GetDropReasons() map[string]uint64 {
+	if x != nil {
+		return x.DropReasons
+	}
+	return nil
+}
+
+// Counters of a single Expose rule of a VPC peering
+type ExposeCounters struct {
+	state       protoimpl.MessageState `protogen:"open.v1"`
+	Peering     string                 `protobuf:"bytes,1,opt,name=peering,proto3" json:"peering,omitempty"`                             // matches VpcPeering.name
+	Vpc         string                 `protobuf:"bytes,2,opt,name=vpc,proto3" json:"vpc,omitempty"`                                     // matches PeeringEntryFor.vpc
+	ExposeIndex uint32                 `protobuf:"varint,3,opt,name=expose_index,json=exposeIndex,proto3" json:"expose_index,omitempty"` // index in PeeringEntryFor.expose
+	Packets     uint64                 `protobuf:"varint,4,opt,name=packets,proto3" json:"packets,omitempty"`
+	Bytes       uint64                 `protobuf:"varint,5,opt,name=bytes,proto3" json:"bytes,omitempty"`
+	Drops       uint64                 `protobuf:"varint,6,opt,name=drops,proto3" json:"drops,omitempty"`
+	// key: drop reason, values sum up to drops
+	DropReasons      map[string]uint64 `protobuf:"bytes,7,rep,name=drop_reasons,json=dropReasons,proto3" json:"drop_reasons,omitempty" protobuf_key:"bytes,1,opt,name=key" protobuf_val:"varint,2,opt,name=value"`
+	Translations     uint64            `protobuf:"varint,8,opt,name=translations,proto3" json:"translations,omitempty"`                                   // packets translated by the NAT of the expose
+	NatAllocFailures uint64            `protobuf:"varint,9,opt,name=nat_alloc_failures,json=natAllocFailures,proto3" json:"nat_alloc_failures,omitempty"` // translations that failed for lack of free addresses or ports
+	unknownFields    protoimpl.UnknownFields
+	sizeCache        protoimpl.SizeCache
+}
+
+func (x *ExposeCounters) Reset() {
+	*x = ExposeCounters{}
+	mi := &file_proto_dataplane_proto_msgTypes[26]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ExposeCounters) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ExposeCounters) ProtoMessage() {}
+
+func (x *ExposeCounters) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[26]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ExposeCounters.ProtoReflect.Descriptor instead.
+func (*ExposeCounters) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{26}
+}
+
+func (x *ExposeCounters) GetPeering() string {
+	if x != nil {
+		return x.Peering
+	}
+	return ""
+}
+
+func (x *ExposeCounters) GetVpc() string {
+	if x != nil {
+		return x.Vpc
+	}
+	return ""
+}
+
+func (x *ExposeCounters) GetExposeIndex() uint32 {
+	if x != nil {
+		return x.ExposeIndex
+	}
+	return 0
+}
+
+func (x *ExposeCounters) GetPackets() uint64 {
+	if x != nil {
+		return x.Packets
+	}
+	return 0
+}
+
+func (x *ExposeCounters) GetBytes() uint64 {
+	if x != nil {
+		return x.Bytes
+	}
+	return 0
+}
+
+func (x *ExposeCounters) GetDrops() uint64 {
+	if x != nil {
+		return x.Drops
+	}
+	return 0
+}
+
+func (x *ExposeCounters) GetDropReasons() map[string]uint64 {
+	if x != nil {
+		return x.DropReasons
+	}
+	return nil
+}
+
+func (x *ExposeCounters) GetTranslations() uint64 {
+	if x != nil {
+		return x.Translations
+	}
+	return 0
+}
+
+func (x *ExposeCounters) GetNatAllocFailures() uint64 {
+	if x != nil {
+		return x.NatAllocFailures
+	}
+	return 0
+}
+
+type FiveTuple struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Protocol      IpProtocol             `protobuf:"varint,1,opt,name=protocol,proto3,enum=config.IpProtocol" json:"protocol,omitempty"`
+	SrcIp         string                 `protobuf:"bytes,2,opt,name=src_ip,json=srcIp,proto3" json:"src_ip,omitempty"`
+	SrcPort       uint32                 `protobuf:"varint,3,opt,name=src_port,json=srcPort,proto3" json:"src_port,omitempty"` // ICMP identifier for ICMP
+	DstIp         string                 `protobuf:"bytes,4,opt,name=dst_ip,json=dstIp,proto3" json:"dst_ip,omitempty"`
+	DstPort       uint32                 `protobuf:"varint,5,opt,name=dst_port,json=dstPort,proto3" json:"dst_port,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *FiveTuple) Reset() {
+	*x = FiveTuple{}
+	mi := &file_proto_dataplane_proto_msgTypes[27]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *FiveTuple) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*FiveTuple) ProtoMessage() {}
+
+func (x *FiveTuple) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[27]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use FiveTuple.ProtoReflect.Descriptor instead.
+func (*FiveTuple) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{27}
+}
+
+func (x *FiveTuple) GetProtocol() IpProtocol {
+	if x != nil {
+		return x.Protocol
+	}
+	return IpProtocol_IP_PROTOCOL_UNSPECIFIED
+}
+
+func (x *FiveTuple) GetSrcIp() string {
+	if x != nil {
+		return x.SrcIp
+	}
+	return ""
+}
+
+func (x *FiveTuple) GetSrcPort() uint32 {
+	if x != nil {
+		return x.SrcPort
+	}
+	return 0
+}
+
+func (x *FiveTuple) GetDstIp() string {
+	if x != nil {
+		return x.DstIp
+	}
+	return ""
+}
+
+func (x *FiveTuple) GetDstPort() uint32 {
+	if x != nil {
+		return x.DstPort
+	}
+	return 0
+}
+
+type NatSession struct {
+	state            protoimpl.MessageState `protogen:"open.v1"`
+	Peering          string                 `protobuf:"bytes,1,opt,name=peering,proto3" json:"peering,omitempty"` // matches VpcPeering.name
+	SrcVpc           string                 `protobuf:"bytes,2,opt,name=src_vpc,json=srcVpc,proto3" json:"src_vpc,omitempty"`
+	DstVpc           string                 `protobuf:"bytes,3,opt,name=dst_vpc,json=dstVpc,proto3" json:"dst_vpc,omitempty"`
+	Original         *FiveTuple             `protobuf:"bytes,4,opt,name=original,proto3" json:"original,omitempty"`
+	Translated       *FiveTuple             `protobuf:"bytes,5,opt,name=translated,proto3" json:"translated,omitempty"`
+	IdleTime         *durationpb.Duration   `protobuf:"bytes,6,opt,name=idle_time,json=idleTime,proto3" json:"idle_time,omitempty"`
+	RemainingTimeout *durationpb.Duration   `protobuf:"bytes,7,opt,name=remaining_timeout,json=remainingTimeout,proto3" json:"remaining_timeout,omitempty"` // until the session expires if it stays idle
+	unknownFields    protoimpl.UnknownFields
+	sizeCache        protoimpl.SizeCache
+}
+
+func (x *NatSession) Reset() {
+	*x = NatSession{}
+	mi := &file_proto_dataplane_proto_msgTypes[28]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *NatSession) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*NatSession) ProtoMessage() {}
+
+func (x *NatSession) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[28]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use NatSession.ProtoReflect.Descriptor instead.
+func (*NatSession) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{28}
+}
+
+func (x *NatSession) GetPeering() string {
+	if x != nil {
+		return x.Peering
+	}
+	return ""
+}
+
+func (x *NatSession) GetSrcVpc() string {
+	if x != nil {
+		return x.SrcVpc
+	}
+	return ""
+}
+
+func (x *NatSession) GetDstVpc() string {
+	if x != nil {
+		return x.DstVpc
+	}
+	return ""
+}
+
+func (x *NatSession) GetOriginal() *FiveTuple {
+	if x != nil {
+		return x.Original
+	}
+	return nil
+}
+
+func (x *NatSession) GetTranslated() *FiveTuple {
+	if x != nil {
+		return x.Translated
+	}
+	return nil
+}
+
+func (x *NatSession) GetIdleTime() *durationpb.Duration {
+	if x != nil {
+		return x.IdleTime
+	}
+	return nil
+}
+
+func (x *NatSession) GetRemainingTimeout() *durationpb.Duration {
+	if x != nil {
+		return x.RemainingTimeout
+	}
+	return nil
+}
+
+// Per-peering aggregate of the NAT session table
+type NatSessionCounters struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Active        uint64                 `protobuf:"varint,1,opt,name=active,proto3" json:"active,omitempty"`
+	Created       uint64                 `protobuf:"varint,2,opt,name=created,proto3" json:"created,omitempty"` // since dataplane start
+	Expired       uint64                 `protobuf:"varint,3,opt,name=expired,proto3" json:"expired,omitempty"` // since dataplane start
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *NatSessionCounters) Reset() {
+	*x = NatSessionCounters{}
+	mi := &file_proto_dataplane_proto_msgTypes[29]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *NatSessionCounters) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*NatSessionCounters) ProtoMessage() {}
+
+func (x *NatSessionCounters) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[29]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use NatSessionCounters.ProtoReflect.Descriptor instead.
+func (*NatSessionCounters) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{29}
+}
+
+func (x *NatSessionCounters) GetActive() uint64 {
+	if x != nil {
+		return x.Active
+	}
+	return 0
+}
+
+func (x *NatSessionCounters) GetCreated() uint64 {
+	if x != nil {
+		return x.Created
+	}
+	return 0
+}
+
+func (x *NatSessionCounters) GetExpired() uint64 {
+	if x != nil {
+		return x.Expired
+	}
+	return 0
+}
+
+// Filters are ANDed, unset filters match any session
+type ListNatSessionsRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Peering       string                 `protobuf:"bytes,1,opt,name=peering,proto3" json:"peering,omitempty"`
+	Vpc           string                 `protobuf:"bytes,2,opt,name=vpc,proto3" json:"vpc,omitempty"`         // either side of the peering
+	Address       string                 `protobuf:"bytes,3,opt,name=address,proto3" json:"address,omitempty"` // any IP of the original or translated tuple
+	Protocol      IpProtocol             `protobuf:"varint,4,opt,name=protocol,proto3,enum=config.IpProtocol" json:"protocol,omitempty"`
+	PageSize      uint32                 `protobuf:"varint,5,opt,name=page_size,json=pageSize,proto3" json:"page_size,omitempty"`   // 0 lets the server choose
+	PageToken     string                 `protobuf:"bytes,6,opt,name=page_token,json=pageToken,proto3" json:"page_token,omitempty"` // next_page_token of the previous page, empty for the first page
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListNatSessionsRequest) Reset() {
+	*x = ListNatSessionsRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[30]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListNatSessionsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListNatSessionsRequest) ProtoMessage() {}
+
+func (x *ListNatSessionsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[30]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListNatSessionsRequest.ProtoReflect.Descriptor instead.
+func (*ListNatSessionsRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{30}
+}
+
+func (x *ListNatSessionsRequest) GetPeering() string {
+	if x != nil {
+		return x.Peering
+	}
+	return ""
+}
+
+func (x *ListNatSessionsRequest) GetVpc() string {
+	if x != nil {
+		return x.Vpc
+	}
+	return ""
+}
+
+func (x *ListNatSessionsRequest) GetAddress() string {
+	if x != nil {
+		return x.Address
+	}
+	return ""
+}
+
+func (x *ListNatSessionsRequest) GetProtocol() IpProtocol {
+	if x != nil {
+		return x.Protocol
+	}
+	return IpProtocol_IP_PROTOCOL_UNSPECIFIED
+}
+
+func (x *ListNatSessionsRequest) GetPageSize() uint32 {
+	if x != nil {
+		return x.PageSize
+	}
+	return 0
+}
+
+func (x *ListNatSessionsRequest) GetPageToken() string {
+	if x != nil {
+		return x.PageToken
+	}
+	return ""
+}
+
+type ListNatSessionsResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Sessions      []*NatSession          `protobuf:"bytes,1,rep,name=sessions,proto3" json:"sessions,omitempty"`
+	NextPageToken string                 `protobuf:"bytes,2,opt,name=next_page_token,json=nextPageToken,proto3" json:"next_page_token,omitempty"` // empty on the last page
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ListNatSessionsResponse) Reset() {
+	*x = ListNatSessionsResponse{}
+	mi := &file_proto_dataplane_proto_msgTypes[31]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ListNatSessionsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListNatSessionsResponse) ProtoMessage() {}
+
+func (x *ListNatSessionsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[31]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListNatSessionsResponse.ProtoReflect.Descriptor instead.
+func (*ListNatSessionsResponse) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{31}
+}
+
+func (x *ListNatSessionsResponse) GetSessions() []*NatSession {
+	if x != nil {
+		return x.Sessions
+	}
+	return nil
+}
+
+func (x *ListNatSessionsResponse) GetNextPageToken() string {
+	if x != nil {
+		return x.NextPageToken
+	}
+	return ""
+}
+
+type RouteNextHop struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Address       string                 `protobuf:"bytes,1,opt,name=address,proto3" json:"address,omitempty"` // empty for directly connected routes
+	Ifname        string                 `protobuf:"bytes,2,opt,name=ifname,proto3" json:"ifname,omitempty"`
+	Vni           uint32                 `protobuf:"varint,3,opt,name=vni,proto3" json:"vni,omitempty"`  // VXLAN VNI of encapsulated next hops, 0 otherwise
+	Vtep          string                 `protobuf:"bytes,4,opt,name=vtep,proto3" json:"vtep,omitempty"` // remote VTEP of encapsulated next hops
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RouteNextHop) Reset() {
+	*x = RouteNextHop{}
+	mi := &file_proto_dataplane_proto_msgTypes[32]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RouteNextHop) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RouteNextHop) ProtoMessage() {}
+
+func (x *RouteNextHop) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[32]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RouteNextHop.ProtoReflect.Descriptor instead.
+func (*RouteNextHop) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{32}
+}
+
+func (x *RouteNextHop) GetAddress() string {
+	if x != nil {
+		return x.Address
+	}
+	return ""
+}
+
+func (x *RouteNextHop) GetIfname() string {
+	if x != nil {
+		return x.Ifname
+	}
+	return ""
+}
+
+func (x *RouteNextHop) GetVni() uint32 {
+	if x != nil {
+		return x.Vni
+	}
+	return 0
+}
+
+func (x *RouteNextHop) GetVtep() string {
+	if x != nil {
+		return x.Vtep
+	}
+	return ""
+}
+
+type Route struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Prefix        string                 `protobuf:"bytes,1,opt,name=prefix,proto3" json:"prefix,omitempty"` // e.g. 10.1.0.0/24 or fd00::/64
+	NextHops      []*RouteNextHop        `protobuf:"bytes,2,rep,name=next_hops,json=nextHops,proto3" json:"next_hops,omitempty"`
+	Protocol      RouteProtocol          `protobuf:"varint,3,opt,name=protocol,proto3,enum=config.RouteProtocol" json:"protocol,omitempty"`
+	Metric        uint32                 `protobuf:"varint,4,opt,name=metric,proto3" json:"metric,omitempty"`
+	Installed     bool                   `protobuf:"varint,5,opt,name=installed,proto3" json:"installed,omitempty"` // in the FIB, not only in the RIB
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *Route) Reset() {
+	*x = Route{}
+	mi := &file_proto_dataplane_proto_msgTypes[33]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *Route) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Route) ProtoMessage() {}
+
+func (x *Route) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[33]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Route.ProtoReflect.Descriptor instead.
+func (*Route) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{33}
+}
+
+func (x *Route) GetPrefix() string {
+	if x != nil {
+		return x.Prefix
+	}
+	return ""
+}
+
+func (x *Route) GetNextHops() []*RouteNextHop {
+	if x != nil {
+		return x.NextHops
+	}
+	return nil
+}
+
+func (x *Route) GetProtocol() RouteProtocol {
+	if x != nil {
+		return x.Protocol
+	}
+	return RouteProtocol_ROUTE_PROTOCOL_UNSPECIFIED
+}
+
+func (x *Route) GetMetric() uint32 {
+	if x != nil {
+		return x.Metric
+	}
+	return 0
+}
+
+func (x *Route) GetInstalled() bool {
+	if x != nil {
+		return x.Installed
+	}
+	return false
+}
+
+type GetRoutesRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Types that are valid to be assigned to Table:
+	//
+	//	*GetRoutesRequest_Vrf
+	//	*GetRoutesRequest_Vpc
+	Table         isGetRoutesRequest_Table `protobuf_oneof:"table"`
+	Protocol      RouteProtocol            `protobuf:"varint,3,opt,name=protocol,proto3,enum=config.RouteProtocol" json:"protocol,omitempty"`
+	PageSize      uint32                   `protobuf:"varint,4,opt,name=page_size,json=pageSize,proto3" json:"page_size,omitempty"`   // 0 lets the server choose
+	PageToken     string                   `protobuf:"bytes,5,opt,name=page_token,json=pageToken,proto3" json:"page_token,omitempty"` // next_page_token of the previous page, empty for the first page
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetRoutesRequest) Reset() {
+	*x = GetRoutesRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[34]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetRoutesRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetRoutesRequest) ProtoMessage() {}
+
+func (x *GetRoutesRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[34]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetRoutesRequest.ProtoReflect.Descriptor instead.
+func (*GetRoutesRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{34}
+}
+
+func (x *GetRoutesRequest) GetTable() isGetRoutesRequest_Table {
+	if x != nil {
+		return x.Table
+	}
+	return nil
+}
+
+func (x *GetRoutesRequest) GetVrf() string {
+	if x != nil {
+		if x, ok := x.Table.(*GetRoutesRequest_Vrf); ok {
+			return x.Vrf
+		}
+	}
+	return ""
+}
+
+func (x *GetRoutesRequest) GetVpc() string {
+	if x != nil {
+		if x, ok := x.Table.(*GetRoutesRequest_Vpc); ok {
+			return x.Vpc
+		}
+	}
+	return ""
+}
+
+func (x *GetRoutesRequest) GetProtocol() RouteProtocol {
+	if x != nil {
+		return x.Protocol
+	}
+	return RouteProtocol_ROUTE_PROTOCOL_UNSPECIFIED
+}
+
+func (x *GetRoutesRequest) GetPageSize() uint32 {
+	if x != nil {
+		return x.PageSize
+	}
+	return 0
+}
+
+func (x *GetRoutesRequest) GetPageToken() string {
+	if x != nil {
+		return x.PageToken
+	}
+	return ""
+}
+
+type isGetRoutesRequest_Table interface {
+	isGetRoutesRequest_Table()
+}
+
+type GetRoutesRequest_Vrf struct {
+	Vrf string `protobuf:"bytes,1,opt,name=vrf,proto3,oneof"` // underlay VRF, e.g. "default"
+}
+
+type GetRoutesRequest_Vpc struct {
+	Vpc string `protobuf:"bytes,2,opt,name=vpc,proto3,oneof"` // VPC name
+}
+
+func (*GetRoutesRequest_Vrf) isGetRoutesRequest_Table() {}
+
+func (*GetRoutesRequest_Vpc) isGetRoutesRequest_Table() {}
+
+type GetRoutesResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Routes        []*Route               `protobuf:"bytes,1,rep,name=routes,proto3" json:"routes,omitempty"`
+	NextPageToken string                 `protobuf:"bytes,2,opt,name=next_page_token,json=nextPageToken,proto3" json:"next_page_token,omitempty"` // empty on the last page
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetRoutesResponse) Reset() {
+	*x = GetRoutesResponse{}
+	mi := &file_proto_dataplane_proto_msgTypes[35]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetRoutesResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetRoutesResponse) ProtoMessage() {}
+
+func (x *GetRoutesResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[35]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
 }
 
-func (x *VpcStatus) GetVni() uint32 {
-	if x != nil {
-		return x.Vni
-	}
-	return 0
+// Deprecated: Use GetRoutesResponse.ProtoReflect.Descriptor instead.
+func (*GetRoutesResponse) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{35}
 }
 
-func (x *VpcStatus) GetRouteCount() uint32 {
+func (x *GetRoutesResponse) GetRoutes() []*Route {
 	if x != nil {
-		return x.RouteCount
+		return x.Routes
 	}
-	return 0
+	return nil
 }
 
-func (x *VpcStatus) GetInterfaces() map[string]*VpcInterfaceStatus {
+func (x *GetRoutesResponse) GetNextPageToken() string {
 	if x != nil {
-		return x.Interfaces
+		return x.NextPageToken
 	}
-	return nil
+	return ""
 }
 
-type VpcPeeringCounters struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	Name          string                 `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
-	SrcVpc        string                 `protobuf:"bytes,2,opt,name=src_vpc,json=srcVpc,proto3" json:"src_vpc,omitempty"`
-	DstVpc        string                 `protobuf:"bytes,3,opt,name=dst_vpc,json=dstVpc,proto3" json:"dst_vpc,omitempty"`
-	Packets       uint64                 `protobuf:"varint,4,opt,name=packets,proto3" json:"packets,omitempty"`
-	Bytes         uint64                 `protobuf:"varint,5,opt,name=bytes,proto3" json:"bytes,omitempty"`
-	Drops         uint64                 `protobuf:"varint,6,opt,name=drops,proto3" json:"drops,omitempty"`
-	Pps           float64                `protobuf:"fixed64,7,opt,name=pps,proto3" json:"pps,omitempty"`
-	Bps           float64                `protobuf:"fixed64,8,opt,name=bps,proto3" json:"bps,omitempty"`
+type LookupRouteRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Types that are valid to be assigned to Table:
+	//
+	//	*LookupRouteRequest_Vrf
+	//	*LookupRouteRequest_Vpc
+	Table         isLookupRouteRequest_Table `protobuf_oneof:"table"`
+	Address       string                     `protobuf:"bytes,3,opt,name=address,proto3" json:"address,omitempty"` // IP address to find the longest matching installed route for
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *VpcPeeringCounters) Reset() {
-	*x = VpcPeeringCounters{}
-	mi := &file_proto_dataplane_proto_msgTypes[21]
+func (x *LookupRouteRequest) Reset() {
+	*x = LookupRouteRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[36]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *VpcPeeringCounters) String() string {
+func (x *LookupRouteRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*VpcPeeringCounters) ProtoMessage() {}
+func (*LookupRouteRequest) ProtoMessage() {}
 
-func (x *VpcPeeringCounters) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[21]
+func (x *LookupRouteRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[36]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1973,92 +3491,81 @@ func (x *VpcPeeringCounters) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use VpcPeeringCounters.ProtoReflect.Descriptor instead.
-func (*VpcPeeringCounters) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{21}
+// Deprecated: Use LookupRouteRequest.ProtoReflect.Descriptor instead.
+func (*LookupRouteRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{36}
 }
 
-func (x *VpcPeeringCounters) GetName() string {
+func (x *LookupRouteRequest) GetTable() isLookupRouteRequest_Table {
 	if x != nil {
-		return x.Name
+		return x.Table
 	}
-	return ""
+	return nil
 }
 
-func (x *VpcPeeringCounters) GetSrcVpc() string {
+func (x *LookupRouteRequest) GetVrf() string {
 	if x != nil {
-		return x.SrcVpc
+		if x, ok := x.Table.(*LookupRouteRequest_Vrf); ok {
+			return x.Vrf
+		}
 	}
 	return ""
 }
 
-func (x *VpcPeeringCounters) GetDstVpc() string {
+func (x *LookupRouteRequest) GetVpc() string {
 	if x != nil {
-		return x.DstVpc
+		if x, ok := x.Table.(*LookupRouteRequest_Vpc); ok {
+			return x.Vpc
+		}
 	}
 	return ""
 }
 
-func (x *VpcPeeringCounters) GetPackets() uint64 {
+func (x *LookupRouteRequest) GetAddress() string {
 	if x != nil {
-		return x.Packets
+		return x.Address
 	}
-	return 0
+	return ""
 }
 
-func (x *VpcPeeringCounters) GetBytes() uint64 {
-	if x != nil {
-		return x.Bytes
-	}
-	return 0
+type isLookupRouteRequest_Table interface {
+	isLookupRouteRequest_Table()
 }
 
-func (x *VpcPeeringCounters) GetDrops() uint64 {
-	if x != nil {
-		return x.Drops
-	}
-	return 0
+type LookupRouteRequest_Vrf struct {
+	Vrf string `protobuf:"bytes,1,opt,name=vrf,proto3,oneof"`
 }
 
-func (x *VpcPeeringCounters) GetPps() float64 {
-	if x != nil {
-		return x.Pps
-	}
-	return 0
+type LookupRouteRequest_Vpc struct {
+	Vpc string `protobuf:"bytes,2,opt,name=vpc,proto3,oneof"`
 }
 
-func (x *VpcPeeringCounters) GetBps() float64 {
-	if x != nil {
-		return x.Bps
-	}
-	return 0
-}
+func (*LookupRouteRequest_Vrf) isLookupRouteRequest_Table() {}
 
-type VpcCounters struct {
+func (*LookupRouteRequest_Vpc) isLookupRouteRequest_Table() {}
+
+type LookupRouteResponse struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
-	Name          string                 `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
-	Packets       uint64                 `protobuf:"varint,2,opt,name=packets,proto3" json:"packets,omitempty"`
-	Drops         uint64                 `protobuf:"varint,3,opt,name=drops,proto3" json:"drops,omitempty"`
-	Bytes         uint64                 `protobuf:"varint,4,opt,name=bytes,proto3" json:"bytes,omitempty"`
+	Route         *Route                 `protobuf:"bytes,1,opt,name=route,proto3" json:"route,omitempty"` // unset if no route matches
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *VpcCounters) Reset() {
-	*x = VpcCounters{}
-	mi := &file_proto_dataplane_proto_msgTypes[22]
+func (x *LookupRouteResponse) Reset() {
+	*x = LookupRouteResponse{}
+	mi := &file_proto_dataplane_proto_msgTypes[37]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *VpcCounters) String() string {
+func (x *LookupRouteResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*VpcCounters) ProtoMessage() {}
+func (*LookupRouteResponse) ProtoMessage() {}
 
-func (x *VpcCounters) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[22]
+func (x *LookupRouteResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[37]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2069,37 +3576,16 @@ func (x *VpcCounters) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use VpcCounters.ProtoReflect.Descriptor instead.
-func (*VpcCounters) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{22}
-}
-
-func (x *VpcCounters) GetName() string {
-	if x != nil {
-		return x.Name
-	}
-	return ""
-}
-
-func (x *VpcCounters) GetPackets() uint64 {
-	if x != nil {
-		return x.Packets
-	}
-	return 0
-}
-
-func (x *VpcCounters) GetDrops() uint64 {
-	if x != nil {
-		return x.Drops
-	}
-	return 0
+// Deprecated: Use LookupRouteResponse.ProtoReflect.Descriptor instead.
+func (*LookupRouteResponse) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{37}
 }
 
-func (x *VpcCounters) GetBytes() uint64 {
+func (x *LookupRouteResponse) GetRoute() *Route {
 	if x != nil {
-		return x.Bytes
+		return x.Route
 	}
-	return 0
+	return nil
 }
 
 type GetDataplaneStatusResponse struct {
@@ -2115,14 +3601,21 @@ type GetDataplaneStatusResponse struct {
 	// key: peering name
 	VpcPeeringCounters map[string]*VpcPeeringCounters `protobuf:"bytes,7,rep,name=vpc_peering_counters,json=vpcPeeringCounters,proto3" json:"vpc_peering_counters,omitempty" protobuf_key:"bytes,1,opt,name=key" protobuf_val:"bytes,2,opt,name=value"`
 	// key: VPC name
-	VpcCounters   map[string]*VpcCounters `protobuf:"bytes,8,rep,name=vpc_counters,json=vpcCounters,proto3" json:"vpc_counters,omitempty" protobuf_key:"bytes,1,opt,name=key" protobuf_val:"bytes,2,opt,name=value"`
+	VpcCounters map[string]*VpcCounters `protobuf:"bytes,8,rep,name=vpc_counters,json=vpcCounters,proto3" json:"vpc_counters,omitempty" protobuf_key:"bytes,1,opt,name=key" protobuf_val:"bytes,2,opt,name=value"`
+	// key: peering name
+	NatSessions    map[string]*NatSessionCounters `protobuf:"bytes,9,rep,name=nat_sessions,json=natSessions,proto3" json:"nat_sessions,omitempty" protobuf_key:"bytes,1,opt,name=key" protobuf_val:"bytes,2,opt,name=value"`
+	ExposeCounters []*ExposeCounters              `protobuf:"bytes,10,rep,name=expose_counters,json=exposeCounters,proto3" json:"expose_counters,omitempty"`
+	// key: remote VTEP address
+	RemoteVteps map[string]*RemoteVtepStatus `protobuf:"bytes,11,rep,name=remote_vteps,json=remoteVteps,proto3" json:"remote_vteps,omitempty" protobuf_key:"bytes,1,opt,name=key" protobuf_val:"bytes,2,opt,name=value"`
+	// key: VNI
+	Vnis          map[uint32]*VniStatus `protobuf:"bytes,12,rep,name=vnis,proto3" json:"vnis,omitempty" protobuf_key:"varint,1,opt,name=key" protobuf_val:"bytes,2,opt,name=value"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
 func (x *GetDataplaneStatusResponse) Reset() {
 	*x = GetDataplaneStatusResponse{}
-	mi := &file_proto_dataplane_proto_msgTypes[23]
+	mi := &file_proto_dataplane_proto_msgTypes[38]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2134,7 +3627,7 @@ func (x *GetDataplaneStatusResponse) String() string {
 func (*GetDataplaneStatusResponse) ProtoMessage() {}
 
 func (x *GetDataplaneStatusResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[23]
+	mi := &file_proto_dataplane_proto_msgTypes[38]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2147,7 +3640,7 @@ func (x *GetDataplaneStatusResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetDataplaneStatusResponse.ProtoReflect.Descriptor instead.
 func (*GetDataplaneStatusResponse) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{23}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{38}
 }
 
 func (x *GetDataplaneStatusResponse) GetInterfaceStatuses() []*InterfaceStatus {
@@ -2206,6 +3699,34 @@ func (x *GetDataplaneStatusResponse) GetVpcCounters() map[string]*VpcCounters {
 	return nil
 }
 
+func (x *GetDataplaneStatusResponse) GetNatSessions() map[string]*NatSessionCounters {
+	if x != nil {
+		return x.NatSessions
+	}
+	return nil
+}
+
+func (x *GetDataplaneStatusResponse) GetExposeCounters() []*ExposeCounters {
+	if x != nil {
+		return x.ExposeCounters
+	}
+	return nil
+}
+
+func (x *GetDataplaneStatusResponse) GetRemoteVteps() map[string]*RemoteVtepStatus {
+	if x != nil {
+		return x.RemoteVteps
+	}
+	return nil
+}
+
+func (x *GetDataplaneStatusResponse) GetVnis() map[uint32]*VniStatus {
+	if x != nil {
+		return x.Vnis
+	}
+	return nil
+}
+
 // Defines a logical interface. May correlate with physical representation
 type Interface struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
@@ -2225,7 +3746,7 @@ type Interface struct {
 
 func (x *Interface) Reset() {
 	*x = Interface{}
-	mi := &file_proto_dataplane_proto_msgTypes[24]
+	mi := &file_proto_dataplane_proto_msgTypes[39]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2237,7 +3758,7 @@ func (x *Interface) String() string {
 func (*Interface) ProtoMessage() {}
 
 func (x *Interface) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[24]
+	mi := &file_proto_dataplane_proto_msgTypes[39]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2250,7 +3771,7 @@ func (x *Interface) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Interface.ProtoReflect.Descriptor instead.
 func (*Interface) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{24}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{39}
 }
 
 func (x *Interface) GetName() string {
@@ -2334,7 +3855,7 @@ type GatewayGroupMember struct {
 
 func (x *GatewayGroupMember) Reset() {
 	*x = GatewayGroupMember{}
-	mi := &file_proto_dataplane_proto_msgTypes[25]
+	mi := &file_proto_dataplane_proto_msgTypes[40]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2346,7 +3867,7 @@ func (x *GatewayGroupMember) String() string {
 func (*GatewayGroupMember) ProtoMessage() {}
 
 func (x *GatewayGroupMember) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[25]
+	mi := &file_proto_dataplane_proto_msgTypes[40]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2359,7 +3880,7 @@ func (x *GatewayGroupMember) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GatewayGroupMember.ProtoReflect.Descriptor instead.
 func (*GatewayGroupMember) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{25}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{40}
 }
 
 func (x *GatewayGroupMember) GetName() string {
@@ -2394,7 +3915,7 @@ type GatewayGroup struct {
 
 func (x *GatewayGroup) Reset() {
 	*x = GatewayGroup{}
-	mi := &file_proto_dataplane_proto_msgTypes[26]
+	mi := &file_proto_dataplane_proto_msgTypes[41]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2406,7 +3927,7 @@ func (x *GatewayGroup) String() string {
 func (*GatewayGroup) ProtoMessage() {}
 
 func (x *GatewayGroup) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[26]
+	mi := &file_proto_dataplane_proto_msgTypes[41]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2419,7 +3940,7 @@ func (x *GatewayGroup) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GatewayGroup.ProtoReflect.Descriptor instead.
 func (*GatewayGroup) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{26}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{41}
 }
 
 func (x *GatewayGroup) GetName() string {
@@ -2450,7 +3971,7 @@ type PeeringIPs struct {
 
 func (x *PeeringIPs) Reset() {
 	*x = PeeringIPs{}
-	mi := &file_proto_dataplane_proto_msgTypes[27]
+	mi := &file_proto_dataplane_proto_msgTypes[42]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2462,7 +3983,7 @@ func (x *PeeringIPs) String() string {
 func (*PeeringIPs) ProtoMessage() {}
 
 func (x *PeeringIPs) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[27]
+	mi := &file_proto_dataplane_proto_msgTypes[42]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2475,7 +3996,7 @@ func (x *PeeringIPs) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use PeeringIPs.ProtoReflect.Descriptor instead.
 func (*PeeringIPs) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{27}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{42}
 }
 
 func (x *PeeringIPs) GetRule() isPeeringIPs_Rule {
@@ -2533,7 +4054,7 @@ type PeeringAs struct {
 
 func (x *PeeringAs) Reset() {
 	*x = PeeringAs{}
-	mi := &file_proto_dataplane_proto_msgTypes[28]
+	mi := &file_proto_dataplane_proto_msgTypes[43]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2545,7 +4066,7 @@ func (x *PeeringAs) String() string {
 func (*PeeringAs) ProtoMessage() {}
 
 func (x *PeeringAs) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[28]
+	mi := &file_proto_dataplane_proto_msgTypes[43]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2558,7 +4079,7 @@ func (x *PeeringAs) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use PeeringAs.ProtoReflect.Descriptor instead.
 func (*PeeringAs) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{28}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{43}
 }
 
 func (x *PeeringAs) GetRule() isPeeringAs_Rule {
@@ -2610,7 +4131,7 @@ type PeeringStatelessNAT struct {
 
 func (x *PeeringStatelessNAT) Reset() {
 	*x = PeeringStatelessNAT{}
-	mi := &file_proto_dataplane_proto_msgTypes[29]
+	mi := &file_proto_dataplane_proto_msgTypes[44]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2622,7 +4143,7 @@ func (x *PeeringStatelessNAT) String() string {
 func (*PeeringStatelessNAT) ProtoMessage() {}
 
 func (x *PeeringStatelessNAT) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[29]
+	mi := &file_proto_dataplane_proto_msgTypes[44]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2635,7 +4156,7 @@ func (x *PeeringStatelessNAT) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use PeeringStatelessNAT.ProtoReflect.Descriptor instead.
 func (*PeeringStatelessNAT) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{29}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{44}
 }
 
 type PeeringStatefulNAT struct {
@@ -2647,7 +4168,7 @@ type PeeringStatefulNAT struct {
 
 func (x *PeeringStatefulNAT) Reset() {
 	*x = PeeringStatefulNAT{}
-	mi := &file_proto_dataplane_proto_msgTypes[30]
+	mi := &file_proto_dataplane_proto_msgTypes[45]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2659,7 +4180,7 @@ func (x *PeeringStatefulNAT) String() string {
 func (*PeeringStatefulNAT) ProtoMessage() {}
 
 func (x *PeeringStatefulNAT) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[30]
+	mi := &file_proto_dataplane_proto_msgTypes[45]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2672,7 +4193,7 @@ func (x *PeeringStatefulNAT) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use PeeringStatefulNAT.ProtoReflect.Descriptor instead.
 func (*PeeringStatefulNAT) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{30}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{45}
 }
 
 func (x *PeeringStatefulNAT) GetIdleTimeout() *durationpb.Duration {
@@ -2698,7 +4219,7 @@ type Expose struct {
 
 func (x *Expose) Reset() {
 	*x = Expose{}
-	mi := &file_proto_dataplane_proto_msgTypes[31]
+	mi := &file_proto_dataplane_proto_msgTypes[46]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2710,7 +4231,7 @@ func (x *Expose) String() string {
 func (*Expose) ProtoMessage() {}
 
 func (x *Expose) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[31]
+	mi := &file_proto_dataplane_proto_msgTypes[46]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2723,7 +4244,7 @@ func (x *Expose) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Expose.ProtoReflect.Descriptor instead.
 func (*Expose) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{31}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{46}
 }
 
 func (x *Expose) GetIps() []*PeeringIPs {
@@ -2792,7 +4313,7 @@ type PeeringEntryFor struct {
 
 func (x *PeeringEntryFor) Reset() {
 	*x = PeeringEntryFor{}
-	mi := &file_proto_dataplane_proto_msgTypes[32]
+	mi := &file_proto_dataplane_proto_msgTypes[47]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2804,7 +4325,7 @@ func (x *PeeringEntryFor) String() string {
 func (*PeeringEntryFor) ProtoMessage() {}
 
 func (x *PeeringEntryFor) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[32]
+	mi := &file_proto_dataplane_proto_msgTypes[47]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2817,7 +4338,7 @@ func (x *PeeringEntryFor) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use PeeringEntryFor.ProtoReflect.Descriptor instead.
 func (*PeeringEntryFor) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{32}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{47}
 }
 
 func (x *PeeringEntryFor) GetVpc() string {
@@ -2846,7 +4367,7 @@ type VpcPeering struct {
 
 func (x *VpcPeering) Reset() {
 	*x = VpcPeering{}
-	mi := &file_proto_dataplane_proto_msgTypes[33]
+	mi := &file_proto_dataplane_proto_msgTypes[48]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2858,7 +4379,7 @@ func (x *VpcPeering) String() string {
 func (*VpcPeering) ProtoMessage() {}
 
 func (x *VpcPeering) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[33]
+	mi := &file_proto_dataplane_proto_msgTypes[48]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2871,7 +4392,7 @@ func (x *VpcPeering) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VpcPeering.ProtoReflect.Descriptor instead.
 func (*VpcPeering) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{33}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{48}
 }
 
 func (x *VpcPeering) GetName() string {
@@ -2907,7 +4428,7 @@ type VPC struct {
 
 func (x *VPC) Reset() {
 	*x = VPC{}
-	mi := &file_proto_dataplane_proto_msgTypes[34]
+	mi := &file_proto_dataplane_proto_msgTypes[49]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2919,7 +4440,7 @@ func (x *VPC) String() string {
 func (*VPC) ProtoMessage() {}
 
 func (x *VPC) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[34]
+	mi := &file_proto_dataplane_proto_msgTypes[49]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2932,7 +4453,7 @@ func (x *VPC) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VPC.ProtoReflect.Descriptor instead.
 func (*VPC) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{34}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{49}
 }
 
 func (x *VPC) GetId() string {
@@ -2974,7 +4495,7 @@ type Overlay struct {
 
 func (x *Overlay) Reset() {
 	*x = Overlay{}
-	mi := &file_proto_dataplane_proto_msgTypes[35]
+	mi := &file_proto_dataplane_proto_msgTypes[50]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2986,7 +4507,7 @@ func (x *Overlay) String() string {
 func (*Overlay) ProtoMessage() {}
 
 func (x *Overlay) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[35]
+	mi := &file_proto_dataplane_proto_msgTypes[50]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2999,7 +4520,7 @@ func (x *Overlay) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Overlay.ProtoReflect.Descriptor instead.
 func (*Overlay) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{35}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{50}
 }
 
 func (x *Overlay) GetVpcs() []*VPC {
@@ -3028,7 +4549,7 @@ type BgpAddressFamilyIPv4 struct {
 
 func (x *BgpAddressFamilyIPv4) Reset() {
 	*x = BgpAddressFamilyIPv4{}
-	mi := &file_proto_dataplane_proto_msgTypes[36]
+	mi := &file_proto_dataplane_proto_msgTypes[51]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3040,7 +4561,7 @@ func (x *BgpAddressFamilyIPv4) String() string {
 func (*BgpAddressFamilyIPv4) ProtoMessage() {}
 
 func (x *BgpAddressFamilyIPv4) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[36]
+	mi := &file_proto_dataplane_proto_msgTypes[51]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3053,7 +4574,7 @@ func (x *BgpAddressFamilyIPv4) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpAddressFamilyIPv4.ProtoReflect.Descriptor instead.
 func (*BgpAddressFamilyIPv4) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{36}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{51}
 }
 
 func (x *BgpAddressFamilyIPv4) GetRedistributeConnected() bool {
@@ -3089,7 +4610,7 @@ type BgpAddressFamilyIPv6 struct {
 
 func (x *BgpAddressFamilyIPv6) Reset() {
 	*x = BgpAddressFamilyIPv6{}
-	mi := &file_proto_dataplane_proto_msgTypes[37]
+	mi := &file_proto_dataplane_proto_msgTypes[52]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3101,7 +4622,7 @@ func (x *BgpAddressFamilyIPv6) String() string {
 func (*BgpAddressFamilyIPv6) ProtoMessage() {}
 
 func (x *BgpAddressFamilyIPv6) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[37]
+	mi := &file_proto_dataplane_proto_msgTypes[52]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3114,7 +4635,7 @@ func (x *BgpAddressFamilyIPv6) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpAddressFamilyIPv6.ProtoReflect.Descriptor instead.
 func (*BgpAddressFamilyIPv6) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{37}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{52}
 }
 
 func (x *BgpAddressFamilyIPv6) GetRedistributeConnected() bool {
@@ -3148,7 +4669,7 @@ type BgpAddressFamilyL2VpnEvpn struct {
 
 func (x *BgpAddressFamilyL2VpnEvpn) Reset() {
 	*x = BgpAddressFamilyL2VpnEvpn{}
-	mi := &file_proto_dataplane_proto_msgTypes[38]
+	mi := &file_proto_dataplane_proto_msgTypes[53]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3160,7 +4681,7 @@ func (x *BgpAddressFamilyL2VpnEvpn) String() string {
 func (*BgpAddressFamilyL2VpnEvpn) ProtoMessage() {}
 
 func (x *BgpAddressFamilyL2VpnEvpn) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[38]
+	mi := &file_proto_dataplane_proto_msgTypes[53]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3173,7 +4694,7 @@ func (x *BgpAddressFamilyL2VpnEvpn) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpAddressFamilyL2VpnEvpn.ProtoReflect.Descriptor instead.
 func (*BgpAddressFamilyL2VpnEvpn) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{38}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{53}
 }
 
 func (x *BgpAddressFamilyL2VpnEvpn) GetAdvertiseAllVni() bool {
@@ -3196,7 +4717,7 @@ type BgpNeighborUpdateSource struct {
 
 func (x *BgpNeighborUpdateSource) Reset() {
 	*x = BgpNeighborUpdateSource{}
-	mi := &file_proto_dataplane_proto_msgTypes[39]
+	mi := &file_proto_dataplane_proto_msgTypes[54]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3208,7 +4729,7 @@ func (x *BgpNeighborUpdateSource) String() string {
 func (*BgpNeighborUpdateSource) ProtoMessage() {}
 
 func (x *BgpNeighborUpdateSource) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[39]
+	mi := &file_proto_dataplane_proto_msgTypes[54]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3221,7 +4742,7 @@ func (x *BgpNeighborUpdateSource) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpNeighborUpdateSource.ProtoReflect.Descriptor instead.
 func (*BgpNeighborUpdateSource) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{39}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{54}
 }
 
 func (x *BgpNeighborUpdateSource) GetSource() isBgpNeighborUpdateSource_Source {
@@ -3278,7 +4799,7 @@ type BgpNeighbor struct {
 
 func (x *BgpNeighbor) Reset() {
 	*x = BgpNeighbor{}
-	mi := &file_proto_dataplane_proto_msgTypes[40]
+	mi := &file_proto_dataplane_proto_msgTypes[55]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3290,7 +4811,7 @@ func (x *BgpNeighbor) String() string {
 func (*BgpNeighbor) ProtoMessage() {}
 
 func (x *BgpNeighbor) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[40]
+	mi := &file_proto_dataplane_proto_msgTypes[55]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3303,7 +4824,7 @@ func (x *BgpNeighbor) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpNeighbor.ProtoReflect.Descriptor instead.
 func (*BgpNeighbor) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{40}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{55}
 }
 
 func (x *BgpNeighbor) GetAddress() string {
@@ -3347,7 +4868,7 @@ type RouteMap struct {
 
 func (x *RouteMap) Reset() {
 	*x = RouteMap{}
-	mi := &file_proto_dataplane_proto_msgTypes[41]
+	mi := &file_proto_dataplane_proto_msgTypes[56]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3359,7 +4880,7 @@ func (x *RouteMap) String() string {
 func (*RouteMap) ProtoMessage() {}
 
 func (x *RouteMap) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[41]
+	mi := &file_proto_dataplane_proto_msgTypes[56]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3372,7 +4893,7 @@ func (x *RouteMap) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use RouteMap.ProtoReflect.Descriptor instead.
 func (*RouteMap) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{41}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{56}
 }
 
 func (x *RouteMap) GetName() string {
@@ -3419,7 +4940,7 @@ type RouterConfig struct {
 
 func (x *RouterConfig) Reset() {
 	*x = RouterConfig{}
-	mi := &file_proto_dataplane_proto_msgTypes[42]
+	mi := &file_proto_dataplane_proto_msgTypes[57]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3431,7 +4952,7 @@ func (x *RouterConfig) String() string {
 func (*RouterConfig) ProtoMessage() {}
 
 func (x *RouterConfig) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[42]
+	mi := &file_proto_dataplane_proto_msgTypes[57]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3444,7 +4965,7 @@ func (x *RouterConfig) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use RouterConfig.ProtoReflect.Descriptor instead.
 func (*RouterConfig) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{42}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{57}
 }
 
 func (x *RouterConfig) GetAsn() string {
@@ -3509,7 +5030,7 @@ type VRF struct {
 
 func (x *VRF) Reset() {
 	*x = VRF{}
-	mi := &file_proto_dataplane_proto_msgTypes[43]
+	mi := &file_proto_dataplane_proto_msgTypes[58]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3521,7 +5042,7 @@ func (x *VRF) String() string {
 func (*VRF) ProtoMessage() {}
 
 func (x *VRF) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[43]
+	mi := &file_proto_dataplane_proto_msgTypes[58]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3534,7 +5055,7 @@ func (x *VRF) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VRF.ProtoReflect.Descriptor instead.
 func (*VRF) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{43}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{58}
 }
 
 func (x *VRF) GetName() string {
@@ -3575,7 +5096,7 @@ type Underlay struct {
 
 func (x *Underlay) Reset() {
 	*x = Underlay{}
-	mi := &file_proto_dataplane_proto_msgTypes[44]
+	mi := &file_proto_dataplane_proto_msgTypes[59]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3587,7 +5108,7 @@ func (x *Underlay) String() string {
 func (*Underlay) ProtoMessage() {}
 
 func (x *Underlay) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[44]
+	mi := &file_proto_dataplane_proto_msgTypes[59]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3600,7 +5121,7 @@ func (x *Underlay) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Underlay.ProtoReflect.Descriptor instead.
 func (*Underlay) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{44}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{59}
 }
 
 func (x *Underlay) GetVrfs() []*VRF {
@@ -3620,7 +5141,7 @@ type TracingConfig struct {
 
 func (x *TracingConfig) Reset() {
 	*x = TracingConfig{}
-	mi := &file_proto_dataplane_proto_msgTypes[45]
+	mi := &file_proto_dataplane_proto_msgTypes[60]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3632,7 +5153,7 @@ func (x *TracingConfig) String() string {
 func (*TracingConfig) ProtoMessage() {}
 
 func (x *TracingConfig) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[45]
+	mi := &file_proto_dataplane_proto_msgTypes[60]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3645,7 +5166,7 @@ func (x *TracingConfig) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use TracingConfig.ProtoReflect.Descriptor instead.
 func (*TracingConfig) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{45}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{60}
 }
 
 func (x *TracingConfig) GetDefault() LogLevel {
@@ -3672,7 +5193,7 @@ type Device struct {
 
 func (x *Device) Reset() {
 	*x = Device{}
-	mi := &file_proto_dataplane_proto_msgTypes[46]
+	mi := &file_proto_dataplane_proto_msgTypes[61]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3684,7 +5205,7 @@ func (x *Device) String() string {
 func (*Device) ProtoMessage() {}
 
 func (x *Device) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[46]
+	mi := &file_proto_dataplane_proto_msgTypes[61]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3697,7 +5218,7 @@ func (x *Device) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Device.ProtoReflect.Descriptor instead.
 func (*Device) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{46}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{61}
 }
 
 func (x *Device) GetTracing() *TracingConfig {
@@ -3722,7 +5243,7 @@ type GatewayConfig struct {
 
 func (x *GatewayConfig) Reset() {
 	*x = GatewayConfig{}
-	mi := &file_proto_dataplane_proto_msgTypes[47]
+	mi := &file_proto_dataplane_proto_msgTypes[62]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -3734,7 +5255,7 @@ func (x *GatewayConfig) String() string {
 func (*GatewayConfig) ProtoMessage() {}
 
 func (x *GatewayConfig) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[47]
+	mi := &file_proto_dataplane_proto_msgTypes[62]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3747,7 +5268,7 @@ func (x *GatewayConfig) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GatewayConfig.ProtoReflect.Descriptor instead.
 func (*GatewayConfig) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{47}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{62}
 }
 
 func (x *GatewayConfig) GetGeneration() int64 {
@@ -3796,7 +5317,7 @@ var File_proto_dataplane_proto protoreflect.FileDescriptor
 
 const file_proto_dataplane_proto_rawDesc = "" +
 	"\n" +
-	"\x15proto/dataplane.proto\x12\x06config\x1a\x1egoogle/protobuf/duration.proto\"\x12\n" +
+	"\x15proto/dataplane.proto\x12\x06config\x1a\x1egoogle/protobuf/duration.proto\x1a\x1fgoogle/protobuf/timestamp.proto\"\x12\n" +
 	"\x10GetConfigRequest\"D\n" +
 	"\x13UpdateConfigRequest\x12-\n" +
 	"\x06config\x18\x01 \x01(\v2\x15.config.GatewayConfigR\x06config\"U\n" +
@@ -3820,35 +5341,63 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"\trouter_id\x18\x01 \x01(\tR\brouterId\x12\x15\n" +
 	"\x03vrf\x18\x02 \x01(\tH\x00R\x03vrf\x88\x01\x01B\x06\n" +
 	"\x04_vrf\"\x1b\n" +
-	"\x19GetDataplaneStatusRequest\"\xb0\x01\n" +
+	"\x19GetDataplaneStatusRequest\"\xd3\x01\n" +
 	"\x0fInterfaceStatus\x12\x16\n" +
 	"\x06ifname\x18\x01 \x01(\tR\x06ifname\x12@\n" +
 	"\voper_status\x18\x02 \x01(\x0e2\x1f.config.InterfaceOperStatusTypeR\n" +
 	"operStatus\x12C\n" +
-	"\fadmin_status\x18\x03 \x01(\x0e2 .config.InterfaceAdminStatusTypeR\vadminStatus\"\xa7\x02\n" +
+	"\fadmin_status\x18\x03 \x01(\x0e2 .config.InterfaceAdminStatusTypeR\vadminStatus\x12!\n" +
+	"\ferror_reason\x18\x04 \x01(\tR\verrorReason\"\x88\x05\n" +
 	"\tFrrStatus\x12:\n" +
 	"\fzebra_status\x18\x01 \x01(\x0e2\x17.config.ZebraStatusTypeR\vzebraStatus\x12D\n" +
 	"\x10frr_agent_status\x18\x02 \x01(\x0e2\x1a.config.FrrAgentStatusTypeR\x0efrrAgentStatus\x12,\n" +
 	"\x12applied_config_gen\x18\x03 \x01(\x03R\x10appliedConfigGen\x12\x1a\n" +
 	"\brestarts\x18\x04 \x01(\rR\brestarts\x12'\n" +
 	"\x0fapplied_configs\x18\x05 \x01(\rR\x0eappliedConfigs\x12%\n" +
-	"\x0efailed_configs\x18\x06 \x01(\rR\rfailedConfigs\"J\n" +
+	"\x0efailed_configs\x18\x06 \x01(\rR\rfailedConfigs\x12/\n" +
+	"\x05state\x18\a \x01(\x0e2\x19.config.FrrLifecycleStateR\x05state\x12;\n" +
+	"\vstate_since\x18\b \x01(\v2\x1a.google.protobuf.TimestampR\n" +
+	"stateSince\x12H\n" +
+	"\x12zebra_status_since\x18\t \x01(\v2\x1a.google.protobuf.TimestampR\x10zebraStatusSince\x12O\n" +
+	"\x16frr_agent_status_since\x18\n" +
+	" \x01(\v2\x1a.google.protobuf.TimestampR\x13frrAgentStatusSince\x12(\n" +
+	"\x10last_apply_error\x18\v \x01(\tR\x0elastApplyError\x12,\n" +
+	"\x12pending_config_gen\x18\f \x01(\x03R\x10pendingConfigGen\"J\n" +
 	"\x13DataplaneStatusInfo\x123\n" +
-	"\x06status\x18\x01 \x01(\x0e2\x1b.config.DataplaneStatusTypeR\x06status\"\xad\x01\n" +
+	"\x06status\x18\x01 \x01(\x0e2\x1b.config.DataplaneStatusTypeR\x06status\"t\n" +
+	"\x16InterfaceQueueCounters\x12\x14\n" +
+	"\x05queue\x18\x01 \x01(\rR\x05queue\x12\x18\n" +
+	"\apackets\x18\x02 \x01(\x04R\apackets\x12\x14\n" +
+	"\x05bytes\x18\x03 \x01(\x04R\x05bytes\x12\x14\n" +
+	"\x05drops\x18\x04 \x01(\x04R\x05drops\"\xa7\x04\n" +
 	"\x11InterfaceCounters\x12\x17\n" +
 	"\atx_bits\x18\x01 \x01(\x04R\x06txBits\x12\x15\n" +
 	"\x06tx_bps\x18\x02 \x01(\x01R\x05txBps\x12\x1b\n" +
 	"\ttx_errors\x18\x03 \x01(\x04R\btxErrors\x12\x17\n" +
 	"\arx_bits\x18\x04 \x01(\x04R\x06rxBits\x12\x15\n" +
 	"\x06rx_bps\x18\x05 \x01(\x01R\x05rxBps\x12\x1b\n" +
-	"\trx_errors\x18\x06 \x01(\x04R\brxErrors\"\xfa\x01\n" +
+	"\trx_errors\x18\x06 \x01(\x04R\brxErrors\x12\x1d\n" +
+	"\n" +
+	"tx_packets\x18\a \x01(\x04R\ttxPackets\x12\x19\n" +
+	"\btx_drops\x18\b \x01(\x04R\atxDrops\x12!\n" +
+	"\ftx_multicast\x18\t \x01(\x04R\vtxMulticast\x12!\n" +
+	"\ftx_broadcast\x18\n" +
+	" \x01(\x04R\vtxBroadcast\x12\x1d\n" +
+	"\n" +
+	"rx_packets\x18\v \x01(\x04R\trxPackets\x12\x19\n" +
+	"\brx_drops\x18\f \x01(\x04R\arxDrops\x12!\n" +
+	"\frx_multicast\x18\r \x01(\x04R\vrxMulticast\x12!\n" +
+	"\frx_broadcast\x18\x0e \x01(\x04R\vrxBroadcast\x12;\n" +
+	"\trx_queues\x18\x0f \x03(\v2\x1e.config.InterfaceQueueCountersR\brxQueues\x12;\n" +
+	"\ttx_queues\x18\x10 \x03(\v2\x1e.config.InterfaceQueueCountersR\btxQueues\"\x9d\x02\n" +
 	"\x16InterfaceRuntimeStatus\x12C\n" +
 	"\fadmin_status\x18\x01 \x01(\x0e2 .config.InterfaceAdminStatusTypeR\vadminStatus\x12@\n" +
 	"\voper_status\x18\x02 \x01(\x0e2\x1f.config.InterfaceOperStatusTypeR\n" +
 	"operStatus\x12\x10\n" +
 	"\x03mac\x18\x03 \x01(\tR\x03mac\x12\x10\n" +
 	"\x03mtu\x18\x04 \x01(\rR\x03mtu\x125\n" +
-	"\bcounters\x18\x05 \x01(\v2\x19.config.InterfaceCountersR\bcounters\"\xc7\x01\n" +
+	"\bcounters\x18\x05 \x01(\v2\x19.config.InterfaceCountersR\bcounters\x12!\n" +
+	"\ferror_reason\x18\x06 \x01(\tR\verrorReason\"\xc7\x01\n" +
 	"\x12BgpMessageCounters\x12\x1e\n" +
 	"\n" +
 	"capability\x18\x01 \x01(\x04R\n" +
@@ -3908,7 +5457,17 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"interfaces\x1aY\n" +
 	"\x0fInterfacesEntry\x12\x10\n" +
 	"\x03key\x18\x01 \x01(\tR\x03key\x120\n" +
-	"\x05value\x18\x02 \x01(\v2\x1a.config.VpcInterfaceStatusR\x05value:\x028\x01\"\xc4\x01\n" +
+	"\x05value\x18\x02 \x01(\v2\x1a.config.VpcInterfaceStatusR\x05value:\x028\x01\"~\n" +
+	"\x10RemoteVtepStatus\x12\x18\n" +
+	"\aaddress\x18\x01 \x01(\tR\aaddress\x12<\n" +
+	"\freachability\x18\x02 \x01(\x0e2\x18.config.VtepReachabilityR\freachability\x12\x12\n" +
+	"\x04vnis\x18\x03 \x03(\rR\x04vnis\"\x87\x01\n" +
+	"\tVniStatus\x12\x10\n" +
+	"\x03vni\x18\x01 \x01(\rR\x03vni\x12\x1b\n" +
+	"\tmac_count\x18\x02 \x01(\rR\bmacCount\x12\x1f\n" +
+	"\vroute_count\x18\x03 \x01(\rR\n" +
+	"routeCount\x12*\n" +
+	"\x11type5_route_count\x18\x04 \x01(\rR\x0ftype5RouteCount\"\xd4\x02\n" +
 	"\x12VpcPeeringCounters\x12\x12\n" +
 	"\x04name\x18\x01 \x01(\tR\x04name\x12\x17\n" +
 	"\asrc_vpc\x18\x02 \x01(\tR\x06srcVpc\x12\x17\n" +
@@ -3917,12 +5476,94 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"\x05bytes\x18\x05 \x01(\x04R\x05bytes\x12\x14\n" +
 	"\x05drops\x18\x06 \x01(\x04R\x05drops\x12\x10\n" +
 	"\x03pps\x18\a \x01(\x01R\x03pps\x12\x10\n" +
-	"\x03bps\x18\b \x01(\x01R\x03bps\"g\n" +
+	"\x03bps\x18\b \x01(\x01R\x03bps\x12N\n" +
+	"\fdrop_reasons\x18\t \x03(\v2+.config.VpcPeeringCounters.DropReasonsEntryR\vdropReasons\x1a>\n" +
+	"\x10DropReasonsEntry\x12\x10\n" +
+	"\x03key\x18\x01 \x01(\tR\x03key\x12\x14\n" +
+	"\x05value\x18\x02 \x01(\x04R\x05value:\x028\x01\"\xf0\x01\n" +
 	"\vVpcCounters\x12\x12\n" +
 	"\x04name\x18\x01 \x01(\tR\x04name\x12\x18\n" +
 	"\apackets\x18\x02 \x01(\x04R\apackets\x12\x14\n" +
 	"\x05drops\x18\x03 \x01(\x04R\x05drops\x12\x14\n" +
-	"\x05bytes\x18\x04 \x01(\x04R\x05bytes\"\xdb\a\n" +
+	"\x05bytes\x18\x04 \x01(\x04R\x05bytes\x12G\n" +
+	"\fdrop_reasons\x18\x05 \x03(\v2$.config.VpcCounters.DropReasonsEntryR\vdropReasons\x1a>\n" +
+	"\x10DropReasonsEntry\x12\x10\n" +
+	"\x03key\x18\x01 \x01(\tR\x03key\x12\x14\n" +
+	"\x05value\x18\x02 \x01(\x04R\x05value:\x028\x01\"\x83\x03\n" +
+	"\x0eExposeCounters\x12\x18\n" +
+	"\apeering\x18\x01 \x01(\tR\apeering\x12\x10\n" +
+	"\x03vpc\x18\x02 \x01(\tR\x03vpc\x12!\n" +
+	"\fexpose_index\x18\x03 \x01(\rR\vexposeIndex\x12\x18\n" +
+	"\apackets\x18\x04 \x01(\x04R\apackets\x12\x14\n" +
+	"\x05bytes\x18\x05 \x01(\x04R\x05bytes\x12\x14\n" +
+	"\x05drops\x18\x06 \x01(\x04R\x05drops\x12J\n" +
+	"\fdrop_reasons\x18\a \x03(\v2'.config.ExposeCounters.DropReasonsEntryR\vdropReasons\x12\"\n" +
+	"\ftranslations\x18\b \x01(\x04R\ftranslations\x12,\n" +
+	"\x12nat_alloc_failures\x18\t \x01(\x04R\x10natAllocFailures\x1a>\n" +
+	"\x10DropReasonsEntry\x12\x10\n" +
+	"\x03key\x18\x01 \x01(\tR\x03key\x12\x14\n" +
+	"\x05value\x18\x02 \x01(\x04R\x05value:\x028\x01\"\x9f\x01\n" +
+	"\tFiveTuple\x12.\n" +
+	"\bprotocol\x18\x01 \x01(\x0e2\x12.config.IpProtocolR\bprotocol\x12\x15\n" +
+	"\x06src_ip\x18\x02 \x01(\tR\x05srcIp\x12\x19\n" +
+	"\bsrc_port\x18\x03 \x01(\rR\asrcPort\x12\x15\n" +
+	"\x06dst_ip\x18\x04 \x01(\tR\x05dstIp\x12\x19\n" +
+	"\bdst_port\x18\x05 \x01(\rR\adstPort\"\xba\x02\n" +
+	"\n" +
+	"NatSession\x12\x18\n" +
+	"\apeering\x18\x01 \x01(\tR\apeering\x12\x17\n" +
+	"\asrc_vpc\x18\x02 \x01(\tR\x06srcVpc\x12\x17\n" +
+	"\adst_vpc\x18\x03 \x01(\tR\x06dstVpc\x12-\n" +
+	"\boriginal\x18\x04 \x01(\v2\x11.config.FiveTupleR\boriginal\x121\n" +
+	"\n" +
+	"translated\x18\x05 \x01(\v2\x11.config.FiveTupleR\n" +
+	"translated\x126\n" +
+	"\tidle_time\x18\x06 \x01(\v2\x19.google.protobuf.DurationR\bidleTime\x12F\n" +
+	"\x11remaining_timeout\x18\a \x01(\v2\x19.google.protobuf.DurationR\x10remainingTimeout\"`\n" +
+	"\x12NatSessionCounters\x12\x16\n" +
+	"\x06active\x18\x01 \x01(\x04R\x06active\x12\x18\n" +
+	"\acreated\x18\x02 \x01(\x04R\acreated\x12\x18\n" +
+	"\aexpired\x18\x03 \x01(\x04R\aexpired\"\xca\x01\n" +
+	"\x16ListNatSessionsRequest\x12\x18\n" +
+	"\apeering\x18\x01 \x01(\tR\apeering\x12\x10\n" +
+	"\x03vpc\x18\x02 \x01(\tR\x03vpc\x12\x18\n" +
+	"\aaddress\x18\x03 \x01(\tR\aaddress\x12.\n" +
+	"\bprotocol\x18\x04 \x01(\x0e2\x12.config.IpProtocolR\bprotocol\x12\x1b\n" +
+	"\tpage_size\x18\x05 \x01(\rR\bpageSize\x12\x1d\n" +
+	"\n" +
+	"page_token\x18\x06 \x01(\tR\tpageToken\"q\n" +
+	"\x17ListNatSessionsResponse\x12.\n" +
+	"\bsessions\x18\x01 \x03(\v2\x12.config.NatSessionR\bsessions\x12&\n" +
+	"\x0fnext_page_token\x18\x02 \x01(\tR\rnextPageToken\"f\n" +
+	"\fRouteNextHop\x12\x18\n" +
+	"\aaddress\x18\x01 \x01(\tR\aaddress\x12\x16\n" +
+	"\x06ifname\x18\x02 \x01(\tR\x06ifname\x12\x10\n" +
+	"\x03vni\x18\x03 \x01(\rR\x03vni\x12\x12\n" +
+	"\x04vtep\x18\x04 \x01(\tR\x04vtep\"\xbb\x01\n" +
+	"\x05Route\x12\x16\n" +
+	"\x06prefix\x18\x01 \x01(\tR\x06prefix\x121\n" +
+	"\tnext_hops\x18\x02 \x03(\v2\x14.config.RouteNextHopR\bnextHops\x121\n" +
+	"\bprotocol\x18\x03 \x01(\x0e2\x15.config.RouteProtocolR\bprotocol\x12\x16\n" +
+	"\x06metric\x18\x04 \x01(\rR\x06metric\x12\x1c\n" +
+	"\tinstalled\x18\x05 \x01(\bR\tinstalled\"\xb2\x01\n" +
+	"\x10GetRoutesRequest\x12\x12\n" +
+	"\x03vrf\x18\x01 \x01(\tH\x00R\x03vrf\x12\x12\n" +
+	"\x03vpc\x18\x02 \x01(\tH\x00R\x03vpc\x121\n" +
+	"\bprotocol\x18\x03 \x01(\x0e2\x15.config.RouteProtocolR\bprotocol\x12\x1b\n" +
+	"\tpage_size\x18\x04 \x01(\rR\bpageSize\x12\x1d\n" +
+	"\n" +
+	"page_token\x18\x05 \x01(\tR\tpageTokenB\a\n" +
+	"\x05table\"b\n" +
+	"\x11GetRoutesResponse\x12%\n" +
+	"\x06routes\x18\x01 \x03(\v2\r.config.RouteR\x06routes\x12&\n" +
+	"\x0fnext_page_token\x18\x02 \x01(\tR\rnextPageToken\"_\n" +
+	"\x12LookupRouteRequest\x12\x12\n" +
+	"\x03vrf\x18\x01 \x01(\tH\x00R\x03vrf\x12\x12\n" +
+	"\x03vpc\x18\x02 \x01(\tH\x00R\x03vpc\x12\x18\n" +
+	"\aaddress\x18\x03 \x01(\tR\aaddressB\a\n" +
+	"\x05table\":\n" +
+	"\x13LookupRouteResponse\x12#\n" +
+	"\x05route\x18\x01 \x01(\v2\r.config.RouteR\x05route\"\x90\f\n" +
 	"\x1aGetDataplaneStatusResponse\x12F\n" +
 	"\x12interface_statuses\x18\x01 \x03(\v2\x17.config.InterfaceStatusR\x11interfaceStatuses\x120\n" +
 	"\n" +
@@ -3932,7 +5573,12 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"\x03bgp\x18\x05 \x01(\v2\x11.config.BgpStatusR\x03bgp\x12@\n" +
 	"\x04vpcs\x18\x06 \x03(\v2,.config.GetDataplaneStatusResponse.VpcsEntryR\x04vpcs\x12l\n" +
 	"\x14vpc_peering_counters\x18\a \x03(\v2:.config.GetDataplaneStatusResponse.VpcPeeringCountersEntryR\x12vpcPeeringCounters\x12V\n" +
-	"\fvpc_counters\x18\b \x03(\v23.config.GetDataplaneStatusResponse.VpcCountersEntryR\vvpcCounters\x1ac\n" +
+	"\fvpc_counters\x18\b \x03(\v23.config.GetDataplaneStatusResponse.VpcCountersEntryR\vvpcCounters\x12V\n" +
+	"\fnat_sessions\x18\t \x03(\v23.config.GetDataplaneStatusResponse.NatSessionsEntryR\vnatSessions\x12?\n" +
+	"\x0fexpose_counters\x18\n" +
+	" \x03(\v2\x16.config.ExposeCountersR\x0eexposeCounters\x12V\n" +
+	"\fremote_vteps\x18\v \x03(\v23.config.GetDataplaneStatusResponse.RemoteVtepsEntryR\vremoteVteps\x12@\n" +
+	"\x04vnis\x18\f \x03(\v2,.config.GetDataplaneStatusResponse.VnisEntryR\x04vnis\x1ac\n" +
 	"\x15InterfaceRuntimeEntry\x12\x10\n" +
 	"\x03key\x18\x01 \x01(\tR\x03key\x124\n" +
 	"\x05value\x18\x02 \x01(\v2\x1e.config.InterfaceRuntimeStatusR\x05value:\x028\x01\x1aJ\n" +
@@ -3944,7 +5590,16 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"\x05value\x18\x02 \x01(\v2\x1a.config.VpcPeeringCountersR\x05value:\x028\x01\x1aS\n" +
 	"\x10VpcCountersEntry\x12\x10\n" +
 	"\x03key\x18\x01 \x01(\tR\x03key\x12)\n" +
-	"\x05value\x18\x02 \x01(\v2\x13.config.VpcCountersR\x05value:\x028\x01\"\xfb\x02\n" +
+	"\x05value\x18\x02 \x01(\v2\x13.config.VpcCountersR\x05value:\x028\x01\x1aZ\n" +
+	"\x10NatSessionsEntry\x12\x10\n" +
+	"\x03key\x18\x01 \x01(\tR\x03key\x120\n" +
+	"\x05value\x18\x02 \x01(\v2\x1a.config.NatSessionCountersR\x05value:\x028\x01\x1aX\n" +
+	"\x10RemoteVtepsEntry\x12\x10\n" +
+	"\x03key\x18\x01 \x01(\tR\x03key\x12.\n" +
+	"\x05value\x18\x02 \x01(\v2\x18.config.RemoteVtepStatusR\x05value:\x028\x01\x1aJ\n" +
+	"\tVnisEntry\x12\x10\n" +
+	"\x03key\x18\x01 \x01(\rR\x03key\x12'\n" +
+	"\x05value\x18\x02 \x01(\v2\x11.config.VniStatusR\x05value:\x028\x01\"\xfb\x02\n" +
 	"\tInterface\x12\x12\n" +
 	"\x04name\x18\x01 \x01(\tR\x04name\x12\x18\n" +
 	"\aipaddrs\x18\x02 \x03(\tR\aipaddrs\x12\"\n" +
@@ -4103,7 +5758,14 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"\x16ZEBRA_STATUS_CONNECTED\x10\x01*X\n" +
 	"\x12FrrAgentStatusType\x12\"\n" +
 	"\x1eFRR_AGENT_STATUS_NOT_CONNECTED\x10\x00\x12\x1e\n" +
-	"\x1aFRR_AGENT_STATUS_CONNECTED\x10\x01*\x88\x01\n" +
+	"\x1aFRR_AGENT_STATUS_CONNECTED\x10\x01*\xa1\x01\n" +
+	"\x11FrrLifecycleState\x12\x15\n" +
+	"\x11FRR_STATE_UNKNOWN\x10\x00\x12\x16\n" +
+	"\x12FRR_STATE_STARTING\x10\x01\x12\x15\n" +
+	"\x11FRR_STATE_RUNNING\x10\x02\x12\x17\n" +
+	"\x13FRR_STATE_RELOADING\x10\x03\x12\x16\n" +
+	"\x12FRR_STATE_STOPPING\x10\x04\x12\x15\n" +
+	"\x11FRR_STATE_CRASHED\x10\x05*\x88\x01\n" +
 	"\x13DataplaneStatusType\x12\x1c\n" +
 	"\x18DATAPLANE_STATUS_UNKNOWN\x10\x00\x12\x1c\n" +
 	"\x18DATAPLANE_STATUS_HEALTHY\x10\x01\x12\x19\n" +
@@ -4115,7 +5777,24 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"\x11BGP_STATE_CONNECT\x10\x02\x12\x14\n" +
 	"\x10BGP_STATE_ACTIVE\x10\x03\x12\x12\n" +
 	"\x0eBGP_STATE_OPEN\x10\x04\x12\x19\n" +
-	"\x15BGP_STATE_ESTABLISHED\x10\x05*X\n" +
+	"\x15BGP_STATE_ESTABLISHED\x10\x05*u\n" +
+	"\x10VtepReachability\x12\x1d\n" +
+	"\x19VTEP_REACHABILITY_UNKNOWN\x10\x00\x12\x1f\n" +
+	"\x1bVTEP_REACHABILITY_REACHABLE\x10\x01\x12!\n" +
+	"\x1dVTEP_REACHABILITY_UNREACHABLE\x10\x02*\x81\x01\n" +
+	"\n" +
+	"IpProtocol\x12\x1b\n" +
+	"\x17IP_PROTOCOL_UNSPECIFIED\x10\x00\x12\x13\n" +
+	"\x0fIP_PROTOCOL_TCP\x10\x01\x12\x13\n" +
+	"\x0fIP_PROTOCOL_UDP\x10\x02\x12\x14\n" +
+	"\x10IP_PROTOCOL_ICMP\x10\x03\x12\x16\n" +
+	"\x12IP_PROTOCOL_ICMPV6\x10\x04*\x99\x01\n" +
+	"\rRouteProtocol\x12\x1e\n" +
+	"\x1aROUTE_PROTOCOL_UNSPECIFIED\x10\x00\x12\x1c\n" +
+	"\x18ROUTE_PROTOCOL_CONNECTED\x10\x01\x12\x19\n" +
+	"\x15ROUTE_PROTOCOL_STATIC\x10\x02\x12\x16\n" +
+	"\x12ROUTE_PROTOCOL_BGP\x10\x03\x12\x17\n" +
+	"\x13ROUTE_PROTOCOL_OSPF\x10\x04*X\n" +
 	"\x06IfType\x12\x14\n" +
 	"\x10IF_TYPE_ETHERNET\x10\x00\x12\x10\n" +
 	"\fIF_TYPE_VLAN\x10\x01\x12\x14\n" +
@@ -4135,12 +5814,15 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"\aWARNING\x10\x02\x12\b\n" +
 	"\x04INFO\x10\x03\x12\t\n" +
 	"\x05DEBUG\x10\x04\x12\t\n" +
-	"\x05TRACE\x10\x052\xd5\x02\n" +
+	"\x05TRACE\x10\x052\xb3\x04\n" +
 	"\rConfigService\x12<\n" +
 	"\tGetConfig\x12\x18.config.GetConfigRequest\x1a\x15.config.GatewayConfig\x12^\n" +
 	"\x13GetConfigGeneration\x12\".config.GetConfigGenerationRequest\x1a#.config.GetConfigGenerationResponse\x12I\n" +
 	"\fUpdateConfig\x12\x1b.config.UpdateConfigRequest\x1a\x1c.config.UpdateConfigResponse\x12[\n" +
-	"\x12GetDataplaneStatus\x12!.config.GetDataplaneStatusRequest\x1a\".config.GetDataplaneStatusResponseB\x0fZ\rpkg/dataplaneb\x06proto3"
+	"\x12GetDataplaneStatus\x12!.config.GetDataplaneStatusRequest\x1a\".config.GetDataplaneStatusResponse\x12R\n" +
+	"\x0fListNatSessions\x12\x1e.config.ListNatSessionsRequest\x1a\x1f.config.ListNatSessionsResponse\x12@\n" +
+	"\tGetRoutes\x12\x18.config.GetRoutesRequest\x1a\x19.config.GetRoutesResponse\x12F\n" +
+	"\vLookupRoute\x12\x1a.config.LookupRouteRequest\x1a\x1b.config.LookupRouteResponseB\x0fZ\rpkg/dataplaneb\x06proto3"
 
 var (
 	file_proto_dataplane_proto_rawDescOnce sync.Once
@@ -4154,8 +5836,8 @@ func file_proto_dataplane_proto_rawDescGZIP() []byte {
 	return file_proto_dataplane_proto_rawDescData
 }
 
-var file_proto_dataplane_proto_enumTypes = make([]protoimpl.EnumInfo, 12)
-var file_proto_dataplane_proto_msgTypes = make([]protoimpl.MessageInfo, 57)
+var file_proto_dataplane_proto_enumTypes = make([]protoimpl.EnumInfo, 16)
+var file_proto_dataplane_proto_msgTypes = make([]protoimpl.MessageInfo, 78)
 var file_proto_dataplane_proto_goTypes = []any{
 	(Error)(0),                          // 0: config.Error
 	(OspfNetworkType)(0),                // 1: config.OspfNetworkType
@@ -4163,157 +5845,218 @@ var file_proto_dataplane_proto_goTypes = []any{
 	(InterfaceAdminStatusType)(0),       // 3: config.InterfaceAdminStatusType
 	(ZebraStatusType)(0),                // 4: config.ZebraStatusType
 	(FrrAgentStatusType)(0),             // 5: config.FrrAgentStatusType
-	(DataplaneStatusType)(0),            // 6: config.DataplaneStatusType
-	(BgpNeighborSessionState)(0),        // 7: config.BgpNeighborSessionState
-	(IfType)(0),                         // 8: config.IfType
-	(IfRole)(0),                         // 9: config.IfRole
-	(BgpAF)(0),                          // 10: config.BgpAF
-	(LogLevel)(0),                       // 11: config.LogLevel
-	(*GetConfigRequest)(nil),            // 12: config.GetConfigRequest
-	(*UpdateConfigRequest)(nil),         // 13: config.UpdateConfigRequest
-	(*UpdateConfigResponse)(nil),        // 14: config.UpdateConfigResponse
-	(*GetConfigGenerationRequest)(nil),  // 15: config.GetConfigGenerationRequest
-	(*GetConfigGenerationResponse)(nil), // 16: config.GetConfigGenerationResponse
-	(*OspfInterface)(nil),               // 17: config.OspfInterface
-	(*OspfConfig)(nil),                  // 18: config.OspfConfig
-	(*GetDataplaneStatusRequest)(nil),   // 19: config.GetDataplaneStatusRequest
-	(*InterfaceStatus)(nil),             // 20: config.InterfaceStatus
-	(*FrrStatus)(nil),                   // 21: config.FrrStatus
-	(*DataplaneStatusInfo)(nil),         // 22: config.DataplaneStatusInfo
-	(*InterfaceCounters)(nil),           // 23: config.InterfaceCounters
-	(*InterfaceRuntimeStatus)(nil),      // 24: config.InterfaceRuntimeStatus
-	(*BgpMessageCounters)(nil),          // 25: config.BgpMessageCounters
-	(*BgpMessages)(nil),                 // 26: config.BgpMessages
-	(*BgpNeighborPrefixes)(nil),         // 27: config.BgpNeighborPrefixes
-	(*BgpNeighborStatus)(nil),           // 28: config.BgpNeighborStatus
-	(*BgpVrfStatus)(nil),                // 29: config.BgpVrfStatus
-	(*BgpStatus)(nil),                   // 30: config.BgpStatus
-	(*VpcInterfaceStatus)(nil),          // 31: config.VpcInterfaceStatus
-	(*VpcStatus)(nil),                   // 32: config.VpcStatus
-	(*VpcPeeringCounters)(nil),          // 33: config.VpcPeeringCounters
-	(*VpcCounters)(nil),                 // 34: config.VpcCounters
-	(*GetDataplaneStatusResponse)(nil),  // 35: config.GetDataplaneStatusResponse
-	(*Interface)(nil),                   // 36: config.Interface
-	(*GatewayGroupMember)(nil),          // 37: config.GatewayGroupMember
-	(*GatewayGroup)(nil),                // 38: config.GatewayGroup
-	(*PeeringIPs)(nil),                  // 39: config.PeeringIPs
-	(*PeeringAs)(nil),                   // 40: config.PeeringAs
-	(*PeeringStatelessNAT)(nil),         // 41: config.PeeringStatelessNAT
-	(*PeeringStatefulNAT)(nil),          // 42: config.PeeringStatefulNAT
-	(*Expose)(nil),                      // 43: config.Expose
-	(*PeeringEntryFor)(nil),             // 44: config.PeeringEntryFor
-	(*VpcPeering)(nil),                  // 45: config.VpcPeering
-	(*VPC)(nil),                         // 46: config.VPC
-	(*Overlay)(nil),                     // 47: config.Overlay
-	(*BgpAddressFamilyIPv4)(nil),        // 48: config.BgpAddressFamilyIPv4
-	(*BgpAddressFamilyIPv6)(nil),        // 49: config.BgpAddressFamilyIPv6
-	(*BgpAddressFamilyL2VpnEvpn)(nil),   // 50: config.BgpAddressFamilyL2vpnEvpn
-	(*BgpNeighborUpdateSource)(nil),     // 51: config.BgpNeighborUpdateSource
-	(*BgpNeighbor)(nil),                 // 52: config.BgpNeighbor
-	(*RouteMap)(nil),                    // 53: config.RouteMap
-	(*RouterConfig)(nil),                // 54: config.RouterConfig
-	(*VRF)(nil),                         // 55: config.VRF
-	(*Underlay)(nil),                    // 56: config.Underlay
-	(*TracingConfig)(nil),               // 57: config.TracingConfig
-	(*Device)(nil),                      // 58: config.Device
-	(*GatewayConfig)(nil),               // 59: config.GatewayConfig
-	nil,                                 // 60: config.BgpVrfStatus.NeighborsEntry
-	nil,                                 // 61: config.BgpStatus.VrfsEntry
-	nil,                                 // 62: config.VpcStatus.InterfacesEntry
-	nil,                                 // 63: config.GetDataplaneStatusResponse.InterfaceRuntimeEntry
-	nil,                                 // 64: config.GetDataplaneStatusResponse.VpcsEntry
-	nil,                                 // 65: config.GetDataplaneStatusResponse.VpcPeeringCountersEntry
-	nil,                                 // 66: config.GetDataplaneStatusResponse.VpcCountersEntry
-	nil,                                 // 67: config.TracingConfig.TaglevelEntry
-	nil,                                 // 68: config.GatewayConfig.CommunitiesEntry
-	(*durationpb.Duration)(nil),         // 69: google.protobuf.Duration
+	(FrrLifecycleState)(0),              // 6: config.FrrLifecycleState
+	(DataplaneStatusType)(0),            // 7: config.DataplaneStatusType
+	(BgpNeighborSessionState)(0),        // 8: config.BgpNeighborSessionState
+	(VtepReachability)(0),               // 9: config.VtepReachability
+	(IpProtocol)(0),                     // 10: config.IpProtocol
+	(RouteProtocol)(0),                  // 11: config.RouteProtocol
+	(IfType)(0),                         // 12: config.IfType
+	(IfRole)(0),                         // 13: config.IfRole
+	(BgpAF)(0),                          // 14: config.BgpAF
+	(LogLevel)(0),                       // 15: config.LogLevel
+	(*GetConfigRequest)(nil),            // 16: config.GetConfigRequest
+	(*UpdateConfigRequest)(nil),         // 17: config.UpdateConfigRequest
+	(*UpdateConfigResponse)(nil),        // 18: config.UpdateConfigResponse
+	(*GetConfigGenerationRequest)(nil),  // 19: config.GetConfigGenerationRequest
+	(*GetConfigGenerationResponse)(nil), // 20: config.GetConfigGenerationResponse
+	(*OspfInterface)(nil),               // 21: config.OspfInterface
+	(*OspfConfig)(nil),                  // 22: config.OspfConfig
+	(*GetDataplaneStatusRequest)(nil),   // 23: config.GetDataplaneStatusRequest
+	(*InterfaceStatus)(nil),             // 24: config.InterfaceStatus
+	(*FrrStatus)(nil),                   // 25: config.FrrStatus
+	(*DataplaneStatusInfo)(nil),         // 26: config.DataplaneStatusInfo
+	(*InterfaceQueueCounters)(nil),      // 27: config.InterfaceQueueCounters
+	(*InterfaceCounters)(nil),           // 28: config.InterfaceCounters
+	(*InterfaceRuntimeStatus)(nil),      // 29: config.InterfaceRuntimeStatus
+	(*BgpMessageCounters)(nil),          // 30: config.BgpMessageCounters
+	(*BgpMessages)(nil),                 // 31: config.BgpMessages
+	(*BgpNeighborPrefixes)(nil),         // 32: config.BgpNeighborPrefixes
+	(*BgpNeighborStatus)(nil),           // 33: config.BgpNeighborStatus
+	(*BgpVrfStatus)(nil),                // 34: config.BgpVrfStatus
+	(*BgpStatus)(nil),                   // 35: config.BgpStatus
+	(*VpcInterfaceStatus)(nil),          // 36: config.VpcInterfaceStatus
+	(*VpcStatus)(nil),                   // 37: config.VpcStatus
+	(*RemoteVtepStatus)(nil),            // 38: config.RemoteVtepStatus
+	(*VniStatus)(nil),                   // 39: config.VniStatus
+	(*VpcPeeringCounters)(nil),          // 40: config.VpcPeeringCounters
+	(*VpcCounters)(nil),                 // 41: config.VpcCounters
+	(*ExposeCounters)(nil),              // 42: config.ExposeCounters
+	(*FiveTuple)(nil),                   // 43: config.FiveTuple
+	(*NatSession)(nil),                  // 44: config.NatSession
+	(*NatSessionCounters)(nil),          // 45: config.NatSessionCounters
+	(*ListNatSessionsRequest)(nil),      // 46: config.ListNatSessionsRequest
+	(*ListNatSessionsResponse)(nil),     // 47: config.ListNatSessionsResponse
+	(*RouteNextHop)(nil),                // 48: config.RouteNextHop
+	(*Route)(nil),                       // 49: config.Route
+	(*GetRoutesRequest)(nil),            // 50: config.GetRoutesRequest
+	(*GetRoutesResponse)(nil),           // 51: config.GetRoutesResponse
+	(*LookupRouteRequest)(nil),          // 52: config.LookupRouteRequest
+	(*LookupRouteResponse)(nil),         // 53: config.LookupRouteResponse
+	(*GetDataplaneStatusResponse)(nil),  // 54: config.GetDataplaneStatusResponse
+	(*Interface)(nil),                   // 55: config.Interface
+	(*GatewayGroupMember)(nil),          // 56: config.GatewayGroupMember
+	(*GatewayGroup)(nil),                // 57: config.GatewayGroup
+	(*PeeringIPs)(nil),                  // 58: config.PeeringIPs
+	(*PeeringAs)(nil),                   // 59: config.PeeringAs
+	(*PeeringStatelessNAT)(nil),         // 60: config.PeeringStatelessNAT
+	(*PeeringStatefulNAT)(nil),          // 61: config.PeeringStatefulNAT
+	(*Expose)(nil),                      // 62: config.Expose
+	(*PeeringEntryFor)(nil),             // 63: config.PeeringEntryFor
+	(*VpcPeering)(nil),                  // 64: config.VpcPeering
+	(*VPC)(nil),                         // 65: config.VPC
+	(*Overlay)(nil),                     // 66: config.Overlay
+	(*BgpAddressFamilyIPv4)(nil),        // 67: config.BgpAddressFamilyIPv4
+	(*BgpAddressFamilyIPv6)(nil),        // 68: config.BgpAddressFamilyIPv6
+	(*BgpAddressFamilyL2VpnEvpn)(nil),   // 69: config.BgpAddressFamilyL2vpnEvpn
+	(*BgpNeighborUpdateSource)(nil),     // 70: config.BgpNeighborUpdateSource
+	(*BgpNeighbor)(nil),                 // 71: config.BgpNeighbor
+	(*RouteMap)(nil),                    // 72: config.RouteMap
+	(*RouterConfig)(nil),                // 73: config.RouterConfig
+	(*VRF)(nil),                         // 74: config.VRF
+	(*Underlay)(nil),                    // 75: config.Underlay
+	(*TracingConfig)(nil),               // 76: config.TracingConfig
+	(*Device)(nil),                      // 77: config.Device
+	(*GatewayConfig)(nil),               // 78: config.GatewayConfig
+	nil,                                 // 79: config.BgpVrfStatus.NeighborsEntry
+	nil,                                 // 80: config.BgpStatus.VrfsEntry
+	nil,                                 // 81: config.VpcStatus.InterfacesEntry
+	nil,                                 // 82: config.VpcPeeringCounters.DropReasonsEntry
+	nil,                                 // 83: config.VpcCounters.DropReasonsEntry
+	nil,                                 // 84: config.ExposeCounters.DropReasonsEntry
+	nil,                                 // 85: config.GetDataplaneStatusResponse.InterfaceRuntimeEntry
+	nil,                                 // 86: config.GetDataplaneStatusResponse.VpcsEntry
+	nil,                                 // 87: config.GetDataplaneStatusResponse.VpcPeeringCountersEntry
+	nil,                                 // 88: config.GetDataplaneStatusResponse.VpcCountersEntry
+	nil,                                 // 89: config.GetDataplaneStatusResponse.NatSessionsEntry
+	nil,                                 // 90: config.GetDataplaneStatusResponse.RemoteVtepsEntry
+	nil,                                 // 91: config.GetDataplaneStatusResponse.VnisEntry
+	nil,                                 // 92: config.TracingConfig.TaglevelEntry
+	nil,                                 // 93: config.GatewayConfig.CommunitiesEntry
+	(*timestamppb.Timestamp)(nil),       // 94: google.protobuf.Timestamp
+	(*durationpb.Duration)(nil),         // 95: google.protobuf.Duration
 }
 var file_proto_dataplane_proto_depIdxs = []int32{
-	59, // 0: config.UpdateConfigRequest.config:type_name -> config.GatewayConfig
-	0,  // 1: config.UpdateConfigResponse.error:type_name -> config.Error
-	1,  // 2: config.OspfInterface.network_type:type_name -> config.OspfNetworkType
-	2,  // 3: config.InterfaceStatus.oper_status:type_name -> config.InterfaceOperStatusType
-	3,  // 4: config.InterfaceStatus.admin_status:type_name -> config.InterfaceAdminStatusType
-	4,  // 5: config.FrrStatus.zebra_status:type_name -> config.ZebraStatusType
-	5,  // 6: config.FrrStatus.frr_agent_status:type_name -> config.FrrAgentStatusType
-	6,  // 7: config.DataplaneStatusInfo.status:type_name -> config.DataplaneStatusType
-	3,  // 8: config.InterfaceRuntimeStatus.admin_status:type_name -> config.InterfaceAdminStatusType
-	2,  // 9: config.InterfaceRuntimeStatus.oper_status:type_name -> config.InterfaceOperStatusType
-	23, // 10: config.InterfaceRuntimeStatus.counters:type_name -> config.InterfaceCounters
-	25, // 11: config.BgpMessages.received:type_name -> config.BgpMessageCounters
-	25, // 12: config.BgpMessages.sent:type_name -> config.BgpMessageCounters
-	7,  // 13: config.BgpNeighborStatus.session_state:type_name -> config.BgpNeighborSessionState
-	26, // 14: config.BgpNeighborStatus.messages:type_name -> config.BgpMessages
-	27, // 15: config.BgpNeighborStatus.ipv4_unicast_prefixes:type_name -> config.BgpNeighborPrefixes
-	27, // 16: config.BgpNeighborStatus.ipv6_unicast_prefixes:type_name -> config.BgpNeighborPrefixes
-	27, // 17: config.BgpNeighborStatus.l2vpn_evpn_prefixes:type_name -> config.BgpNeighborPrefixes
-	60, // 18: config.BgpVrfStatus.neighbors:type_name -> config.BgpVrfStatus.NeighborsEntry
-	61, // 19: config.BgpStatus.vrfs:type_name -> config.BgpStatus.VrfsEntry
-	3,  // 20: config.VpcInterfaceStatus.admin_status:type_name -> config.InterfaceAdminStatusType
-	2,  // 21: config.VpcInterfaceStatus.oper_status:type_name -> config.InterfaceOperStatusType
-	62, // 22: config.VpcStatus.interfaces:type_name -> config.VpcStatus.InterfacesEntry
-	20, // 23: config.GetDataplaneStatusResponse.interface_statuses:type_name -> config.InterfaceStatus
-	21, // 24: config.GetDataplaneStatusResponse.frr_status:type_name -> config.FrrStatus
-	22, // 25: config.GetDataplaneStatusResponse.dataplane_status:type_name -> config.DataplaneStatusInfo
-	63, // 26: config.GetDataplaneStatusResponse.interface_runtime:type_name -> config.GetDataplaneStatusResponse.InterfaceRuntimeEntry
-	30, // 27: config.GetDataplaneStatusResponse.bgp:type_name -> config.BgpStatus
-	64, // 28: config.GetDataplaneStatusResponse.vpcs:type_name -> config.GetDataplaneStatusResponse.VpcsEntry
-	65, // 29: config.GetDataplaneStatusResponse.vpc_peering_counters:type_name -> config.GetDataplaneStatusResponse.VpcPeeringCountersEntry
-	66, // 30: config.GetDataplaneStatusResponse.vpc_counters:type_name -> config.GetDataplaneStatusResponse.VpcCountersEntry
-	8,  // 31: config.Interface.type:type_name -> config.IfType
-	9,  // 32: config.Interface.role:type_name -> config.IfRole
-	17, // 33: config.Interface.ospf:type_name -> config.OspfInterface
-	37, // 34: config.GatewayGroup.members:type_name -> config.GatewayGroupMember
-	69, // 35: config.PeeringStatefulNAT.idleTimeout:type_name -> google.protobuf.Duration
-	39, // 36: config.Expose.ips:type_name -> config.PeeringIPs
-	40, // 37: config.Expose.as:type_name -> config.PeeringAs
-	41, // 38: config.Expose.stateless:type_name -> config.PeeringStatelessNAT
-	42, // 39: config.Expose.stateful:type_name -> config.PeeringStatefulNAT
-	43, // 40: config.PeeringEntryFor.expose:type_name -> config.Expose
-	44, // 41: config.VpcPeering.for:type_name -> config.PeeringEntryFor
-	36, // 42: config.VPC.interfaces:type_name -> config.Interface
-	46, // 43: config.Overlay.vpcs:type_name -> config.VPC
-	45, // 44: config.Overlay.peerings:type_name -> config.VpcPeering
-	10, // 45: config.BgpNeighbor.af_activate:type_name -> config.BgpAF
-	51, // 46: config.BgpNeighbor.update_source:type_name -> config.BgpNeighborUpdateSource
-	52, // 47: config.RouterConfig.neighbors:type_name -> config.BgpNeighbor
-	48, // 48: config.RouterConfig.ipv4_unicast:type_name -> config.BgpAddressFamilyIPv4
-	49, // 49: config.RouterConfig.ipv6_unicast:type_name -> config.BgpAddressFamilyIPv6
-	50, // 50: config.RouterConfig.l2vpn_evpn:type_name -> config.BgpAddressFamilyL2vpnEvpn
-	53, // 51: config.RouterConfig.route_maps:type_name -> config.RouteMap
-	36, // 52: config.VRF.interfaces:type_name -> config.Interface
-	54, // 53: config.VRF.router:type_name -> config.RouterConfig
-	18, // 54: config.VRF.ospf:type_name -> config.OspfConfig
-	55, // 55: config.Underlay.vrfs:type_name -> config.VRF
-	11, // 56: config.TracingConfig.default:type_name -> config.LogLevel
-	67, // 57: config.TracingConfig.taglevel:type_name -> config.TracingConfig.TaglevelEntry
-	57, // 58: config.Device.tracing:type_name -> config.TracingConfig
-	58, // 59: config.GatewayConfig.device:type_name -> config.Device
-	56, // 60: config.GatewayConfig.underlay:type_name -> config.Underlay
-	47, // 61: config.GatewayConfig.overlay:type_name -> config.Overlay
-	38, // 62: config.GatewayConfig.gw_groups:type_name -> config.GatewayGroup
-	68, // 63: config.GatewayConfig.communities:type_name -> config.GatewayConfig.CommunitiesEntry
-	28, // 64: config.BgpVrfStatus.NeighborsEntry.value:type_name -> config.BgpNeighborStatus
-	29, // 65: config.BgpStatus.VrfsEntry.value:type_name -> config.BgpVrfStatus
-	31, // 66: config.VpcStatus.InterfacesEntry.value:type_name -> config.VpcInterfaceStatus
-	24, // 67: config.GetDataplaneStatusResponse.InterfaceRuntimeEntry.value:type_name -> config.InterfaceRuntimeStatus
-	32, // 68: config.GetDataplaneStatusResponse.VpcsEntry.value:type_name -> config.VpcStatus
-	33, // 69: config.GetDataplaneStatusResponse.VpcPeeringCountersEntry.value:type_name -> config.VpcPeeringCounters
-	34, // 70: config.GetDataplaneStatusResponse.VpcCountersEntry.value:type_name -> config.VpcCounters
-	11, // 71: config.TracingConfig.TaglevelEntry.value:type_name -> config.LogLevel
-	12, // 72: config.ConfigService.GetConfig:input_type -> config.GetConfigRequest
-	15, // 73: config.ConfigService.GetConfigGeneration:input_type -> config.GetConfigGenerationRequest
-	13, // 74: config.ConfigService.UpdateConfig:input_type -> config.UpdateConfigRequest
-	19, // 75: config.ConfigService.GetDataplaneStatus:input_type -> config.GetDataplaneStatusRequest
-	59, // 76: config.ConfigService.GetConfig:output_type -> config.GatewayConfig
-	16, // 77: config.ConfigService.GetConfigGeneration:output_type -> config.GetConfigGenerationResponse
-	14, // 78: config.ConfigService.UpdateConfig:output_type -> config.UpdateConfigResponse
-	35, // 79: config.ConfigService.GetDataplaneStatus:output_type -> config.GetDataplaneStatusResponse
-	76, // [76:80] is the sub-list for method output_type
-	72, // [72:76] is the sub-list for method input_type
-	72, // [72:72] is the sub-list for extension type_name
-	72, // [72:72] is the sub-list for extension extendee
-	0,  // [0:72] is the sub-list for field type_name
+	78,  // 0: config.UpdateConfigRequest.config:type_name -> config.GatewayConfig
+	0,   // 1: config.UpdateConfigResponse.error:type_name -> config.Error
+	1,   // 2: config.OspfInterface.network_type:type_name -> config.OspfNetworkType
+	2,   // 3: config.InterfaceStatus.oper_status:type_name -> config.InterfaceOperStatusType
+	3,   // 4: config.InterfaceStatus.admin_status:type_name -> config.InterfaceAdminStatusType
+	4,   // 5: config.FrrStatus.zebra_status:type_name -> config.ZebraStatusType
+	5,   // 6: config.FrrStatus.frr_agent_status:type_name -> config.FrrAgentStatusType
+	6,   // 7: config.FrrStatus.state:type_name -> config.FrrLifecycleState
+	94,  // 8: config.FrrStatus.state_since:type_name -> google.protobuf.Timestamp
+	94,  // 9: config.FrrStatus.zebra_status_since:type_name -> google.protobuf.Timestamp
+	94,  // 10: config.FrrStatus.frr_agent_status_since:type_name -> google.protobuf.Timestamp
+	7,   // 11: config.DataplaneStatusInfo.status:type_name -> config.DataplaneStatusType
+	27,  // 12: config.InterfaceCounters.rx_queues:type_name -> config.InterfaceQueueCounters
+	27,  // 13: config.InterfaceCounters.tx_queues:type_name -> config.InterfaceQueueCounters
+	3,   // 14: config.InterfaceRuntimeStatus.admin_status:type_name -> config.InterfaceAdminStatusType
+	2,   // 15: config.InterfaceRuntimeStatus.oper_status:type_name -> config.InterfaceOperStatusType
+	28,  // 16: config.InterfaceRuntimeStatus.counters:type_name -> config.InterfaceCounters
+	30,  // 17: config.BgpMessages.received:type_name -> config.BgpMessageCounters
+	30,  // 18: config.BgpMessages.sent:type_name -> config.BgpMessageCounters
+	8,   // 19: config.BgpNeighborStatus.session_state:type_name -> config.BgpNeighborSessionState
+	31,  // 20: config.BgpNeighborStatus.messages:type_name -> config.BgpMessages
+	32,  // 21: config.BgpNeighborStatus.ipv4_unicast_prefixes:type_name -> config.BgpNeighborPrefixes
+	32,  // 22: config.BgpNeighborStatus.ipv6_unicast_prefixes:type_name -> config.BgpNeighborPrefixes
+	32,  // 23: config.BgpNeighborStatus.l2vpn_evpn_prefixes:type_name -> config.BgpNeighborPrefixes
+	79,  // 24: config.BgpVrfStatus.neighbors:type_name -> config.BgpVrfStatus.NeighborsEntry
+	80,  // 25: config.BgpStatus.vrfs:type_name -> config.BgpStatus.VrfsEntry
+	3,   // 26: config.VpcInterfaceStatus.admin_status:type_name -> config.InterfaceAdminStatusType
+	2,   // 27: config.VpcInterfaceStatus.oper_status:type_name -> config.InterfaceOperStatusType
+	81,  // 28: config.VpcStatus.interfaces:type_name -> config.VpcStatus.InterfacesEntry
+	9,   // 29: config.RemoteVtepStatus.reachability:type_name -> config.VtepReachability
+	82,  // 30: config.VpcPeeringCounters.drop_reasons:type_name -> config.VpcPeeringCounters.DropReasonsEntry
+	83,  // 31: config.VpcCounters.drop_reasons:type_name -> config.VpcCounters.DropReasonsEntry
+	84,  // 32: config.ExposeCounters.drop_reasons:type_name -> config.ExposeCounters.DropReasonsEntry
+	10,  // 33: config.FiveTuple.protocol:type_name -> config.IpProtocol
+	43,  // 34: config.NatSession.original:type_name -> config.FiveTuple
+	43,  // 35: config.NatSession.translated:type_name -> config.FiveTuple
+	95,  // 36: config.NatSession.idle_time:type_name -> google.protobuf.Duration
+	95,  // 37: config.NatSession.remaining_timeout:type_name -> google.protobuf.Duration
+	10,  // 38: config.ListNatSessionsRequest.protocol:type_name -> config.IpProtocol
+	44,  // 39: config.ListNatSessionsResponse.sessions:type_name -> config.NatSession
+	48,  // 40: config.Route.next_hops:type_name -> config.RouteNextHop
+	11,  // 41: config.Route.protocol:type_name -> config.RouteProtocol
+	11,  // 42: config.GetRoutesRequest.protocol:type_name -> config.RouteProtocol
+	49,  // 43: config.GetRoutesResponse.routes:type_name -> config.Route
+	49,  // 44: config.LookupRouteResponse.route:type_name -> config.Route
+	24,  // 45: config.GetDataplaneStatusResponse.interface_statuses:type_name -> config.InterfaceStatus
+	25,  // 46: config.GetDataplaneStatusResponse.frr_status:type_name -> config.FrrStatus
+	26,  // 47: config.GetDataplaneStatusResponse.dataplane_status:type_name -> config.DataplaneStatusInfo
+	85,  // 48: config.GetDataplaneStatusResponse.interface_runtime:type_name -> config.GetDataplaneStatusResponse.InterfaceRuntimeEntry
+	35,  // 49: config.GetDataplaneStatusResponse.bgp:type_name -> config.BgpStatus
+	86,  // 50: config.GetDataplaneStatusResponse.vpcs:type_name -> config.GetDataplaneStatusResponse.VpcsEntry
+	87,  // 51: config.GetDataplaneStatusResponse.vpc_peering_counters:type_name -> config.GetDataplaneStatusResponse.VpcPeeringCountersEntry
+	88,  // 52: config.GetDataplaneStatusResponse.vpc_counters:type_name -> config.GetDataplaneStatusResponse.VpcCountersEntry
+	89,  // 53: config.GetDataplaneStatusResponse.nat_sessions:type_name -> config.GetDataplaneStatusResponse.NatSessionsEntry
+	42,  // 54: config.GetDataplaneStatusResponse.expose_counters:type_name -> config.ExposeCounters
+	90,  // 55: config.GetDataplaneStatusResponse.remote_vteps:type_name -> config.GetDataplaneStatusResponse.RemoteVtepsEntry
+	91,  // 56: config.GetDataplaneStatusResponse.vnis:type_name -> config.GetDataplaneStatusResponse.VnisEntry
+	12,  // 57: config.Interface.type:type_name -> config.IfType
+	13,  // 58: config.Interface.role:type_name -> config.IfRole
+	21,  // 59: config.Interface.ospf:type_name -> config.OspfInterface
+	56,  // 60: config.GatewayGroup.members:type_name -> config.GatewayGroupMember
+	95,  // 61: config.PeeringStatefulNAT.idleTimeout:type_name -> google.protobuf.Duration
+	58,  // 62: config.Expose.ips:type_name -> config.PeeringIPs
+	59,  // 63: config.Expose.as:type_name -> config.PeeringAs
+	60,  // 64: config.Expose.stateless:type_name -> config.PeeringStatelessNAT
+	61,  // 65: config.Expose.stateful:type_name -> config.PeeringStatefulNAT
+	62,  // 66: config.PeeringEntryFor.expose:type_name -> config.Expose
+	63,  // 67: config.VpcPeering.for:type_name -> config.PeeringEntryFor
+	55,  // 68: config.VPC.interfaces:type_name -> config.Interface
+	65,  // 69: config.Overlay.vpcs:type_name -> config.VPC
+	64,  // 70: config.Overlay.peerings:type_name -> config.VpcPeering
+	14,  // 71: config.BgpNeighbor.af_activate:type_name -> config.BgpAF
+	70,  // 72: config.BgpNeighbor.update_source:type_name -> config.BgpNeighborUpdateSource
+	71,  // 73: config.RouterConfig.neighbors:type_name -> config.BgpNeighbor
+	67,  // 74: config.RouterConfig.ipv4_unicast:type_name -> config.BgpAddressFamilyIPv4
+	68,  // 75: config.RouterConfig.ipv6_unicast:type_name -> config.BgpAddressFamilyIPv6
+	69,  // 76: config.RouterConfig.l2vpn_evpn:type_name -> config.BgpAddressFamilyL2vpnEvpn
+	72,  // 77: config.RouterConfig.route_maps:type_name -> config.RouteMap
+	55,  // 78: config.VRF.interfaces:type_name -> config.Interface
+	73,  // 79: config.VRF.router:type_name -> config.RouterConfig
+	22,  // 80: config.VRF.ospf:type_name -> config.OspfConfig
+	74,  // 81: config.Underlay.vrfs:type_name -> config.VRF
+	15,  // 82: config.TracingConfig.default:type_name -> config.LogLevel
+	92,  // 83: config.TracingConfig.taglevel:type_name -> config.TracingConfig.TaglevelEntry
+	76,  // 84: config.Device.tracing:type_name -> config.TracingConfig
+	77,  // 85: config.GatewayConfig.device:type_name -> config.Device
+	75,  // 86: config.GatewayConfig.underlay:type_name -> config.Underlay
+	66,  // 87: config.GatewayConfig.overlay:type_name -> config.Overlay
+	57,  // 88: config.GatewayConfig.gw_groups:type_name -> config.GatewayGroup
+	93,  // 89: config.GatewayConfig.communities:type_name -> config.GatewayConfig.CommunitiesEntry
+	33,  // 90: config.BgpVrfStatus.NeighborsEntry.value:type_name -> config.BgpNeighborStatus
+	34,  // 91: config.BgpStatus.VrfsEntry.value:type_name -> config.BgpVrfStatus
+	36,  // 92: config.VpcStatus.InterfacesEntry.value:type_name -> config.VpcInterfaceStatus
+	29,  // 93: config.GetDataplaneStatusResponse.InterfaceRuntimeEntry.value:type_name -> config.InterfaceRuntimeStatus
+	37,  // 94: config.GetDataplaneStatusResponse.VpcsEntry.value:type_name -> config.VpcStatus
+	40,  // 95: config.GetDataplaneStatusResponse.VpcPeeringCountersEntry.value:type_name -> config.VpcPeeringCounters
+	41,  // 96: config.GetDataplaneStatusResponse.VpcCountersEntry.value:type_name -> config.VpcCounters
+	45,  // 97: config.GetDataplaneStatusResponse.NatSessionsEntry.value:type_name -> config.NatSessionCounters
+	38,  // 98: config.GetDataplaneStatusResponse.RemoteVtepsEntry.value:type_name -> config.RemoteVtepStatus
+	39,  // 99: config.GetDataplaneStatusResponse.VnisEntry.value:type_name -> config.VniStatus
+	15,  // 100: config.TracingConfig.TaglevelEntry.value:type_name -> config.LogLevel
+	16,  // 101: config.ConfigService.GetConfig:input_type -> config.GetConfigRequest
+	19,  // 102: config.ConfigService.GetConfigGeneration:input_type -> config.GetConfigGenerationRequest
+	17,  // 103: config.ConfigService.UpdateConfig:input_type -> config.UpdateConfigRequest
+	23,  // 104: config.ConfigService.GetDataplaneStatus:input_type -> config.GetDataplaneStatusRequest
+	46,  // 105: config.ConfigService.ListNatSessions:input_type -> config.ListNatSessionsRequest
+	50,  // 106: config.ConfigService.GetRoutes:input_type -> config.GetRoutesRequest
+	52,  // 107: config.ConfigService.LookupRoute:input_type -> config.LookupRouteRequest
+	78,  // 108: config.ConfigService.GetConfig:output_type -> config.GatewayConfig
+	20,  // 109: config.ConfigService.GetConfigGeneration:output_type -> config.GetConfigGenerationResponse
+	18,  // 110: config.ConfigService.UpdateConfig:output_type -> config.UpdateConfigResponse
+	54,  // 111: config.ConfigService.GetDataplaneStatus:output_type -> config.GetDataplaneStatusResponse
+	47,  // 112: config.ConfigService.ListNatSessions:output_type -> config.ListNatSessionsResponse
+	51,  // 113: config.ConfigService.GetRoutes:output_type -> config.GetRoutesResponse
+	53,  // 114: config.ConfigService.LookupRoute:output_type -> config.LookupRouteResponse
+	108, // [108:115] is the sub-list for method output_type
+	101, // [101:108] is the sub-list for method input_type
+	101, // [101:101] is the sub-list for extension type_name
+	101, // [101:101] is the sub-list for extension extendee
+	0,   // [0:101] is the sub-list for field type_name
 }
 
 func init() { file_proto_dataplane_proto_init() }
@@ -4323,32 +6066,40 @@ func file_proto_dataplane_proto_init() {
 	}
 	file_proto_dataplane_proto_msgTypes[5].OneofWrappers = []any{}
 	file_proto_dataplane_proto_msgTypes[6].OneofWrappers = []any{}
-	file_proto_dataplane_proto_msgTypes[24].OneofWrappers = []any{}
-	file_proto_dataplane_proto_msgTypes[27].OneofWrappers = []any{
+	file_proto_dataplane_proto_msgTypes[34].OneofWrappers = []any{
+		(*GetRoutesRequest_Vrf)(nil),
+		(*GetRoutesRequest_Vpc)(nil),
+	}
+	file_proto_dataplane_proto_msgTypes[36].OneofWrappers = []any{
+		(*LookupRouteRequest_Vrf)(nil),
+		(*LookupRouteRequest_Vpc)(nil),
+	}
+	file_proto_dataplane_proto_msgTypes[39].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[42].OneofWrappers = []any{
 		(*PeeringIPs_Cidr)(nil),
 		(*PeeringIPs_Not)(nil),
 	}
-	file_proto_dataplane_proto_msgTypes[28].OneofWrappers = []any{
+	file_proto_dataplane_proto_msgTypes[43].OneofWrappers = []any{
 		(*PeeringAs_Cidr)(nil),
 		(*PeeringAs_Not)(nil),
 	}
-	file_proto_dataplane_proto_msgTypes[31].OneofWrappers = []any{
+	file_proto_dataplane_proto_msgTypes[46].OneofWrappers = []any{
 		(*Expose_Stateless)(nil),
 		(*Expose_Stateful)(nil),
 	}
-	file_proto_dataplane_proto_msgTypes[39].OneofWrappers = []any{
+	file_proto_dataplane_proto_msgTypes[54].OneofWrappers = []any{
 		(*BgpNeighborUpdateSource_Address)(nil),
 		(*BgpNeighborUpdateSource_Interface)(nil),
 	}
-	file_proto_dataplane_proto_msgTypes[42].OneofWrappers = []any{}
-	file_proto_dataplane_proto_msgTypes[43].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[57].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[58].OneofWrappers = []any{}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: unsafe.Slice(unsafe.StringData(file_proto_dataplane_proto_rawDesc), len(file_proto_dataplane_proto_rawDesc)),
-			NumEnums:      12,
-			NumMessages:   57,
+			NumEnums:      16,
+			NumMessages:   78,
 			NumExtensions: 0,
 			NumServices:   1,
 		},
@@ -26,6 +26,9 @@ const (
 	ConfigService_GetConfigGeneration_FullMethodName = "/config.ConfigService/GetConfigGeneration"
 	ConfigService_UpdateConfig_FullMethodName        = "/config.ConfigService/UpdateConfig"
 	ConfigService_GetDataplaneStatus_FullMethodName  = "/config.ConfigService/GetDataplaneStatus"
+	ConfigService_ListNatSessions_FullMethodName     = "/config.ConfigService/ListNatSessions"
+	ConfigService_GetRoutes_FullMethodName           = "/config.ConfigService/GetRoutes"
+	ConfigService_LookupRoute_FullMethodName         = "/config.ConfigService/LookupRoute"
 )
 
 // ConfigServiceClient is the client API for ConfigService service.
@@ -36,6 +39,9 @@ type ConfigServiceClient interface {
 	GetConfigGeneration(ctx context.Context, in *GetConfigGenerationRequest, opts ...grpc.CallOption) (*GetConfigGenerationResponse, error)
 	UpdateConfig(ctx context.Context, in *UpdateConfigRequest, opts ...grpc.CallOption) (*UpdateConfigResponse, error)
 	GetDataplaneStatus(ctx context.Context, in *GetDataplaneStatusRequest, opts ...grpc.CallOption) (*GetDataplaneStatusResponse, error)
+	ListNatSessions(ctx context.Context, in *ListNatSessionsRequest, opts ...grpc.CallOption) (*ListNatSessionsResponse, error)
+	GetRoutes(ctx context.Context, in *GetRoutesRequest, opts ...grpc.CallOption) (*GetRoutesResponse, error)
+	LookupRoute(ctx context.Context, in *LookupRouteRequest, opts ...grpc.CallOption) (*LookupRouteResponse, error)
 }
 
 type configServiceClient struct {
@@ -86,6 +92,36 @@ func (c *configServiceClient) GetDataplaneStatus(ctx context.Context, in *GetDat
 	return out, nil
 }
 
+func (c *configServiceClient) ListNatSessions(ctx context.Context, in *ListNatSessionsRequest, opts ...grpc.CallOption) (*ListNatSessionsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListNatSessionsResponse)
+	err := c.cc.Invoke(ctx, ConfigService_ListNatSessions_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *configServiceClient) GetRoutes(ctx context.Context, in *GetRoutesRequest, opts ...grpc.CallOption) (*GetRoutesResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(GetRoutesResponse)
+	err := c.cc.Invoke(ctx, ConfigService_GetRoutes_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *configServiceClient) LookupRoute(ctx context.Context, in *LookupRouteRequest, opts ...grpc.CallOption) (*LookupRouteResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(LookupRouteResponse)
+	err := c.cc.Invoke(ctx, ConfigService_LookupRoute_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // ConfigServiceServer is the server API for ConfigService service.
 // All implementations must embed UnimplementedConfigServiceServer
 // for forward compatibility.
@@ -94,6 +130,9 @@ type ConfigServiceServer interface {
 	GetConfigGeneration(context.Context, *GetConfigGenerationRequest) (*GetConfigGenerationResponse, error)
 	UpdateConfig(context.Context, *UpdateConfigRequest) (*UpdateConfigResponse, error)
 	GetDataplaneStatus(context.Context, *GetDataplaneStatusRequest) (*GetDataplaneStatusResponse, error)
+	ListNatSessions(context.Context, *ListNatSessionsRequest) (*ListNatSessionsResponse, error)
+	GetRoutes(context.Context, *GetRoutesRequest) (*GetRoutesResponse, error)
+	LookupRoute(context.Context, *LookupRouteRequest) (*LookupRouteResponse, error)
 	mustEmbedUnimplementedConfigServiceServer()
 }
 
@@ -116,6 +155,15 @@ func (UnimplementedConfigServiceServer) UpdateConfig(context.Context, *UpdateCon
 func (UnimplementedConfigServiceServer) GetDataplaneStatus(context.Context, *GetDataplaneStatusRequest) (*GetDataplaneStatusResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method GetDataplaneStatus not implemented")
 }
+func (UnimplementedConfigServiceServer) ListNatSessions(context.Context, *ListNatSessionsRequest) (*ListNatSessionsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListNatSessions not implemented")
+}
+func (UnimplementedConfigServiceServer) GetRoutes(context.Context, *GetRoutesRequest) (*GetRoutesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetRoutes not implemented")
+}
+func (UnimplementedConfigServiceServer) LookupRoute(context.Context, *LookupRouteRequest) (*LookupRouteResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method LookupRoute not implemented")
+}
 func (UnimplementedConfigServiceServer) mustEmbedUnimplementedConfigServiceServer() {}
 func (UnimplementedConfigServiceServer) testEmbeddedByValue()                       {}
 
@@ -209,6 +257,60 @@ func _ConfigService_GetDataplaneStatus_Handler(srv interface{}, ctx context.Cont
 	return interceptor(ctx, in, info, handler)
 }
 
+func _ConfigService_ListNatSessions_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListNatSessionsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ConfigServiceServer).ListNatSessions(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: ConfigService_ListNatSessions_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ConfigServiceServer).ListNatSessions(ctx, req.(*ListNatSessionsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _ConfigService_GetRoutes_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetRoutesRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ConfigServiceServer).GetRoutes(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: ConfigService_GetRoutes_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ConfigServiceServer).GetRoutes(ctx, req.(*GetRoutesRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _ConfigService_LookupRoute_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(LookupRouteRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ConfigServiceServer).LookupRoute(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: ConfigService_LookupRoute_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ConfigServiceServer).LookupRoute(ctx, req.(*LookupRouteRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // ConfigService_ServiceDesc is the grpc.ServiceDesc for ConfigService service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -232,6 +334,18 @@ var ConfigService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "GetDataplaneStatus",
 			Handler:    _ConfigService_GetDataplaneStatus_Handler,
 		},
+		{
+			MethodName: "ListNatSessions",
+			Handler:    _ConfigService_ListNatSessions_Handler,
+		},
+		{
+			MethodName: "GetRoutes",
+			Handler:    _ConfigService_GetRoutes_Handler,
+		},
+		{
+			MethodName: "LookupRoute",
+			Handler:    _ConfigService_LookupRoute_Handler,
+		},
 	},
 	Streams:  []grpc.StreamDesc{},
 	Metadata: "proto/dataplane.proto",
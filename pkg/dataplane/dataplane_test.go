@@ -6,6 +6,8 @@ package dataplane_test
 import (
 	context "context"
 	"net"
+	"os"
+	"path/filepath"
 	"testing"
 	"time"
 
@@ -15,6 +17,8 @@ import (
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/credentials/insecure"
 	"google.golang.org/grpc/status"
+	"google.golang.org/protobuf/encoding/protojson"
+	"google.golang.org/protobuf/proto"
 )
 
 func TestDataplaneClient(t *testing.T) {
@@ -65,4 +69,47 @@ func TestDataplaneClient(t *testing.T) {
 		require.Equal(t, codes.OK, status.Code(err), "unexpected error code for get config generation")
 		require.Equal(t, int64(42), resp.Generation, "unexpected response for get config generation")
 	}
+
+	{
+		resp, err := client.ListNatSessions(ctx, &dataplane.ListNatSessionsRequest{}, grpc.WaitForReady(true))
+		require.NoError(t, err, "failed to list nat sessions")
+		require.Empty(t, resp.Sessions, "unexpected response for list nat sessions")
+	}
+
+	{
+		resp, err := client.GetRoutes(ctx, &dataplane.GetRoutesRequest{Table: &dataplane.GetRoutesRequest_Vrf{Vrf: "default"}}, grpc.WaitForReady(true))
+		require.NoError(t, err, "failed to get routes")
+		require.Empty(t, resp.Routes, "unexpected response for get routes")
+	}
+
+	{
+		resp, err := client.LookupRoute(ctx, &dataplane.LookupRouteRequest{Table: &dataplane.LookupRouteRequest_Vrf{Vrf: "default"}, Address: "10.0.0.1"}, grpc.WaitForReady(true))
+		require.NoError(t, err, "failed to lookup route")
+		require.Nil(t, resp.Route, "unexpected response for lookup route")
+	}
+}
+
+// The Rust json module produces the golden files, protojson must read them and
+// write the same JSON back. Whitespace of protojson output is unstable, so the
+// documents are compared as JSON.
+func TestJSONGoldens(t *testing.T) {
+	for _, test := range []struct {
+		name string
+		msg  proto.Message
+	}{
+		{name: "gateway_config", msg: &dataplane.GatewayConfig{}},
+		{name: "dataplane_status", msg: &dataplane.GetDataplaneStatusResponse{}},
+	} {
+		t.Run(test.name, func(t *testing.T) {
+			golden, err := os.ReadFile(filepath.Join("..", "..", "tests", "testdata", test.name+".json"))
+			require.NoError(t, err)
+
+			err = protojson.Unmarshal(golden, test.msg)
+			require.NoError(t, err)
+
+			data, err := protojson.Marshal(test.msg)
+			require.NoError(t, err)
+			require.JSONEq(t, string(golden), string(data))
+		})
+	}
 }
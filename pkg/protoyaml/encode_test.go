@@ -4,11 +4,14 @@
 package protoyaml_test
 
 import (
+	"os"
+	"path/filepath"
 	"testing"
 
 	"github.com/stretchr/testify/require"
 	"go.githedgehog.com/gateway-proto/pkg/dataplane"
 	"go.githedgehog.com/gateway-proto/pkg/protoyaml"
+	"google.golang.org/protobuf/proto"
 )
 
 func TestMarshalUnmarshalYAML(t *testing.T) {
@@ -141,3 +144,27 @@ generation: "42"
 		})
 	}
 }
+
+// The Rust yaml module produces the golden files, they must survive a round
+// trip through MarshalYAML unchanged.
+func TestGoldens(t *testing.T) {
+	for _, test := range []struct {
+		name string
+		msg  proto.Message
+	}{
+		{name: "gateway_config", msg: &dataplane.GatewayConfig{}},
+		{name: "dataplane_status", msg: &dataplane.GetDataplaneStatusResponse{}},
+	} {
+		t.Run(test.name, func(t *testing.T) {
+			golden, err := os.ReadFile(filepath.Join("..", "..", "tests", "testdata", test.name+".yaml"))
+			require.NoError(t, err)
+
+			err = protoyaml.UnmarshalYAML(golden, test.msg)
+			require.NoError(t, err)
+
+			data, err := protoyaml.MarshalYAML(test.msg)
+			require.NoError(t, err)
+			require.Equal(t, string(golden), string(data))
+		})
+	}
+}
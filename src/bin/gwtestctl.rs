@@ -0,0 +1,685 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Rust counterpart of the Go `gwtestctl`: a simple client for the dataplane
+//! config service and a fake server backed by [`InMemoryConfigService`].
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use gateway_config::config::{
+    GetConfigGenerationRequest, GetConfigRequest, GetDataplaneStatusRequest, GetRoutesRequest,
+    GetRoutesResponse, IpProtocol, ListNatSessionsRequest, ListNatSessionsResponse,
+    LookupRouteRequest, RouteProtocol,
+};
+use gateway_config::error::UpdateConfigError;
+use gateway_config::health::{HealthOptions, evaluate_health};
+use gateway_config::json::{self, JsonError};
+use gateway_config::memory::InMemoryConfigService;
+use gateway_config::prometheus::{StatusExporter, encode_status};
+use gateway_config::routes::RouteTable;
+use gateway_config::schema::json_schema;
+use gateway_config::table::{TableOptions, render_status};
+use gateway_config::target::{Target, TargetError};
+use gateway_config::textproto::{self, TextprotoError};
+use gateway_config::yaml::{self, YamlError};
+use gateway_config::{GatewayConfig, VERSION};
+use prost::{Message, Name};
+use std::fmt;
+use std::io::{self, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+const EXAMPLES: &str = "\
+Run fake server:
+  gwtestctl server -t unix://tmp/gateway.sock  # unix socket
+  gwtestctl server -t tcp://localhost:5123     # tcp socket
+  gwtestctl server -t tcp://:5123              # tcp socket on all interfaces
+  gwtestctl server -t tcp://:0                 # tcp socket on all interfaces with random port
+And run client:
+  gwtestctl get-config -t unix://tmp/gateway.sock       # read current config from server
+  gwtestctl get-config -t tcp://:5123 > config.yaml     # save current config to file
+  gwtestctl update-config -t tcp://:5123 -f config.yaml # update config on server
+  gwtestctl get-config-gen -t tcp://:5123               # read current config generation from server
+  gwtestctl get-status -t tcp://:5123                   # read current dataplane status from server
+  gwtestctl get-status -t tcp://:5123 -o table          # show dataplane status as tables
+  gwtestctl health -t tcp://:5123                       # check dataplane health, exits with 1 unless healthy
+  gwtestctl nat-sessions -t tcp://:5123 --vpc vpc-1     # list stateful NAT sessions of a VPC
+  gwtestctl routes -t tcp://:5123 --vrf default         # list routes of an underlay VRF
+  gwtestctl lookup-route -t tcp://:5123 --vpc vpc-1 10.1.0.5 # find the route used for an address
+  gwtestctl exporter -t tcp://:5123 -l :9100            # serve dataplane status as Prometheus metrics
+Or work with config files:
+  gwtestctl validate -f config.yaml         # check config against the schema
+  gwtestctl diff old.yaml new.yaml          # show changes between two configs
+
+Config is a YAML representation of the GatewayConfig protobuf message, e.g.:
+  generation: 42
+  underlay:
+    vrfs:
+    - name: vrf1
+      interfaces:
+      - name: eth0
+        type: IF_TYPE_VLAN
+
+Files ending in .json are read as protojson and files ending in .txtpb or
+.textproto as text format, anything else as YAML. Use - to read YAML from stdin.";
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "gwtestctl",
+    version = VERSION,
+    about = "Simple Hedgehog Gateway Dataplane gRPC client and fake server for testing",
+    after_help = EXAMPLES,
+)]
+struct Cli {
+    /// Verbose output (includes debug)
+    #[arg(short, long, global = true, conflicts_with = "brief")]
+    verbose: bool,
+    /// Brief output (only warn and error)
+    #[arg(short, long, global = true)]
+    brief: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args, Debug)]
+struct TargetArgs {
+    /// gRPC client/server target: unix://name (Linux abstract socket) or
+    /// tcp://host:port or tcp://: for random port
+    #[arg(short, long)]
+    target: Target,
+    /// Timeout for connecting and for each request, in seconds
+    #[arg(long, default_value_t = 5)]
+    timeout: u64,
+}
+
+#[derive(Args, Debug)]
+struct ConfigFileArgs {
+    /// Path to config file
+    #[arg(short = 'f', long)]
+    config_file: PathBuf,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum Format {
+    Yaml,
+    Json,
+    Textproto,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum StatusFormat {
+    Yaml,
+    Json,
+    Textproto,
+    /// Aligned tables, colored if writing to a terminal and NO_COLOR is unset
+    Table,
+    /// Prometheus text exposition format
+    Prometheus,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum Protocol {
+    Tcp,
+    Udp,
+    Icmp,
+    Icmpv6,
+}
+
+impl From<Protocol> for IpProtocol {
+    fn from(protocol: Protocol) -> Self {
+        match protocol {
+            Protocol::Tcp => Self::Tcp,
+            Protocol::Udp => Self::Udp,
+            Protocol::Icmp => Self::Icmp,
+            Protocol::Icmpv6 => Self::Icmpv6,
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum RoutingProtocol {
+    Connected,
+    Static,
+    Bgp,
+    Ospf,
+}
+
+impl From<RoutingProtocol> for RouteProtocol {
+    fn from(protocol: RoutingProtocol) -> Self {
+        match protocol {
+            RoutingProtocol::Connected => Self::Connected,
+            RoutingProtocol::Static => Self::Static,
+            RoutingProtocol::Bgp => Self::Bgp,
+            RoutingProtocol::Ospf => Self::Ospf,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+#[group(required = true, multiple = false)]
+struct RouteTableArgs {
+    /// Route table of this underlay VRF
+    #[arg(long)]
+    vrf: Option<String>,
+    /// Route table of this VPC
+    #[arg(long)]
+    vpc: Option<String>,
+}
+
+impl RouteTableArgs {
+    fn into_route_table(self) -> RouteTable {
+        match (self.vrf, self.vpc) {
+            (Some(vrf), _) => RouteTable::Vrf(vrf),
+            (None, vpc) => RouteTable::Vpc(vpc.unwrap_or_default()),
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Get config
+    #[command(visible_alias = "get")]
+    GetConfig {
+        #[command(flatten)]
+        target: TargetArgs,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Format::Yaml)]
+        output: Format,
+    },
+    /// Get config generation
+    #[command(visible_aliases = ["get-config-gen", "gen"])]
+    GetGeneration {
+        #[command(flatten)]
+        target: TargetArgs,
+    },
+    /// Update config from file
+    #[command(visible_alias = "set")]
+    UpdateConfig {
+        #[command(flatten)]
+        target: TargetArgs,
+        #[command(flatten)]
+        file: ConfigFileArgs,
+    },
+    /// Get dataplane status
+    #[command(visible_alias = "status")]
+    GetStatus {
+        #[command(flatten)]
+        target: TargetArgs,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = StatusFormat::Yaml)]
+        output: StatusFormat,
+    },
+    /// Check dataplane health, exits with 1 unless healthy
+    Health {
+        #[command(flatten)]
+        target: TargetArgs,
+        /// Config generation FRR must have applied
+        #[arg(long)]
+        expected_generation: Option<i64>,
+        /// Fraction of enabled BGP neighbors that must be established
+        #[arg(long, default_value_t = 1.0)]
+        min_established_ratio: f64,
+    },
+    /// List stateful NAT sessions, following all pages
+    #[command(visible_alias = "nat")]
+    NatSessions {
+        #[command(flatten)]
+        target: TargetArgs,
+        /// Only sessions of this VPC peering
+        #[arg(long)]
+        peering: Option<String>,
+        /// Only sessions from or to this VPC
+        #[arg(long)]
+        vpc: Option<String>,
+        /// Only sessions with this original or translated IP address
+        #[arg(long)]
+        address: Option<String>,
+        /// Only sessions of this protocol
+        #[arg(long, value_enum)]
+        protocol: Option<Protocol>,
+        /// Sessions to request per page, 0 lets the server choose
+        #[arg(long, default_value_t = 0)]
+        page_size: u32,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Format::Yaml)]
+        output: Format,
+    },
+    /// List routes of a VRF or VPC, following all pages
+    #[command(visible_alias = "get-routes")]
+    Routes {
+        #[command(flatten)]
+        target: TargetArgs,
+        #[command(flatten)]
+        table: RouteTableArgs,
+        /// Only routes learned through this protocol
+        #[arg(long, value_enum)]
+        protocol: Option<RoutingProtocol>,
+        /// Routes to request per page, 0 lets the server choose
+        #[arg(long, default_value_t = 0)]
+        page_size: u32,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Format::Yaml)]
+        output: Format,
+    },
+    /// Find the installed route of a VRF or VPC with the longest prefix
+    /// matching an address, exits with 1 if none matches
+    LookupRoute {
+        #[command(flatten)]
+        target: TargetArgs,
+        #[command(flatten)]
+        table: RouteTableArgs,
+        /// IP address to look up
+        address: String,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Format::Yaml)]
+        output: Format,
+    },
+    /// Check that a config file is valid
+    Validate {
+        #[command(flatten)]
+        file: ConfigFileArgs,
+    },
+    /// Show the differences between two config files, exits with 1 if they
+    /// differ
+    Diff {
+        old: PathBuf,
+        new: PathBuf,
+        /// Format to compare the configs in
+        #[arg(short, long, value_enum, default_value_t = Format::Yaml)]
+        output: Format,
+    },
+    /// Serve dataplane status as Prometheus metrics on /metrics, fetching it
+    /// on every scrape
+    Exporter {
+        #[command(flatten)]
+        target: TargetArgs,
+        /// Address to serve metrics on, an empty host means all interfaces
+        #[arg(short, long, default_value = ":9100")]
+        listen: String,
+    },
+    /// Run fake server
+    #[command(visible_alias = "server")]
+    FakeServer {
+        /// gRPC server target: unix://name (Linux abstract socket) or
+        /// tcp://host:port or tcp://: for random port
+        #[arg(short, long)]
+        target: Target,
+    },
+}
+
+#[derive(thiserror::Error, Debug)]
+enum CliError {
+    #[error("Failed to read {0}: {1}")]
+    Read(String, io::Error),
+    #[error("Invalid config in {0}: {1}")]
+    Yaml(String, YamlError),
+    #[error("Invalid config in {0}: {1}")]
+    Json(String, JsonError),
+    #[error("Invalid config in {0}: {1}")]
+    Textproto(String, TextprotoError),
+    #[error("Encoding failed: {0}")]
+    Encode(String),
+    #[error(transparent)]
+    Target(#[from] TargetError),
+    #[error("Request failed: {0}")]
+    Status(#[from] tonic::Status),
+    #[error("Updating config failed: {0}")]
+    UpdateConfig(#[from] UpdateConfigError),
+    #[error("Serving failed: {0}")]
+    Serve(#[from] tonic::transport::Error),
+    #[error("{0}")]
+    Io(#[from] io::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Debug => "DBG",
+            Self::Info => "INF",
+            Self::Warn => "WRN",
+            Self::Error => "ERR",
+        })
+    }
+}
+
+static LOG_LEVEL: OnceLock<Level> = OnceLock::new();
+
+fn log(level: Level, message: fmt::Arguments<'_>) {
+    if level >= *LOG_LEVEL.get().unwrap_or(&Level::Info) {
+        eprintln!("{level} {message}");
+    }
+}
+
+macro_rules! debug {
+    ($($arg:tt)*) => { log(Level::Debug, format_args!($($arg)*)) };
+}
+
+macro_rules! info {
+    ($($arg:tt)*) => { log(Level::Info, format_args!($($arg)*)) };
+}
+
+macro_rules! error {
+    ($($arg:tt)*) => { log(Level::Error, format_args!($($arg)*)) };
+}
+
+fn encode<M: Message + Name>(msg: &M, format: Format) -> Result<String, CliError> {
+    match format {
+        Format::Yaml => yaml::to_yaml(msg).map_err(|e| CliError::Encode(e.to_string())),
+        Format::Json => json::to_json_pretty(msg)
+            .map(|json| json + "\n")
+            .map_err(|e| CliError::Encode(e.to_string())),
+        Format::Textproto => {
+            textproto::to_textproto(msg).map_err(|e| CliError::Encode(e.to_string()))
+        }
+    }
+}
+
+/// Read a config file in the format given by its extension, or YAML from
+/// stdin if the path is `-`.
+fn load_config(path: &Path) -> Result<GatewayConfig, CliError> {
+    let name = path.display().to_string();
+    let data = if path == Path::new("-") {
+        let mut data = String::new();
+        io::stdin()
+            .read_to_string(&mut data)
+            .map_err(|e| CliError::Read(name.clone(), e))?;
+        data
+    } else {
+        std::fs::read_to_string(path).map_err(|e| CliError::Read(name.clone(), e))?
+    };
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => json::from_json(&data).map_err(|e| CliError::Json(name, e)),
+        Some("txtpb" | "textproto") => {
+            textproto::from_textproto(&data).map_err(|e| CliError::Textproto(name, e))
+        }
+        _ => yaml::from_yaml(&data).map_err(|e| CliError::Yaml(name, e)),
+    }
+}
+
+impl TargetArgs {
+    async fn connect(
+        &self,
+    ) -> Result<gateway_config::ConfigServiceClient<tonic::transport::Channel>, CliError> {
+        let client = self
+            .target
+            .connect(Duration::from_secs(self.timeout))
+            .await?;
+        debug!("Connected target={}", self.target);
+        Ok(client)
+    }
+}
+
+/// Run `command`, returning whether it succeeded.
+async fn run(command: Command) -> Result<bool, CliError> {
+    match command {
+        Command::GetConfig { target, output } => {
+            info!("Getting config target={}", target.target);
+            let config = target
+                .connect()
+                .await?
+                .get_config(GetConfigRequest {})
+                .await?
+                .into_inner();
+            info!("Response generation={}", config.generation);
+            print!("{}", encode(&config, output)?);
+        }
+        Command::GetGeneration { target } => {
+            info!("Getting config generation target={}", target.target);
+            let response = target
+                .connect()
+                .await?
+                .get_config_generation(GetConfigGenerationRequest {})
+                .await?
+                .into_inner();
+            println!("{}", response.generation);
+        }
+        Command::UpdateConfig { target, file } => {
+            info!(
+                "Updating config target={} file={}",
+                target.target,
+                file.config_file.display()
+            );
+            let config = load_config(&file.config_file)?;
+            let message = target.connect().await?.apply_config(config).await?;
+            info!("Response message={message:?}");
+        }
+        Command::GetStatus { target, output } => {
+            info!("Getting dataplane status target={}", target.target);
+            let status = target
+                .connect()
+                .await?
+                .get_dataplane_status(GetDataplaneStatusRequest {})
+                .await?
+                .into_inner();
+            let text = match output {
+                StatusFormat::Yaml => encode(&status, Format::Yaml)?,
+                StatusFormat::Json => encode(&status, Format::Json)?,
+                StatusFormat::Textproto => encode(&status, Format::Textproto)?,
+                StatusFormat::Table => {
+                    let color =
+                        io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+                    render_status(&status, TableOptions { color })
+                }
+                StatusFormat::Prometheus => encode_status(&status),
+            };
+            print!("{text}");
+        }
+        Command::Health {
+            target,
+            expected_generation,
+            min_established_ratio,
+        } => {
+            info!("Checking dataplane health target={}", target.target);
+            let status = target
+                .connect()
+                .await?
+                .get_dataplane_status(GetDataplaneStatusRequest {})
+                .await?
+                .into_inner();
+            let report = evaluate_health(
+                &status,
+                &HealthOptions {
+                    expected_generation,
+                    min_established_ratio,
+                },
+            );
+            println!("{report}");
+            return Ok(report.is_healthy());
+        }
+        Command::NatSessions {
+            target,
+            peering,
+            vpc,
+            address,
+            protocol,
+            page_size,
+            output,
+        } => {
+            info!("Listing NAT sessions target={}", target.target);
+            let sessions = target
+                .connect()
+                .await?
+                .list_all_nat_sessions(ListNatSessionsRequest {
+                    peering: peering.unwrap_or_default(),
+                    vpc: vpc.unwrap_or_default(),
+                    address: address.unwrap_or_default(),
+                    protocol: protocol
+                        .map_or(IpProtocol::Unspecified, IpProtocol::from)
+                        .into(),
+                    page_size,
+                    page_token: String::new(),
+                })
+                .await?;
+            info!("Response sessions={}", sessions.len());
+            let response = ListNatSessionsResponse {
+                sessions,
+                next_page_token: String::new(),
+            };
+            print!("{}", encode(&response, output)?);
+        }
+        Command::Routes {
+            target,
+            table,
+            protocol,
+            page_size,
+            output,
+        } => {
+            info!("Listing routes target={}", target.target);
+            let routes = target
+                .connect()
+                .await?
+                .get_all_routes(GetRoutesRequest {
+                    table: Some(table.into_route_table().into()),
+                    protocol: protocol
+                        .map_or(RouteProtocol::Unspecified, RouteProtocol::from)
+                        .into(),
+                    page_size,
+                    page_token: String::new(),
+                })
+                .await?;
+            info!("Response routes={}", routes.len());
+            let response = GetRoutesResponse {
+                routes,
+                next_page_token: String::new(),
+            };
+            print!("{}", encode(&response, output)?);
+        }
+        Command::LookupRoute {
+            target,
+            table,
+            address,
+            output,
+        } => {
+            info!(
+                "Looking up route target={} address={address}",
+                target.target
+            );
+            let response = target
+                .connect()
+                .await?
+                .lookup_route(LookupRouteRequest {
+                    table: Some(table.into_route_table().into()),
+                    address,
+                })
+                .await?
+                .into_inner();
+            if response.route.is_none() {
+                info!("No matching route");
+                return Ok(false);
+            }
+            print!("{}", encode(&response, output)?);
+        }
+        Command::Validate { file } => return validate(&file.config_file),
+        Command::Diff { old, new, output } => {
+            let old_text = encode(&load_config(&old)?, output)?;
+            let new_text = encode(&load_config(&new)?, output)?;
+            if old_text != new_text {
+                let diff = similar::TextDiff::from_lines(&old_text, &new_text);
+                print!(
+                    "{}",
+                    diff.unified_diff()
+                        .header(&old.display().to_string(), &new.display().to_string())
+                );
+                return Ok(false);
+            }
+            info!("No differences");
+        }
+        Command::Exporter { target, listen } => {
+            let client = target.connect().await?;
+            let addr = if listen.starts_with(':') {
+                format!("0.0.0.0{listen}")
+            } else {
+                listen
+            };
+            let listener = tokio::net::TcpListener::bind(&addr).await?;
+            info!(
+                "Starting exporter target={} listen={}",
+                target.target,
+                listener.local_addr()?
+            );
+            StatusExporter::new(client)
+                .with_rpc_timeout(Duration::from_secs(target.timeout))
+                .serve(listener, async {
+                    let _ = tokio::signal::ctrl_c().await;
+                    info!("Shutting down");
+                })
+                .await?;
+        }
+        Command::FakeServer { target } => {
+            let listener = target.listen().await?;
+            info!("Starting fake server target={}", listener.local_target()?);
+            listener
+                .serve(InMemoryConfigService::new(), async {
+                    let _ = tokio::signal::ctrl_c().await;
+                    info!("Shutting down");
+                })
+                .await?;
+        }
+    }
+    Ok(true)
+}
+
+fn validate(path: &Path) -> Result<bool, CliError> {
+    let config = load_config(path)?;
+    let value = json::to_json_value(&config).map_err(|e| CliError::Encode(e.to_string()))?;
+    let schema = json_schema::<GatewayConfig>().map_err(|e| CliError::Encode(e.to_string()))?;
+    let validator = jsonschema::options()
+        .should_validate_formats(true)
+        .build(&schema)
+        .map_err(|e| CliError::Encode(e.to_string()))?;
+    let mut valid = true;
+    for err in validator.iter_errors(&value) {
+        error!("{}: {err} at {}", path.display(), err.instance_path());
+        valid = false;
+    }
+    if valid {
+        info!(
+            "{}: valid, generation {}",
+            path.display(),
+            config.generation
+        );
+    }
+    Ok(valid)
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let level = if cli.verbose {
+        Level::Debug
+    } else if cli.brief {
+        Level::Warn
+    } else {
+        Level::Info
+    };
+    let _ = LOG_LEVEL.set(level);
+    info!("Hedgehog Gateway Test Client version={VERSION}");
+
+    match run(cli.command).await {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            let mut message = err.to_string();
+            let mut source = std::error::Error::source(&err);
+            while let Some(err) = source {
+                // Most errors already include their source in the message
+                let detail = err.to_string();
+                if !message.ends_with(&detail) {
+                    message = format!("{message}: {detail}");
+                }
+                source = err.source();
+            }
+            error!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -82,7 +82,8 @@ where
 
 impl TypeGenerator for PeeringStatefulNat {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
-        let seconds = d.gen_i64(Bound::Included(&0), Bound::Included(&i64::MAX))?;
+        // Largest value allowed by the `google.protobuf.Duration` spec
+        let seconds = d.gen_i64(Bound::Included(&0), Bound::Included(&315_576_000_000))?;
         let nanos = d.gen_i32(Bound::Included(&0), Bound::Included(&999_999_999))?;
         let idle_timeout = Some(Duration { seconds, nanos });
         Some(PeeringStatefulNat { idle_timeout })
@@ -5,11 +5,14 @@ use crate::bolero::support::{LinuxIfName, choose};
 use crate::config::{
     BgpMessageCounters, BgpMessages, BgpNeighborPrefixes, BgpNeighborSessionState,
     BgpNeighborStatus, BgpStatus, BgpVrfStatus, DataplaneStatusInfo, DataplaneStatusType,
-    FrrAgentStatusType, FrrStatus, GetDataplaneStatusRequest, GetDataplaneStatusResponse,
-    InterfaceAdminStatusType, InterfaceCounters, InterfaceOperStatusType, InterfaceRuntimeStatus,
-    InterfaceStatus, VpcCounters, VpcInterfaceStatus, VpcPeeringCounters, VpcStatus,
-    ZebraStatusType,
+    ExposeCounters, FrrAgentStatusType, FrrLifecycleState, FrrStatus, GetDataplaneStatusRequest,
+    GetDataplaneStatusResponse, InterfaceAdminStatusType, InterfaceCounters,
+    InterfaceOperStatusType, InterfaceQueueCounters, InterfaceRuntimeStatus, InterfaceStatus,
+    NatSessionCounters, RemoteVtepStatus, VniStatus, VpcCounters, VpcInterfaceStatus,
+    VpcPeeringCounters, VpcStatus, VtepReachability, ZebraStatusType,
 };
+use crate::drops::{DropReason, add_drops};
+use crate::google::protobuf::Timestamp;
 use bolero::{Driver, TypeGenerator};
 use std::ops::Bound;
 
@@ -60,6 +63,21 @@ impl TypeGenerator for FrrAgentStatusType {
     }
 }
 
+impl TypeGenerator for FrrLifecycleState {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let variants = [
+            FrrLifecycleState::FrrStateUnknown,
+            FrrLifecycleState::FrrStateStarting,
+            FrrLifecycleState::FrrStateRunning,
+            FrrLifecycleState::FrrStateReloading,
+            FrrLifecycleState::FrrStateStopping,
+            FrrLifecycleState::FrrStateCrashed,
+        ];
+        let index = d.gen_usize(Bound::Included(&0), Bound::Included(&(variants.len() - 1)))?;
+        Some(variants[index])
+    }
+}
+
 impl TypeGenerator for DataplaneStatusType {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
         let variants = [
@@ -79,16 +97,46 @@ impl TypeGenerator for GetDataplaneStatusRequest {
     }
 }
 
+/// Error reason matching the operational status: empty unless down or in error.
+fn gen_error_reason<D: Driver>(d: &mut D, oper: InterfaceOperStatusType) -> Option<String> {
+    let reasons: &[&str] = match oper {
+        InterfaceOperStatusType::InterfaceStatusOperDown => {
+            &["", "no carrier", "administratively down", "link flap"]
+        }
+        InterfaceOperStatusType::InterfaceStatusError => &[
+            "",
+            "driver error",
+            "firmware not responding",
+            "port init failed",
+        ],
+        _ => &[""],
+    };
+    Some(choose(d, reasons)?.to_string())
+}
+
 impl TypeGenerator for InterfaceStatus {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let oper = d.produce::<InterfaceOperStatusType>()?;
         Some(InterfaceStatus {
             ifname: d.produce::<LinuxIfName>()?.0,
-            oper_status: d.produce::<InterfaceOperStatusType>()?.into(),
+            oper_status: oper.into(),
             admin_status: d.produce::<InterfaceAdminStatusType>()?.into(),
+            error_reason: gen_error_reason(d, oper)?,
         })
     }
 }
 
+/// Some time in 2025.
+fn gen_timestamp<D: Driver>(d: &mut D) -> Option<Timestamp> {
+    Some(Timestamp {
+        seconds: d.gen_i64(
+            Bound::Included(&1_735_689_600),
+            Bound::Excluded(&1_767_225_600),
+        )?,
+        nanos: d.gen_i32(Bound::Included(&0), Bound::Included(&999_999_999))?,
+    })
+}
+
 impl TypeGenerator for FrrStatus {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
         let restart_weight = d.gen_u8(Bound::Included(&0), Bound::Included(&100))?;
@@ -98,13 +146,44 @@ impl TypeGenerator for FrrStatus {
             _ => d.gen_u32(Bound::Included(&51), Bound::Included(&1000))?,
         };
 
+        let applied_config_gen = d.produce::<i64>()?;
+        let failed_configs = d.produce::<u32>()?;
+        let last_apply_error = if failed_configs == 0 {
+            String::new()
+        } else {
+            choose(
+                d,
+                &[
+                    "",
+                    "line 12: Unknown command",
+                    "vtysh exited with status 1",
+                    "reload timed out",
+                ],
+            )?
+            .to_string()
+        };
+        let state_since = gen_timestamp(d);
+        let zebra_status_since = gen_timestamp(d);
+        let frr_agent_status_since = gen_timestamp(d);
+        let pending_config_gen = if d.gen_bool(None)? {
+            applied_config_gen.saturating_add(1)
+        } else {
+            0
+        };
+
         Some(FrrStatus {
             zebra_status: d.produce::<ZebraStatusType>()?.into(),
             frr_agent_status: d.produce::<FrrAgentStatusType>()?.into(),
-            applied_config_gen: d.produce::<i64>()?,
+            applied_config_gen,
             restarts,
             applied_configs: d.produce::<u32>()?,
-            failed_configs: d.produce::<u32>()?,
+            failed_configs,
+            state: d.produce::<FrrLifecycleState>()?.into(),
+            state_since: choose(d, &[state_since, None])?,
+            zebra_status_since: choose(d, &[zebra_status_since, None])?,
+            frr_agent_status_since: choose(d, &[frr_agent_status_since, None])?,
+            last_apply_error,
+            pending_config_gen,
         })
     }
 }
@@ -117,6 +196,36 @@ impl TypeGenerator for DataplaneStatusInfo {
     }
 }
 
+/// Split `packets` and `drops` of one direction among up to 4 queues.
+fn gen_queues<D: Driver>(
+    d: &mut D,
+    packets: u64,
+    drops: u64,
+) -> Option<Vec<InterfaceQueueCounters>> {
+    let count = d.gen_u32(Bound::Included(&0), Bound::Included(&4))?;
+    let mut queues = Vec::new();
+    let (mut packets_left, mut drops_left) = (packets, drops);
+    for queue in 0..count {
+        let (packets, drops) = if queue + 1 == count {
+            (packets_left, drops_left)
+        } else {
+            (
+                d.gen_u64(Bound::Included(&0), Bound::Included(&packets_left))?,
+                d.gen_u64(Bound::Included(&0), Bound::Included(&drops_left))?,
+            )
+        };
+        packets_left -= packets;
+        drops_left -= drops;
+        queues.push(InterfaceQueueCounters {
+            queue,
+            packets,
+            bytes: packets.saturating_mul(64),
+            drops,
+        });
+    }
+    Some(queues)
+}
+
 #[allow(clippy::cast_precision_loss)]
 impl TypeGenerator for InterfaceCounters {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
@@ -127,6 +236,22 @@ impl TypeGenerator for InterfaceCounters {
         let rx_bps = d.gen_u64(Bound::Included(&0), Bound::Included(&5_000_000))? as f64;
         let tx_bps = d.gen_u64(Bound::Included(&0), Bound::Included(&5_000_000))? as f64;
 
+        // At least 64 bytes per packet
+        let rx_packets = d.gen_u64(Bound::Included(&0), Bound::Included(&(rx_bits / 512)))?;
+        let tx_packets = d.gen_u64(Bound::Included(&0), Bound::Included(&(tx_bits / 512)))?;
+        let rx_multicast = d.gen_u64(Bound::Included(&0), Bound::Included(&rx_packets))?;
+        let rx_broadcast = d.gen_u64(
+            Bound::Included(&0),
+            Bound::Included(&(rx_packets - rx_multicast)),
+        )?;
+        let tx_multicast = d.gen_u64(Bound::Included(&0), Bound::Included(&tx_packets))?;
+        let tx_broadcast = d.gen_u64(
+            Bound::Included(&0),
+            Bound::Included(&(tx_packets - tx_multicast)),
+        )?;
+        let rx_drops = d.gen_u64(Bound::Included(&0), Bound::Included(&10_000))?;
+        let tx_drops = d.gen_u64(Bound::Included(&0), Bound::Included(&10_000))?;
+
         Some(InterfaceCounters {
             tx_bits,
             tx_bps,
@@ -134,6 +259,16 @@ impl TypeGenerator for InterfaceCounters {
             rx_bits,
             rx_bps,
             rx_errors,
+            tx_packets,
+            tx_drops,
+            tx_multicast,
+            tx_broadcast,
+            rx_packets,
+            rx_drops,
+            rx_multicast,
+            rx_broadcast,
+            rx_queues: gen_queues(d, rx_packets, rx_drops)?,
+            tx_queues: gen_queues(d, tx_packets, tx_drops)?,
         })
     }
 }
@@ -143,10 +278,11 @@ impl TypeGenerator for InterfaceRuntimeStatus {
         let mtu = d.gen_u32(Bound::Included(&576), Bound::Included(&9216))?;
         let produced_ic = d.produce::<InterfaceCounters>();
         let counters_pick = choose(d, &[produced_ic, None])?;
+        let oper = d.produce::<InterfaceOperStatusType>()?;
 
         Some(InterfaceRuntimeStatus {
             admin_status: d.produce::<InterfaceAdminStatusType>()?.into(),
-            oper_status: d.produce::<InterfaceOperStatusType>()?.into(),
+            oper_status: oper.into(),
             mac: format!(
                 "02:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
                 d.gen_u8(Bound::Included(&0), Bound::Included(&255))?,
@@ -157,6 +293,7 @@ impl TypeGenerator for InterfaceRuntimeStatus {
             ),
             mtu,
             counters: counters_pick,
+            error_reason: gen_error_reason(d, oper)?,
         })
     }
 }
@@ -320,6 +457,52 @@ impl TypeGenerator for VpcStatus {
     }
 }
 
+impl TypeGenerator for VtepReachability {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let variants = [
+            VtepReachability::Unknown,
+            VtepReachability::Reachable,
+            VtepReachability::Unreachable,
+        ];
+        let index = d.gen_usize(Bound::Included(&0), Bound::Included(&(variants.len() - 1)))?;
+        Some(variants[index])
+    }
+}
+
+impl TypeGenerator for RemoteVtepStatus {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let address = format!(
+            "10.255.{}.{}",
+            d.gen_u8(Bound::Included(&0), Bound::Included(&255))?,
+            d.gen_u8(Bound::Included(&1), Bound::Included(&254))?
+        );
+        let nvnis = d.gen_usize(Bound::Included(&0), Bound::Included(&4))?;
+        let mut vnis = Vec::with_capacity(nvnis);
+        for _ in 0..nvnis {
+            vnis.push(d.gen_u32(Bound::Included(&1), Bound::Included(&16_777_215))?);
+        }
+        vnis.sort_unstable();
+        vnis.dedup();
+        Some(RemoteVtepStatus {
+            address,
+            reachability: d.produce::<VtepReachability>()?.into(),
+            vnis,
+        })
+    }
+}
+
+impl TypeGenerator for VniStatus {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let route_count = d.gen_u32(Bound::Included(&0), Bound::Included(&50_000))?;
+        Some(VniStatus {
+            vni: d.gen_u32(Bound::Included(&1), Bound::Included(&16_777_215))?,
+            mac_count: d.gen_u32(Bound::Included(&0), Bound::Included(&route_count))?,
+            route_count,
+            type5_route_count: d.gen_u32(Bound::Included(&0), Bound::Included(&route_count))?,
+        })
+    }
+}
+
 #[allow(clippy::cast_precision_loss)]
 impl TypeGenerator for VpcPeeringCounters {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
@@ -335,6 +518,7 @@ impl TypeGenerator for VpcPeeringCounters {
         let packets = d.gen_u64(Bound::Included(&0), Bound::Included(&10_000_000))?;
         let bytes = packets.saturating_mul(64);
         let drops = d.gen_u64(Bound::Included(&0), Bound::Included(&packets))?;
+        let drop_reasons = gen_drop_reasons(d, drops)?;
         let pps = d.gen_u64(Bound::Included(&0), Bound::Included(&100_000))? as f64;
         let bps = d.gen_u64(Bound::Included(&0), Bound::Included(&5_000_000))? as f64;
 
@@ -347,6 +531,7 @@ impl TypeGenerator for VpcPeeringCounters {
             drops,
             pps,
             bps,
+            drop_reasons,
         })
     }
 }
@@ -359,16 +544,119 @@ impl TypeGenerator for VpcCounters {
         );
         let packets = d.gen_u64(Bound::Included(&0), Bound::Included(&100_000_000))?;
         let drops = d.gen_u64(Bound::Included(&0), Bound::Included(&packets))?;
+        let drop_reasons = gen_drop_reasons(d, drops)?;
         let bytes = packets.saturating_mul(64);
         Some(VpcCounters {
             name,
             packets,
             drops,
             bytes,
+            drop_reasons,
+        })
+    }
+}
+
+/// Split `drops` among random drop reasons, all of them attributed.
+fn gen_drop_reasons<D: Driver>(
+    d: &mut D,
+    drops: u64,
+) -> Option<std::collections::HashMap<String, u64>> {
+    let mut reasons = std::collections::HashMap::new();
+    let mut left = drops;
+    for reason in DropReason::ALL {
+        if left == 0 {
+            break;
+        }
+        if d.gen_bool(None)? {
+            let n = d.gen_u64(Bound::Included(&1), Bound::Included(&left))?;
+            add_drops(&mut reasons, reason, n);
+            left -= n;
+        }
+    }
+    if left > 0 {
+        add_drops(&mut reasons, choose(d, &DropReason::ALL)?, left);
+    }
+    Some(reasons)
+}
+
+impl TypeGenerator for ExposeCounters {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let a = d.gen_u32(Bound::Included(&1), Bound::Included(&64))?;
+        let b = d.gen_u32(Bound::Included(&1), Bound::Included(&64))?;
+        let peering = format!("vpc-{}--vpc-{}", a.min(b), a.max(b));
+        let vpc = format!("vpc-{}", choose(d, &[a, b])?);
+        let expose_index = d.gen_u32(Bound::Included(&0), Bound::Included(&7))?;
+        let packets = d.gen_u64(Bound::Included(&0), Bound::Included(&10_000_000))?;
+
+        let drops = d.gen_u64(Bound::Included(&0), Bound::Included(&packets))?;
+        let drop_reasons = gen_drop_reasons(d, drops)?;
+
+        let translations = d.gen_u64(Bound::Included(&0), Bound::Included(&packets))?;
+        let nat_alloc_failures = d.gen_u64(Bound::Included(&0), Bound::Included(&drops))?;
+
+        Some(ExposeCounters {
+            peering,
+            vpc,
+            expose_index,
+            packets,
+            bytes: packets.saturating_mul(64),
+            drops,
+            drop_reasons,
+            translations,
+            nat_alloc_failures,
+        })
+    }
+}
+
+impl TypeGenerator for NatSessionCounters {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let expired = d.gen_u64(Bound::Included(&0), Bound::Included(&1_000_000))?;
+        let active = d.gen_u64(Bound::Included(&0), Bound::Included(&65_536))?;
+        Some(NatSessionCounters {
+            active,
+            created: expired + active,
+            expired,
         })
     }
 }
 
+/// Status of the VNIs of `vpcs` and a few more, and of remote VTEPs that
+/// learned some of them.
+fn gen_evpn<D: Driver>(
+    d: &mut D,
+    vpcs: &std::collections::HashMap<String, VpcStatus>,
+) -> Option<(
+    std::collections::HashMap<String, RemoteVtepStatus>,
+    std::collections::HashMap<u32, VniStatus>,
+)> {
+    let mut vni_pool: Vec<u32> = vpcs.values().map(|v| v.vni).collect();
+    let nextra = d.gen_usize(Bound::Included(&0), Bound::Included(&2))?;
+    for _ in 0..nextra {
+        vni_pool.push(d.gen_u32(Bound::Included(&1), Bound::Included(&16_777_215))?);
+    }
+    let mut vnis = std::collections::HashMap::new();
+    for vni in &vni_pool {
+        let mut status = d.produce::<VniStatus>()?;
+        status.vni = *vni;
+        vnis.insert(*vni, status);
+    }
+    let nvteps = d.gen_usize(Bound::Included(&0), Bound::Included(&4))?;
+    let mut remote_vteps = std::collections::HashMap::new();
+    for _ in 0..nvteps {
+        let mut vtep = d.produce::<RemoteVtepStatus>()?;
+        vtep.vnis.clear();
+        for vni in &vni_pool {
+            if d.gen_bool(None)? {
+                vtep.vnis.push(*vni);
+            }
+        }
+        vtep.vnis.sort_unstable();
+        vtep.vnis.dedup();
+        remote_vteps.insert(vtep.address.clone(), vtep);
+    }
+    Some((remote_vteps, vnis))
+}
+
 impl TypeGenerator for GetDataplaneStatusResponse {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
         // 0..=8 interface statuses, unique names
@@ -437,6 +725,27 @@ impl TypeGenerator for GetDataplaneStatusResponse {
             vpc_counters.insert(c.name.clone(), c);
         }
 
+        // NAT session counts (0..=4), keyed by peering name
+        let nnat = d.gen_usize(Bound::Included(&0), Bound::Included(&4))?;
+        let mut nat_sessions = std::collections::HashMap::new();
+        for _ in 0..nnat {
+            let name = format!(
+                "vpc-{}--vpc-{}",
+                d.gen_u32(Bound::Included(&1), Bound::Included(&64))?,
+                d.gen_u32(Bound::Included(&1), Bound::Included(&64))?
+            );
+            nat_sessions.insert(name, d.produce::<NatSessionCounters>()?);
+        }
+
+        // Per-expose counters (0..=6)
+        let nexpose = d.gen_usize(Bound::Included(&0), Bound::Included(&6))?;
+        let mut expose_counters = Vec::with_capacity(nexpose);
+        for _ in 0..nexpose {
+            expose_counters.push(d.produce::<ExposeCounters>()?);
+        }
+
+        let (remote_vteps, vnis) = gen_evpn(d, &vpcs)?;
+
         Some(GetDataplaneStatusResponse {
             interface_statuses,
             frr_status,
@@ -446,6 +755,10 @@ impl TypeGenerator for GetDataplaneStatusResponse {
             vpcs,
             vpc_peering_counters,
             vpc_counters,
+            nat_sessions,
+            expose_counters,
+            remote_vteps,
+            vnis,
         })
     }
 }
@@ -453,6 +766,7 @@ impl TypeGenerator for GetDataplaneStatusResponse {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::drops::DropCounters;
 
     #[test]
     fn test_interface_status_type() {
@@ -512,6 +826,35 @@ mod test {
                 assert!(!s.ifname.is_empty());
                 assert!((0..=3).contains(&s.oper_status));
                 assert!((0..=2).contains(&s.admin_status));
+                if !s.error_reason.is_empty() {
+                    assert!(matches!(
+                        s.oper_status(),
+                        InterfaceOperStatusType::InterfaceStatusOperDown
+                            | InterfaceOperStatusType::InterfaceStatusError
+                    ));
+                }
+            });
+    }
+
+    #[test]
+    fn test_interface_counters() {
+        bolero::check!()
+            .with_type::<InterfaceCounters>()
+            .for_each(|c| {
+                assert!(c.rx_multicast + c.rx_broadcast <= c.rx_packets);
+                assert!(c.tx_multicast + c.tx_broadcast <= c.tx_packets);
+                assert!(c.rx_packets.saturating_mul(512) <= c.rx_bits);
+                assert!(c.tx_packets.saturating_mul(512) <= c.tx_bits);
+                for (queues, packets, drops) in [
+                    (&c.rx_queues, c.rx_packets, c.rx_drops),
+                    (&c.tx_queues, c.tx_packets, c.tx_drops),
+                ] {
+                    assert!(queues.iter().map(|q| q.packets).sum::<u64>() <= packets);
+                    assert!(queues.iter().map(|q| q.drops).sum::<u64>() <= drops);
+                    if !queues.is_empty() {
+                        assert_eq!(queues.iter().map(|q| q.packets).sum::<u64>(), packets);
+                    }
+                }
             });
     }
 
@@ -521,7 +864,13 @@ mod test {
         bolero::check!().with_type::<FrrStatus>().for_each(|frr| {
             assert!((0..=1).contains(&frr.zebra_status));
             assert!((0..=1).contains(&frr.frr_agent_status));
+            assert!((0..=5).contains(&frr.state));
             assert!(frr.restarts <= 1000);
+            assert!(frr.failed_configs > 0 || frr.last_apply_error.is_empty());
+            assert!(
+                frr.pending_config_gen == 0
+                    || frr.pending_config_gen == frr.applied_config_gen.saturating_add(1)
+            );
             if frr.restarts > 0 {
                 some_restarts = true;
             }
@@ -660,12 +1009,45 @@ mod test {
                     assert!(c.packets >= c.drops);
                     assert!(c.pps >= 0.0);
                     assert!(c.bps >= 0.0);
+                    assert_eq!(c.attributed_drops(), c.drops);
                 }
 
                 for (name, c) in &resp.vpc_counters {
                     assert_eq!(name, &c.name);
                     assert!(!c.name.is_empty());
                     assert!(c.packets >= c.drops);
+                    assert_eq!(c.attributed_drops(), c.drops);
+                }
+
+                for c in &resp.expose_counters {
+                    assert!(c.peering.split("--").any(|vpc| vpc == c.vpc));
+                    assert!(c.packets >= c.drops);
+                    assert_eq!(c.attributed_drops(), c.drops);
+                    assert!(
+                        c.drop_reasons
+                            .keys()
+                            .all(|reason| DropReason::from_str_name(reason).is_some())
+                    );
+                    assert!(c.nat_alloc_failures <= c.drops);
+                }
+
+                for (name, c) in &resp.nat_sessions {
+                    assert!(!name.is_empty());
+                    assert_eq!(c.created, c.active + c.expired);
+                }
+
+                // VPCs and remote VTEPs refer to known VNIs
+                for (vni, status) in &resp.vnis {
+                    assert_eq!(*vni, status.vni);
+                    assert!(status.type5_route_count <= status.route_count);
+                }
+                for vpc in resp.vpcs.values() {
+                    assert!(resp.vnis.contains_key(&vpc.vni));
+                }
+                for (address, vtep) in &resp.remote_vteps {
+                    assert_eq!(address, &vtep.address);
+                    assert!((0..=2).contains(&vtep.reachability));
+                    assert!(vtep.vnis.iter().all(|vni| resp.vnis.contains_key(vni)));
                 }
             });
 
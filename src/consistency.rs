@@ -0,0 +1,528 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Consistency of a [`GatewayConfig`] with the [`GetDataplaneStatusResponse`]
+//! of the dataplane it was applied to.
+//!
+//! [`check_consistency`] reports every [`Mismatch`] between what is configured
+//! and what the dataplane reports, with the values of both sides:
+//!
+//! - VPCs are matched by name against `vpcs`, their id, VNI and interfaces
+//!   must agree, and VPCs only in the status are reported too;
+//! - BGP neighbors are matched by VRF and address against `bgp`, their ASNs
+//!   must agree with the router ASN and `remote_asn`, and neighbors only in the
+//!   status are reported too;
+//! - underlay interfaces must be in `interface_runtime`, with the configured
+//!   MTU if there is one. Interfaces only in the status are not reported, the
+//!   dataplane may well have more than configured.
+
+use crate::config::{GatewayConfig, GetDataplaneStatusResponse, VpcStatus};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mismatch {
+    /// A configured VPC isn't in the status.
+    VpcMissing { vpc: String },
+    /// The status has a VPC that isn't configured.
+    VpcUnexpected { vpc: String },
+    VpcIdMismatch {
+        vpc: String,
+        config: String,
+        status: String,
+    },
+    VniMismatch {
+        vpc: String,
+        config: u32,
+        status: u32,
+    },
+    /// A configured VPC interface isn't in the VPC status.
+    VpcInterfaceMissing { vpc: String, ifname: String },
+    /// A configured BGP neighbor isn't in the status.
+    BgpNeighborMissing { vrf: String, neighbor: String },
+    /// The status has a BGP neighbor that isn't configured.
+    BgpNeighborUnexpected { vrf: String, neighbor: String },
+    /// The router ASN of the VRF differs from the local AS of the neighbor.
+    LocalAsMismatch {
+        vrf: String,
+        neighbor: String,
+        config: String,
+        status: u32,
+    },
+    /// `remote_asn` differs from the peer AS of the neighbor.
+    PeerAsMismatch {
+        vrf: String,
+        neighbor: String,
+        config: String,
+        status: u32,
+    },
+    /// A configured underlay interface isn't in `interface_runtime`.
+    InterfaceMissing { ifname: String },
+    MtuMismatch {
+        ifname: String,
+        config: u32,
+        status: u32,
+    },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::VpcMissing { vpc } => write!(f, "VPC {vpc} is configured but not in status"),
+            Self::VpcUnexpected { vpc } => write!(f, "VPC {vpc} is in status but not configured"),
+            Self::VpcIdMismatch {
+                vpc,
+                config,
+                status,
+            } => write!(
+                f,
+                "VPC {vpc} has id {config:?} in config but {status:?} in status"
+            ),
+            Self::VniMismatch {
+                vpc,
+                config,
+                status,
+            } => write!(
+                f,
+                "VPC {vpc} has VNI {config} in config but {status} in status"
+            ),
+            Self::VpcInterfaceMissing { vpc, ifname } => write!(
+                f,
+                "VPC {vpc} interface {ifname} is configured but not in status"
+            ),
+            Self::BgpNeighborMissing { vrf, neighbor } => write!(
+                f,
+                "BGP neighbor {neighbor} in VRF {vrf} is configured but not in status"
+            ),
+            Self::BgpNeighborUnexpected { vrf, neighbor } => write!(
+                f,
+                "BGP neighbor {neighbor} in VRF {vrf} is in status but not configured"
+            ),
+            Self::LocalAsMismatch {
+                vrf,
+                neighbor,
+                config,
+                status,
+            } => write!(
+                f,
+                "BGP neighbor {neighbor} in VRF {vrf} has local AS {config} in config but {status} in status"
+            ),
+            Self::PeerAsMismatch {
+                vrf,
+                neighbor,
+                config,
+                status,
+            } => write!(
+                f,
+                "BGP neighbor {neighbor} in VRF {vrf} has peer AS {config} in config but {status} in status"
+            ),
+            Self::InterfaceMissing { ifname } => {
+                write!(f, "Interface {ifname} is configured but not in status")
+            }
+            Self::MtuMismatch {
+                ifname,
+                config,
+                status,
+            } => write!(
+                f,
+                "Interface {ifname} has MTU {config} in config but {status} in status"
+            ),
+        }
+    }
+}
+
+/// Every mismatch between `config` and `status`, VPCs first, then BGP
+/// neighbors and interfaces, each sorted by name.
+#[must_use]
+pub fn check_consistency(
+    config: &GatewayConfig,
+    status: &GetDataplaneStatusResponse,
+) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+    check_vpcs(config, status, &mut mismatches);
+    check_bgp(config, status, &mut mismatches);
+    check_interfaces(config, status, &mut mismatches);
+    mismatches
+}
+
+fn check_vpcs(
+    config: &GatewayConfig,
+    status: &GetDataplaneStatusResponse,
+    mismatches: &mut Vec<Mismatch>,
+) {
+    // The map is keyed by name, prefer the name field if it is set
+    let mut reported: BTreeMap<&str, &VpcStatus> = status
+        .vpcs
+        .iter()
+        .map(|(key, vpc)| {
+            let name = if vpc.name.is_empty() { key } else { &vpc.name };
+            (name.as_str(), vpc)
+        })
+        .collect();
+    let configured: BTreeMap<_, _> = config
+        .overlay
+        .iter()
+        .flat_map(|overlay| &overlay.vpcs)
+        .map(|vpc| (vpc.name.as_str(), vpc))
+        .collect();
+
+    for (name, vpc) in configured {
+        let Some(vpc_status) = reported.remove(name) else {
+            mismatches.push(Mismatch::VpcMissing {
+                vpc: name.to_string(),
+            });
+            continue;
+        };
+        if !vpc_status.id.is_empty() && vpc_status.id != vpc.id {
+            mismatches.push(Mismatch::VpcIdMismatch {
+                vpc: name.to_string(),
+                config: vpc.id.clone(),
+                status: vpc_status.id.clone(),
+            });
+        }
+        if vpc_status.vni != vpc.vni {
+            mismatches.push(Mismatch::VniMismatch {
+                vpc: name.to_string(),
+                config: vpc.vni,
+                status: vpc_status.vni,
+            });
+        }
+        let ifnames: BTreeSet<_> = vpc_status
+            .interfaces
+            .iter()
+            .map(|(key, iface)| {
+                if iface.ifname.is_empty() {
+                    key.as_str()
+                } else {
+                    iface.ifname.as_str()
+                }
+            })
+            .collect();
+        let configured: BTreeSet<_> = vpc.interfaces.iter().map(|i| i.name.as_str()).collect();
+        for ifname in configured.difference(&ifnames) {
+            mismatches.push(Mismatch::VpcInterfaceMissing {
+                vpc: name.to_string(),
+                ifname: (*ifname).to_string(),
+            });
+        }
+    }
+    for name in reported.into_keys() {
+        mismatches.push(Mismatch::VpcUnexpected {
+            vpc: name.to_string(),
+        });
+    }
+}
+
+fn check_bgp(
+    config: &GatewayConfig,
+    status: &GetDataplaneStatusResponse,
+    mismatches: &mut Vec<Mismatch>,
+) {
+    let mut reported: BTreeMap<_, _> = status
+        .bgp
+        .iter()
+        .flat_map(|bgp| &bgp.vrfs)
+        .flat_map(|(vrf, vrf_status)| {
+            vrf_status
+                .neighbors
+                .iter()
+                .map(move |(addr, neighbor)| ((vrf.as_str(), addr.as_str()), neighbor))
+        })
+        .collect();
+    let configured: BTreeMap<_, _> = config
+        .underlay
+        .iter()
+        .flat_map(|underlay| &underlay.vrfs)
+        .filter_map(|vrf| Some((vrf.name.as_str(), vrf.router.as_ref()?)))
+        .flat_map(|(vrf, router)| {
+            router
+                .neighbors
+                .iter()
+                .map(move |neighbor| ((vrf, neighbor.address.as_str()), (router, neighbor)))
+        })
+        .collect();
+
+    for ((vrf, addr), (router, neighbor)) in configured {
+        let Some(neighbor_status) = reported.remove(&(vrf, addr)) else {
+            mismatches.push(Mismatch::BgpNeighborMissing {
+                vrf: vrf.to_string(),
+                neighbor: addr.to_string(),
+            });
+            continue;
+        };
+        if !asn_matches(&router.asn, neighbor_status.local_as) {
+            mismatches.push(Mismatch::LocalAsMismatch {
+                vrf: vrf.to_string(),
+                neighbor: addr.to_string(),
+                config: router.asn.clone(),
+                status: neighbor_status.local_as,
+            });
+        }
+        if !asn_matches(&neighbor.remote_asn, neighbor_status.peer_as) {
+            mismatches.push(Mismatch::PeerAsMismatch {
+                vrf: vrf.to_string(),
+                neighbor: addr.to_string(),
+                config: neighbor.remote_asn.clone(),
+                status: neighbor_status.peer_as,
+            });
+        }
+    }
+    for (vrf, addr) in reported.into_keys() {
+        mismatches.push(Mismatch::BgpNeighborUnexpected {
+            vrf: vrf.to_string(),
+            neighbor: addr.to_string(),
+        });
+    }
+}
+
+/// Whether a configured ASN matches the reported one. Unset ASNs in the status
+/// aren't reported and match anything.
+fn asn_matches(config: &str, status: u32) -> bool {
+    status == 0 || config.trim().parse::<u32>() == Ok(status)
+}
+
+fn check_interfaces(
+    config: &GatewayConfig,
+    status: &GetDataplaneStatusResponse,
+    mismatches: &mut Vec<Mismatch>,
+) {
+    let configured: BTreeMap<_, _> = config
+        .underlay
+        .iter()
+        .flat_map(|underlay| &underlay.vrfs)
+        .flat_map(|vrf| &vrf.interfaces)
+        .map(|iface| (iface.name.as_str(), iface))
+        .collect();
+    for (ifname, iface) in configured {
+        let Some(runtime) = status.interface_runtime.get(ifname) else {
+            mismatches.push(Mismatch::InterfaceMissing {
+                ifname: ifname.to_string(),
+            });
+            continue;
+        };
+        if let Some(mtu) = iface.mtu
+            && mtu != runtime.mtu
+        {
+            mismatches.push(Mismatch::MtuMismatch {
+                ifname: ifname.to_string(),
+                config: mtu,
+                status: runtime.mtu,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        BgpNeighbor, BgpNeighborStatus, BgpStatus, BgpVrfStatus, Interface, InterfaceRuntimeStatus,
+        Overlay, RouterConfig, Underlay, Vpc, VpcInterfaceStatus, Vrf,
+    };
+
+    fn config() -> GatewayConfig {
+        GatewayConfig {
+            overlay: Some(Overlay {
+                vpcs: vec![Vpc {
+                    id: "id-1".into(),
+                    name: "vpc-1".into(),
+                    vni: 1001,
+                    interfaces: vec![Interface {
+                        name: "vlan100".into(),
+                        ..Default::default()
+                    }],
+                }],
+                ..Default::default()
+            }),
+            underlay: Some(Underlay {
+                vrfs: vec![Vrf {
+                    name: "default".into(),
+                    interfaces: vec![Interface {
+                        name: "eth0".into(),
+                        mtu: Some(9000),
+                        ..Default::default()
+                    }],
+                    router: Some(RouterConfig {
+                        asn: "65000".into(),
+                        neighbors: vec![BgpNeighbor {
+                            address: "10.0.0.2".into(),
+                            remote_asn: "65001".into(),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn status() -> GetDataplaneStatusResponse {
+        GetDataplaneStatusResponse {
+            vpcs: [(
+                "vpc-1".to_string(),
+                VpcStatus {
+                    id: "id-1".into(),
+                    name: "vpc-1".into(),
+                    vni: 1001,
+                    interfaces: [(
+                        "vlan100".to_string(),
+                        VpcInterfaceStatus {
+                            ifname: "vlan100".into(),
+                            ..Default::default()
+                        },
+                    )]
+                    .into(),
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            bgp: Some(BgpStatus {
+                vrfs: [(
+                    "default".to_string(),
+                    BgpVrfStatus {
+                        neighbors: [(
+                            "10.0.0.2".to_string(),
+                            BgpNeighborStatus {
+                                local_as: 65000,
+                                peer_as: 65001,
+                                ..Default::default()
+                            },
+                        )]
+                        .into(),
+                    },
+                )]
+                .into(),
+            }),
+            interface_runtime: [(
+                "eth0".to_string(),
+                InterfaceRuntimeStatus {
+                    mtu: 9000,
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_consistent() {
+        assert_eq!(check_consistency(&config(), &status()), []);
+    }
+
+    #[test]
+    fn test_vpcs() {
+        let mut status = status();
+        let vpc = status.vpcs.get_mut("vpc-1").unwrap();
+        vpc.vni = 2001;
+        vpc.id = "id-2".into();
+        vpc.interfaces.clear();
+        status.vpcs.insert(
+            "vpc-9".into(),
+            VpcStatus {
+                name: "vpc-9".into(),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            check_consistency(&config(), &status),
+            [
+                Mismatch::VpcIdMismatch {
+                    vpc: "vpc-1".into(),
+                    config: "id-1".into(),
+                    status: "id-2".into(),
+                },
+                Mismatch::VniMismatch {
+                    vpc: "vpc-1".into(),
+                    config: 1001,
+                    status: 2001,
+                },
+                Mismatch::VpcInterfaceMissing {
+                    vpc: "vpc-1".into(),
+                    ifname: "vlan100".into(),
+                },
+                Mismatch::VpcUnexpected {
+                    vpc: "vpc-9".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bgp_and_interfaces() {
+        let mut status = status();
+        let vrf = status
+            .bgp
+            .as_mut()
+            .unwrap()
+            .vrfs
+            .get_mut("default")
+            .unwrap();
+        let neighbor = vrf.neighbors.remove("10.0.0.2").unwrap();
+        vrf.neighbors.insert(
+            "10.0.0.3".into(),
+            BgpNeighborStatus {
+                peer_as: 65002,
+                ..neighbor
+            },
+        );
+        status.interface_runtime.get_mut("eth0").unwrap().mtu = 1500;
+        let mismatches = check_consistency(&config(), &status);
+        assert_eq!(
+            mismatches,
+            [
+                Mismatch::BgpNeighborMissing {
+                    vrf: "default".into(),
+                    neighbor: "10.0.0.2".into(),
+                },
+                Mismatch::BgpNeighborUnexpected {
+                    vrf: "default".into(),
+                    neighbor: "10.0.0.3".into(),
+                },
+                Mismatch::MtuMismatch {
+                    ifname: "eth0".into(),
+                    config: 9000,
+                    status: 1500,
+                },
+            ]
+        );
+        assert_eq!(
+            mismatches[2].to_string(),
+            "Interface eth0 has MTU 9000 in config but 1500 in status"
+        );
+
+        status.interface_runtime.clear();
+        assert_eq!(
+            check_consistency(&config(), &status)[2],
+            Mismatch::InterfaceMissing {
+                ifname: "eth0".into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_asn() {
+        let mut status = status();
+        let vrf = status
+            .bgp
+            .as_mut()
+            .unwrap()
+            .vrfs
+            .get_mut("default")
+            .unwrap();
+        let neighbor = vrf.neighbors.get_mut("10.0.0.2").unwrap();
+        neighbor.local_as = 65100;
+        neighbor.peer_as = 0;
+        assert_eq!(
+            check_consistency(&config(), &status),
+            [Mismatch::LocalAsMismatch {
+                vrf: "default".into(),
+                neighbor: "10.0.0.2".into(),
+                config: "65000".into(),
+                status: 65100,
+            }]
+        );
+    }
+}
@@ -0,0 +1,238 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Drop reasons of the `drop_reasons` maps in the status counters.
+//!
+//! The maps are keyed by strings so that dataplanes can report reasons this
+//! crate doesn't know yet. [`DropReason`] names the known ones, and
+//! [`DropCounters`] gives [`VpcCounters`], [`VpcPeeringCounters`] and
+//! [`ExposeCounters`] the same view of their breakdown: drops not attributed to
+//! any reason are reported as [`UNSPECIFIED`], so the breakdown sums up to the
+//! total.
+
+use crate::config::{ExposeCounters, VpcCounters, VpcPeeringCounters};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::BuildHasher;
+
+/// Key used for drops not attributed to any reason.
+pub const UNSPECIFIED: &str = "unspecified";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DropReason {
+    /// No route to the destination.
+    NoRoute,
+    /// Denied by an ACL or peering policy.
+    AclDeny,
+    /// No free address or port for a NAT translation.
+    NatExhausted,
+    /// Larger than the MTU and not fragmentable.
+    MtuExceeded,
+    TtlExpired,
+    /// VXLAN packet for a VNI without a VPC.
+    UnknownVni,
+}
+
+impl DropReason {
+    pub const ALL: [Self; 6] = [
+        Self::NoRoute,
+        Self::AclDeny,
+        Self::NatExhausted,
+        Self::MtuExceeded,
+        Self::TtlExpired,
+        Self::UnknownVni,
+    ];
+
+    /// Key of the reason in the `drop_reasons` maps.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoRoute => "no_route",
+            Self::AclDeny => "acl_deny",
+            Self::NatExhausted => "nat_exhausted",
+            Self::MtuExceeded => "mtu_exceeded",
+            Self::TtlExpired => "ttl_expired",
+            Self::UnknownVni => "unknown_vni",
+        }
+    }
+
+    #[must_use]
+    pub fn from_str_name(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|reason| reason.as_str() == value)
+    }
+}
+
+impl fmt::Display for DropReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Add `count` drops for `reason` to a `drop_reasons` map.
+pub fn add_drops<S: BuildHasher>(
+    reasons: &mut HashMap<String, u64, S>,
+    reason: DropReason,
+    count: u64,
+) {
+    let entry = reasons.entry(reason.as_str().to_string()).or_default();
+    *entry = entry.saturating_add(count);
+}
+
+/// Sum several `drop_reasons` maps, e.g. of all exposes of a peering.
+#[must_use]
+pub fn merge_drop_reasons<'a>(
+    maps: impl IntoIterator<Item = &'a HashMap<String, u64>>,
+) -> HashMap<String, u64> {
+    let mut merged: HashMap<String, u64> = HashMap::new();
+    for map in maps {
+        for (reason, count) in map {
+            let entry = merged.entry(reason.clone()).or_default();
+            *entry = entry.saturating_add(*count);
+        }
+    }
+    merged
+}
+
+/// Counters with a total of drops and their breakdown by reason.
+pub trait DropCounters {
+    /// Total drops.
+    fn total_drops(&self) -> u64;
+
+    /// Drops by reason, as reported.
+    fn drop_reasons(&self) -> &HashMap<String, u64>;
+
+    /// Sum of the drops attributed to a reason.
+    fn attributed_drops(&self) -> u64 {
+        self.drop_reasons()
+            .values()
+            .fold(0, |sum, count| sum.saturating_add(*count))
+    }
+
+    /// Drops not attributed to any reason.
+    fn unattributed_drops(&self) -> u64 {
+        self.total_drops().saturating_sub(self.attributed_drops())
+    }
+
+    /// Drops attributed to `reason`.
+    fn drops_for(&self, reason: DropReason) -> u64 {
+        self.drop_reasons()
+            .get(reason.as_str())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Drops by reason, including the [`UNSPECIFIED`] remainder if any, so
+    /// that they sum up to the total unless more drops are attributed than
+    /// counted. Reasons without drops are left out.
+    fn drop_breakdown(&self) -> BTreeMap<&str, u64> {
+        let mut breakdown: BTreeMap<&str, u64> = self
+            .drop_reasons()
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(reason, count)| (reason.as_str(), *count))
+            .collect();
+        let unattributed = self.unattributed_drops();
+        if unattributed > 0 {
+            let entry = breakdown.entry(UNSPECIFIED).or_default();
+            *entry = entry.saturating_add(unattributed);
+        }
+        breakdown
+    }
+}
+
+macro_rules! impl_drop_counters {
+    ($($ty:ty),*) => {
+        $(impl DropCounters for $ty {
+            fn total_drops(&self) -> u64 {
+                self.drops
+            }
+
+            fn drop_reasons(&self) -> &HashMap<String, u64> {
+                &self.drop_reasons
+            }
+        })*
+    };
+}
+
+impl_drop_counters!(VpcCounters, VpcPeeringCounters, ExposeCounters);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        for reason in DropReason::ALL {
+            assert_eq!(DropReason::from_str_name(reason.as_str()), Some(reason));
+            assert_eq!(reason.to_string(), reason.as_str());
+        }
+        assert_eq!(DropReason::from_str_name(UNSPECIFIED), None);
+    }
+
+    #[test]
+    fn test_breakdown_sums_to_total() {
+        let mut counters = VpcCounters {
+            name: "vpc-1".into(),
+            drops: 10,
+            ..Default::default()
+        };
+        add_drops(&mut counters.drop_reasons, DropReason::NoRoute, 3);
+        add_drops(&mut counters.drop_reasons, DropReason::NoRoute, 2);
+        add_drops(&mut counters.drop_reasons, DropReason::TtlExpired, 1);
+        counters.drop_reasons.insert("future_reason".into(), 1);
+        counters
+            .drop_reasons
+            .insert(DropReason::AclDeny.to_string(), 0);
+
+        assert_eq!(counters.drops_for(DropReason::NoRoute), 5);
+        assert_eq!(counters.drops_for(DropReason::AclDeny), 0);
+        assert_eq!(counters.attributed_drops(), 7);
+        assert_eq!(counters.unattributed_drops(), 3);
+        let breakdown = counters.drop_breakdown();
+        assert_eq!(
+            breakdown,
+            BTreeMap::from([
+                ("future_reason", 1),
+                ("no_route", 5),
+                ("ttl_expired", 1),
+                (UNSPECIFIED, 3),
+            ])
+        );
+        assert_eq!(breakdown.values().sum::<u64>(), counters.drops);
+
+        // Over-attributed drops are reported as they are
+        counters.drops = 2;
+        assert_eq!(counters.unattributed_drops(), 0);
+        assert_eq!(counters.drop_breakdown().values().sum::<u64>(), 7);
+    }
+
+    #[test]
+    fn test_merge() {
+        let exposes = [
+            ExposeCounters {
+                drops: 3,
+                drop_reasons: [
+                    ("no_route".to_string(), 1),
+                    ("nat_exhausted".to_string(), 2),
+                ]
+                .into(),
+                ..Default::default()
+            },
+            ExposeCounters {
+                drops: 4,
+                drop_reasons: [("nat_exhausted".to_string(), 4)].into(),
+                ..Default::default()
+            },
+        ];
+        let peering = VpcPeeringCounters {
+            drops: exposes.iter().map(DropCounters::total_drops).sum(),
+            drop_reasons: merge_drop_reasons(exposes.iter().map(DropCounters::drop_reasons)),
+            ..Default::default()
+        };
+        assert_eq!(peering.drops_for(DropReason::NatExhausted), 6);
+        assert_eq!(peering.drops_for(DropReason::NoRoute), 1);
+        assert_eq!(peering.unattributed_drops(), 0);
+    }
+}
@@ -0,0 +1,347 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Mapping between the protocol [`Error`] enum and [`tonic::Status`].
+//!
+//! A dataplane may report a failed config update either in-band, as an
+//! [`UpdateConfigResponse`] with a non-`ERROR_NONE` error, or out-of-band, as
+//! a gRPC [`Status`]. Servers should use [`UpdateConfigError::into_status`] to
+//! build statuses so that the original [`Error`] survives the trip in an
+//! `ErrorInfo` detail. Clients can use [`UpdateConfigError::from_result`] (or
+//! [`ConfigServiceClient::apply_config`]) to handle both styles the same way.
+
+use crate::ConfigServiceClient;
+use crate::config::{Error, GatewayConfig, UpdateConfigRequest, UpdateConfigResponse};
+use prost::Message;
+use std::collections::HashMap;
+use tonic::transport::Channel;
+use tonic::{Code, Response, Status};
+
+/// Domain used in the `ErrorInfo` detail attached to statuses built by this crate.
+pub const ERROR_DOMAIN: &str = "gateway.githedgehog.com";
+
+const ERROR_INFO_TYPE_URL: &str = "type.googleapis.com/google.rpc.ErrorInfo";
+
+/// `google.rpc.Status`, carried in the `grpc-status-details-bin` trailer.
+#[derive(Clone, PartialEq, Message)]
+struct RpcStatus {
+    #[prost(int32, tag = "1")]
+    code: i32,
+    #[prost(string, tag = "2")]
+    message: String,
+    #[prost(message, repeated, tag = "3")]
+    details: Vec<Any>,
+}
+
+/// `google.protobuf.Any`.
+#[derive(Clone, PartialEq, Message)]
+struct Any {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+/// `google.rpc.ErrorInfo`.
+#[derive(Clone, PartialEq, Message)]
+struct ErrorInfo {
+    #[prost(string, tag = "1")]
+    reason: String,
+    #[prost(string, tag = "2")]
+    domain: String,
+    #[prost(map = "string, string", tag = "3")]
+    metadata: HashMap<String, String>,
+}
+
+/// The first `ErrorInfo` detail of `status`, if any.
+fn error_info(status: &Status) -> Option<ErrorInfo> {
+    RpcStatus::decode(status.details())
+        .ok()?
+        .details
+        .into_iter()
+        .find(|any| any.type_url == ERROR_INFO_TYPE_URL)
+        .and_then(|any| ErrorInfo::decode(any.value.as_slice()).ok())
+}
+
+impl From<Error> for Code {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::None => Code::Ok,
+            Error::ValidationFailed => Code::InvalidArgument,
+            Error::ApplyFailed => Code::Internal,
+            Error::UnknownError => Code::Unknown,
+        }
+    }
+}
+
+/// Normalized error of a config update, regardless of how the server reported it.
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum UpdateConfigError {
+    #[error("Config validation failed: {0}")]
+    ValidationFailed(String),
+    #[error("Config apply failed: {0}")]
+    ApplyFailed(String),
+    #[error("Unknown config update error: {0}")]
+    Unknown(String),
+    #[error("gRPC request failed: {0}")]
+    Rpc(Status),
+}
+
+impl UpdateConfigError {
+    /// Build an error from a protocol [`Error`] and message.
+    ///
+    /// Returns `None` for [`Error::None`].
+    #[must_use]
+    pub fn new(error: Error, message: impl Into<String>) -> Option<Self> {
+        let message = message.into();
+        match error {
+            Error::None => None,
+            Error::ValidationFailed => Some(Self::ValidationFailed(message)),
+            Error::ApplyFailed => Some(Self::ApplyFailed(message)),
+            Error::UnknownError => Some(Self::Unknown(message)),
+        }
+    }
+
+    /// Extract the error reported in-band by an [`UpdateConfigResponse`].
+    ///
+    /// Unrecognized error values are treated as [`Error::UnknownError`].
+    #[must_use]
+    pub fn from_response(response: &UpdateConfigResponse) -> Option<Self> {
+        let error = Error::try_from(response.error).unwrap_or(Error::UnknownError);
+        Self::new(error, response.message.clone())
+    }
+
+    /// Normalize the result of an `UpdateConfig` call.
+    ///
+    /// On success, returns the message from the response (usually empty).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the call failed or the response carries an error.
+    pub fn from_result(
+        result: Result<Response<UpdateConfigResponse>, Status>,
+    ) -> Result<String, Self> {
+        let response = result.map_err(Self::from)?.into_inner();
+        match Self::from_response(&response) {
+            Some(err) => Err(err),
+            None => Ok(response.message),
+        }
+    }
+
+    /// The protocol error this maps to. [`Self::Rpc`] maps to [`Error::UnknownError`].
+    #[must_use]
+    pub fn error(&self) -> Error {
+        match self {
+            Self::ValidationFailed(_) => Error::ValidationFailed,
+            Self::ApplyFailed(_) => Error::ApplyFailed,
+            Self::Unknown(_) | Self::Rpc(_) => Error::UnknownError,
+        }
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        match self {
+            Self::ValidationFailed(msg) | Self::ApplyFailed(msg) | Self::Unknown(msg) => msg,
+            Self::Rpc(status) => status.message(),
+        }
+    }
+
+    /// Build the in-band [`UpdateConfigResponse`] representation of this error.
+    #[must_use]
+    pub fn into_response(self) -> UpdateConfigResponse {
+        UpdateConfigResponse {
+            error: self.error().into(),
+            message: self.message().to_string(),
+        }
+    }
+
+    /// Build the out-of-band [`Status`] representation of this error.
+    ///
+    /// The status carries an `ErrorInfo` detail with the [`Error`] name as the reason,
+    /// so the error can be recovered exactly by [`UpdateConfigError::from`].
+    /// [`Self::Rpc`] statuses are returned unchanged.
+    #[must_use]
+    pub fn into_status(self) -> Status {
+        if let Self::Rpc(status) = self {
+            return status;
+        }
+        let error = self.error();
+        let code = Code::from(error);
+        let info = ErrorInfo {
+            reason: error.as_str_name().to_string(),
+            domain: ERROR_DOMAIN.to_string(),
+            metadata: HashMap::new(),
+        };
+        let details = RpcStatus {
+            code: code.into(),
+            message: self.message().to_string(),
+            details: vec![Any {
+                type_url: ERROR_INFO_TYPE_URL.to_string(),
+                value: info.encode_to_vec(),
+            }],
+        };
+        Status::with_details(code, self.message(), details.encode_to_vec().into())
+    }
+}
+
+impl From<Status> for UpdateConfigError {
+    fn from(status: Status) -> Self {
+        if let Some(info) = error_info(&status) {
+            if info.domain == ERROR_DOMAIN {
+                if let Some(err) = Error::from_str_name(&info.reason)
+                    .and_then(|error| Self::new(error, status.message()))
+                {
+                    return err;
+                }
+            }
+        }
+
+        // Without details, only InvalidArgument is unambiguous, everything else
+        // may as well come from the transport or some middleware
+        match status.code() {
+            Code::InvalidArgument => Self::ValidationFailed(status.message().to_string()),
+            _ => Self::Rpc(status),
+        }
+    }
+}
+
+impl From<UpdateConfigError> for Status {
+    fn from(err: UpdateConfigError) -> Self {
+        err.into_status()
+    }
+}
+
+impl ConfigServiceClient<Channel> {
+    /// Send `config` with `UpdateConfig` and normalize the outcome.
+    ///
+    /// On success, returns the message from the response (usually empty).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the call failed or the dataplane rejected the config,
+    /// no matter whether it was reported with a [`Status`] or in the response.
+    pub async fn apply_config(
+        &mut self,
+        config: GatewayConfig,
+    ) -> Result<String, UpdateConfigError> {
+        let result = self
+            .update_config(UpdateConfigRequest {
+                config: Some(config),
+            })
+            .await;
+        UpdateConfigError::from_result(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERRORS: [Error; 3] = [
+        Error::ValidationFailed,
+        Error::ApplyFailed,
+        Error::UnknownError,
+    ];
+
+    #[test]
+    fn test_none_is_not_an_error() {
+        assert!(UpdateConfigError::new(Error::None, "all good").is_none());
+        let response = UpdateConfigResponse {
+            error: Error::None.into(),
+            message: "all good".into(),
+        };
+        assert!(UpdateConfigError::from_response(&response).is_none());
+        let result = UpdateConfigError::from_result(Ok(Response::new(response)));
+        assert_eq!(result.unwrap(), "all good");
+    }
+
+    #[test]
+    fn test_status_round_trip() {
+        for error in ERRORS {
+            let err = UpdateConfigError::new(error, "boom").unwrap();
+            let status = err.into_status();
+            assert_eq!(status.code(), Code::from(error));
+            assert_eq!(status.message(), "boom");
+
+            let back = UpdateConfigError::from(status);
+            assert_eq!(back.error(), error);
+            assert_eq!(back.message(), "boom");
+        }
+    }
+
+    #[test]
+    fn test_status_details_match_tonic_types() {
+        use tonic_types::{ErrorDetails, StatusExt};
+
+        let status = UpdateConfigError::new(Error::ApplyFailed, "boom")
+            .unwrap()
+            .into_status();
+        let info = status.get_details_error_info().unwrap();
+        assert_eq!(info.reason, "ERROR_APPLY_FAILED");
+        assert_eq!(info.domain, ERROR_DOMAIN);
+
+        let status = Status::with_error_details(
+            Code::Internal,
+            "boom",
+            ErrorDetails::with_error_info("ERROR_VALIDATION_FAILED", ERROR_DOMAIN, HashMap::new()),
+        );
+        let err = UpdateConfigError::from(status);
+        assert_eq!(err.error(), Error::ValidationFailed);
+    }
+
+    #[test]
+    fn test_response_round_trip() {
+        for error in ERRORS {
+            let response = UpdateConfigError::new(error, "boom")
+                .unwrap()
+                .into_response();
+            assert_eq!(response.error, i32::from(error));
+
+            let back = UpdateConfigError::from_response(&response).unwrap();
+            assert_eq!(back.error(), error);
+            assert_eq!(back.message(), "boom");
+        }
+    }
+
+    #[test]
+    fn test_both_styles_normalize_the_same() {
+        for error in ERRORS {
+            let in_band = UpdateConfigError::from_result(Ok(Response::new(
+                UpdateConfigError::new(error, "boom")
+                    .unwrap()
+                    .into_response(),
+            )))
+            .unwrap_err();
+            let out_of_band =
+                UpdateConfigError::from_result(Err(UpdateConfigError::new(error, "boom")
+                    .unwrap()
+                    .into_status()))
+                .unwrap_err();
+            assert_eq!(in_band.error(), out_of_band.error());
+            assert_eq!(in_band.message(), out_of_band.message());
+        }
+    }
+
+    #[test]
+    fn test_plain_status() {
+        let err = UpdateConfigError::from(Status::invalid_argument("bad vni"));
+        assert!(matches!(err, UpdateConfigError::ValidationFailed(ref msg) if msg == "bad vni"));
+
+        let err = UpdateConfigError::from(Status::unavailable("connection refused"));
+        assert!(
+            matches!(err, UpdateConfigError::Rpc(ref status) if status.code() == Code::Unavailable)
+        );
+        assert_eq!(err.error(), Error::UnknownError);
+        assert_eq!(err.into_status().code(), Code::Unavailable);
+    }
+
+    #[test]
+    fn test_unknown_error_value() {
+        let response = UpdateConfigResponse {
+            error: 42,
+            message: "from the future".into(),
+        };
+        let err = UpdateConfigError::from_response(&response).unwrap();
+        assert_eq!(err.error(), Error::UnknownError);
+    }
+}
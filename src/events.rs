@@ -0,0 +1,447 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Events derived from successive dataplane statuses.
+//!
+//! [`diff_status`] compares two [`GetDataplaneStatusResponse`]s and returns what
+//! changed between them as [`StatusEvent`]s, [`StatusEventDetector`] does the
+//! same for a stream of statuses. Neighbors and interfaces missing from a status
+//! count as being in the unset or unknown state, so they report a transition
+//! when they appear or disappear. Counters that went down, e.g. because the
+//! dataplane restarted, don't produce events.
+
+use crate::config::{
+    BgpNeighborSessionState, BgpNeighborStatus, DataplaneStatusType, FrrLifecycleState,
+    GetDataplaneStatusResponse, InterfaceOperStatusType,
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatusEvent {
+    /// `DataplaneStatusInfo.status` changed.
+    DataplaneStatusChanged {
+        from: DataplaneStatusType,
+        to: DataplaneStatusType,
+    },
+    /// `FrrStatus.state` changed.
+    FrrStateChanged {
+        from: FrrLifecycleState,
+        to: FrrLifecycleState,
+    },
+    /// `FrrStatus.restarts` went up.
+    FrrRestarted { restarts: u32, increase: u32 },
+    /// `FrrStatus.failed_configs` went up.
+    FrrConfigFailed {
+        failed_configs: u32,
+        increase: u32,
+        last_apply_error: String,
+    },
+    /// Operational status of an interface changed.
+    InterfaceOperChanged {
+        ifname: String,
+        from: InterfaceOperStatusType,
+        to: InterfaceOperStatusType,
+    },
+    /// BGP session state of a neighbor changed.
+    BgpSessionStateChanged {
+        vrf: String,
+        neighbor: String,
+        from: BgpNeighborSessionState,
+        to: BgpNeighborSessionState,
+    },
+    /// `connections_dropped` of a BGP neighbor went up.
+    BgpConnectionsDropped {
+        vrf: String,
+        neighbor: String,
+        increase: u64,
+        last_reset_reason: String,
+    },
+}
+
+impl fmt::Display for StatusEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DataplaneStatusChanged { from, to } => write!(
+                f,
+                "Dataplane status changed from {} to {}",
+                from.as_str_name(),
+                to.as_str_name()
+            ),
+            Self::FrrStateChanged { from, to } => write!(
+                f,
+                "FRR state changed from {} to {}",
+                from.as_str_name(),
+                to.as_str_name()
+            ),
+            Self::FrrRestarted { restarts, increase } => {
+                write!(f, "FRR restarted {increase} time(s), {restarts} in total")
+            }
+            Self::FrrConfigFailed {
+                failed_configs,
+                increase,
+                last_apply_error,
+            } => {
+                write!(
+                    f,
+                    "FRR failed to apply {increase} config(s), {failed_configs} in total"
+                )?;
+                if !last_apply_error.is_empty() {
+                    write!(f, ", last error: {last_apply_error}")?;
+                }
+                Ok(())
+            }
+            Self::InterfaceOperChanged { ifname, from, to } => write!(
+                f,
+                "Interface {ifname} changed from {} to {}",
+                from.as_str_name(),
+                to.as_str_name()
+            ),
+            Self::BgpSessionStateChanged {
+                vrf,
+                neighbor,
+                from,
+                to,
+            } => write!(
+                f,
+                "BGP neighbor {neighbor} in VRF {vrf} changed from {} to {}",
+                from.as_str_name(),
+                to.as_str_name()
+            ),
+            Self::BgpConnectionsDropped {
+                vrf,
+                neighbor,
+                increase,
+                last_reset_reason,
+            } => {
+                write!(
+                    f,
+                    "BGP neighbor {neighbor} in VRF {vrf} dropped {increase} connection(s)"
+                )?;
+                if !last_reset_reason.is_empty() {
+                    write!(f, ", last reset reason: {last_reset_reason}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Events for everything that changed from `previous` to `current`.
+///
+/// Events are ordered by kind, dataplane and FRR first, then interfaces and BGP
+/// neighbors sorted by name.
+#[must_use]
+pub fn diff_status(
+    previous: &GetDataplaneStatusResponse,
+    current: &GetDataplaneStatusResponse,
+) -> Vec<StatusEvent> {
+    let mut events = Vec::new();
+
+    let dataplane = |status: &GetDataplaneStatusResponse| {
+        status
+            .dataplane_status
+            .as_ref()
+            .map_or(DataplaneStatusType::DataplaneStatusUnknown, |info| {
+                info.status()
+            })
+    };
+    let (from, to) = (dataplane(previous), dataplane(current));
+    if from != to {
+        events.push(StatusEvent::DataplaneStatusChanged { from, to });
+    }
+
+    if let (Some(prev), Some(cur)) = (&previous.frr_status, &current.frr_status) {
+        if cur.state != prev.state {
+            events.push(StatusEvent::FrrStateChanged {
+                from: prev.state(),
+                to: cur.state(),
+            });
+        }
+        if cur.restarts > prev.restarts {
+            events.push(StatusEvent::FrrRestarted {
+                restarts: cur.restarts,
+                increase: cur.restarts - prev.restarts,
+            });
+        }
+        if cur.failed_configs > prev.failed_configs {
+            events.push(StatusEvent::FrrConfigFailed {
+                failed_configs: cur.failed_configs,
+                increase: cur.failed_configs - prev.failed_configs,
+                last_apply_error: cur.last_apply_error.clone(),
+            });
+        }
+    }
+
+    let (prev_ifaces, cur_ifaces) = (oper_statuses(previous), oper_statuses(current));
+    let ifnames: BTreeSet<_> = prev_ifaces.keys().chain(cur_ifaces.keys()).collect();
+    for ifname in ifnames {
+        let status = |ifaces: &BTreeMap<&str, InterfaceOperStatusType>| {
+            ifaces
+                .get(ifname)
+                .copied()
+                .unwrap_or(InterfaceOperStatusType::InterfaceStatusUnknown)
+        };
+        let (from, to) = (status(&prev_ifaces), status(&cur_ifaces));
+        if from != to {
+            events.push(StatusEvent::InterfaceOperChanged {
+                ifname: (*ifname).to_string(),
+                from,
+                to,
+            });
+        }
+    }
+
+    let (prev_neighbors, cur_neighbors) = (bgp_neighbors(previous), bgp_neighbors(current));
+    let keys: BTreeSet<_> = prev_neighbors.keys().chain(cur_neighbors.keys()).collect();
+    for &(vrf, neighbor) in keys {
+        let prev = prev_neighbors.get(&(vrf, neighbor));
+        let cur = cur_neighbors.get(&(vrf, neighbor));
+        let state = |n: Option<&&BgpNeighborStatus>| {
+            n.map_or(BgpNeighborSessionState::BgpStateUnset, |n| {
+                n.session_state()
+            })
+        };
+        let (from, to) = (state(prev), state(cur));
+        if from != to {
+            events.push(StatusEvent::BgpSessionStateChanged {
+                vrf: vrf.to_string(),
+                neighbor: neighbor.to_string(),
+                from,
+                to,
+            });
+        }
+        if let (Some(prev), Some(cur)) = (prev, cur)
+            && cur.connections_dropped > prev.connections_dropped
+        {
+            events.push(StatusEvent::BgpConnectionsDropped {
+                vrf: vrf.to_string(),
+                neighbor: neighbor.to_string(),
+                increase: cur.connections_dropped - prev.connections_dropped,
+                last_reset_reason: cur.last_reset_reason.clone(),
+            });
+        }
+    }
+    events
+}
+
+/// Operational status by interface, from `interface_runtime` where available.
+fn oper_statuses(status: &GetDataplaneStatusResponse) -> BTreeMap<&str, InterfaceOperStatusType> {
+    let mut ifaces: BTreeMap<_, _> = status
+        .interface_statuses
+        .iter()
+        .map(|iface| (iface.ifname.as_str(), iface.oper_status()))
+        .collect();
+    ifaces.extend(
+        status
+            .interface_runtime
+            .iter()
+            .map(|(ifname, runtime)| (ifname.as_str(), runtime.oper_status())),
+    );
+    ifaces
+}
+
+fn bgp_neighbors(
+    status: &GetDataplaneStatusResponse,
+) -> BTreeMap<(&str, &str), &BgpNeighborStatus> {
+    status
+        .bgp
+        .iter()
+        .flat_map(|bgp| &bgp.vrfs)
+        .flat_map(|(vrf, vrf_status)| {
+            vrf_status
+                .neighbors
+                .iter()
+                .map(move |(addr, neighbor)| ((vrf.as_str(), addr.as_str()), neighbor))
+        })
+        .collect()
+}
+
+/// Detects events across a stream of statuses.
+#[derive(Debug, Default)]
+pub struct StatusEventDetector {
+    previous: Option<GetDataplaneStatusResponse>,
+}
+
+impl StatusEventDetector {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compare `status` with the previously observed one and remember it for
+    /// the next call. The first status only sets the baseline.
+    pub fn observe(&mut self, status: GetDataplaneStatusResponse) -> Vec<StatusEvent> {
+        let events = self
+            .previous
+            .as_ref()
+            .map(|previous| diff_status(previous, &status))
+            .unwrap_or_default();
+        self.previous = Some(status);
+        events
+    }
+
+    /// Forget the baseline, e.g. after losing the connection to the dataplane.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        BgpStatus, BgpVrfStatus, DataplaneStatusInfo, FrrStatus, InterfaceRuntimeStatus,
+        InterfaceStatus,
+    };
+
+    fn with_neighbor(state: BgpNeighborSessionState, dropped: u64) -> GetDataplaneStatusResponse {
+        GetDataplaneStatusResponse {
+            bgp: Some(BgpStatus {
+                vrfs: [(
+                    "default".to_string(),
+                    BgpVrfStatus {
+                        neighbors: [(
+                            "10.0.0.1".to_string(),
+                            BgpNeighborStatus {
+                                session_state: state.into(),
+                                connections_dropped: dropped,
+                                last_reset_reason: "Hold Timer Expired".into(),
+                                ..Default::default()
+                            },
+                        )]
+                        .into(),
+                    },
+                )]
+                .into(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_changes() {
+        let status = with_neighbor(BgpNeighborSessionState::BgpStateEstablished, 1);
+        assert!(diff_status(&status, &status).is_empty());
+    }
+
+    #[test]
+    fn test_bgp() {
+        let prev = with_neighbor(BgpNeighborSessionState::BgpStateEstablished, 1);
+        let cur = with_neighbor(BgpNeighborSessionState::BgpStateActive, 3);
+        let events = diff_status(&prev, &cur);
+        assert_eq!(
+            events,
+            [
+                StatusEvent::BgpSessionStateChanged {
+                    vrf: "default".into(),
+                    neighbor: "10.0.0.1".into(),
+                    from: BgpNeighborSessionState::BgpStateEstablished,
+                    to: BgpNeighborSessionState::BgpStateActive,
+                },
+                StatusEvent::BgpConnectionsDropped {
+                    vrf: "default".into(),
+                    neighbor: "10.0.0.1".into(),
+                    increase: 2,
+                    last_reset_reason: "Hold Timer Expired".into(),
+                },
+            ]
+        );
+        assert_eq!(
+            events[1].to_string(),
+            "BGP neighbor 10.0.0.1 in VRF default dropped 2 connection(s), last reset reason: Hold Timer Expired"
+        );
+
+        // Gone neighbors are unset, dropped connections going down is a reset
+        let events = diff_status(&cur, &GetDataplaneStatusResponse::default());
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0],
+            StatusEvent::BgpSessionStateChanged {
+                to: BgpNeighborSessionState::BgpStateUnset,
+                ..
+            }
+        ));
+        let prev = with_neighbor(BgpNeighborSessionState::BgpStateActive, 3);
+        let cur = with_neighbor(BgpNeighborSessionState::BgpStateActive, 0);
+        assert!(diff_status(&prev, &cur).is_empty());
+    }
+
+    #[test]
+    fn test_dataplane_frr_and_interfaces() {
+        let prev = GetDataplaneStatusResponse {
+            dataplane_status: Some(DataplaneStatusInfo {
+                status: DataplaneStatusType::DataplaneStatusHealthy.into(),
+            }),
+            frr_status: Some(FrrStatus {
+                restarts: 1,
+                failed_configs: 2,
+                ..Default::default()
+            }),
+            interface_statuses: vec![InterfaceStatus {
+                ifname: "eth0".into(),
+                oper_status: InterfaceOperStatusType::InterfaceStatusOperUp.into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut cur = prev.clone();
+        cur.dataplane_status = Some(DataplaneStatusInfo {
+            status: DataplaneStatusType::DataplaneStatusError.into(),
+        });
+        cur.frr_status = Some(FrrStatus {
+            state: FrrLifecycleState::FrrStateStarting.into(),
+            restarts: 2,
+            failed_configs: 3,
+            last_apply_error: "reload timed out".into(),
+            ..Default::default()
+        });
+        // Runtime status wins over the basic one
+        cur.interface_runtime.insert(
+            "eth0".into(),
+            InterfaceRuntimeStatus {
+                oper_status: InterfaceOperStatusType::InterfaceStatusOperDown.into(),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            diff_status(&prev, &cur),
+            [
+                StatusEvent::DataplaneStatusChanged {
+                    from: DataplaneStatusType::DataplaneStatusHealthy,
+                    to: DataplaneStatusType::DataplaneStatusError,
+                },
+                StatusEvent::FrrStateChanged {
+                    from: FrrLifecycleState::FrrStateUnknown,
+                    to: FrrLifecycleState::FrrStateStarting,
+                },
+                StatusEvent::FrrRestarted {
+                    restarts: 2,
+                    increase: 1
+                },
+                StatusEvent::FrrConfigFailed {
+                    failed_configs: 3,
+                    increase: 1,
+                    last_apply_error: "reload timed out".into(),
+                },
+                StatusEvent::InterfaceOperChanged {
+                    ifname: "eth0".into(),
+                    from: InterfaceOperStatusType::InterfaceStatusOperUp,
+                    to: InterfaceOperStatusType::InterfaceStatusOperDown,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detector() {
+        let mut detector = StatusEventDetector::new();
+        let up = with_neighbor(BgpNeighborSessionState::BgpStateEstablished, 0);
+        let down = with_neighbor(BgpNeighborSessionState::BgpStateIdle, 0);
+        assert!(detector.observe(down.clone()).is_empty());
+        assert_eq!(detector.observe(up.clone()).len(), 1);
+        assert!(detector.observe(up).is_empty());
+        detector.reset();
+        assert!(detector.observe(down).is_empty());
+    }
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! EVPN status of the gateway VTEP: remote VTEPs and VNIs.
+//!
+//! `GetDataplaneStatusResponse.vnis` is keyed by VNI, the same number as the
+//! `vni` of the [`VpcStatus`] using it, and remote VTEPs list the VNIs they
+//! were learned for. The functions here follow these links in both directions.
+
+use crate::config::{
+    GetDataplaneStatusResponse, RemoteVtepStatus, VniStatus, VpcStatus, VtepReachability,
+};
+
+/// EVPN view of a VPC.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VpcEvpnStatus<'a> {
+    pub vpc: &'a VpcStatus,
+    /// Status of the VNI of the VPC, if the dataplane reports it.
+    pub vni: Option<&'a VniStatus>,
+    /// Remote VTEPs that learned the VNI of the VPC, sorted by address.
+    pub remote_vteps: Vec<&'a RemoteVtepStatus>,
+}
+
+impl VpcEvpnStatus<'_> {
+    /// Whether some remote VTEP learned the VNI but none of them is reachable.
+    #[must_use]
+    pub fn all_vteps_unreachable(&self) -> bool {
+        !self.remote_vteps.is_empty()
+            && self
+                .remote_vteps
+                .iter()
+                .all(|vtep| vtep.reachability() == VtepReachability::Unreachable)
+    }
+}
+
+/// EVPN view of the VPC named `vpc`, `None` if it isn't in the status.
+#[must_use]
+pub fn vpc_evpn<'a>(
+    status: &'a GetDataplaneStatusResponse,
+    vpc: &str,
+) -> Option<VpcEvpnStatus<'a>> {
+    let vpc = status.vpcs.get(vpc)?;
+    Some(VpcEvpnStatus {
+        vpc,
+        vni: status.vnis.get(&vpc.vni),
+        remote_vteps: remote_vteps_for_vni(status, vpc.vni),
+    })
+}
+
+/// The VPC using `vni`, if any.
+///
+/// If several VPCs claim the same VNI, the one whose name sorts first.
+#[must_use]
+pub fn vpc_for_vni(status: &GetDataplaneStatusResponse, vni: u32) -> Option<&VpcStatus> {
+    status
+        .vpcs
+        .iter()
+        .filter(|(_, vpc)| vpc.vni == vni)
+        .min_by_key(|(name, _)| *name)
+        .map(|(_, vpc)| vpc)
+}
+
+/// Remote VTEPs that learned `vni`, sorted by address.
+#[must_use]
+pub fn remote_vteps_for_vni(
+    status: &GetDataplaneStatusResponse,
+    vni: u32,
+) -> Vec<&RemoteVtepStatus> {
+    let mut vteps: Vec<_> = status
+        .remote_vteps
+        .values()
+        .filter(|vtep| vtep.vnis.contains(&vni))
+        .collect();
+    vteps.sort_by(|a, b| a.address.cmp(&b.address));
+    vteps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vtep(address: &str, reachability: VtepReachability, vnis: &[u32]) -> RemoteVtepStatus {
+        RemoteVtepStatus {
+            address: address.into(),
+            reachability: reachability.into(),
+            vnis: vnis.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_vpc_evpn() {
+        let status = GetDataplaneStatusResponse {
+            vpcs: [(
+                "vpc-1".to_string(),
+                VpcStatus {
+                    name: "vpc-1".into(),
+                    vni: 1001,
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            vnis: [(
+                1001,
+                VniStatus {
+                    vni: 1001,
+                    mac_count: 4,
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            remote_vteps: [
+                vtep("10.255.0.3", VtepReachability::Unreachable, &[1001]),
+                vtep("10.255.0.2", VtepReachability::Unreachable, &[1001, 1002]),
+                vtep("10.255.0.4", VtepReachability::Reachable, &[1002]),
+            ]
+            .into_iter()
+            .map(|vtep| (vtep.address.clone(), vtep))
+            .collect(),
+            ..Default::default()
+        };
+
+        let evpn = vpc_evpn(&status, "vpc-1").unwrap();
+        assert_eq!(evpn.vni.unwrap().mac_count, 4);
+        let addresses: Vec<_> = evpn
+            .remote_vteps
+            .iter()
+            .map(|v| v.address.as_str())
+            .collect();
+        assert_eq!(addresses, ["10.255.0.2", "10.255.0.3"]);
+        assert!(evpn.all_vteps_unreachable());
+        assert!(vpc_evpn(&status, "vpc-2").is_none());
+
+        assert_eq!(vpc_for_vni(&status, 1001).unwrap().name, "vpc-1");
+        assert!(vpc_for_vni(&status, 1002).is_none());
+        assert_eq!(remote_vteps_for_vni(&status, 1002).len(), 2);
+    }
+
+    #[test]
+    fn test_vni_collision() {
+        let status = GetDataplaneStatusResponse {
+            vpcs: ["vpc-3", "vpc-1", "vpc-2"]
+                .into_iter()
+                .map(|name| {
+                    let vpc = VpcStatus {
+                        name: name.into(),
+                        vni: 1001,
+                        ..Default::default()
+                    };
+                    (name.to_string(), vpc)
+                })
+                .collect(),
+            ..Default::default()
+        };
+        assert_eq!(vpc_for_vni(&status, 1001).unwrap().name, "vpc-1");
+    }
+}
@@ -0,0 +1,585 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Fault injection for testing clients against flaky dataplanes.
+//!
+//! [`FaultInjector`] wraps any [`ConfigService`] and, for every call, asks a
+//! [`FaultSchedule`] which [`Fault`]s to inject. Schedules can be scripted
+//! ([`ScriptedFaults`]) or seeded-random ([`RandomFaults`]).
+
+use crate::config::{
+    GatewayConfig, GetConfigGenerationRequest, GetConfigGenerationResponse, GetConfigRequest,
+    GetDataplaneStatusRequest, GetDataplaneStatusResponse, GetRoutesRequest, GetRoutesResponse,
+    ListNatSessionsRequest, ListNatSessionsResponse, LookupRouteRequest, LookupRouteResponse,
+    UpdateConfigRequest, UpdateConfigResponse,
+};
+use crate::{ConfigService, Error};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+use tonic::{Code, Request, Response, Status};
+
+/// RPCs of the config service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rpc {
+    GetConfig,
+    GetConfigGeneration,
+    UpdateConfig,
+    GetDataplaneStatus,
+    ListNatSessions,
+    GetRoutes,
+    LookupRoute,
+}
+
+impl Rpc {
+    /// Method name as in the proto service definition.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::GetConfig => "GetConfig",
+            Self::GetConfigGeneration => "GetConfigGeneration",
+            Self::UpdateConfig => "UpdateConfig",
+            Self::GetDataplaneStatus => "GetDataplaneStatus",
+            Self::ListNatSessions => "ListNatSessions",
+            Self::GetRoutes => "GetRoutes",
+            Self::LookupRoute => "LookupRoute",
+        }
+    }
+
+    #[must_use]
+    pub fn from_str_name(value: &str) -> Option<Self> {
+        match value {
+            "GetConfig" => Some(Self::GetConfig),
+            "GetConfigGeneration" => Some(Self::GetConfigGeneration),
+            "UpdateConfig" => Some(Self::UpdateConfig),
+            "GetDataplaneStatus" => Some(Self::GetDataplaneStatus),
+            "ListNatSessions" => Some(Self::ListNatSessions),
+            "GetRoutes" => Some(Self::GetRoutes),
+            "LookupRoute" => Some(Self::LookupRoute),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// Delay the call, then continue with the remaining faults or the real call.
+    Delay(Duration),
+    /// Fail the call with a status with the given code, as a broken transport would.
+    Error(Code),
+    /// Answer `UpdateConfig` with `ERROR_APPLY_FAILED` without passing it on.
+    /// Ignored for other RPCs.
+    ApplyFailed,
+    /// Answer `GetConfigGeneration` with the generation that was current before
+    /// the last accepted update. Ignored for other RPCs.
+    StaleGeneration,
+    /// Drop the stream mid-call, the client gets `CANCELLED` right away as if
+    /// the stream had been reset.
+    Drop,
+}
+
+/// Decides which faults to inject into each call.
+pub trait FaultSchedule: Send + 'static {
+    /// Faults to inject into the next call of `rpc`, applied in order.
+    ///
+    /// [`Fault::Delay`]s accumulate, the first other fault that applies to `rpc`
+    /// decides the outcome of the call.
+    fn next(&mut self, rpc: Rpc) -> Vec<Fault>;
+
+    /// Whether [`Self::next`] may still return faults of the same kind as
+    /// `fault`, regardless of their parameters.
+    ///
+    /// Used to skip bookkeeping for faults that will never be injected.
+    fn may_inject(&self, fault: &Fault) -> bool {
+        let _ = fault;
+        true
+    }
+}
+
+/// Scripted schedule: faults for each call are queued per RPC.
+///
+/// Once the queue for an RPC is exhausted, calls go through untouched.
+#[derive(Debug, Default, Clone)]
+pub struct ScriptedFaults {
+    queues: HashMap<Rpc, VecDeque<Vec<Fault>>>,
+}
+
+impl ScriptedFaults {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue faults for the next not yet scripted call of `rpc`.
+    ///
+    /// An empty list lets that call through untouched.
+    #[must_use]
+    pub fn then(mut self, rpc: Rpc, faults: impl IntoIterator<Item = Fault>) -> Self {
+        self.queues
+            .entry(rpc)
+            .or_default()
+            .push_back(faults.into_iter().collect());
+        self
+    }
+
+    /// Queue the same faults for the next `count` calls of `rpc`.
+    #[must_use]
+    pub fn times(mut self, rpc: Rpc, count: usize, faults: &[Fault]) -> Self {
+        for _ in 0..count {
+            self = self.then(rpc, faults.iter().cloned());
+        }
+        self
+    }
+}
+
+impl FaultSchedule for ScriptedFaults {
+    fn next(&mut self, rpc: Rpc) -> Vec<Fault> {
+        self.queues
+            .get_mut(&rpc)
+            .and_then(VecDeque::pop_front)
+            .unwrap_or_default()
+    }
+
+    fn may_inject(&self, fault: &Fault) -> bool {
+        let kind = std::mem::discriminant(fault);
+        self.queues
+            .values()
+            .flatten()
+            .flatten()
+            .any(|queued| std::mem::discriminant(queued) == kind)
+    }
+}
+
+/// Probability of each fault for [`RandomFaults`], each in `0.0..=1.0`.
+#[derive(Debug, Clone, Default)]
+pub struct FaultProbabilities {
+    pub delay: f64,
+    /// Delays are uniformly distributed up to this value.
+    pub max_delay: Duration,
+    pub error: f64,
+    pub apply_failed: f64,
+    pub stale_generation: f64,
+    pub drop: f64,
+}
+
+/// Seeded-random schedule, the same seed produces the same sequence of faults.
+#[derive(Debug, Clone)]
+pub struct RandomFaults {
+    rng: SplitMix64,
+    probabilities: FaultProbabilities,
+}
+
+impl RandomFaults {
+    #[must_use]
+    pub fn new(seed: u64, probabilities: FaultProbabilities) -> Self {
+        Self {
+            rng: SplitMix64(seed),
+            probabilities,
+        }
+    }
+}
+
+impl FaultSchedule for RandomFaults {
+    fn next(&mut self, rpc: Rpc) -> Vec<Fault> {
+        let p = &self.probabilities;
+        let mut faults = Vec::new();
+        if self.rng.chance(p.delay) {
+            faults.push(Fault::Delay(p.max_delay.mul_f64(self.rng.next_f64())));
+        }
+        // Always draw for every fault to keep the sequence independent of the RPC
+        let error = self.rng.chance(p.error);
+        let apply_failed = self.rng.chance(p.apply_failed);
+        let stale = self.rng.chance(p.stale_generation);
+        let drop = self.rng.chance(p.drop);
+        if drop {
+            faults.push(Fault::Drop);
+        } else if error {
+            faults.push(Fault::Error(Code::Unavailable));
+        } else if apply_failed && rpc == Rpc::UpdateConfig {
+            faults.push(Fault::ApplyFailed);
+        } else if stale && rpc == Rpc::GetConfigGeneration {
+            faults.push(Fault::StaleGeneration);
+        }
+        faults
+    }
+
+    fn may_inject(&self, fault: &Fault) -> bool {
+        let p = &self.probabilities;
+        let probability = match fault {
+            Fault::Delay(_) => p.delay,
+            Fault::Error(_) => p.error,
+            Fault::ApplyFailed => p.apply_failed,
+            Fault::StaleGeneration => p.stale_generation,
+            Fault::Drop => p.drop,
+        };
+        probability > 0.0
+    }
+}
+
+/// `SplitMix64`, good enough for picking faults and jitter and doesn't need a
+/// dependency.
+#[derive(Debug, Clone)]
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0.0..1.0`.
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    fn chance(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+}
+
+/// What the injector decided to do with a call.
+enum Outcome {
+    Forward,
+    Fail(Status),
+    ApplyFailed,
+    StaleGeneration,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Generation before the last accepted update
+    previous_generation: i64,
+    injected: Vec<(Rpc, Fault)>,
+}
+
+/// [`ConfigService`] wrapper injecting faults according to a [`FaultSchedule`].
+pub struct FaultInjector<S> {
+    inner: S,
+    schedule: Mutex<Box<dyn FaultSchedule>>,
+    state: Mutex<State>,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+impl<S: ConfigService> FaultInjector<S> {
+    pub fn new(inner: S, schedule: impl FaultSchedule) -> Self {
+        Self {
+            inner,
+            schedule: Mutex::new(Box::new(schedule)),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// The wrapped service.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Faults injected so far, in order.
+    #[must_use]
+    pub fn injected(&self) -> Vec<(Rpc, Fault)> {
+        lock(&self.state).injected.clone()
+    }
+
+    fn applies(rpc: Rpc, fault: &Fault) -> bool {
+        match fault {
+            Fault::ApplyFailed => rpc == Rpc::UpdateConfig,
+            Fault::StaleGeneration => rpc == Rpc::GetConfigGeneration,
+            Fault::Delay(_) | Fault::Error(_) | Fault::Drop => true,
+        }
+    }
+
+    async fn inject(&self, rpc: Rpc) -> Outcome {
+        let faults = lock(&self.schedule).next(rpc);
+        let mut delay = Duration::ZERO;
+        let mut outcome = Outcome::Forward;
+        {
+            let mut state = lock(&self.state);
+            for fault in faults {
+                if !Self::applies(rpc, &fault) {
+                    continue;
+                }
+                state.injected.push((rpc, fault.clone()));
+                match fault {
+                    Fault::Delay(d) => delay += d,
+                    Fault::Error(code) => {
+                        outcome = Outcome::Fail(Status::new(code, "injected fault"));
+                    }
+                    Fault::ApplyFailed => outcome = Outcome::ApplyFailed,
+                    Fault::StaleGeneration => outcome = Outcome::StaleGeneration,
+                    Fault::Drop => {
+                        outcome = Outcome::Fail(Status::cancelled("stream dropped"));
+                    }
+                }
+                if !matches!(outcome, Outcome::Forward) {
+                    break;
+                }
+            }
+        }
+
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        outcome
+    }
+}
+
+#[tonic::async_trait]
+impl<S: ConfigService> ConfigService for FaultInjector<S> {
+    async fn get_config(
+        &self,
+        request: Request<GetConfigRequest>,
+    ) -> Result<Response<GatewayConfig>, Status> {
+        match self.inject(Rpc::GetConfig).await {
+            Outcome::Fail(status) => Err(status),
+            _ => self.inner.get_config(request).await,
+        }
+    }
+
+    async fn get_config_generation(
+        &self,
+        request: Request<GetConfigGenerationRequest>,
+    ) -> Result<Response<GetConfigGenerationResponse>, Status> {
+        match self.inject(Rpc::GetConfigGeneration).await {
+            Outcome::Fail(status) => Err(status),
+            Outcome::StaleGeneration => Ok(Response::new(GetConfigGenerationResponse {
+                generation: lock(&self.state).previous_generation,
+            })),
+            _ => self.inner.get_config_generation(request).await,
+        }
+    }
+
+    async fn update_config(
+        &self,
+        request: Request<UpdateConfigRequest>,
+    ) -> Result<Response<UpdateConfigResponse>, Status> {
+        match self.inject(Rpc::UpdateConfig).await {
+            Outcome::Fail(status) => Err(status),
+            Outcome::ApplyFailed => Ok(Response::new(UpdateConfigResponse {
+                error: Error::ApplyFailed.into(),
+                message: "injected fault".into(),
+            })),
+            _ => {
+                // Only needed to answer a later StaleGeneration
+                let before = if lock(&self.schedule).may_inject(&Fault::StaleGeneration) {
+                    self.inner
+                        .get_config_generation(Request::new(GetConfigGenerationRequest {}))
+                        .await
+                        .map(|resp| resp.into_inner().generation)
+                        .ok()
+                } else {
+                    None
+                };
+                let response = self.inner.update_config(request).await?;
+                if let Some(before) = before {
+                    if response.get_ref().error == i32::from(Error::None) {
+                        lock(&self.state).previous_generation = before;
+                    }
+                }
+                Ok(response)
+            }
+        }
+    }
+
+    async fn get_dataplane_status(
+        &self,
+        request: Request<GetDataplaneStatusRequest>,
+    ) -> Result<Response<GetDataplaneStatusResponse>, Status> {
+        match self.inject(Rpc::GetDataplaneStatus).await {
+            Outcome::Fail(status) => Err(status),
+            _ => self.inner.get_dataplane_status(request).await,
+        }
+    }
+
+    async fn list_nat_sessions(
+        &self,
+        request: Request<ListNatSessionsRequest>,
+    ) -> Result<Response<ListNatSessionsResponse>, Status> {
+        match self.inject(Rpc::ListNatSessions).await {
+            Outcome::Fail(status) => Err(status),
+            _ => self.inner.list_nat_sessions(request).await,
+        }
+    }
+
+    async fn get_routes(
+        &self,
+        request: Request<GetRoutesRequest>,
+    ) -> Result<Response<GetRoutesResponse>, Status> {
+        match self.inject(Rpc::GetRoutes).await {
+            Outcome::Fail(status) => Err(status),
+            _ => self.inner.get_routes(request).await,
+        }
+    }
+
+    async fn lookup_route(
+        &self,
+        request: Request<LookupRouteRequest>,
+    ) -> Result<Response<LookupRouteResponse>, Status> {
+        match self.inject(Rpc::LookupRoute).await {
+            Outcome::Fail(status) => Err(status),
+            _ => self.inner.lookup_route(request).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::InMemoryConfigService;
+    use crate::record::{RecordedCall, Recorder, read_recording};
+
+    fn update(generation: i64) -> Request<UpdateConfigRequest> {
+        Request::new(UpdateConfigRequest {
+            config: Some(GatewayConfig {
+                generation,
+                ..Default::default()
+            }),
+        })
+    }
+
+    async fn generation<S: ConfigService>(service: &S) -> Result<i64, Status> {
+        service
+            .get_config_generation(Request::new(GetConfigGenerationRequest {}))
+            .await
+            .map(|resp| resp.into_inner().generation)
+    }
+
+    #[tokio::test]
+    async fn test_scripted_faults() {
+        let schedule = ScriptedFaults::new()
+            .then(Rpc::UpdateConfig, [Fault::ApplyFailed])
+            .then(Rpc::UpdateConfig, [])
+            .then(Rpc::UpdateConfig, [])
+            .then(Rpc::GetConfigGeneration, [Fault::Error(Code::Unavailable)])
+            .then(Rpc::GetConfigGeneration, [Fault::StaleGeneration])
+            // StaleGeneration doesn't apply to GetConfig, call goes through
+            .then(Rpc::GetConfig, [Fault::StaleGeneration]);
+        let service = FaultInjector::new(InMemoryConfigService::new(), schedule);
+
+        let resp = service.update_config(update(1)).await.unwrap().into_inner();
+        assert_eq!(resp.error, i32::from(Error::ApplyFailed));
+        let resp = service.update_config(update(1)).await.unwrap().into_inner();
+        assert_eq!(resp.error, i32::from(Error::None));
+        service.update_config(update(2)).await.unwrap();
+
+        let err = generation(&service).await.unwrap_err();
+        assert_eq!(err.code(), Code::Unavailable);
+        assert_eq!(generation(&service).await.unwrap(), 1);
+        assert_eq!(generation(&service).await.unwrap(), 2);
+
+        let config = service
+            .get_config(Request::new(GetConfigRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(config.generation, 2);
+
+        assert_eq!(
+            service.injected(),
+            vec![
+                (Rpc::UpdateConfig, Fault::ApplyFailed),
+                (Rpc::GetConfigGeneration, Fault::Error(Code::Unavailable)),
+                (Rpc::GetConfigGeneration, Fault::StaleGeneration),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delay_and_drop() {
+        let schedule = ScriptedFaults::new()
+            .then(
+                Rpc::GetDataplaneStatus,
+                [Fault::Delay(Duration::from_millis(50))],
+            )
+            .then(Rpc::GetDataplaneStatus, [Fault::Drop]);
+        let service = FaultInjector::new(InMemoryConfigService::new(), schedule);
+
+        let start = tokio::time::Instant::now();
+        service
+            .get_dataplane_status(Request::new(GetDataplaneStatusRequest {}))
+            .await
+            .unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(50));
+
+        // Fails right away instead of hanging until the client gives up
+        let dropped = tokio::time::timeout(
+            Duration::from_secs(5),
+            service.get_dataplane_status(Request::new(GetDataplaneStatusRequest {})),
+        )
+        .await
+        .unwrap()
+        .unwrap_err();
+        assert_eq!(dropped.code(), Code::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_no_extra_calls() {
+        let schedule = ScriptedFaults::new().then(Rpc::UpdateConfig, []);
+        let recorder = Recorder::new(InMemoryConfigService::new(), Vec::new());
+        let service = FaultInjector::new(recorder, schedule);
+        service.update_config(update(1)).await.unwrap();
+
+        let (_, recording) = service.into_inner().into_parts();
+        let calls = read_recording(recording.as_slice()).unwrap();
+        let rpcs: Vec<_> = calls.iter().map(RecordedCall::rpc).collect();
+        assert_eq!(rpcs, [Some(Rpc::UpdateConfig)]);
+    }
+
+    #[test]
+    fn test_random_faults_are_deterministic() {
+        let probabilities = FaultProbabilities {
+            delay: 0.3,
+            max_delay: Duration::from_millis(100),
+            error: 0.2,
+            apply_failed: 0.2,
+            stale_generation: 0.2,
+            drop: 0.05,
+        };
+        let rpcs = [
+            Rpc::GetConfig,
+            Rpc::GetConfigGeneration,
+            Rpc::UpdateConfig,
+            Rpc::GetDataplaneStatus,
+        ];
+        let run = |seed| {
+            let mut schedule = RandomFaults::new(seed, probabilities.clone());
+            (0..200)
+                .map(|i| schedule.next(rpcs[i % rpcs.len()]))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(run(42), run(42));
+        assert_ne!(run(42), run(43));
+
+        let faults = run(42);
+        assert!(faults.iter().flatten().any(|f| *f == Fault::ApplyFailed));
+        assert!(
+            faults
+                .iter()
+                .flatten()
+                .any(|f| *f == Fault::StaleGeneration)
+        );
+        assert!(faults.iter().any(Vec::is_empty));
+        for fault in faults.iter().flatten() {
+            if let Fault::Delay(d) = fault {
+                assert!(*d <= Duration::from_millis(100));
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_faults_never() {
+        let mut schedule = RandomFaults::new(1, FaultProbabilities::default());
+        for _ in 0..100 {
+            assert!(schedule.next(Rpc::UpdateConfig).is_empty());
+        }
+    }
+}
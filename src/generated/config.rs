@@ -5,12 +5,32 @@
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetConfigRequest {}
+impl ::prost::Name for GetConfigRequest {
+    const NAME: &'static str = "GetConfigRequest";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.GetConfigRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.GetConfigRequest".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateConfigRequest {
     #[prost(message, optional, tag = "1")]
     pub config: ::core::option::Option<GatewayConfig>,
 }
+impl ::prost::Name for UpdateConfigRequest {
+    const NAME: &'static str = "UpdateConfigRequest";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.UpdateConfigRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.UpdateConfigRequest".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct UpdateConfigResponse {
@@ -19,15 +39,45 @@ pub struct UpdateConfigResponse {
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
 }
+impl ::prost::Name for UpdateConfigResponse {
+    const NAME: &'static str = "UpdateConfigResponse";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.UpdateConfigResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.UpdateConfigResponse".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetConfigGenerationRequest {}
+impl ::prost::Name for GetConfigGenerationRequest {
+    const NAME: &'static str = "GetConfigGenerationRequest";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.GetConfigGenerationRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.GetConfigGenerationRequest".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetConfigGenerationResponse {
     #[prost(int64, tag = "1")]
     pub generation: i64,
 }
+impl ::prost::Name for GetConfigGenerationResponse {
+    const NAME: &'static str = "GetConfigGenerationResponse";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.GetConfigGenerationResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.GetConfigGenerationResponse".into()
+    }
+}
 /// OSPF Interface configuration
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -41,6 +91,16 @@ pub struct OspfInterface {
     #[prost(enumeration = "OspfNetworkType", optional, tag = "4")]
     pub network_type: ::core::option::Option<i32>,
 }
+impl ::prost::Name for OspfInterface {
+    const NAME: &'static str = "OspfInterface";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.OspfInterface".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.OspfInterface".into()
+    }
+}
 /// OSPF process configuration
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -50,10 +110,29 @@ pub struct OspfConfig {
     #[prost(string, optional, tag = "2")]
     pub vrf: ::core::option::Option<::prost::alloc::string::String>,
 }
+impl ::prost::Name for OspfConfig {
+    const NAME: &'static str = "OspfConfig";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.OspfConfig".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.OspfConfig".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetDataplaneStatusRequest {}
-/// TODO: Consider adding an optional string field to InterfaceStatus for additional error messages or descriptions.
+impl ::prost::Name for GetDataplaneStatusRequest {
+    const NAME: &'static str = "GetDataplaneStatusRequest";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.GetDataplaneStatusRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.GetDataplaneStatusRequest".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct InterfaceStatus {
@@ -63,9 +142,22 @@ pub struct InterfaceStatus {
     pub oper_status: i32,
     #[prost(enumeration = "InterfaceAdminStatusType", tag = "3")]
     pub admin_status: i32,
+    /// why the interface is down or in error, empty otherwise
+    #[prost(string, tag = "4")]
+    pub error_reason: ::prost::alloc::string::String,
+}
+impl ::prost::Name for InterfaceStatus {
+    const NAME: &'static str = "InterfaceStatus";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.InterfaceStatus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.InterfaceStatus".into()
+    }
 }
 #[derive(::serde::Deserialize, ::serde::Serialize)]
-#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FrrStatus {
     #[prost(enumeration = "ZebraStatusType", tag = "1")]
     pub zebra_status: i32,
@@ -79,6 +171,35 @@ pub struct FrrStatus {
     pub applied_configs: u32,
     #[prost(uint32, tag = "6")]
     pub failed_configs: u32,
+    #[prost(enumeration = "FrrLifecycleState", tag = "7")]
+    pub state: i32,
+    /// last transition of state
+    #[prost(message, optional, tag = "8")]
+    pub state_since: ::core::option::Option<super::google::protobuf::Timestamp>,
+    /// last transition of zebra_status
+    #[prost(message, optional, tag = "9")]
+    pub zebra_status_since: ::core::option::Option<super::google::protobuf::Timestamp>,
+    /// last transition of frr_agent_status
+    #[prost(message, optional, tag = "10")]
+    pub frr_agent_status_since: ::core::option::Option<
+        super::google::protobuf::Timestamp,
+    >,
+    /// why the last config apply failed, empty if it succeeded
+    #[prost(string, tag = "11")]
+    pub last_apply_error: ::prost::alloc::string::String,
+    /// generation being applied, 0 if none
+    #[prost(int64, tag = "12")]
+    pub pending_config_gen: i64,
+}
+impl ::prost::Name for FrrStatus {
+    const NAME: &'static str = "FrrStatus";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.FrrStatus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.FrrStatus".into()
+    }
 }
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
@@ -86,8 +207,40 @@ pub struct DataplaneStatusInfo {
     #[prost(enumeration = "DataplaneStatusType", tag = "1")]
     pub status: i32,
 }
+impl ::prost::Name for DataplaneStatusInfo {
+    const NAME: &'static str = "DataplaneStatusInfo";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.DataplaneStatusInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.DataplaneStatusInfo".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct InterfaceQueueCounters {
+    #[prost(uint32, tag = "1")]
+    pub queue: u32,
+    #[prost(uint64, tag = "2")]
+    pub packets: u64,
+    #[prost(uint64, tag = "3")]
+    pub bytes: u64,
+    #[prost(uint64, tag = "4")]
+    pub drops: u64,
+}
+impl ::prost::Name for InterfaceQueueCounters {
+    const NAME: &'static str = "InterfaceQueueCounters";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.InterfaceQueueCounters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.InterfaceQueueCounters".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InterfaceCounters {
     #[prost(uint64, tag = "1")]
     pub tx_bits: u64,
@@ -101,6 +254,40 @@ pub struct InterfaceCounters {
     pub rx_bps: f64,
     #[prost(uint64, tag = "6")]
     pub rx_errors: u64,
+    #[prost(uint64, tag = "7")]
+    pub tx_packets: u64,
+    #[prost(uint64, tag = "8")]
+    pub tx_drops: u64,
+    /// packets, included in tx_packets
+    #[prost(uint64, tag = "9")]
+    pub tx_multicast: u64,
+    /// packets, included in tx_packets
+    #[prost(uint64, tag = "10")]
+    pub tx_broadcast: u64,
+    #[prost(uint64, tag = "11")]
+    pub rx_packets: u64,
+    #[prost(uint64, tag = "12")]
+    pub rx_drops: u64,
+    /// packets, included in rx_packets
+    #[prost(uint64, tag = "13")]
+    pub rx_multicast: u64,
+    /// packets, included in rx_packets
+    #[prost(uint64, tag = "14")]
+    pub rx_broadcast: u64,
+    #[prost(message, repeated, tag = "15")]
+    pub rx_queues: ::prost::alloc::vec::Vec<InterfaceQueueCounters>,
+    #[prost(message, repeated, tag = "16")]
+    pub tx_queues: ::prost::alloc::vec::Vec<InterfaceQueueCounters>,
+}
+impl ::prost::Name for InterfaceCounters {
+    const NAME: &'static str = "InterfaceCounters";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.InterfaceCounters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.InterfaceCounters".into()
+    }
 }
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -115,6 +302,19 @@ pub struct InterfaceRuntimeStatus {
     pub mtu: u32,
     #[prost(message, optional, tag = "5")]
     pub counters: ::core::option::Option<InterfaceCounters>,
+    /// why the interface is down or in error, empty otherwise
+    #[prost(string, tag = "6")]
+    pub error_reason: ::prost::alloc::string::String,
+}
+impl ::prost::Name for InterfaceRuntimeStatus {
+    const NAME: &'static str = "InterfaceRuntimeStatus";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.InterfaceRuntimeStatus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.InterfaceRuntimeStatus".into()
+    }
 }
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
@@ -132,6 +332,16 @@ pub struct BgpMessageCounters {
     #[prost(uint64, tag = "6")]
     pub update: u64,
 }
+impl ::prost::Name for BgpMessageCounters {
+    const NAME: &'static str = "BgpMessageCounters";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.BgpMessageCounters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.BgpMessageCounters".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BgpMessages {
@@ -140,6 +350,16 @@ pub struct BgpMessages {
     #[prost(message, optional, tag = "2")]
     pub sent: ::core::option::Option<BgpMessageCounters>,
 }
+impl ::prost::Name for BgpMessages {
+    const NAME: &'static str = "BgpMessages";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.BgpMessages".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.BgpMessages".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BgpNeighborPrefixes {
@@ -150,6 +370,16 @@ pub struct BgpNeighborPrefixes {
     #[prost(uint32, tag = "3")]
     pub sent: u32,
 }
+impl ::prost::Name for BgpNeighborPrefixes {
+    const NAME: &'static str = "BgpNeighborPrefixes";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.BgpNeighborPrefixes".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.BgpNeighborPrefixes".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BgpNeighborStatus {
@@ -182,6 +412,16 @@ pub struct BgpNeighborStatus {
     #[prost(message, optional, tag = "14")]
     pub l2vpn_evpn_prefixes: ::core::option::Option<BgpNeighborPrefixes>,
 }
+impl ::prost::Name for BgpNeighborStatus {
+    const NAME: &'static str = "BgpNeighborStatus";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.BgpNeighborStatus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.BgpNeighborStatus".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BgpVrfStatus {
@@ -192,6 +432,16 @@ pub struct BgpVrfStatus {
         BgpNeighborStatus,
     >,
 }
+impl ::prost::Name for BgpVrfStatus {
+    const NAME: &'static str = "BgpVrfStatus";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.BgpVrfStatus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.BgpVrfStatus".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BgpStatus {
@@ -199,6 +449,16 @@ pub struct BgpStatus {
     #[prost(map = "string, message", tag = "1")]
     pub vrfs: ::std::collections::HashMap<::prost::alloc::string::String, BgpVrfStatus>,
 }
+impl ::prost::Name for BgpStatus {
+    const NAME: &'static str = "BgpStatus";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.BgpStatus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.BgpStatus".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct VpcInterfaceStatus {
@@ -209,6 +469,16 @@ pub struct VpcInterfaceStatus {
     #[prost(enumeration = "InterfaceOperStatusType", tag = "3")]
     pub oper_status: i32,
 }
+impl ::prost::Name for VpcInterfaceStatus {
+    const NAME: &'static str = "VpcInterfaceStatus";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.VpcInterfaceStatus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.VpcInterfaceStatus".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VpcStatus {
@@ -218,9 +488,10 @@ pub struct VpcStatus {
     /// matches VPC.name
     #[prost(string, tag = "2")]
     pub name: ::prost::alloc::string::String,
+    /// key of GetDataplaneStatusResponse.vnis
     #[prost(uint32, tag = "3")]
     pub vni: u32,
-    /// routes inside AF_UNICAST, TODO: add more later
+    /// routes inside AF_UNICAST, GetRoutes lists them
     #[prost(uint32, tag = "4")]
     pub route_count: u32,
     /// key: interface name
@@ -230,6 +501,64 @@ pub struct VpcStatus {
         VpcInterfaceStatus,
     >,
 }
+impl ::prost::Name for VpcStatus {
+    const NAME: &'static str = "VpcStatus";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.VpcStatus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.VpcStatus".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RemoteVtepStatus {
+    /// underlay address of the remote VTEP
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(enumeration = "VtepReachability", tag = "2")]
+    pub reachability: i32,
+    /// VNIs learned from the remote VTEP through EVPN
+    #[prost(uint32, repeated, tag = "3")]
+    pub vnis: ::prost::alloc::vec::Vec<u32>,
+}
+impl ::prost::Name for RemoteVtepStatus {
+    const NAME: &'static str = "RemoteVtepStatus";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.RemoteVtepStatus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.RemoteVtepStatus".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct VniStatus {
+    /// matches VpcStatus.vni
+    #[prost(uint32, tag = "1")]
+    pub vni: u32,
+    /// MACs learned through EVPN Type-2 routes
+    #[prost(uint32, tag = "2")]
+    pub mac_count: u32,
+    /// EVPN routes of all types
+    #[prost(uint32, tag = "3")]
+    pub route_count: u32,
+    /// EVPN Type-5 (IP prefix) routes, included in route_count
+    #[prost(uint32, tag = "4")]
+    pub type5_route_count: u32,
+}
+impl ::prost::Name for VniStatus {
+    const NAME: &'static str = "VniStatus";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.VniStatus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.VniStatus".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VpcPeeringCounters {
@@ -249,9 +578,22 @@ pub struct VpcPeeringCounters {
     pub pps: f64,
     #[prost(double, tag = "8")]
     pub bps: f64,
+    /// key: drop reason, values sum up to drops
+    #[prost(map = "string, uint64", tag = "9")]
+    pub drop_reasons: ::std::collections::HashMap<::prost::alloc::string::String, u64>,
+}
+impl ::prost::Name for VpcPeeringCounters {
+    const NAME: &'static str = "VpcPeeringCounters";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.VpcPeeringCounters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.VpcPeeringCounters".into()
+    }
 }
 #[derive(::serde::Deserialize, ::serde::Serialize)]
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VpcCounters {
     #[prost(string, tag = "1")]
     pub name: ::prost::alloc::string::String,
@@ -261,6 +603,340 @@ pub struct VpcCounters {
     pub drops: u64,
     #[prost(uint64, tag = "4")]
     pub bytes: u64,
+    /// key: drop reason, values sum up to drops
+    #[prost(map = "string, uint64", tag = "5")]
+    pub drop_reasons: ::std::collections::HashMap<::prost::alloc::string::String, u64>,
+}
+impl ::prost::Name for VpcCounters {
+    const NAME: &'static str = "VpcCounters";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.VpcCounters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.VpcCounters".into()
+    }
+}
+/// Counters of a single Expose rule of a VPC peering
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExposeCounters {
+    /// matches VpcPeering.name
+    #[prost(string, tag = "1")]
+    pub peering: ::prost::alloc::string::String,
+    /// matches PeeringEntryFor.vpc
+    #[prost(string, tag = "2")]
+    pub vpc: ::prost::alloc::string::String,
+    /// index in PeeringEntryFor.expose
+    #[prost(uint32, tag = "3")]
+    pub expose_index: u32,
+    #[prost(uint64, tag = "4")]
+    pub packets: u64,
+    #[prost(uint64, tag = "5")]
+    pub bytes: u64,
+    #[prost(uint64, tag = "6")]
+    pub drops: u64,
+    /// key: drop reason, values sum up to drops
+    #[prost(map = "string, uint64", tag = "7")]
+    pub drop_reasons: ::std::collections::HashMap<::prost::alloc::string::String, u64>,
+    /// packets translated by the NAT of the expose
+    #[prost(uint64, tag = "8")]
+    pub translations: u64,
+    /// translations that failed for lack of free addresses or ports
+    #[prost(uint64, tag = "9")]
+    pub nat_alloc_failures: u64,
+}
+impl ::prost::Name for ExposeCounters {
+    const NAME: &'static str = "ExposeCounters";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.ExposeCounters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.ExposeCounters".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FiveTuple {
+    #[prost(enumeration = "IpProtocol", tag = "1")]
+    pub protocol: i32,
+    #[prost(string, tag = "2")]
+    pub src_ip: ::prost::alloc::string::String,
+    /// ICMP identifier for ICMP
+    #[prost(uint32, tag = "3")]
+    pub src_port: u32,
+    #[prost(string, tag = "4")]
+    pub dst_ip: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "5")]
+    pub dst_port: u32,
+}
+impl ::prost::Name for FiveTuple {
+    const NAME: &'static str = "FiveTuple";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.FiveTuple".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.FiveTuple".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct NatSession {
+    /// matches VpcPeering.name
+    #[prost(string, tag = "1")]
+    pub peering: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub src_vpc: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub dst_vpc: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub original: ::core::option::Option<FiveTuple>,
+    #[prost(message, optional, tag = "5")]
+    pub translated: ::core::option::Option<FiveTuple>,
+    #[prost(message, optional, tag = "6")]
+    pub idle_time: ::core::option::Option<super::google::protobuf::Duration>,
+    /// until the session expires if it stays idle
+    #[prost(message, optional, tag = "7")]
+    pub remaining_timeout: ::core::option::Option<super::google::protobuf::Duration>,
+}
+impl ::prost::Name for NatSession {
+    const NAME: &'static str = "NatSession";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.NatSession".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.NatSession".into()
+    }
+}
+/// Per-peering aggregate of the NAT session table
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct NatSessionCounters {
+    #[prost(uint64, tag = "1")]
+    pub active: u64,
+    /// since dataplane start
+    #[prost(uint64, tag = "2")]
+    pub created: u64,
+    /// since dataplane start
+    #[prost(uint64, tag = "3")]
+    pub expired: u64,
+}
+impl ::prost::Name for NatSessionCounters {
+    const NAME: &'static str = "NatSessionCounters";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.NatSessionCounters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.NatSessionCounters".into()
+    }
+}
+/// Filters are ANDed, unset filters match any session
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListNatSessionsRequest {
+    #[prost(string, tag = "1")]
+    pub peering: ::prost::alloc::string::String,
+    /// either side of the peering
+    #[prost(string, tag = "2")]
+    pub vpc: ::prost::alloc::string::String,
+    /// any IP of the original or translated tuple
+    #[prost(string, tag = "3")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(enumeration = "IpProtocol", tag = "4")]
+    pub protocol: i32,
+    /// 0 lets the server choose
+    #[prost(uint32, tag = "5")]
+    pub page_size: u32,
+    /// next_page_token of the previous page, empty for the first page
+    #[prost(string, tag = "6")]
+    pub page_token: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ListNatSessionsRequest {
+    const NAME: &'static str = "ListNatSessionsRequest";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.ListNatSessionsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.ListNatSessionsRequest".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNatSessionsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub sessions: ::prost::alloc::vec::Vec<NatSession>,
+    /// empty on the last page
+    #[prost(string, tag = "2")]
+    pub next_page_token: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ListNatSessionsResponse {
+    const NAME: &'static str = "ListNatSessionsResponse";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.ListNatSessionsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.ListNatSessionsResponse".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RouteNextHop {
+    /// empty for directly connected routes
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub ifname: ::prost::alloc::string::String,
+    /// VXLAN VNI of encapsulated next hops, 0 otherwise
+    #[prost(uint32, tag = "3")]
+    pub vni: u32,
+    /// remote VTEP of encapsulated next hops
+    #[prost(string, tag = "4")]
+    pub vtep: ::prost::alloc::string::String,
+}
+impl ::prost::Name for RouteNextHop {
+    const NAME: &'static str = "RouteNextHop";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.RouteNextHop".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.RouteNextHop".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Route {
+    /// e.g. 10.1.0.0/24 or fd00::/64
+    #[prost(string, tag = "1")]
+    pub prefix: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub next_hops: ::prost::alloc::vec::Vec<RouteNextHop>,
+    #[prost(enumeration = "RouteProtocol", tag = "3")]
+    pub protocol: i32,
+    #[prost(uint32, tag = "4")]
+    pub metric: u32,
+    /// in the FIB, not only in the RIB
+    #[prost(bool, tag = "5")]
+    pub installed: bool,
+}
+impl ::prost::Name for Route {
+    const NAME: &'static str = "Route";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.Route".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.Route".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetRoutesRequest {
+    #[prost(enumeration = "RouteProtocol", tag = "3")]
+    pub protocol: i32,
+    /// 0 lets the server choose
+    #[prost(uint32, tag = "4")]
+    pub page_size: u32,
+    /// next_page_token of the previous page, empty for the first page
+    #[prost(string, tag = "5")]
+    pub page_token: ::prost::alloc::string::String,
+    #[prost(oneof = "get_routes_request::Table", tags = "1, 2")]
+    pub table: ::core::option::Option<get_routes_request::Table>,
+}
+/// Nested message and enum types in `GetRoutesRequest`.
+pub mod get_routes_request {
+    #[derive(::serde::Deserialize, ::serde::Serialize)]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Table {
+        /// underlay VRF, e.g. "default"
+        #[prost(string, tag = "1")]
+        Vrf(::prost::alloc::string::String),
+        /// VPC name
+        #[prost(string, tag = "2")]
+        Vpc(::prost::alloc::string::String),
+    }
+}
+impl ::prost::Name for GetRoutesRequest {
+    const NAME: &'static str = "GetRoutesRequest";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.GetRoutesRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.GetRoutesRequest".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRoutesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub routes: ::prost::alloc::vec::Vec<Route>,
+    /// empty on the last page
+    #[prost(string, tag = "2")]
+    pub next_page_token: ::prost::alloc::string::String,
+}
+impl ::prost::Name for GetRoutesResponse {
+    const NAME: &'static str = "GetRoutesResponse";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.GetRoutesResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.GetRoutesResponse".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LookupRouteRequest {
+    /// IP address to find the longest matching installed route for
+    #[prost(string, tag = "3")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(oneof = "lookup_route_request::Table", tags = "1, 2")]
+    pub table: ::core::option::Option<lookup_route_request::Table>,
+}
+/// Nested message and enum types in `LookupRouteRequest`.
+pub mod lookup_route_request {
+    #[derive(::serde::Deserialize, ::serde::Serialize)]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Table {
+        #[prost(string, tag = "1")]
+        Vrf(::prost::alloc::string::String),
+        #[prost(string, tag = "2")]
+        Vpc(::prost::alloc::string::String),
+    }
+}
+impl ::prost::Name for LookupRouteRequest {
+    const NAME: &'static str = "LookupRouteRequest";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.LookupRouteRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.LookupRouteRequest".into()
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LookupRouteResponse {
+    /// unset if no route matches
+    #[prost(message, optional, tag = "1")]
+    pub route: ::core::option::Option<Route>,
+}
+impl ::prost::Name for LookupRouteResponse {
+    const NAME: &'static str = "LookupRouteResponse";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.LookupRouteResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.LookupRouteResponse".into()
+    }
 }
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -294,6 +970,33 @@ pub struct GetDataplaneStatusResponse {
         ::prost::alloc::string::String,
         VpcCounters,
     >,
+    /// key: peering name
+    #[prost(map = "string, message", tag = "9")]
+    pub nat_sessions: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        NatSessionCounters,
+    >,
+    #[prost(message, repeated, tag = "10")]
+    pub expose_counters: ::prost::alloc::vec::Vec<ExposeCounters>,
+    /// key: remote VTEP address
+    #[prost(map = "string, message", tag = "11")]
+    pub remote_vteps: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        RemoteVtepStatus,
+    >,
+    /// key: VNI
+    #[prost(map = "uint32, message", tag = "12")]
+    pub vnis: ::std::collections::HashMap<u32, VniStatus>,
+}
+impl ::prost::Name for GetDataplaneStatusResponse {
+    const NAME: &'static str = "GetDataplaneStatusResponse";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.GetDataplaneStatusResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.GetDataplaneStatusResponse".into()
+    }
 }
 /// Defines a logical interface. May correlate with physical representation
 #[derive(::serde::Deserialize, ::serde::Serialize)]
@@ -326,6 +1029,16 @@ pub struct Interface {
     #[prost(string, optional, tag = "10")]
     pub pci: ::core::option::Option<::prost::alloc::string::String>,
 }
+impl ::prost::Name for Interface {
+    const NAME: &'static str = "Interface";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.Interface".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.Interface".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GatewayGroupMember {
@@ -336,6 +1049,16 @@ pub struct GatewayGroupMember {
     #[prost(string, tag = "3")]
     pub ipaddress: ::prost::alloc::string::String,
 }
+impl ::prost::Name for GatewayGroupMember {
+    const NAME: &'static str = "GatewayGroupMember";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.GatewayGroupMember".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.GatewayGroupMember".into()
+    }
+}
 /// Defines a redundancy group
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -347,6 +1070,16 @@ pub struct GatewayGroup {
     #[prost(message, repeated, tag = "2")]
     pub members: ::prost::alloc::vec::Vec<GatewayGroupMember>,
 }
+impl ::prost::Name for GatewayGroup {
+    const NAME: &'static str = "GatewayGroup";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.GatewayGroup".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.GatewayGroup".into()
+    }
+}
 /// Defines the list of prefixes that VPCs can expose
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -365,6 +1098,16 @@ pub mod peering_i_ps {
         Not(::prost::alloc::string::String),
     }
 }
+impl ::prost::Name for PeeringIPs {
+    const NAME: &'static str = "PeeringIPs";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.PeeringIPs".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.PeeringIPs".into()
+    }
+}
 /// Defines the list of prefixes that exposing IPs will be translated to
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -383,15 +1126,45 @@ pub mod peering_as {
         Not(::prost::alloc::string::String),
     }
 }
+impl ::prost::Name for PeeringAs {
+    const NAME: &'static str = "PeeringAs";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.PeeringAs".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.PeeringAs".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct PeeringStatelessNat {}
+impl ::prost::Name for PeeringStatelessNat {
+    const NAME: &'static str = "PeeringStatelessNAT";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.PeeringStatelessNAT".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.PeeringStatelessNAT".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct PeeringStatefulNat {
     #[prost(message, optional, tag = "1")]
     pub idle_timeout: ::core::option::Option<super::google::protobuf::Duration>,
 }
+impl ::prost::Name for PeeringStatefulNat {
+    const NAME: &'static str = "PeeringStatefulNAT";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.PeeringStatefulNAT".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.PeeringStatefulNAT".into()
+    }
+}
 /// Defines a rule between exposing IP and translated
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -414,6 +1187,16 @@ pub mod expose {
         Stateful(super::PeeringStatefulNat),
     }
 }
+impl ::prost::Name for Expose {
+    const NAME: &'static str = "Expose";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.Expose".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.Expose".into()
+    }
+}
 /// Defines a list of exposures per VPC
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -423,6 +1206,16 @@ pub struct PeeringEntryFor {
     #[prost(message, repeated, tag = "2")]
     pub expose: ::prost::alloc::vec::Vec<Expose>,
 }
+impl ::prost::Name for PeeringEntryFor {
+    const NAME: &'static str = "PeeringEntryFor";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.PeeringEntryFor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.PeeringEntryFor".into()
+    }
+}
 /// There can be only one peering per pair of given VPCs
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -437,6 +1230,16 @@ pub struct VpcPeering {
     #[prost(string, tag = "3")]
     pub gateway_group: ::prost::alloc::string::String,
 }
+impl ::prost::Name for VpcPeering {
+    const NAME: &'static str = "VpcPeering";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.VpcPeering".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.VpcPeering".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Vpc {
@@ -451,6 +1254,16 @@ pub struct Vpc {
     #[prost(message, repeated, tag = "4")]
     pub interfaces: ::prost::alloc::vec::Vec<Interface>,
 }
+impl ::prost::Name for Vpc {
+    const NAME: &'static str = "VPC";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.VPC".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.VPC".into()
+    }
+}
 /// Description of VPCs and Peerings between VPCs
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -460,6 +1273,16 @@ pub struct Overlay {
     #[prost(message, repeated, tag = "2")]
     pub peerings: ::prost::alloc::vec::Vec<VpcPeering>,
 }
+impl ::prost::Name for Overlay {
+    const NAME: &'static str = "Overlay";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.Overlay".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.Overlay".into()
+    }
+}
 /// BGP options for IPv4 UNICAST AFI
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -471,6 +1294,16 @@ pub struct BgpAddressFamilyIPv4 {
     #[prost(string, repeated, tag = "3")]
     pub networks: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+impl ::prost::Name for BgpAddressFamilyIPv4 {
+    const NAME: &'static str = "BgpAddressFamilyIPv4";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.BgpAddressFamilyIPv4".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.BgpAddressFamilyIPv4".into()
+    }
+}
 /// BGP options for IPv6 UNICAST AFI
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -482,6 +1315,16 @@ pub struct BgpAddressFamilyIPv6 {
     #[prost(string, repeated, tag = "3")]
     pub networks: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+impl ::prost::Name for BgpAddressFamilyIPv6 {
+    const NAME: &'static str = "BgpAddressFamilyIPv6";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.BgpAddressFamilyIPv6".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.BgpAddressFamilyIPv6".into()
+    }
+}
 /// BGP options for L2VPN EVPN AFI
 #[cfg_attr(any(feature = "bolero", test), derive(::bolero::TypeGenerator))]
 #[derive(::serde::Deserialize, ::serde::Serialize)]
@@ -490,6 +1333,16 @@ pub struct BgpAddressFamilyL2vpnEvpn {
     #[prost(bool, tag = "1")]
     pub advertise_all_vni: bool,
 }
+impl ::prost::Name for BgpAddressFamilyL2vpnEvpn {
+    const NAME: &'static str = "BgpAddressFamilyL2vpnEvpn";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.BgpAddressFamilyL2vpnEvpn".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.BgpAddressFamilyL2vpnEvpn".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BgpNeighborUpdateSource {
@@ -509,6 +1362,16 @@ pub mod bgp_neighbor_update_source {
         Interface(::prost::alloc::string::String),
     }
 }
+impl ::prost::Name for BgpNeighborUpdateSource {
+    const NAME: &'static str = "BgpNeighborUpdateSource";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.BgpNeighborUpdateSource".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.BgpNeighborUpdateSource".into()
+    }
+}
 /// BGP neighbor config
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -522,6 +1385,16 @@ pub struct BgpNeighbor {
     #[prost(message, optional, tag = "4")]
     pub update_source: ::core::option::Option<BgpNeighborUpdateSource>,
 }
+impl ::prost::Name for BgpNeighbor {
+    const NAME: &'static str = "BgpNeighbor";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.BgpNeighbor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.BgpNeighbor".into()
+    }
+}
 /// IP Prefix filtering route map description
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -535,6 +1408,16 @@ pub struct RouteMap {
     #[prost(uint32, tag = "4")]
     pub sequence: u32,
 }
+impl ::prost::Name for RouteMap {
+    const NAME: &'static str = "RouteMap";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.RouteMap".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.RouteMap".into()
+    }
+}
 /// BGP Router config description for VRFs
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -554,6 +1437,16 @@ pub struct RouterConfig {
     #[prost(message, repeated, tag = "7")]
     pub route_maps: ::prost::alloc::vec::Vec<RouteMap>,
 }
+impl ::prost::Name for RouterConfig {
+    const NAME: &'static str = "RouterConfig";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.RouterConfig".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.RouterConfig".into()
+    }
+}
 /// VRF description with list of connected interfaces and its BGP config
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -568,6 +1461,16 @@ pub struct Vrf {
     #[prost(message, optional, tag = "4")]
     pub ospf: ::core::option::Option<OspfConfig>,
 }
+impl ::prost::Name for Vrf {
+    const NAME: &'static str = "VRF";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.VRF".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.VRF".into()
+    }
+}
 /// List of all non-VPC VRFs
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -575,6 +1478,16 @@ pub struct Underlay {
     #[prost(message, repeated, tag = "1")]
     pub vrfs: ::prost::alloc::vec::Vec<Vrf>,
 }
+impl ::prost::Name for Underlay {
+    const NAME: &'static str = "Underlay";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.Underlay".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.Underlay".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TracingConfig {
@@ -583,6 +1496,16 @@ pub struct TracingConfig {
     #[prost(map = "string, enumeration(LogLevel)", tag = "2")]
     pub taglevel: ::std::collections::HashMap<::prost::alloc::string::String, i32>,
 }
+impl ::prost::Name for TracingConfig {
+    const NAME: &'static str = "TracingConfig";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.TracingConfig".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.TracingConfig".into()
+    }
+}
 /// System level config options
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -590,6 +1513,16 @@ pub struct Device {
     #[prost(message, optional, tag = "5")]
     pub tracing: ::core::option::Option<TracingConfig>,
 }
+impl ::prost::Name for Device {
+    const NAME: &'static str = "Device";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.Device".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.Device".into()
+    }
+}
 /// Complete Gateway config options
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -608,6 +1541,16 @@ pub struct GatewayConfig {
     #[prost(map = "uint32, string", tag = "7")]
     pub communities: ::std::collections::HashMap<u32, ::prost::alloc::string::String>,
 }
+impl ::prost::Name for GatewayConfig {
+    const NAME: &'static str = "GatewayConfig";
+    const PACKAGE: &'static str = "config";
+    fn full_name() -> ::prost::alloc::string::String {
+        "config.GatewayConfig".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/config.GatewayConfig".into()
+    }
+}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -739,8 +1682,6 @@ impl InterfaceAdminStatusType {
         }
     }
 }
-/// TODO: Consider adding additional states such as FRR_STATUS_STARTING, FRR_STATUS_STOPPING,
-/// or other states that might be relevant for tracking the lifecycle of FRR processes.
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -771,26 +1712,66 @@ impl ZebraStatusType {
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
-pub enum FrrAgentStatusType {
-    FrrAgentStatusNotConnected = 0,
-    FrrAgentStatusConnected = 1,
-}
-impl FrrAgentStatusType {
+pub enum FrrAgentStatusType {
+    FrrAgentStatusNotConnected = 0,
+    FrrAgentStatusConnected = 1,
+}
+impl FrrAgentStatusType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::FrrAgentStatusNotConnected => "FRR_AGENT_STATUS_NOT_CONNECTED",
+            Self::FrrAgentStatusConnected => "FRR_AGENT_STATUS_CONNECTED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FRR_AGENT_STATUS_NOT_CONNECTED" => Some(Self::FrrAgentStatusNotConnected),
+            "FRR_AGENT_STATUS_CONNECTED" => Some(Self::FrrAgentStatusConnected),
+            _ => None,
+        }
+    }
+}
+/// Lifecycle of the FRR processes, UNKNOWN for dataplanes that don't report it
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FrrLifecycleState {
+    FrrStateUnknown = 0,
+    FrrStateStarting = 1,
+    FrrStateRunning = 2,
+    FrrStateReloading = 3,
+    FrrStateStopping = 4,
+    FrrStateCrashed = 5,
+}
+impl FrrLifecycleState {
     /// String value of the enum field names used in the ProtoBuf definition.
     ///
     /// The values are not transformed in any way and thus are considered stable
     /// (if the ProtoBuf definition does not change) and safe for programmatic use.
     pub fn as_str_name(&self) -> &'static str {
         match self {
-            Self::FrrAgentStatusNotConnected => "FRR_AGENT_STATUS_NOT_CONNECTED",
-            Self::FrrAgentStatusConnected => "FRR_AGENT_STATUS_CONNECTED",
+            Self::FrrStateUnknown => "FRR_STATE_UNKNOWN",
+            Self::FrrStateStarting => "FRR_STATE_STARTING",
+            Self::FrrStateRunning => "FRR_STATE_RUNNING",
+            Self::FrrStateReloading => "FRR_STATE_RELOADING",
+            Self::FrrStateStopping => "FRR_STATE_STOPPING",
+            Self::FrrStateCrashed => "FRR_STATE_CRASHED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
     pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
         match value {
-            "FRR_AGENT_STATUS_NOT_CONNECTED" => Some(Self::FrrAgentStatusNotConnected),
-            "FRR_AGENT_STATUS_CONNECTED" => Some(Self::FrrAgentStatusConnected),
+            "FRR_STATE_UNKNOWN" => Some(Self::FrrStateUnknown),
+            "FRR_STATE_STARTING" => Some(Self::FrrStateStarting),
+            "FRR_STATE_RUNNING" => Some(Self::FrrStateRunning),
+            "FRR_STATE_RELOADING" => Some(Self::FrrStateReloading),
+            "FRR_STATE_STOPPING" => Some(Self::FrrStateStopping),
+            "FRR_STATE_CRASHED" => Some(Self::FrrStateCrashed),
             _ => None,
         }
     }
@@ -867,6 +1848,108 @@ impl BgpNeighborSessionState {
         }
     }
 }
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum VtepReachability {
+    Unknown = 0,
+    Reachable = 1,
+    Unreachable = 2,
+}
+impl VtepReachability {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "VTEP_REACHABILITY_UNKNOWN",
+            Self::Reachable => "VTEP_REACHABILITY_REACHABLE",
+            Self::Unreachable => "VTEP_REACHABILITY_UNREACHABLE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VTEP_REACHABILITY_UNKNOWN" => Some(Self::Unknown),
+            "VTEP_REACHABILITY_REACHABLE" => Some(Self::Reachable),
+            "VTEP_REACHABILITY_UNREACHABLE" => Some(Self::Unreachable),
+            _ => None,
+        }
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum IpProtocol {
+    Unspecified = 0,
+    Tcp = 1,
+    Udp = 2,
+    Icmp = 3,
+    Icmpv6 = 4,
+}
+impl IpProtocol {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "IP_PROTOCOL_UNSPECIFIED",
+            Self::Tcp => "IP_PROTOCOL_TCP",
+            Self::Udp => "IP_PROTOCOL_UDP",
+            Self::Icmp => "IP_PROTOCOL_ICMP",
+            Self::Icmpv6 => "IP_PROTOCOL_ICMPV6",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "IP_PROTOCOL_UNSPECIFIED" => Some(Self::Unspecified),
+            "IP_PROTOCOL_TCP" => Some(Self::Tcp),
+            "IP_PROTOCOL_UDP" => Some(Self::Udp),
+            "IP_PROTOCOL_ICMP" => Some(Self::Icmp),
+            "IP_PROTOCOL_ICMPV6" => Some(Self::Icmpv6),
+            _ => None,
+        }
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RouteProtocol {
+    Unspecified = 0,
+    Connected = 1,
+    Static = 2,
+    Bgp = 3,
+    Ospf = 4,
+}
+impl RouteProtocol {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "ROUTE_PROTOCOL_UNSPECIFIED",
+            Self::Connected => "ROUTE_PROTOCOL_CONNECTED",
+            Self::Static => "ROUTE_PROTOCOL_STATIC",
+            Self::Bgp => "ROUTE_PROTOCOL_BGP",
+            Self::Ospf => "ROUTE_PROTOCOL_OSPF",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ROUTE_PROTOCOL_UNSPECIFIED" => Some(Self::Unspecified),
+            "ROUTE_PROTOCOL_CONNECTED" => Some(Self::Connected),
+            "ROUTE_PROTOCOL_STATIC" => Some(Self::Static),
+            "ROUTE_PROTOCOL_BGP" => Some(Self::Bgp),
+            "ROUTE_PROTOCOL_OSPF" => Some(Self::Ospf),
+            _ => None,
+        }
+    }
+}
 /// Defines interface representation on the Gateway
 #[cfg_attr(any(feature = "bolero", test), derive(::bolero::TypeGenerator))]
 #[derive(::serde::Deserialize, ::serde::Serialize)]
@@ -1188,6 +2271,78 @@ pub mod config_service_client {
                 .insert(GrpcMethod::new("config.ConfigService", "GetDataplaneStatus"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn list_nat_sessions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListNatSessionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNatSessionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/config.ConfigService/ListNatSessions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("config.ConfigService", "ListNatSessions"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_routes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRoutesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetRoutesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/config.ConfigService/GetRoutes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("config.ConfigService", "GetRoutes"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn lookup_route(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LookupRouteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LookupRouteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/config.ConfigService/LookupRoute",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("config.ConfigService", "LookupRoute"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -1206,28 +2361,63 @@ pub mod config_service_server {
         async fn get_config(
             &self,
             request: tonic::Request<super::GetConfigRequest>,
-        ) -> std::result::Result<tonic::Response<super::GatewayConfig>, tonic::Status>;
+        ) -> std::result::Result<tonic::Response<super::GatewayConfig>, tonic::Status> {
+            Err(tonic::Status::unimplemented("Not yet implemented"))
+        }
         async fn get_config_generation(
             &self,
             request: tonic::Request<super::GetConfigGenerationRequest>,
         ) -> std::result::Result<
             tonic::Response<super::GetConfigGenerationResponse>,
             tonic::Status,
-        >;
+        > {
+            Err(tonic::Status::unimplemented("Not yet implemented"))
+        }
         async fn update_config(
             &self,
             request: tonic::Request<super::UpdateConfigRequest>,
         ) -> std::result::Result<
             tonic::Response<super::UpdateConfigResponse>,
             tonic::Status,
-        >;
+        > {
+            Err(tonic::Status::unimplemented("Not yet implemented"))
+        }
         async fn get_dataplane_status(
             &self,
             request: tonic::Request<super::GetDataplaneStatusRequest>,
         ) -> std::result::Result<
             tonic::Response<super::GetDataplaneStatusResponse>,
             tonic::Status,
-        >;
+        > {
+            Err(tonic::Status::unimplemented("Not yet implemented"))
+        }
+        async fn list_nat_sessions(
+            &self,
+            request: tonic::Request<super::ListNatSessionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListNatSessionsResponse>,
+            tonic::Status,
+        > {
+            Err(tonic::Status::unimplemented("Not yet implemented"))
+        }
+        async fn get_routes(
+            &self,
+            request: tonic::Request<super::GetRoutesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetRoutesResponse>,
+            tonic::Status,
+        > {
+            Err(tonic::Status::unimplemented("Not yet implemented"))
+        }
+        async fn lookup_route(
+            &self,
+            request: tonic::Request<super::LookupRouteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LookupRouteResponse>,
+            tonic::Status,
+        > {
+            Err(tonic::Status::unimplemented("Not yet implemented"))
+        }
     }
     #[derive(Debug)]
     pub struct ConfigServiceServer<T> {
@@ -1487,6 +2677,142 @@ pub mod config_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/config.ConfigService/ListNatSessions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListNatSessionsSvc<T: ConfigService>(pub Arc<T>);
+                    impl<
+                        T: ConfigService,
+                    > tonic::server::UnaryService<super::ListNatSessionsRequest>
+                    for ListNatSessionsSvc<T> {
+                        type Response = super::ListNatSessionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListNatSessionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ConfigService>::list_nat_sessions(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListNatSessionsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/config.ConfigService/GetRoutes" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetRoutesSvc<T: ConfigService>(pub Arc<T>);
+                    impl<
+                        T: ConfigService,
+                    > tonic::server::UnaryService<super::GetRoutesRequest>
+                    for GetRoutesSvc<T> {
+                        type Response = super::GetRoutesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRoutesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ConfigService>::get_routes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetRoutesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/config.ConfigService/LookupRoute" => {
+                    #[allow(non_camel_case_types)]
+                    struct LookupRouteSvc<T: ConfigService>(pub Arc<T>);
+                    impl<
+                        T: ConfigService,
+                    > tonic::server::UnaryService<super::LookupRouteRequest>
+                    for LookupRouteSvc<T> {
+                        type Response = super::LookupRouteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LookupRouteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ConfigService>::lookup_route(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LookupRouteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -0,0 +1,341 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Config distribution to all members of a [`GatewayGroup`].
+
+use crate::ConfigServiceClient;
+use crate::config::{
+    GatewayConfig, GatewayGroup, GatewayGroupMember, GetConfigGenerationRequest,
+    UpdateConfigRequest, UpdateConfigResponse,
+};
+use crate::error::UpdateConfigError;
+use futures::future::join_all;
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::time::Duration;
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Response, Status};
+
+#[derive(thiserror::Error, Debug)]
+pub enum GroupClientError {
+    #[error("Invalid address {1:?} for gateway group member {0}")]
+    InvalidAddress(String, String),
+    #[error("Invalid endpoint for gateway group member {0}: {1}")]
+    InvalidEndpoint(String, tonic::transport::Error),
+}
+
+#[derive(Debug, Clone)]
+pub struct GroupClientConfig {
+    /// Port the dataplane gRPC server listens on, the same for all members.
+    pub port: u16,
+    pub connect_timeout: Duration,
+    /// Timeout for each individual RPC to a member.
+    pub rpc_timeout: Duration,
+}
+
+impl Default for GroupClientConfig {
+    fn default() -> Self {
+        Self {
+            port: 50051,
+            connect_timeout: Duration::from_secs(5),
+            rpc_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Outcome of pushing a config to a single member.
+#[derive(Debug, Clone)]
+pub struct MemberUpdate {
+    pub member: GatewayGroupMember,
+    /// Response returned by the member, or the status the call failed with.
+    pub response: Result<UpdateConfigResponse, Status>,
+}
+
+impl MemberUpdate {
+    /// Normalized error for this member, if any, see [`UpdateConfigError`].
+    #[must_use]
+    pub fn error(&self) -> Option<UpdateConfigError> {
+        match &self.response {
+            Ok(response) => UpdateConfigError::from_response(response),
+            Err(status) => Some(UpdateConfigError::from(status.clone())),
+        }
+    }
+
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.error().is_none()
+    }
+}
+
+/// Generations reported by the group members.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenerationSkew {
+    /// key: member name
+    pub generations: BTreeMap<String, i64>,
+    /// key: member name, value: error message
+    pub unreachable: BTreeMap<String, String>,
+}
+
+impl GenerationSkew {
+    #[must_use]
+    pub fn min(&self) -> Option<i64> {
+        self.generations.values().copied().min()
+    }
+
+    #[must_use]
+    pub fn max(&self) -> Option<i64> {
+        self.generations.values().copied().max()
+    }
+
+    /// Whether reachable members report different generations.
+    #[must_use]
+    pub fn is_skewed(&self) -> bool {
+        self.min() != self.max()
+    }
+
+    /// Members reporting a generation other than `generation`.
+    pub fn lagging(&self, generation: i64) -> impl Iterator<Item = (&str, i64)> {
+        self.generations
+            .iter()
+            .filter(move |(_, g)| **g != generation)
+            .map(|(name, g)| (name.as_str(), *g))
+    }
+}
+
+/// Outcome of pushing a config to the whole group.
+#[derive(Debug, Clone)]
+pub struct GroupUpdateReport {
+    pub group: String,
+    pub generation: i64,
+    /// One entry per member, in the order of [`GatewayGroup::members`].
+    pub members: Vec<MemberUpdate>,
+    /// Generations reported by the members after the update.
+    pub skew: GenerationSkew,
+}
+
+impl GroupUpdateReport {
+    pub fn succeeded(&self) -> impl Iterator<Item = &MemberUpdate> {
+        self.members.iter().filter(|m| m.is_ok())
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &MemberUpdate> {
+        self.members.iter().filter(|m| !m.is_ok())
+    }
+
+    /// All members accepted the config and report its generation.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.failed().next().is_none()
+            && self.skew.unreachable.is_empty()
+            && self.skew.lagging(self.generation).next().is_none()
+    }
+
+    /// Some, but not all, members accepted the config.
+    #[must_use]
+    pub fn is_partial_failure(&self) -> bool {
+        let failed = self.failed().count();
+        failed > 0 && failed < self.members.len()
+    }
+}
+
+/// Client connected to every member of a gateway group.
+#[derive(Debug, Clone)]
+pub struct GroupClient {
+    group: String,
+    members: Vec<(GatewayGroupMember, ConfigServiceClient<Channel>)>,
+    rpc_timeout: Duration,
+}
+
+fn member_uri(member: &GatewayGroupMember, port: u16) -> Result<String, GroupClientError> {
+    // Be lenient and accept the address with a prefix length as well
+    let addr = member.ipaddress.split('/').next().unwrap_or_default();
+    let ip = addr.parse::<IpAddr>().map_err(|_| {
+        GroupClientError::InvalidAddress(member.name.clone(), member.ipaddress.clone())
+    })?;
+    Ok(match ip {
+        IpAddr::V4(ip) => format!("http://{ip}:{port}"),
+        IpAddr::V6(ip) => format!("http://[{ip}]:{port}"),
+    })
+}
+
+impl GroupClient {
+    /// Create clients for all members of `group`.
+    ///
+    /// Connections are established lazily, so unreachable members don't prevent
+    /// creating the client and are reported on each call instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a member address is not a valid IP address.
+    pub fn new(group: &GatewayGroup, config: &GroupClientConfig) -> Result<Self, GroupClientError> {
+        let members = group
+            .members
+            .iter()
+            .map(|member| {
+                let uri = member_uri(member, config.port)?;
+                let endpoint = Endpoint::from_shared(uri)
+                    .map_err(|e| GroupClientError::InvalidEndpoint(member.name.clone(), e))?
+                    .connect_timeout(config.connect_timeout);
+                Ok((
+                    member.clone(),
+                    ConfigServiceClient::new(endpoint.connect_lazy()),
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            group: group.name.clone(),
+            members,
+            rpc_timeout: config.rpc_timeout,
+        })
+    }
+
+    /// Create a group client from already established member clients.
+    #[must_use]
+    pub fn from_clients(
+        group: impl Into<String>,
+        members: Vec<(GatewayGroupMember, ConfigServiceClient<Channel>)>,
+        rpc_timeout: Duration,
+    ) -> Self {
+        Self {
+            group: group.into(),
+            members,
+            rpc_timeout,
+        }
+    }
+
+    #[must_use]
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    pub fn members(&self) -> impl Iterator<Item = &GatewayGroupMember> {
+        self.members.iter().map(|(member, _)| member)
+    }
+
+    async fn call<T>(
+        &self,
+        fut: impl Future<Output = Result<Response<T>, Status>>,
+    ) -> Result<T, Status> {
+        match tokio::time::timeout(self.rpc_timeout, fut).await {
+            Ok(result) => result.map(Response::into_inner),
+            Err(_) => Err(Status::deadline_exceeded("request timed out")),
+        }
+    }
+
+    /// Query the config generation of all members concurrently.
+    pub async fn generations(&self) -> GenerationSkew {
+        let results = join_all(self.members.iter().map(|(member, client)| async move {
+            let mut client = client.clone();
+            let result = self
+                .call(client.get_config_generation(GetConfigGenerationRequest {}))
+                .await;
+            (member.name.clone(), result)
+        }))
+        .await;
+
+        let mut skew = GenerationSkew::default();
+        for (name, result) in results {
+            match result {
+                Ok(resp) => {
+                    skew.generations.insert(name, resp.generation);
+                }
+                Err(status) => {
+                    skew.unreachable.insert(name, status.message().to_string());
+                }
+            }
+        }
+        skew
+    }
+
+    /// Push `config` to all members concurrently and check the generation they report afterwards.
+    pub async fn update_config(&self, config: &GatewayConfig) -> GroupUpdateReport {
+        let members = join_all(self.members.iter().map(|(member, client)| async move {
+            let mut client = client.clone();
+            let response = self
+                .call(client.update_config(UpdateConfigRequest {
+                    config: Some(config.clone()),
+                }))
+                .await;
+            MemberUpdate {
+                member: member.clone(),
+                response,
+            }
+        }))
+        .await;
+
+        GroupUpdateReport {
+            group: self.group.clone(),
+            generation: config.generation,
+            members,
+            skew: self.generations().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, ipaddress: &str) -> GatewayGroupMember {
+        GatewayGroupMember {
+            name: name.into(),
+            priority: 1,
+            ipaddress: ipaddress.into(),
+        }
+    }
+
+    #[test]
+    fn test_member_uri() {
+        assert_eq!(
+            member_uri(&member("gw1", "10.0.0.1"), 50051).unwrap(),
+            "http://10.0.0.1:50051"
+        );
+        assert_eq!(
+            member_uri(&member("gw1", "10.0.0.1/32"), 50051).unwrap(),
+            "http://10.0.0.1:50051"
+        );
+        assert_eq!(
+            member_uri(&member("gw2", "fd00::1"), 1234).unwrap(),
+            "http://[fd00::1]:1234"
+        );
+        assert!(matches!(
+            member_uri(&member("gw3", "gw3.local"), 1234),
+            Err(GroupClientError::InvalidAddress(name, _)) if name == "gw3"
+        ));
+    }
+
+    #[test]
+    fn test_skew() {
+        let mut skew = GenerationSkew::default();
+        assert!(!skew.is_skewed());
+
+        skew.generations.insert("gw1".into(), 3);
+        skew.generations.insert("gw2".into(), 3);
+        assert!(!skew.is_skewed());
+        assert_eq!(skew.lagging(3).count(), 0);
+
+        skew.generations.insert("gw3".into(), 2);
+        assert!(skew.is_skewed());
+        assert_eq!(skew.min(), Some(2));
+        assert_eq!(skew.max(), Some(3));
+        assert_eq!(skew.lagging(3).collect::<Vec<_>>(), vec![("gw3", 2)]);
+    }
+
+    #[tokio::test]
+    async fn test_new_from_group() {
+        let group = GatewayGroup {
+            name: "group".into(),
+            members: vec![member("gw1", "10.0.0.1"), member("gw2", "fd00::2")],
+        };
+        let client = GroupClient::new(&group, &GroupClientConfig::default()).unwrap();
+        assert_eq!(client.group(), "group");
+        assert_eq!(
+            client
+                .members()
+                .map(|m| m.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["gw1", "gw2"]
+        );
+    }
+}
@@ -0,0 +1,632 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Overall health of a gateway, derived from its [`GetDataplaneStatusResponse`].
+//!
+//! [`evaluate_health`] applies one set of rules to every part of the status and
+//! returns a [`HealthReport`] with the worst level found and a reason for every
+//! part that isn't healthy:
+//!
+//! - the dataplane must report `HEALTHY`, `INIT` is degraded;
+//! - FRR must be connected to the agent and to zebra and, if a generation is
+//!   expected, have applied it;
+//! - interfaces that are administratively up must be operationally up, the same
+//!   for VPC interfaces;
+//! - of the enabled BGP neighbors, at least
+//!   [`HealthOptions::min_established_ratio`] must be established, none at all
+//!   is unhealthy;
+//! - remote VTEPs must be reachable, and every VPC with remote VTEPs must be
+//!   able to reach at least one of them.
+
+use crate::config::{
+    BgpNeighborSessionState, DataplaneStatusInfo, DataplaneStatusType, FrrAgentStatusType,
+    FrrLifecycleState, GetDataplaneStatusResponse, InterfaceAdminStatusType,
+    InterfaceOperStatusType, VtepReachability, ZebraStatusType,
+};
+use crate::evpn::vpc_evpn;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HealthLevel {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+impl fmt::Display for HealthLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Healthy => "HEALTHY",
+            Self::Degraded => "DEGRADED",
+            Self::Unhealthy => "UNHEALTHY",
+        })
+    }
+}
+
+/// Part of the status a [`HealthReason`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HealthComponent {
+    Dataplane,
+    Frr,
+    Interfaces,
+    Bgp,
+    Vpcs,
+    Evpn,
+}
+
+impl fmt::Display for HealthComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Dataplane => "dataplane",
+            Self::Frr => "frr",
+            Self::Interfaces => "interfaces",
+            Self::Bgp => "bgp",
+            Self::Vpcs => "vpcs",
+            Self::Evpn => "evpn",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReason {
+    pub level: HealthLevel,
+    pub component: HealthComponent,
+    pub message: String,
+}
+
+impl fmt::Display for HealthReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.level, self.component, self.message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+    /// Worst level of all reasons, healthy if there are none.
+    pub level: HealthLevel,
+    /// Why the gateway isn't healthy, in the order of [`HealthComponent`].
+    pub reasons: Vec<HealthReason>,
+}
+
+impl HealthReport {
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.level == HealthLevel::Healthy
+    }
+}
+
+impl fmt::Display for HealthReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.level)?;
+        for reason in &self.reasons {
+            write!(f, "\n  {reason}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HealthOptions {
+    /// Generation FRR should have applied, usually the one last pushed.
+    pub expected_generation: Option<i64>,
+    /// Fraction of enabled BGP neighbors that must be established, below it the
+    /// gateway is degraded.
+    pub min_established_ratio: f64,
+}
+
+impl Default for HealthOptions {
+    fn default() -> Self {
+        Self {
+            expected_generation: None,
+            min_established_ratio: 1.0,
+        }
+    }
+}
+
+/// Fold `status` into a [`HealthReport`].
+#[must_use]
+pub fn evaluate_health(
+    status: &GetDataplaneStatusResponse,
+    options: &HealthOptions,
+) -> HealthReport {
+    let mut reasons = Reasons::default();
+    check_dataplane(status, &mut reasons);
+    check_frr(status, options, &mut reasons);
+    check_interfaces(status, &mut reasons);
+    check_bgp(status, options, &mut reasons);
+    check_vpcs(status, &mut reasons);
+    check_evpn(status, &mut reasons);
+    let reasons = reasons.0;
+    HealthReport {
+        level: reasons
+            .iter()
+            .map(|reason| reason.level)
+            .max()
+            .unwrap_or(HealthLevel::Healthy),
+        reasons,
+    }
+}
+
+#[derive(Default)]
+struct Reasons(Vec<HealthReason>);
+
+impl Reasons {
+    fn add(&mut self, level: HealthLevel, component: HealthComponent, message: impl Into<String>) {
+        self.0.push(HealthReason {
+            level,
+            component,
+            message: message.into(),
+        });
+    }
+}
+
+fn check_dataplane(status: &GetDataplaneStatusResponse, reasons: &mut Reasons) {
+    let (level, message) = match status
+        .dataplane_status
+        .as_ref()
+        .map(DataplaneStatusInfo::status)
+    {
+        Some(DataplaneStatusType::DataplaneStatusHealthy) => return,
+        Some(DataplaneStatusType::DataplaneStatusInit) => {
+            (HealthLevel::Degraded, "dataplane is initializing")
+        }
+        Some(DataplaneStatusType::DataplaneStatusError) => {
+            (HealthLevel::Unhealthy, "dataplane reports an error")
+        }
+        Some(DataplaneStatusType::DataplaneStatusUnknown) | None => {
+            (HealthLevel::Unhealthy, "dataplane status is unknown")
+        }
+    };
+    reasons.add(level, HealthComponent::Dataplane, message);
+}
+
+fn check_frr(status: &GetDataplaneStatusResponse, options: &HealthOptions, reasons: &mut Reasons) {
+    let Some(frr) = &status.frr_status else {
+        reasons.add(
+            HealthLevel::Unhealthy,
+            HealthComponent::Frr,
+            "FRR status is unknown",
+        );
+        return;
+    };
+    if frr.frr_agent_status() != FrrAgentStatusType::FrrAgentStatusConnected {
+        reasons.add(
+            HealthLevel::Unhealthy,
+            HealthComponent::Frr,
+            "not connected to the FRR agent",
+        );
+    }
+    if frr.zebra_status() != ZebraStatusType::ZebraStatusConnected {
+        reasons.add(
+            HealthLevel::Unhealthy,
+            HealthComponent::Frr,
+            "not connected to zebra",
+        );
+    }
+    match frr.state() {
+        FrrLifecycleState::FrrStateCrashed => {
+            reasons.add(HealthLevel::Unhealthy, HealthComponent::Frr, "FRR crashed");
+        }
+        FrrLifecycleState::FrrStateStopping => {
+            reasons.add(
+                HealthLevel::Unhealthy,
+                HealthComponent::Frr,
+                "FRR is stopping",
+            );
+        }
+        FrrLifecycleState::FrrStateStarting => {
+            reasons.add(
+                HealthLevel::Degraded,
+                HealthComponent::Frr,
+                "FRR is starting",
+            );
+        }
+        // Dataplanes that don't report the state are judged by the connections
+        FrrLifecycleState::FrrStateRunning
+        | FrrLifecycleState::FrrStateReloading
+        | FrrLifecycleState::FrrStateUnknown => {}
+    }
+    if let Some(expected) = options.expected_generation
+        && frr.applied_config_gen != expected
+    {
+        let pending = if frr.pending_config_gen == expected {
+            " (being applied)"
+        } else {
+            ""
+        };
+        reasons.add(
+            HealthLevel::Degraded,
+            HealthComponent::Frr,
+            format!(
+                "applied config generation {}, expected {expected}{pending}",
+                frr.applied_config_gen
+            ),
+        );
+    }
+    if !frr.last_apply_error.is_empty() {
+        reasons.add(
+            HealthLevel::Degraded,
+            HealthComponent::Frr,
+            format!("last config apply failed: {}", frr.last_apply_error),
+        );
+    }
+}
+
+fn check_interfaces(status: &GetDataplaneStatusResponse, reasons: &mut Reasons) {
+    // Runtime status is more detailed, it wins over the basic one
+    let mut interfaces: BTreeMap<_, _> = status
+        .interface_statuses
+        .iter()
+        .map(|iface| {
+            (
+                iface.ifname.as_str(),
+                (
+                    iface.admin_status(),
+                    iface.oper_status(),
+                    iface.error_reason.as_str(),
+                ),
+            )
+        })
+        .collect();
+    interfaces.extend(status.interface_runtime.iter().map(|(ifname, runtime)| {
+        (
+            ifname.as_str(),
+            (
+                runtime.admin_status(),
+                runtime.oper_status(),
+                runtime.error_reason.as_str(),
+            ),
+        )
+    }));
+    for (ifname, (admin, oper, error_reason)) in interfaces {
+        if let Some(problem) = interface_problem(admin, oper) {
+            let message = if error_reason.is_empty() {
+                format!("interface {ifname} {problem}")
+            } else {
+                format!("interface {ifname} {problem}: {error_reason}")
+            };
+            reasons.add(HealthLevel::Degraded, HealthComponent::Interfaces, message);
+        }
+    }
+}
+
+fn check_bgp(status: &GetDataplaneStatusResponse, options: &HealthOptions, reasons: &mut Reasons) {
+    let neighbors: BTreeMap<_, _> = status
+        .bgp
+        .iter()
+        .flat_map(|bgp| &bgp.vrfs)
+        .flat_map(|(vrf, vrf_status)| {
+            vrf_status
+                .neighbors
+                .iter()
+                .map(move |(addr, neighbor)| ((vrf.as_str(), addr.as_str()), neighbor))
+        })
+        .filter(|(_, neighbor)| neighbor.enabled)
+        .collect();
+    let down: Vec<_> = neighbors
+        .iter()
+        .filter(|(_, n)| n.session_state() != BgpNeighborSessionState::BgpStateEstablished)
+        .map(|((vrf, addr), n)| format!("{addr} in {vrf} ({})", session_state(n.session_state())))
+        .collect();
+    if down.is_empty() {
+        return;
+    }
+    let established = neighbors.len() - down.len();
+    #[allow(clippy::cast_precision_loss)]
+    let ratio = established as f64 / neighbors.len() as f64;
+    let level = if established == 0 {
+        HealthLevel::Unhealthy
+    } else if ratio < options.min_established_ratio {
+        HealthLevel::Degraded
+    } else {
+        return;
+    };
+    reasons.add(
+        level,
+        HealthComponent::Bgp,
+        format!(
+            "{established}/{} BGP sessions established, not established: {}",
+            neighbors.len(),
+            down.join(", ")
+        ),
+    );
+}
+
+fn check_vpcs(status: &GetDataplaneStatusResponse, reasons: &mut Reasons) {
+    let vpcs: BTreeMap<_, _> = status.vpcs.iter().collect();
+    for (name, vpc) in vpcs {
+        let interfaces: BTreeMap<_, _> = vpc.interfaces.iter().collect();
+        for (key, iface) in interfaces {
+            let ifname = if iface.ifname.is_empty() {
+                key
+            } else {
+                &iface.ifname
+            };
+            if let Some(problem) = interface_problem(iface.admin_status(), iface.oper_status()) {
+                reasons.add(
+                    HealthLevel::Degraded,
+                    HealthComponent::Vpcs,
+                    format!("VPC {name} interface {ifname} {problem}"),
+                );
+            }
+        }
+    }
+}
+
+fn check_evpn(status: &GetDataplaneStatusResponse, reasons: &mut Reasons) {
+    let vteps: BTreeMap<_, _> = status.remote_vteps.iter().collect();
+    for (address, vtep) in vteps {
+        if vtep.reachability() == VtepReachability::Unreachable {
+            reasons.add(
+                HealthLevel::Degraded,
+                HealthComponent::Evpn,
+                format!("remote VTEP {address} is unreachable"),
+            );
+        }
+    }
+    let names: BTreeSet<_> = status.vpcs.keys().collect();
+    for name in names {
+        if vpc_evpn(status, name).is_some_and(|evpn| evpn.all_vteps_unreachable()) {
+            reasons.add(
+                HealthLevel::Degraded,
+                HealthComponent::Evpn,
+                format!("VPC {name} has no reachable remote VTEP"),
+            );
+        }
+    }
+}
+
+/// What's wrong with an interface, if anything. Administratively down
+/// interfaces are intended to be down.
+fn interface_problem(
+    admin: InterfaceAdminStatusType,
+    oper: InterfaceOperStatusType,
+) -> Option<&'static str> {
+    if admin == InterfaceAdminStatusType::InterfaceAdminStatusDown {
+        return None;
+    }
+    match oper {
+        InterfaceOperStatusType::InterfaceStatusOperUp => None,
+        InterfaceOperStatusType::InterfaceStatusOperDown => Some("is down"),
+        InterfaceOperStatusType::InterfaceStatusError => Some("is in error"),
+        InterfaceOperStatusType::InterfaceStatusUnknown => Some("has unknown status"),
+    }
+}
+
+fn session_state(state: BgpNeighborSessionState) -> &'static str {
+    match state {
+        BgpNeighborSessionState::BgpStateUnset => "unknown",
+        BgpNeighborSessionState::BgpStateIdle => "Idle",
+        BgpNeighborSessionState::BgpStateConnect => "Connect",
+        BgpNeighborSessionState::BgpStateActive => "Active",
+        BgpNeighborSessionState::BgpStateOpen => "Open",
+        BgpNeighborSessionState::BgpStateEstablished => "Established",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        BgpNeighborStatus, BgpStatus, BgpVrfStatus, FrrStatus, InterfaceStatus, RemoteVtepStatus,
+        VpcInterfaceStatus, VpcStatus,
+    };
+
+    fn healthy() -> GetDataplaneStatusResponse {
+        GetDataplaneStatusResponse {
+            dataplane_status: Some(DataplaneStatusInfo {
+                status: DataplaneStatusType::DataplaneStatusHealthy.into(),
+            }),
+            frr_status: Some(FrrStatus {
+                zebra_status: ZebraStatusType::ZebraStatusConnected.into(),
+                frr_agent_status: FrrAgentStatusType::FrrAgentStatusConnected.into(),
+                applied_config_gen: 7,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn with_neighbors(
+        mut status: GetDataplaneStatusResponse,
+        neighbors: &[(BgpNeighborSessionState, bool)],
+    ) -> GetDataplaneStatusResponse {
+        let neighbors = neighbors
+            .iter()
+            .enumerate()
+            .map(|(i, (state, enabled))| {
+                (
+                    format!("10.0.0.{i}"),
+                    BgpNeighborStatus {
+                        enabled: *enabled,
+                        session_state: (*state).into(),
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect();
+        status.bgp = Some(BgpStatus {
+            vrfs: [("default".to_string(), BgpVrfStatus { neighbors })].into(),
+        });
+        status
+    }
+
+    #[test]
+    fn test_healthy() {
+        let report = evaluate_health(&healthy(), &HealthOptions::default());
+        assert!(report.is_healthy());
+        assert!(report.reasons.is_empty());
+        assert_eq!(report.to_string(), "HEALTHY");
+    }
+
+    #[test]
+    fn test_empty_status() {
+        let report = evaluate_health(
+            &GetDataplaneStatusResponse::default(),
+            &HealthOptions::default(),
+        );
+        assert_eq!(
+            report.to_string(),
+            "UNHEALTHY\n  \
+             UNHEALTHY dataplane: dataplane status is unknown\n  \
+             UNHEALTHY frr: FRR status is unknown"
+        );
+    }
+
+    #[test]
+    fn test_frr_generation() {
+        let options = HealthOptions {
+            expected_generation: Some(8),
+            ..Default::default()
+        };
+        let report = evaluate_health(&healthy(), &options);
+        assert_eq!(report.level, HealthLevel::Degraded);
+        assert_eq!(
+            report.reasons[0].message,
+            "applied config generation 7, expected 8"
+        );
+    }
+
+    #[test]
+    fn test_frr_lifecycle() {
+        let mut status = healthy();
+        let frr = status.frr_status.as_mut().unwrap();
+        frr.state = FrrLifecycleState::FrrStateReloading.into();
+        frr.pending_config_gen = 8;
+        let options = HealthOptions {
+            expected_generation: Some(8),
+            ..Default::default()
+        };
+        assert_eq!(
+            evaluate_health(&status, &options).to_string(),
+            "DEGRADED\n  \
+             DEGRADED frr: applied config generation 7, expected 8 (being applied)"
+        );
+
+        let frr = status.frr_status.as_mut().unwrap();
+        frr.state = FrrLifecycleState::FrrStateCrashed.into();
+        frr.last_apply_error = "vtysh exited with status 1".into();
+        assert_eq!(
+            evaluate_health(&status, &HealthOptions::default()).to_string(),
+            "UNHEALTHY\n  \
+             UNHEALTHY frr: FRR crashed\n  \
+             DEGRADED frr: last config apply failed: vtysh exited with status 1"
+        );
+    }
+
+    #[test]
+    fn test_bgp() {
+        use BgpNeighborSessionState::{BgpStateActive, BgpStateEstablished, BgpStateIdle};
+
+        // Disabled neighbors don't count
+        let status = with_neighbors(
+            healthy(),
+            &[(BgpStateEstablished, true), (BgpStateIdle, false)],
+        );
+        assert!(evaluate_health(&status, &HealthOptions::default()).is_healthy());
+
+        let status = with_neighbors(
+            healthy(),
+            &[(BgpStateEstablished, true), (BgpStateActive, true)],
+        );
+        let report = evaluate_health(&status, &HealthOptions::default());
+        assert_eq!(report.level, HealthLevel::Degraded);
+        assert_eq!(
+            report.reasons[0].message,
+            "1/2 BGP sessions established, not established: 10.0.0.1 in default (Active)"
+        );
+        let options = HealthOptions {
+            min_established_ratio: 0.5,
+            ..Default::default()
+        };
+        assert!(evaluate_health(&status, &options).is_healthy());
+
+        let status = with_neighbors(healthy(), &[(BgpStateIdle, true)]);
+        assert_eq!(
+            evaluate_health(&status, &options).level,
+            HealthLevel::Unhealthy
+        );
+    }
+
+    #[test]
+    fn test_evpn() {
+        let mut status = healthy();
+        status.vpcs.insert(
+            "vpc-1".into(),
+            VpcStatus {
+                vni: 1001,
+                ..Default::default()
+            },
+        );
+        status.remote_vteps.insert(
+            "10.255.0.2".into(),
+            RemoteVtepStatus {
+                address: "10.255.0.2".into(),
+                reachability: VtepReachability::Unreachable.into(),
+                vnis: vec![1001],
+            },
+        );
+        assert_eq!(
+            evaluate_health(&status, &HealthOptions::default()).to_string(),
+            "DEGRADED\n  \
+             DEGRADED evpn: remote VTEP 10.255.0.2 is unreachable\n  \
+             DEGRADED evpn: VPC vpc-1 has no reachable remote VTEP"
+        );
+
+        status.remote_vteps.insert(
+            "10.255.0.3".into(),
+            RemoteVtepStatus {
+                address: "10.255.0.3".into(),
+                reachability: VtepReachability::Reachable.into(),
+                vnis: vec![1001],
+            },
+        );
+        assert_eq!(
+            evaluate_health(&status, &HealthOptions::default()).to_string(),
+            "DEGRADED\n  \
+             DEGRADED evpn: remote VTEP 10.255.0.2 is unreachable"
+        );
+    }
+
+    #[test]
+    fn test_interfaces() {
+        let mut status = healthy();
+        status.interface_statuses = vec![
+            InterfaceStatus {
+                ifname: "eth0".into(),
+                admin_status: InterfaceAdminStatusType::InterfaceAdminStatusUp.into(),
+                oper_status: InterfaceOperStatusType::InterfaceStatusOperDown.into(),
+                error_reason: "no carrier".into(),
+            },
+            InterfaceStatus {
+                ifname: "eth1".into(),
+                admin_status: InterfaceAdminStatusType::InterfaceAdminStatusDown.into(),
+                oper_status: InterfaceOperStatusType::InterfaceStatusOperDown.into(),
+                error_reason: "administratively down".into(),
+            },
+        ];
+        status.vpcs.insert(
+            "vpc-1".into(),
+            VpcStatus {
+                interfaces: [(
+                    "vlan100".to_string(),
+                    VpcInterfaceStatus {
+                        ifname: "vlan100".into(),
+                        admin_status: InterfaceAdminStatusType::InterfaceAdminStatusUp.into(),
+                        oper_status: InterfaceOperStatusType::InterfaceStatusError.into(),
+                    },
+                )]
+                .into(),
+                ..Default::default()
+            },
+        );
+        let report = evaluate_health(&status, &HealthOptions::default());
+        assert_eq!(
+            report.to_string(),
+            "DEGRADED\n  \
+             DEGRADED interfaces: interface eth0 is down: no carrier\n  \
+             DEGRADED vpcs: VPC vpc-1 interface vlan100 is in error"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! [protojson](https://protobuf.dev/programming-guides/json/) encoding of messages.
+//!
+//! The `serde` implementations derived on the generated types follow the Rust
+//! representation (numeric enums, snake case names, tagged oneofs), which is not
+//! what the Go tools read and write. Use the functions here to exchange JSON with
+//! them: field names are camelCase, enums use their names, 64-bit integers are
+//! strings, `google.protobuf.Duration` is a string such as `"30s"` and
+//! `google.protobuf.Timestamp` an RFC 3339 string.
+//!
+//! The `pkg/dataplane` Go tests read the golden files in `tests/testdata` with
+//! protojson and check that they encode back to the same JSON.
+
+use crate::reflect::{self, ReflectError};
+use prost::{Message, Name};
+use prost_reflect::{DeserializeOptions, DynamicMessage, SerializeOptions};
+
+#[derive(thiserror::Error, Debug)]
+pub enum JsonError {
+    #[error("{0}")]
+    Reflect(#[from] ReflectError),
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Options matching the defaults of Go `protojson.Marshal`.
+fn serialize_options() -> SerializeOptions {
+    SerializeOptions::new()
+        .stringify_64_bit_integers(true)
+        .use_enum_numbers(false)
+        .use_proto_field_name(false)
+        .skip_default_fields(true)
+}
+
+/// Options matching the defaults of Go `protojson.Unmarshal`.
+fn deserialize_options() -> DeserializeOptions {
+    DeserializeOptions::new().deny_unknown_fields(true)
+}
+
+fn serialize<M, S>(msg: &M, serializer: S) -> Result<S::Ok, JsonError>
+where
+    M: Message + Name,
+    S: serde::Serializer<Error = serde_json::Error>,
+{
+    let dynamic = reflect::to_dynamic(msg)?;
+    Ok(dynamic.serialize_with_options(serializer, &serialize_options())?)
+}
+
+fn into_string(bytes: Vec<u8>) -> Result<String, JsonError> {
+    String::from_utf8(bytes).map_err(|e| JsonError::Json(serde::ser::Error::custom(e)))
+}
+
+/// Encode `msg` as compact protojson.
+///
+/// # Errors
+///
+/// Returns an error if the message can't be encoded.
+pub fn to_json<M: Message + Name>(msg: &M) -> Result<String, JsonError> {
+    let mut serializer = serde_json::Serializer::new(Vec::new());
+    serialize(msg, &mut serializer)?;
+    into_string(serializer.into_inner())
+}
+
+/// Encode `msg` as indented protojson.
+///
+/// # Errors
+///
+/// Returns an error if the message can't be encoded.
+pub fn to_json_pretty<M: Message + Name>(msg: &M) -> Result<String, JsonError> {
+    let mut serializer = serde_json::Serializer::pretty(Vec::new());
+    serialize(msg, &mut serializer)?;
+    into_string(serializer.into_inner())
+}
+
+/// Encode `msg` as a protojson [`serde_json::Value`].
+///
+/// # Errors
+///
+/// Returns an error if the message can't be encoded.
+pub fn to_json_value<M: Message + Name>(msg: &M) -> Result<serde_json::Value, JsonError> {
+    serialize(msg, serde_json::value::Serializer)
+}
+
+fn from_dynamic<M: Message + Default>(dynamic: &DynamicMessage) -> Result<M, JsonError> {
+    Ok(reflect::from_dynamic(dynamic)?)
+}
+
+/// Decode protojson into `M`. Unknown fields are rejected.
+///
+/// Both camelCase and original proto field names are accepted.
+///
+/// # Errors
+///
+/// Returns an error if `json` is not valid protojson for `M`.
+pub fn from_json<M: Message + Name + Default>(json: &str) -> Result<M, JsonError> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let dynamic = DynamicMessage::deserialize_with_options(
+        reflect::message_descriptor::<M>()?,
+        &mut deserializer,
+        &deserialize_options(),
+    )?;
+    deserializer.end()?;
+    from_dynamic(&dynamic)
+}
+
+/// Decode a protojson [`serde_json::Value`] into `M`. Unknown fields are rejected.
+///
+/// # Errors
+///
+/// Returns an error if `value` is not valid protojson for `M`.
+pub fn from_json_value<M: Message + Name + Default>(
+    value: serde_json::Value,
+) -> Result<M, JsonError> {
+    let dynamic = DynamicMessage::deserialize_with_options(
+        reflect::message_descriptor::<M>()?,
+        value,
+        &deserialize_options(),
+    )?;
+    from_dynamic(&dynamic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        Expose, GatewayConfig, IfType, Interface, PeeringIPs, PeeringStatefulNat, expose,
+        peering_i_ps,
+    };
+    use crate::google::protobuf::Duration;
+    use serde_json::json;
+
+    #[test]
+    fn test_protojson_mapping() {
+        let config = GatewayConfig {
+            generation: 42,
+            communities: [(1, "65000:1".to_string())].into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            to_json_value(&config).unwrap(),
+            json!({"generation": "42", "communities": {"1": "65000:1"}})
+        );
+
+        let iface = Interface {
+            name: "eth0".into(),
+            r#type: IfType::Vtep.into(),
+            system_name: Some("eth1".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            to_json_value(&iface).unwrap(),
+            json!({"name": "eth0", "type": "IF_TYPE_VTEP", "systemName": "eth1"})
+        );
+
+        let expose = Expose {
+            ips: vec![PeeringIPs {
+                rule: Some(peering_i_ps::Rule::Not("10.0.0.0/24".into())),
+            }],
+            r#as: vec![],
+            nat: Some(expose::Nat::Stateful(PeeringStatefulNat {
+                idle_timeout: Some(Duration {
+                    seconds: 30,
+                    nanos: 500_000_000,
+                }),
+            })),
+        };
+        assert_eq!(
+            to_json_value(&expose).unwrap(),
+            json!({"ips": [{"not": "10.0.0.0/24"}], "stateful": {"idleTimeout": "30.500s"}})
+        );
+    }
+
+    #[test]
+    fn test_from_json() {
+        let config: GatewayConfig =
+            from_json(r#"{"generation": "7", "gw_groups": [{"name": "g"}]}"#).unwrap();
+        assert_eq!(config.generation, 7);
+        assert_eq!(config.gw_groups[0].name, "g");
+
+        let err = from_json::<GatewayConfig>(r#"{"generation": 7, "bogus": 1}"#).unwrap_err();
+        assert!(err.to_string().contains("bogus"), "{err}");
+
+        assert!(from_json::<GatewayConfig>(r#"{"generation": "7"} {}"#).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let config = GatewayConfig {
+            generation: i64::MAX,
+            communities: [(1, "65000:1".to_string()), (2, "65000:2".to_string())].into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            from_json::<GatewayConfig>(&to_json(&config).unwrap()).unwrap(),
+            config
+        );
+        assert_eq!(
+            from_json::<GatewayConfig>(&to_json_pretty(&config).unwrap()).unwrap(),
+            config
+        );
+        assert_eq!(
+            from_json_value::<GatewayConfig>(to_json_value(&config).unwrap()).unwrap(),
+            config
+        );
+    }
+
+    #[cfg(feature = "bolero")]
+    #[test]
+    fn test_round_trip_bolero() {
+        use crate::config::GetDataplaneStatusResponse;
+
+        bolero::check!()
+            .with_type::<GatewayConfig>()
+            .for_each(|config| {
+                let json = to_json(config).unwrap();
+                assert_eq!(&from_json::<GatewayConfig>(&json).unwrap(), config);
+            });
+        bolero::check!()
+            .with_type::<GetDataplaneStatusResponse>()
+            .for_each(|status| {
+                let json = to_json(status).unwrap();
+                assert_eq!(
+                    &from_json::<GetDataplaneStatusResponse>(&json).unwrap(),
+                    status
+                );
+            });
+    }
+}
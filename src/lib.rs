@@ -7,6 +7,7 @@
 pub mod config {
     include!("generated/config.rs");
 }
+#[allow(clippy::pedantic)]
 pub mod google {
     pub mod protobuf {
         include!("generated/google.protobuf.rs");
@@ -40,7 +41,11 @@ pub use config::{
     Error,
 
     Expose,
+    ExposeCounters,
+    // NAT sessions
+    FiveTuple,
     FrrAgentStatusType,
+    FrrLifecycleState,
     FrrStatus,
     // ---------- Top-level config ----------
     GatewayConfig,
@@ -52,6 +57,8 @@ pub use config::{
     GetConfigRequest,
     GetDataplaneStatusRequest,
     GetDataplaneStatusResponse,
+    GetRoutesRequest,
+    GetRoutesResponse,
 
     IfRole,
     IfType,
@@ -66,7 +73,14 @@ pub use config::{
 
     // ---------- Dataplane & FRR status (existing) ----------
     InterfaceStatus,
+    IpProtocol,
+    ListNatSessionsRequest,
+    ListNatSessionsResponse,
     LogLevel,
+    LookupRouteRequest,
+    LookupRouteResponse,
+    NatSession,
+    NatSessionCounters,
     // ---------- Underlay ----------
     OspfConfig,
     OspfInterface,
@@ -76,10 +90,17 @@ pub use config::{
     PeeringAs,
     PeeringEntryFor,
     PeeringIPs,
+    // EVPN
+    RemoteVtepStatus,
+    // Routes
+    Route,
+    RouteNextHop,
+    RouteProtocol,
     RouterConfig,
     Underlay,
     UpdateConfigRequest,
     UpdateConfigResponse,
+    VniStatus,
     Vpc,
     VpcCounters,
 
@@ -91,6 +112,7 @@ pub use config::{
     VpcStatus,
 
     Vrf,
+    VtepReachability,
 
     ZebraStatusType,
     // ---------- Service definitions ----------
@@ -108,4 +130,36 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 #[cfg(feature = "bolero")]
 pub mod bolero;
 
+pub mod consistency;
+pub mod drops;
 mod duration;
+pub mod error;
+pub mod events;
+pub mod evpn;
+pub mod fault;
+pub mod group;
+pub mod health;
+#[cfg(feature = "encoding")]
+pub mod json;
+pub mod memory;
+pub mod nat;
+#[cfg(feature = "otel")]
+pub mod otel;
+pub mod page;
+pub mod poller;
+pub mod prometheus;
+pub mod rate;
+pub mod reconcile;
+pub mod record;
+#[cfg(feature = "encoding")]
+pub mod reflect;
+pub mod routes;
+#[cfg(feature = "encoding")]
+pub mod schema;
+pub mod table;
+#[cfg(feature = "connect")]
+pub mod target;
+#[cfg(feature = "encoding")]
+pub mod textproto;
+#[cfg(feature = "encoding")]
+pub mod yaml;
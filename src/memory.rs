@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! In-memory [`ConfigService`] that just saves and returns config, the Rust
+//! counterpart of the Go `MockConfigServiceServer`.
+
+use crate::config::{
+    DataplaneStatusInfo, DataplaneStatusType, FrrAgentStatusType, FrrLifecycleState, FrrStatus,
+    GatewayConfig, GetConfigGenerationRequest, GetConfigGenerationResponse, GetConfigRequest,
+    GetDataplaneStatusRequest, GetDataplaneStatusResponse, GetRoutesRequest, GetRoutesResponse,
+    ListNatSessionsRequest, ListNatSessionsResponse, LookupRouteRequest, LookupRouteResponse,
+    NatSession, Route, UpdateConfigRequest, UpdateConfigResponse, ZebraStatusType,
+};
+use crate::error::UpdateConfigError;
+use crate::nat::page_nat_sessions;
+use crate::routes::{RouteTable, lookup_route, page_routes};
+use crate::{ConfigService, Error};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tonic::{Request, Response, Status};
+
+#[derive(Debug, Default)]
+struct State {
+    config: GatewayConfig,
+    status: GetDataplaneStatusResponse,
+    nat_sessions: Vec<NatSession>,
+    routes: HashMap<RouteTable, Vec<Route>>,
+    frr_follows_config: bool,
+}
+
+/// Config service keeping the last received config and a dataplane status in memory.
+///
+/// By default, every accepted config is immediately reported as applied by FRR in
+/// the returned status. Clones share the same state, so a clone can be kept to
+/// inspect or modify the state while another one is being served.
+#[derive(Debug, Clone)]
+pub struct InMemoryConfigService {
+    state: Arc<Mutex<State>>,
+}
+
+impl Default for InMemoryConfigService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryConfigService {
+    #[must_use]
+    pub fn new() -> Self {
+        let status = GetDataplaneStatusResponse {
+            frr_status: Some(FrrStatus {
+                zebra_status: ZebraStatusType::ZebraStatusConnected.into(),
+                frr_agent_status: FrrAgentStatusType::FrrAgentStatusConnected.into(),
+                state: FrrLifecycleState::FrrStateRunning.into(),
+                ..Default::default()
+            }),
+            dataplane_status: Some(DataplaneStatusInfo {
+                status: DataplaneStatusType::DataplaneStatusHealthy.into(),
+            }),
+            ..Default::default()
+        };
+        Self {
+            state: Arc::new(Mutex::new(State {
+                config: GatewayConfig::default(),
+                status,
+                nat_sessions: Vec::new(),
+                routes: HashMap::new(),
+                frr_follows_config: true,
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // The state is always consistent, it's fine to keep using it after a panic
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Last config received (or the default config if none received yet).
+    #[must_use]
+    pub fn config(&self) -> GatewayConfig {
+        self.lock().config.clone()
+    }
+
+    /// Status returned by `GetDataplaneStatus`.
+    #[must_use]
+    pub fn status(&self) -> GetDataplaneStatusResponse {
+        self.lock().status.clone()
+    }
+
+    pub fn set_status(&self, status: GetDataplaneStatusResponse) {
+        self.lock().status = status;
+    }
+
+    /// Modify the status returned by `GetDataplaneStatus` in place.
+    pub fn update_status<F: FnOnce(&mut GetDataplaneStatusResponse)>(&self, f: F) {
+        f(&mut self.lock().status);
+    }
+
+    /// Session table served by `ListNatSessions`, in the order pages are cut from.
+    ///
+    /// The aggregate counts in the status are not derived from it, set them
+    /// with [`Self::update_status`] if needed.
+    pub fn set_nat_sessions(&self, sessions: Vec<NatSession>) {
+        self.lock().nat_sessions = sessions;
+    }
+
+    /// Routes of `table` served by `GetRoutes` and `LookupRoute`.
+    ///
+    /// Requests for tables that were never set fail with `NOT_FOUND`.
+    pub fn set_routes(&self, table: RouteTable, routes: Vec<Route>) {
+        self.lock().routes.insert(table, routes);
+    }
+
+    /// Whether accepted configs are immediately reported as applied by FRR.
+    ///
+    /// If disabled, `FrrStatus.applied_config_gen` has to be moved forward with
+    /// [`Self::update_status`].
+    pub fn set_frr_follows_config(&self, follows: bool) {
+        self.lock().frr_follows_config = follows;
+    }
+}
+
+#[tonic::async_trait]
+impl ConfigService for InMemoryConfigService {
+    async fn get_config(
+        &self,
+        _request: Request<GetConfigRequest>,
+    ) -> Result<Response<GatewayConfig>, Status> {
+        Ok(Response::new(self.config()))
+    }
+
+    async fn get_config_generation(
+        &self,
+        _request: Request<GetConfigGenerationRequest>,
+    ) -> Result<Response<GetConfigGenerationResponse>, Status> {
+        Ok(Response::new(GetConfigGenerationResponse {
+            generation: self.lock().config.generation,
+        }))
+    }
+
+    async fn update_config(
+        &self,
+        request: Request<UpdateConfigRequest>,
+    ) -> Result<Response<UpdateConfigResponse>, Status> {
+        let Some(config) = request.into_inner().config else {
+            return Err(UpdateConfigError::ValidationFailed("missing config".into()).into());
+        };
+
+        let mut state = self.lock();
+        let follows = state.frr_follows_config;
+        let frr = state
+            .status
+            .frr_status
+            .get_or_insert_with(FrrStatus::default);
+        if follows {
+            frr.applied_config_gen = config.generation;
+            frr.applied_configs = frr.applied_configs.saturating_add(1);
+            frr.pending_config_gen = 0;
+        } else {
+            // Stays pending until the status is set explicitly
+            frr.pending_config_gen = config.generation;
+        }
+        state.config = config;
+
+        Ok(Response::new(UpdateConfigResponse {
+            error: Error::None.into(),
+            message: String::new(),
+        }))
+    }
+
+    async fn get_dataplane_status(
+        &self,
+        _request: Request<GetDataplaneStatusRequest>,
+    ) -> Result<Response<GetDataplaneStatusResponse>, Status> {
+        Ok(Response::new(self.status()))
+    }
+
+    async fn list_nat_sessions(
+        &self,
+        request: Request<ListNatSessionsRequest>,
+    ) -> Result<Response<ListNatSessionsResponse>, Status> {
+        page_nat_sessions(&self.lock().nat_sessions, request.get_ref()).map(Response::new)
+    }
+
+    async fn get_routes(
+        &self,
+        request: Request<GetRoutesRequest>,
+    ) -> Result<Response<GetRoutesResponse>, Status> {
+        let request = request.into_inner();
+        let table = request.route_table()?;
+        let state = self.lock();
+        let routes = state
+            .routes
+            .get(&table)
+            .ok_or_else(|| Status::not_found(format!("no routes for {table}")))?;
+        page_routes(routes, &request).map(Response::new)
+    }
+
+    async fn lookup_route(
+        &self,
+        request: Request<LookupRouteRequest>,
+    ) -> Result<Response<LookupRouteResponse>, Status> {
+        let request = request.into_inner();
+        let table = request.route_table()?;
+        let state = self.lock();
+        let routes = state
+            .routes
+            .get(&table)
+            .ok_or_else(|| Status::not_found(format!("no routes for {table}")))?;
+        lookup_route(routes, &request).map(Response::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_update_and_get() {
+        let service = InMemoryConfigService::new();
+        let config = GatewayConfig {
+            generation: 42,
+            ..Default::default()
+        };
+
+        let resp = service
+            .update_config(Request::new(UpdateConfigRequest {
+                config: Some(config.clone()),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(resp.error, i32::from(Error::None));
+
+        let got = service
+            .get_config(Request::new(GetConfigRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(got, config);
+
+        let generation = service
+            .get_config_generation(Request::new(GetConfigGenerationRequest {}))
+            .await
+            .unwrap()
+            .into_inner()
+            .generation;
+        assert_eq!(generation, 42);
+
+        let status = service
+            .get_dataplane_status(Request::new(GetDataplaneStatusRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(status.frr_status.unwrap().applied_config_gen, 42);
+    }
+
+    #[tokio::test]
+    async fn test_frr_does_not_follow() {
+        let service = InMemoryConfigService::new();
+        service.set_frr_follows_config(false);
+        service
+            .update_config(Request::new(UpdateConfigRequest {
+                config: Some(GatewayConfig {
+                    generation: 7,
+                    ..Default::default()
+                }),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(service.config().generation, 7);
+        let frr = service.status().frr_status.unwrap();
+        assert_eq!(frr.applied_config_gen, 0);
+        assert_eq!(frr.pending_config_gen, 7);
+    }
+
+    #[tokio::test]
+    async fn test_missing_config() {
+        let service = InMemoryConfigService::new();
+        let status = service
+            .update_config(Request::new(UpdateConfigRequest { config: None }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+}
@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Querying the stateful NAT session table with `ListNatSessions`.
+//!
+//! Servers can use [`page_nat_sessions`] to apply the request filters and cut
+//! a page out of their session table, clients can use
+//! [`ConfigServiceClient::list_all_nat_sessions`] to follow the pages.
+//!
+//! Paging is done by [`crate::page`], so sessions created or expired between
+//! two pages may be skipped or returned twice.
+
+use crate::ConfigServiceClient;
+use crate::config::{
+    FiveTuple, IpProtocol, ListNatSessionsRequest, ListNatSessionsResponse, NatSession,
+};
+use crate::page::{cut_page, follow_pages};
+use std::net::IpAddr;
+use tonic::Status;
+use tonic::transport::Channel;
+
+pub use crate::page::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+fn same_address(a: &str, b: &str) -> bool {
+    match (a.parse::<IpAddr>(), b.parse::<IpAddr>()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn tuple_has_address(tuple: Option<&FiveTuple>, address: &str) -> bool {
+    tuple.is_some_and(|t| same_address(&t.src_ip, address) || same_address(&t.dst_ip, address))
+}
+
+impl ListNatSessionsRequest {
+    /// Whether `session` passes all filters of the request.
+    ///
+    /// The protocol is matched against the original tuple, addresses are
+    /// compared as IP addresses when both sides parse as such.
+    #[must_use]
+    pub fn matches(&self, session: &NatSession) -> bool {
+        if !self.peering.is_empty() && session.peering != self.peering {
+            return false;
+        }
+        if !self.vpc.is_empty() && session.src_vpc != self.vpc && session.dst_vpc != self.vpc {
+            return false;
+        }
+        if !self.address.is_empty()
+            && !tuple_has_address(session.original.as_ref(), &self.address)
+            && !tuple_has_address(session.translated.as_ref(), &self.address)
+        {
+            return false;
+        }
+        if self.protocol() != IpProtocol::Unspecified
+            && session
+                .original
+                .as_ref()
+                .is_none_or(|t| t.protocol != self.protocol)
+        {
+            return false;
+        }
+        true
+    }
+}
+
+/// Build the response to `request` from the whole session `table`.
+///
+/// # Errors
+///
+/// Returns `INVALID_ARGUMENT` if the page token wasn't produced by this function.
+pub fn page_nat_sessions<'a>(
+    table: impl IntoIterator<Item = &'a NatSession>,
+    request: &ListNatSessionsRequest,
+) -> Result<ListNatSessionsResponse, Status> {
+    let (sessions, next_page_token) = cut_page(
+        table.into_iter().filter(|session| request.matches(session)),
+        request.page_size,
+        &request.page_token,
+    )?;
+    Ok(ListNatSessionsResponse {
+        sessions,
+        next_page_token,
+    })
+}
+
+impl ConfigServiceClient<Channel> {
+    /// Call `ListNatSessions` until the last page and collect all sessions.
+    ///
+    /// The page token of `request` is used for the first call, so a listing
+    /// can be resumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the status of the first failed call, or `INTERNAL` if the
+    /// server returns the same page token twice in a row.
+    pub async fn list_all_nat_sessions(
+        &self,
+        request: ListNatSessionsRequest,
+    ) -> Result<Vec<NatSession>, Status> {
+        follow_pages(request.page_token.clone(), |page_token| {
+            let mut client = self.clone();
+            let request = ListNatSessionsRequest {
+                page_token,
+                ..request.clone()
+            };
+            async move {
+                let page = client.list_nat_sessions(request).await?.into_inner();
+                Ok((page.sessions, page.next_page_token))
+            }
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(peering: &str, protocol: IpProtocol, src_ip: &str, nat_ip: &str) -> NatSession {
+        let (src_vpc, dst_vpc) = peering.split_once("--").unwrap();
+        NatSession {
+            peering: peering.into(),
+            src_vpc: src_vpc.into(),
+            dst_vpc: dst_vpc.into(),
+            original: Some(FiveTuple {
+                protocol: protocol.into(),
+                src_ip: src_ip.into(),
+                src_port: 40000,
+                dst_ip: "10.2.0.1".into(),
+                dst_port: 443,
+            }),
+            translated: Some(FiveTuple {
+                protocol: protocol.into(),
+                src_ip: nat_ip.into(),
+                src_port: 1024,
+                dst_ip: "10.2.0.1".into(),
+                dst_port: 443,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_filters() {
+        let tcp = session("vpc-1--vpc-2", IpProtocol::Tcp, "10.1.0.5", "192.0.2.1");
+        let udp = session("vpc-1--vpc-3", IpProtocol::Udp, "fd00::5", "2001:db8::1");
+
+        let all = ListNatSessionsRequest::default();
+        assert!(all.matches(&tcp) && all.matches(&udp));
+
+        let by_peering = ListNatSessionsRequest {
+            peering: "vpc-1--vpc-3".into(),
+            ..Default::default()
+        };
+        assert!(!by_peering.matches(&tcp) && by_peering.matches(&udp));
+
+        let by_vpc = ListNatSessionsRequest {
+            vpc: "vpc-2".into(),
+            ..Default::default()
+        };
+        assert!(by_vpc.matches(&tcp) && !by_vpc.matches(&udp));
+
+        // Translated addresses match too, IPv6 is compared normalized
+        let by_address = ListNatSessionsRequest {
+            address: "2001:db8:0::1".into(),
+            ..Default::default()
+        };
+        assert!(!by_address.matches(&tcp) && by_address.matches(&udp));
+
+        let by_protocol = ListNatSessionsRequest {
+            protocol: IpProtocol::Tcp.into(),
+            ..Default::default()
+        };
+        assert!(by_protocol.matches(&tcp) && !by_protocol.matches(&udp));
+
+        let none = ListNatSessionsRequest {
+            vpc: "vpc-1".into(),
+            protocol: IpProtocol::Icmp.into(),
+            ..Default::default()
+        };
+        assert!(!none.matches(&tcp) && !none.matches(&udp));
+    }
+
+    #[test]
+    fn test_pages() {
+        let table: Vec<_> = (0_u32..5)
+            .map(|i| {
+                let protocol = if i.is_multiple_of(2) {
+                    IpProtocol::Tcp
+                } else {
+                    IpProtocol::Udp
+                };
+                session(
+                    "vpc-1--vpc-2",
+                    protocol,
+                    &format!("10.1.0.{i}"),
+                    "192.0.2.1",
+                )
+            })
+            .collect();
+        let mut request = ListNatSessionsRequest {
+            protocol: IpProtocol::Tcp.into(),
+            page_size: 2,
+            ..Default::default()
+        };
+
+        let page = page_nat_sessions(&table, &request).unwrap();
+        assert_eq!(page.sessions, vec![table[0].clone(), table[2].clone()]);
+        assert!(!page.next_page_token.is_empty());
+
+        request.page_token = page.next_page_token;
+        let page = page_nat_sessions(&table, &request).unwrap();
+        assert_eq!(page.sessions, vec![table[4].clone()]);
+        assert_eq!(page.next_page_token, "");
+
+        // A full last page doesn't promise another one
+        request.page_token = String::new();
+        request.page_size = 3;
+        let page = page_nat_sessions(&table, &request).unwrap();
+        assert_eq!(page.sessions.len(), 3);
+        assert_eq!(page.next_page_token, "");
+
+        request.page_token = "bogus".into();
+        let err = page_nat_sessions(&table, &request).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+}
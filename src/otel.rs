@@ -0,0 +1,351 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! OpenTelemetry metrics fed from [`GetDataplaneStatusResponse`] snapshots.
+//!
+//! [`StatusMetrics::register`] creates observable instruments on a [`Meter`]
+//! that report the latest snapshot whenever the meter provider collects.
+//! Snapshots are set with [`StatusMetrics::update`] or fetched periodically by
+//! [`StatusMetrics::poll`]. Until there is a snapshot, or after fetching one
+//! failed, only `gateway.status.up` is reported, as 0.
+//!
+//! The instruments mirror the metrics of [`crate::prometheus`]: counters for
+//! bits, bytes, packets, drops and errors, gauges for rates and BGP session
+//! state, with the same `ifname`, `queue`, `vrf`, `neighbor`, `vpc` and
+//! `peering` attributes.
+
+use crate::ConfigServiceClient;
+use crate::config::{
+    GetDataplaneStatusRequest, GetDataplaneStatusResponse, InterfaceCounters,
+    InterfaceQueueCounters, VpcCounters, VpcPeeringCounters,
+};
+use crate::prometheus::SESSION_STATES;
+use opentelemetry::KeyValue;
+use opentelemetry::metrics::{AsyncInstrument, Meter};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+use tonic::transport::Channel;
+
+type Snapshot = Arc<RwLock<Option<GetDataplaneStatusResponse>>>;
+type QueueCounter = fn(&InterfaceQueueCounters) -> u64;
+type VpcCounter = fn(&VpcCounters) -> u64;
+type PeeringCounter = fn(&VpcPeeringCounters) -> u64;
+type PeeringRate = fn(&VpcPeeringCounters) -> f64;
+
+/// Observable instruments reporting the latest status snapshot.
+///
+/// Clones share the snapshot.
+#[derive(Debug, Clone, Default)]
+pub struct StatusMetrics {
+    snapshot: Snapshot,
+}
+
+/// Wrap `observe` into an instrument callback that runs it on the snapshot, if
+/// there is one.
+fn callback<T>(
+    snapshot: &Snapshot,
+    observe: impl Fn(&GetDataplaneStatusResponse, &dyn AsyncInstrument<T>) + Send + Sync + 'static,
+) -> impl Fn(&dyn AsyncInstrument<T>) + Send + Sync + 'static {
+    let snapshot = snapshot.clone();
+    move |instrument| {
+        let snapshot = snapshot.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some(status) = snapshot.as_ref() {
+            observe(status, instrument);
+        }
+    }
+}
+
+/// Observe the receive and transmit values `get` returns for every interface
+/// with counters.
+fn observe_interfaces<T>(
+    status: &GetDataplaneStatusResponse,
+    instrument: &dyn AsyncInstrument<T>,
+    get: fn(&InterfaceCounters) -> (T, T),
+) {
+    for (ifname, runtime) in &status.interface_runtime {
+        let Some(counters) = &runtime.counters else {
+            continue;
+        };
+        let (rx, tx) = get(counters);
+        for (direction, value) in [("receive", rx), ("transmit", tx)] {
+            instrument.observe(
+                value,
+                &[
+                    KeyValue::new("ifname", ifname.clone()),
+                    KeyValue::new("direction", direction),
+                ],
+            );
+        }
+    }
+}
+
+/// Observe the value `get` returns for every receive and transmit queue of
+/// every interface with counters.
+fn observe_queues(
+    status: &GetDataplaneStatusResponse,
+    instrument: &dyn AsyncInstrument<u64>,
+    get: QueueCounter,
+) {
+    for (ifname, runtime) in &status.interface_runtime {
+        let Some(counters) = &runtime.counters else {
+            continue;
+        };
+        for (direction, queues) in [
+            ("receive", &counters.rx_queues),
+            ("transmit", &counters.tx_queues),
+        ] {
+            for queue in queues {
+                instrument.observe(
+                    get(queue),
+                    &[
+                        KeyValue::new("ifname", ifname.clone()),
+                        KeyValue::new("direction", direction),
+                        KeyValue::new("queue", i64::from(queue.queue)),
+                    ],
+                );
+            }
+        }
+    }
+}
+
+fn peering_attributes(name: &str, src_vpc: &str, dst_vpc: &str) -> [KeyValue; 3] {
+    [
+        KeyValue::new("peering", name.to_string()),
+        KeyValue::new("src_vpc", src_vpc.to_string()),
+        KeyValue::new("dst_vpc", dst_vpc.to_string()),
+    ]
+}
+
+impl StatusMetrics {
+    /// Register the status instruments on `meter`.
+    #[must_use]
+    pub fn register(meter: &Meter) -> Self {
+        let metrics = Self::default();
+        metrics.register_interfaces(meter);
+        metrics.register_bgp(meter);
+        metrics.register_vpcs(meter);
+
+        let snapshot = metrics.snapshot.clone();
+        meter
+            .u64_observable_gauge("gateway.status.up")
+            .with_description("Whether a dataplane status snapshot is available.")
+            .with_callback(move |instrument| {
+                let snapshot = snapshot.read().unwrap_or_else(PoisonError::into_inner);
+                instrument.observe(u64::from(snapshot.is_some()), &[]);
+            })
+            .build();
+        metrics
+    }
+
+    fn register_interfaces(&self, meter: &Meter) {
+        meter
+            .u64_observable_counter("gateway.interface.io")
+            .with_unit("bit")
+            .with_description("Bits received and sent on the interface.")
+            .with_callback(callback(&self.snapshot, |status, instrument| {
+                observe_interfaces(status, instrument, |c| (c.rx_bits, c.tx_bits));
+            }))
+            .build();
+        meter
+            .u64_observable_counter("gateway.interface.errors")
+            .with_unit("{error}")
+            .with_description("Receive and transmit errors on the interface.")
+            .with_callback(callback(&self.snapshot, |status, instrument| {
+                observe_interfaces(status, instrument, |c| (c.rx_errors, c.tx_errors));
+            }))
+            .build();
+        meter
+            .u64_observable_counter("gateway.interface.packets")
+            .with_unit("{packet}")
+            .with_description("Packets received and sent on the interface.")
+            .with_callback(callback(&self.snapshot, |status, instrument| {
+                observe_interfaces(status, instrument, |c| (c.rx_packets, c.tx_packets));
+            }))
+            .build();
+        meter
+            .u64_observable_counter("gateway.interface.drops")
+            .with_unit("{packet}")
+            .with_description("Receive and transmit drops on the interface.")
+            .with_callback(callback(&self.snapshot, |status, instrument| {
+                observe_interfaces(status, instrument, |c| (c.rx_drops, c.tx_drops));
+            }))
+            .build();
+        meter
+            .u64_observable_counter("gateway.interface.multicast")
+            .with_unit("{packet}")
+            .with_description("Multicast packets received and sent on the interface.")
+            .with_callback(callback(&self.snapshot, |status, instrument| {
+                observe_interfaces(status, instrument, |c| (c.rx_multicast, c.tx_multicast));
+            }))
+            .build();
+        meter
+            .u64_observable_counter("gateway.interface.broadcast")
+            .with_unit("{packet}")
+            .with_description("Broadcast packets received and sent on the interface.")
+            .with_callback(callback(&self.snapshot, |status, instrument| {
+                observe_interfaces(status, instrument, |c| (c.rx_broadcast, c.tx_broadcast));
+            }))
+            .build();
+        let queue_counters: [(&str, &str, &str, QueueCounter); 3] = [
+            ("packets", "{packet}", "Packets handled", |q| q.packets),
+            ("bytes", "By", "Bytes handled", |q| q.bytes),
+            ("drops", "{packet}", "Packets dropped", |q| q.drops),
+        ];
+        for (name, unit, description, get) in queue_counters {
+            meter
+                .u64_observable_counter(format!("gateway.interface.queue.{name}"))
+                .with_unit(unit)
+                .with_description(format!("{description} by the interface queue."))
+                .with_callback(callback(&self.snapshot, move |status, instrument| {
+                    observe_queues(status, instrument, get);
+                }))
+                .build();
+        }
+        meter
+            .f64_observable_gauge("gateway.interface.bit_rate")
+            .with_unit("bit/s")
+            .with_description("Receive and transmit rate of the interface.")
+            .with_callback(callback(&self.snapshot, |status, instrument| {
+                observe_interfaces(status, instrument, |c| (c.rx_bps, c.tx_bps));
+            }))
+            .build();
+    }
+
+    fn register_bgp(&self, meter: &Meter) {
+        meter
+            .u64_observable_gauge("gateway.bgp.neighbor.session_state")
+            .with_description("BGP session state, 1 for the current state.")
+            .with_callback(callback(&self.snapshot, |status, instrument| {
+                let vrfs = status.bgp.iter().flat_map(|bgp| &bgp.vrfs);
+                for (vrf, vrf_status) in vrfs {
+                    for (addr, neighbor) in &vrf_status.neighbors {
+                        for (state, name) in SESSION_STATES {
+                            instrument.observe(
+                                u64::from(neighbor.session_state() == state),
+                                &[
+                                    KeyValue::new("vrf", vrf.clone()),
+                                    KeyValue::new("neighbor", addr.clone()),
+                                    KeyValue::new("state", name),
+                                ],
+                            );
+                        }
+                    }
+                }
+            }))
+            .build();
+    }
+
+    fn register_vpcs(&self, meter: &Meter) {
+        let counters: [(
+            &'static str,
+            &'static str,
+            &'static str,
+            VpcCounter,
+            PeeringCounter,
+        ); 3] = [
+            (
+                "packets",
+                "{packet}",
+                "Packets forwarded",
+                |c| c.packets,
+                |c| c.packets,
+            ),
+            ("bytes", "By", "Bytes forwarded", |c| c.bytes, |c| c.bytes),
+            (
+                "drops",
+                "{packet}",
+                "Packets dropped",
+                |c| c.drops,
+                |c| c.drops,
+            ),
+        ];
+        for (name, unit, description, vpc, peering) in counters {
+            meter
+                .u64_observable_counter(format!("gateway.vpc.{name}"))
+                .with_unit(unit)
+                .with_description(format!("{description} for the VPC."))
+                .with_callback(callback(&self.snapshot, move |status, instrument| {
+                    for (name, counters) in &status.vpc_counters {
+                        instrument.observe(vpc(counters), &[KeyValue::new("vpc", name.clone())]);
+                    }
+                }))
+                .build();
+            meter
+                .u64_observable_counter(format!("gateway.vpc_peering.{name}"))
+                .with_unit(unit)
+                .with_description(format!("{description} over the VPC peering."))
+                .with_callback(callback(&self.snapshot, move |status, instrument| {
+                    for (name, counters) in &status.vpc_peering_counters {
+                        instrument.observe(
+                            peering(counters),
+                            &peering_attributes(name, &counters.src_vpc, &counters.dst_vpc),
+                        );
+                    }
+                }))
+                .build();
+        }
+        let rates: [(&'static str, &'static str, &'static str, PeeringRate); 2] = [
+            ("packet_rate", "{packet}/s", "Packet rate", |c| c.pps),
+            ("bit_rate", "bit/s", "Bit rate", |c| c.bps),
+        ];
+        for (name, unit, description, rate) in rates {
+            meter
+                .f64_observable_gauge(format!("gateway.vpc_peering.{name}"))
+                .with_unit(unit)
+                .with_description(format!("{description} of the VPC peering."))
+                .with_callback(callback(&self.snapshot, move |status, instrument| {
+                    for (name, counters) in &status.vpc_peering_counters {
+                        instrument.observe(
+                            rate(counters),
+                            &peering_attributes(name, &counters.src_vpc, &counters.dst_vpc),
+                        );
+                    }
+                }))
+                .build();
+        }
+    }
+
+    /// Replace the snapshot reported by the instruments.
+    pub fn update(&self, status: GetDataplaneStatusResponse) {
+        *self
+            .snapshot
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(status);
+    }
+
+    /// Drop the snapshot, only `gateway.status.up` is reported until the next
+    /// update.
+    pub fn clear(&self) {
+        *self
+            .snapshot
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Fetch the status with `client` every `interval` and update the snapshot,
+    /// clearing it whenever fetching fails or takes longer than `rpc_timeout`.
+    ///
+    /// Never returns, spawn it or select it with a shutdown signal.
+    pub async fn poll(
+        &self,
+        mut client: ConfigServiceClient<Channel>,
+        interval: Duration,
+        rpc_timeout: Duration,
+    ) {
+        let mut ticker = tokio::time::interval(interval.max(Duration::from_millis(1)));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match tokio::time::timeout(
+                rpc_timeout,
+                client.get_dataplane_status(GetDataplaneStatusRequest {}),
+            )
+            .await
+            {
+                Ok(Ok(response)) => self.update(response.into_inner()),
+                Ok(Err(_)) | Err(_) => self.clear(),
+            }
+        }
+    }
+}
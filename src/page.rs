@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Token-based pagination shared by the listing RPCs.
+//!
+//! Page tokens produced here are offsets into the filtered listing, so entries
+//! added or removed between two pages may be skipped or returned twice.
+
+use std::future::Future;
+use tonic::Status;
+
+/// Page size used when the request leaves it to the server.
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+/// Larger requested page sizes are capped to this.
+pub const MAX_PAGE_SIZE: usize = 1000;
+
+/// Cut the page starting at `page_token` out of `items`, returning it with
+/// the token of the next page, empty if it is the last one.
+///
+/// # Errors
+///
+/// Returns `INVALID_ARGUMENT` if the page token wasn't produced by this function.
+pub fn cut_page<'a, T: Clone + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+    page_size: u32,
+    page_token: &str,
+) -> Result<(Vec<T>, String), Status> {
+    let offset = if page_token.is_empty() {
+        0
+    } else {
+        page_token
+            .parse::<usize>()
+            .map_err(|_| Status::invalid_argument("invalid page token"))?
+    };
+    let page_size = match usize::try_from(page_size).unwrap_or(MAX_PAGE_SIZE) {
+        0 => DEFAULT_PAGE_SIZE,
+        size => size.min(MAX_PAGE_SIZE),
+    };
+
+    let mut items = items.into_iter().skip(offset);
+    let page: Vec<T> = items.by_ref().take(page_size).cloned().collect();
+    let next_page_token = if items.next().is_some() {
+        (offset + page.len()).to_string()
+    } else {
+        String::new()
+    };
+    Ok((page, next_page_token))
+}
+
+/// Call `fetch` from `page_token` until the last page and collect all items.
+///
+/// `fetch` gets the token of the page to return and returns its items with
+/// the token of the next page, empty if it is the last one.
+///
+/// # Errors
+///
+/// Returns the first error of `fetch`, or `INTERNAL` if the server returns
+/// the same page token twice in a row.
+pub async fn follow_pages<T, F, Fut>(mut page_token: String, mut fetch: F) -> Result<Vec<T>, Status>
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, String), Status>>,
+{
+    let mut items = Vec::new();
+    loop {
+        let (page, next_page_token) = fetch(page_token.clone()).await?;
+        items.extend(page);
+        if next_page_token.is_empty() {
+            return Ok(items);
+        }
+        if next_page_token == page_token {
+            return Err(Status::internal("server repeated the page token"));
+        }
+        page_token = next_page_token;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cut_page() {
+        let items: Vec<u32> = (0..5).collect();
+
+        let (page, token) = cut_page(&items, 2, "").unwrap();
+        assert_eq!(page, [0, 1]);
+        let (page, token) = cut_page(&items, 2, &token).unwrap();
+        assert_eq!(page, [2, 3]);
+        let (page, token) = cut_page(&items, 2, &token).unwrap();
+        assert_eq!(page, [4]);
+        assert_eq!(token, "");
+
+        // A full last page doesn't promise another one
+        let (page, token) = cut_page(&items, 5, "").unwrap();
+        assert_eq!(page.len(), 5);
+        assert_eq!(token, "");
+
+        let (page, _) = cut_page(&items, 0, "").unwrap();
+        assert_eq!(page.len(), 5);
+
+        let err = cut_page(&items, 2, "bogus").unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_follow_pages() {
+        let items: Vec<u32> = (0..5).collect();
+
+        let all = follow_pages(String::new(), |token| {
+            let page = cut_page(&items, 2, &token);
+            async move { page }
+        })
+        .await
+        .unwrap();
+        assert_eq!(all, items);
+
+        // Resumes from the given token
+        let rest = follow_pages("3".into(), |token| {
+            let page = cut_page(&items, 2, &token);
+            async move { page }
+        })
+        .await
+        .unwrap();
+        assert_eq!(rest, [3, 4]);
+
+        let err = follow_pages(String::new(), |_| async {
+            Ok((vec![0_u32], "1".to_string()))
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Internal);
+    }
+}
@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Periodic polling of the dataplane status, shared through a [`watch`] channel.
+//!
+//! [`StatusPoller`] calls `GetDataplaneStatus` on a jittered interval and
+//! publishes a [`PolledStatus`] after every attempt. It keeps the last good
+//! snapshot across failures, so consumers decide themselves how stale a status
+//! they accept, and counts the failures since the last success.
+
+use crate::ConfigServiceClient;
+use crate::config::GetDataplaneStatusRequest;
+use crate::fault::SplitMix64;
+use crate::rate::StatusSnapshot;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::time::MissedTickBehavior;
+use tonic::Status;
+use tonic::transport::Channel;
+
+#[derive(Debug, Clone)]
+pub struct StatusPollerConfig {
+    /// Interval between the starts of consecutive polls.
+    pub interval: Duration,
+    /// Fraction of the interval each wait is randomly shortened or lengthened
+    /// by, e.g. 0.1 for ±10%, so that many pollers don't stay in lockstep.
+    /// Clamped to `0.0..=1.0`, NaN disables it.
+    pub jitter: f64,
+    /// Timeout for each `GetDataplaneStatus` call.
+    pub rpc_timeout: Duration,
+}
+
+impl Default for StatusPollerConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(5),
+            jitter: 0.1,
+            rpc_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// What the poller knows after its latest attempt.
+#[derive(Debug, Clone, Default)]
+pub struct PolledStatus {
+    /// Last status fetched successfully, if any.
+    pub snapshot: Option<StatusSnapshot>,
+    /// Polls that failed since the last success.
+    pub consecutive_failures: u32,
+    /// Error of the latest poll if it failed.
+    pub last_error: Option<String>,
+}
+
+impl PolledStatus {
+    /// Time since the last good snapshot was taken.
+    #[must_use]
+    pub fn age(&self) -> Option<Duration> {
+        self.snapshot.as_ref().map(|snapshot| snapshot.at.elapsed())
+    }
+
+    /// Whether there is no snapshot or it is older than `max_age`.
+    #[must_use]
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        self.age().is_none_or(|age| age > max_age)
+    }
+}
+
+/// Polls the dataplane status and publishes it to all subscribers.
+pub struct StatusPoller {
+    client: ConfigServiceClient<Channel>,
+    config: StatusPollerConfig,
+    status: watch::Sender<PolledStatus>,
+    rng: SplitMix64,
+}
+
+impl StatusPoller {
+    #[must_use]
+    pub fn new(client: ConfigServiceClient<Channel>, config: StatusPollerConfig) -> Self {
+        Self {
+            client,
+            config,
+            status: watch::Sender::new(PolledStatus::default()),
+            rng: SplitMix64(RandomState::new().hash_one(0)),
+        }
+    }
+
+    /// Receiver of the status published after every poll.
+    #[must_use]
+    pub fn subscribe(&self) -> watch::Receiver<PolledStatus> {
+        self.status.subscribe()
+    }
+
+    /// Poll once and publish the outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the call, `DEADLINE_EXCEEDED` if it timed out.
+    pub async fn poll_once(&mut self) -> Result<(), Status> {
+        let mut client = self.client.clone();
+        let result = tokio::time::timeout(
+            self.config.rpc_timeout,
+            client.get_dataplane_status(GetDataplaneStatusRequest {}),
+        )
+        .await
+        .unwrap_or_else(|_| Err(Status::deadline_exceeded("timed out")));
+        match result {
+            Ok(response) => {
+                let snapshot = StatusSnapshot::now(response.into_inner());
+                self.status.send_modify(|status| {
+                    status.snapshot = Some(snapshot);
+                    status.consecutive_failures = 0;
+                    status.last_error = None;
+                });
+                Ok(())
+            }
+            Err(err) => {
+                self.status.send_modify(|status| {
+                    status.consecutive_failures = status.consecutive_failures.saturating_add(1);
+                    status.last_error = Some(err.to_string());
+                });
+                Err(err)
+            }
+        }
+    }
+
+    /// Time from the start of a poll to the start of the next one, the
+    /// interval with jitter applied.
+    fn next_delay(&mut self) -> Duration {
+        let jitter = if self.config.jitter.is_nan() {
+            0.0
+        } else {
+            self.config.jitter.clamp(0.0, 1.0)
+        };
+        let factor = 1.0 + jitter * (2.0 * self.rng.next_f64() - 1.0);
+        Duration::try_from_secs_f64(self.config.interval.as_secs_f64() * factor)
+            .unwrap_or(self.config.interval)
+    }
+
+    /// Keep polling until every receiver is dropped, so subscribe before
+    /// running. The first poll happens right away.
+    ///
+    /// Polls start an interval apart however long they take, a poll that
+    /// overruns the interval is followed by the next one right away.
+    pub async fn run(mut self) {
+        // The period is replaced by a jittered one after every tick
+        let mut ticker = tokio::time::interval(self.config.interval.max(Duration::from_millis(1)));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            let start = tokio::select! {
+                () = self.status.closed() => return,
+                start = ticker.tick() => start,
+            };
+            // Failures are published, nothing else to do with them here
+            let _ = self.poll_once().await;
+            ticker.reset_at(start + self.next_delay());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GetDataplaneStatusResponse;
+    use std::time::Instant;
+
+    fn test_poller(interval: Duration, jitter: f64) -> StatusPoller {
+        let channel = Channel::from_static("http://[::1]:1").connect_lazy();
+        StatusPoller::new(
+            ConfigServiceClient::new(channel),
+            StatusPollerConfig {
+                interval,
+                jitter,
+                rpc_timeout: Duration::from_millis(100),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_jitter() {
+        let interval = Duration::from_secs(10);
+        let mut poller = test_poller(interval, 0.2);
+        for _ in 0..1000 {
+            let delay = poller.next_delay();
+            assert!(delay >= Duration::from_secs(8) && delay <= Duration::from_secs(12));
+        }
+        let mut poller = test_poller(interval, 0.0);
+        assert_eq!(poller.next_delay(), interval);
+
+        // Out of range jitter neither panics nor goes negative
+        for jitter in [f64::NAN, f64::INFINITY, -1.0, 5.0] {
+            let mut poller = test_poller(interval, jitter);
+            assert!(poller.next_delay() <= Duration::from_secs(20));
+        }
+        let mut poller = test_poller(Duration::MAX, 0.5);
+        poller.next_delay();
+    }
+
+    #[test]
+    fn test_staleness() {
+        let mut status = PolledStatus::default();
+        assert_eq!(status.age(), None);
+        assert!(status.is_stale(Duration::MAX));
+
+        status.snapshot = Some(StatusSnapshot {
+            at: Instant::now().checked_sub(Duration::from_secs(60)).unwrap(),
+            status: GetDataplaneStatusResponse::default(),
+        });
+        assert!(status.age().unwrap() >= Duration::from_secs(60));
+        assert!(status.is_stale(Duration::from_secs(30)));
+        assert!(!status.is_stale(Duration::from_secs(600)));
+    }
+
+    #[tokio::test]
+    async fn test_failure_is_published() {
+        let mut poller = test_poller(Duration::from_secs(1), 0.0);
+        let rx = poller.subscribe();
+        assert!(poller.poll_once().await.is_err());
+        assert!(poller.poll_once().await.is_err());
+        let status = rx.borrow();
+        assert_eq!(status.consecutive_failures, 2);
+        assert!(status.last_error.is_some());
+        assert!(status.snapshot.is_none());
+    }
+}
@@ -0,0 +1,1162 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! [Prometheus text exposition](https://prometheus.io/docs/instrumenting/exposition_formats/)
+//! of [`GetDataplaneStatusResponse`].
+//!
+//! [`encode_status`] turns a status into metrics named `gateway_*`, labelled
+//! with `ifname`, `vrf` and `neighbor`, `vpc`, `peering`, `vni` or `vtep`. Counters of the
+//! status become `_total` counters, everything else gauges, and enum states are
+//! exposed as one series per state with value 1 for the current one. The output
+//! is sorted, so equal statuses always give the same text.
+//!
+//! [`StatusExporter`] is a tiny HTTP server that serves the metrics of a
+//! dataplane on `/metrics`, fetching the status with a [`ConfigServiceClient`]
+//! on every scrape.
+
+use crate::ConfigServiceClient;
+use crate::config::{
+    BgpMessageCounters, BgpNeighborPrefixes, BgpNeighborSessionState, BgpNeighborStatus,
+    DataplaneStatusType, FrrAgentStatusType, FrrLifecycleState, GetDataplaneStatusRequest,
+    GetDataplaneStatusResponse, InterfaceAdminStatusType, InterfaceOperStatusType,
+    VtepReachability, ZebraStatusType,
+};
+use crate::drops::DropCounters;
+use crate::evpn::vpc_for_vni;
+use std::fmt::Write;
+use std::future::Future;
+use std::io;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tonic::Status;
+use tonic::transport::Channel;
+
+/// Content type of the text exposition format.
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Counter,
+    Gauge,
+}
+
+impl Kind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+        }
+    }
+}
+
+/// Samples of one metric, written out together.
+struct Family {
+    name: &'static str,
+    help: &'static str,
+    kind: Kind,
+    samples: Vec<(String, String)>,
+}
+
+impl Family {
+    fn new(name: &'static str, kind: Kind, help: &'static str) -> Self {
+        Self {
+            name,
+            help,
+            kind,
+            samples: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, labels: &[(&str, &str)], value: impl Value) {
+        let mut text = String::new();
+        for (i, (name, value)) in labels.iter().enumerate() {
+            if i > 0 {
+                text.push(',');
+            }
+            let _ = write!(text, "{name}=\"{}\"", escape_label(value));
+        }
+        self.samples.push((text, value.format()));
+    }
+
+    fn write(mut self, out: &mut String) {
+        if self.samples.is_empty() {
+            return;
+        }
+        self.samples.sort();
+        let _ = writeln!(out, "# HELP {} {}", self.name, self.help);
+        let _ = writeln!(out, "# TYPE {} {}", self.name, self.kind.as_str());
+        for (labels, value) in self.samples {
+            if labels.is_empty() {
+                let _ = writeln!(out, "{} {value}", self.name);
+            } else {
+                let _ = writeln!(out, "{}{{{labels}}} {value}", self.name);
+            }
+        }
+    }
+}
+
+/// Sample values, integers are written exactly.
+trait Value {
+    fn format(self) -> String;
+}
+
+macro_rules! integer_value {
+    ($($t:ty),*) => {$(
+        impl Value for $t {
+            fn format(self) -> String {
+                self.to_string()
+            }
+        }
+    )*};
+}
+
+integer_value!(u32, u64, i64, usize);
+
+impl Value for f64 {
+    fn format(self) -> String {
+        if self.is_nan() {
+            "NaN".to_string()
+        } else if self.is_infinite() {
+            if self > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+        } else {
+            self.to_string()
+        }
+    }
+}
+
+impl Value for bool {
+    fn format(self) -> String {
+        u8::from(self).to_string()
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// One series per state name, 1 for `current` and 0 for the others.
+fn state_set<T: Copy + PartialEq>(
+    family: &mut Family,
+    labels: &[(&str, &str)],
+    states: &[(T, &str)],
+    current: T,
+) {
+    for (state, name) in states {
+        let mut labels = labels.to_vec();
+        labels.push(("state", name));
+        family.add(&labels, *state == current);
+    }
+}
+
+const DATAPLANE_STATES: [(DataplaneStatusType, &str); 4] = [
+    (DataplaneStatusType::DataplaneStatusUnknown, "unknown"),
+    (DataplaneStatusType::DataplaneStatusHealthy, "healthy"),
+    (DataplaneStatusType::DataplaneStatusInit, "init"),
+    (DataplaneStatusType::DataplaneStatusError, "error"),
+];
+
+const FRR_STATES: [(FrrLifecycleState, &str); 6] = [
+    (FrrLifecycleState::FrrStateUnknown, "unknown"),
+    (FrrLifecycleState::FrrStateStarting, "starting"),
+    (FrrLifecycleState::FrrStateRunning, "running"),
+    (FrrLifecycleState::FrrStateReloading, "reloading"),
+    (FrrLifecycleState::FrrStateStopping, "stopping"),
+    (FrrLifecycleState::FrrStateCrashed, "crashed"),
+];
+
+const VTEP_REACHABILITY: [(VtepReachability, &str); 3] = [
+    (VtepReachability::Unknown, "unknown"),
+    (VtepReachability::Reachable, "reachable"),
+    (VtepReachability::Unreachable, "unreachable"),
+];
+
+pub(crate) const SESSION_STATES: [(BgpNeighborSessionState, &str); 6] = [
+    (BgpNeighborSessionState::BgpStateUnset, "unset"),
+    (BgpNeighborSessionState::BgpStateIdle, "idle"),
+    (BgpNeighborSessionState::BgpStateConnect, "connect"),
+    (BgpNeighborSessionState::BgpStateActive, "active"),
+    (BgpNeighborSessionState::BgpStateOpen, "open"),
+    (BgpNeighborSessionState::BgpStateEstablished, "established"),
+];
+
+/// Encode `status` in the Prometheus text exposition format.
+#[must_use]
+pub fn encode_status(status: &GetDataplaneStatusResponse) -> String {
+    let mut out = String::new();
+    for family in dataplane_families(status)
+        .into_iter()
+        .chain(interface_families(status))
+        .chain(interface_packet_families(status))
+        .chain(interface_queue_families(status))
+        .chain(bgp_families(status))
+        .chain(bgp_prefix_families(status))
+        .chain(vpc_families(status))
+        .chain(drop_reason_families(status))
+        .chain(expose_families(status))
+        .chain(nat_families(status))
+        .chain(evpn_families(status))
+    {
+        family.write(&mut out);
+    }
+    out
+}
+
+fn dataplane_families(status: &GetDataplaneStatusResponse) -> Vec<Family> {
+    let mut dataplane = Family::new(
+        "gateway_dataplane_status",
+        Kind::Gauge,
+        "Dataplane status, 1 for the current state.",
+    );
+    if let Some(info) = &status.dataplane_status {
+        state_set(&mut dataplane, &[], &DATAPLANE_STATES, info.status());
+    }
+
+    let mut agent = Family::new(
+        "gateway_frr_agent_connected",
+        Kind::Gauge,
+        "Whether the dataplane is connected to the FRR agent.",
+    );
+    let mut zebra = Family::new(
+        "gateway_frr_zebra_connected",
+        Kind::Gauge,
+        "Whether the dataplane is connected to zebra.",
+    );
+    let mut generation = Family::new(
+        "gateway_frr_applied_config_generation",
+        Kind::Gauge,
+        "Generation of the config last applied by FRR.",
+    );
+    let mut restarts = Family::new(
+        "gateway_frr_restarts_total",
+        Kind::Counter,
+        "Number of FRR restarts.",
+    );
+    let mut applied = Family::new(
+        "gateway_frr_applied_configs_total",
+        Kind::Counter,
+        "Number of configs applied by FRR.",
+    );
+    let mut failed = Family::new(
+        "gateway_frr_failed_configs_total",
+        Kind::Counter,
+        "Number of configs FRR failed to apply.",
+    );
+    let mut state = Family::new(
+        "gateway_frr_state",
+        Kind::Gauge,
+        "Lifecycle state of FRR, 1 for the current state.",
+    );
+    let mut state_since = Family::new(
+        "gateway_frr_state_since_seconds",
+        Kind::Gauge,
+        "Unix time of the last FRR state transition.",
+    );
+    let mut pending = Family::new(
+        "gateway_frr_pending_config_generation",
+        Kind::Gauge,
+        "Generation of the config FRR is applying, 0 if none.",
+    );
+    if let Some(frr) = &status.frr_status {
+        state_set(&mut state, &[], &FRR_STATES, frr.state());
+        if let Some(since) = &frr.state_since {
+            state_since.add(&[], since.seconds);
+        }
+        pending.add(&[], frr.pending_config_gen);
+        agent.add(
+            &[],
+            frr.frr_agent_status() == FrrAgentStatusType::FrrAgentStatusConnected,
+        );
+        zebra.add(
+            &[],
+            frr.zebra_status() == ZebraStatusType::ZebraStatusConnected,
+        );
+        generation.add(&[], frr.applied_config_gen);
+        restarts.add(&[], frr.restarts);
+        applied.add(&[], frr.applied_configs);
+        failed.add(&[], frr.failed_configs);
+    }
+    vec![
+        dataplane,
+        agent,
+        zebra,
+        state,
+        state_since,
+        generation,
+        pending,
+        restarts,
+        applied,
+        failed,
+    ]
+}
+
+fn interface_families(status: &GetDataplaneStatusResponse) -> Vec<Family> {
+    let mut admin = Family::new(
+        "gateway_interface_admin_up",
+        Kind::Gauge,
+        "Whether the interface is administratively up.",
+    );
+    let mut oper = Family::new(
+        "gateway_interface_oper_up",
+        Kind::Gauge,
+        "Whether the interface is operationally up.",
+    );
+    let mut mtu = Family::new(
+        "gateway_interface_mtu_bytes",
+        Kind::Gauge,
+        "MTU of the interface.",
+    );
+    let mut rx_bits = Family::new(
+        "gateway_interface_rx_bits_total",
+        Kind::Counter,
+        "Bits received on the interface.",
+    );
+    let mut tx_bits = Family::new(
+        "gateway_interface_tx_bits_total",
+        Kind::Counter,
+        "Bits sent on the interface.",
+    );
+    let mut rx_errors = Family::new(
+        "gateway_interface_rx_errors_total",
+        Kind::Counter,
+        "Receive errors on the interface.",
+    );
+    let mut tx_errors = Family::new(
+        "gateway_interface_tx_errors_total",
+        Kind::Counter,
+        "Transmit errors on the interface.",
+    );
+    let mut rx_bps = Family::new(
+        "gateway_interface_rx_bits_per_second",
+        Kind::Gauge,
+        "Receive rate of the interface as reported by the dataplane.",
+    );
+    let mut tx_bps = Family::new(
+        "gateway_interface_tx_bits_per_second",
+        Kind::Gauge,
+        "Transmit rate of the interface as reported by the dataplane.",
+    );
+
+    // Runtime status is more detailed, only use the basic one for the rest
+    for (ifname, runtime) in &status.interface_runtime {
+        let labels = [("ifname", ifname.as_str())];
+        admin.add(
+            &labels,
+            runtime.admin_status() == InterfaceAdminStatusType::InterfaceAdminStatusUp,
+        );
+        oper.add(
+            &labels,
+            runtime.oper_status() == InterfaceOperStatusType::InterfaceStatusOperUp,
+        );
+        mtu.add(&labels, runtime.mtu);
+        if let Some(counters) = &runtime.counters {
+            rx_bits.add(&labels, counters.rx_bits);
+            tx_bits.add(&labels, counters.tx_bits);
+            rx_errors.add(&labels, counters.rx_errors);
+            tx_errors.add(&labels, counters.tx_errors);
+            rx_bps.add(&labels, counters.rx_bps);
+            tx_bps.add(&labels, counters.tx_bps);
+        }
+    }
+    for iface in &status.interface_statuses {
+        if status.interface_runtime.contains_key(&iface.ifname) {
+            continue;
+        }
+        let labels = [("ifname", iface.ifname.as_str())];
+        admin.add(
+            &labels,
+            iface.admin_status() == InterfaceAdminStatusType::InterfaceAdminStatusUp,
+        );
+        oper.add(
+            &labels,
+            iface.oper_status() == InterfaceOperStatusType::InterfaceStatusOperUp,
+        );
+    }
+    vec![
+        admin, oper, mtu, rx_bits, tx_bits, rx_errors, tx_errors, rx_bps, tx_bps,
+    ]
+}
+
+fn interface_packet_families(status: &GetDataplaneStatusResponse) -> Vec<Family> {
+    let mut rx_packets = Family::new(
+        "gateway_interface_rx_packets_total",
+        Kind::Counter,
+        "Packets received on the interface.",
+    );
+    let mut tx_packets = Family::new(
+        "gateway_interface_tx_packets_total",
+        Kind::Counter,
+        "Packets sent on the interface.",
+    );
+    let mut rx_drops = Family::new(
+        "gateway_interface_rx_drops_total",
+        Kind::Counter,
+        "Received packets dropped by the interface.",
+    );
+    let mut tx_drops = Family::new(
+        "gateway_interface_tx_drops_total",
+        Kind::Counter,
+        "Packets dropped by the interface before being sent.",
+    );
+    let mut rx_multicast = Family::new(
+        "gateway_interface_rx_multicast_packets_total",
+        Kind::Counter,
+        "Multicast packets received on the interface.",
+    );
+    let mut tx_multicast = Family::new(
+        "gateway_interface_tx_multicast_packets_total",
+        Kind::Counter,
+        "Multicast packets sent on the interface.",
+    );
+    let mut rx_broadcast = Family::new(
+        "gateway_interface_rx_broadcast_packets_total",
+        Kind::Counter,
+        "Broadcast packets received on the interface.",
+    );
+    let mut tx_broadcast = Family::new(
+        "gateway_interface_tx_broadcast_packets_total",
+        Kind::Counter,
+        "Broadcast packets sent on the interface.",
+    );
+    for (ifname, runtime) in &status.interface_runtime {
+        let Some(counters) = &runtime.counters else {
+            continue;
+        };
+        let labels = [("ifname", ifname.as_str())];
+        rx_packets.add(&labels, counters.rx_packets);
+        tx_packets.add(&labels, counters.tx_packets);
+        rx_drops.add(&labels, counters.rx_drops);
+        tx_drops.add(&labels, counters.tx_drops);
+        rx_multicast.add(&labels, counters.rx_multicast);
+        tx_multicast.add(&labels, counters.tx_multicast);
+        rx_broadcast.add(&labels, counters.rx_broadcast);
+        tx_broadcast.add(&labels, counters.tx_broadcast);
+    }
+    vec![
+        rx_packets,
+        tx_packets,
+        rx_drops,
+        tx_drops,
+        rx_multicast,
+        tx_multicast,
+        rx_broadcast,
+        tx_broadcast,
+    ]
+}
+
+fn interface_queue_families(status: &GetDataplaneStatusResponse) -> Vec<Family> {
+    let mut packets = Family::new(
+        "gateway_interface_queue_packets_total",
+        Kind::Counter,
+        "Packets handled by the interface queue.",
+    );
+    let mut bytes = Family::new(
+        "gateway_interface_queue_bytes_total",
+        Kind::Counter,
+        "Bytes handled by the interface queue.",
+    );
+    let mut drops = Family::new(
+        "gateway_interface_queue_drops_total",
+        Kind::Counter,
+        "Packets dropped by the interface queue.",
+    );
+    for (ifname, runtime) in &status.interface_runtime {
+        let Some(counters) = &runtime.counters else {
+            continue;
+        };
+        for (direction, queues) in [("rx", &counters.rx_queues), ("tx", &counters.tx_queues)] {
+            for queue in queues {
+                let id = queue.queue.to_string();
+                let labels = [
+                    ("ifname", ifname.as_str()),
+                    ("direction", direction),
+                    ("queue", id.as_str()),
+                ];
+                packets.add(&labels, queue.packets);
+                bytes.add(&labels, queue.bytes);
+                drops.add(&labels, queue.drops);
+            }
+        }
+    }
+    vec![packets, bytes, drops]
+}
+
+fn bgp_families(status: &GetDataplaneStatusResponse) -> Vec<Family> {
+    let mut info = Family::new(
+        "gateway_bgp_neighbor_info",
+        Kind::Gauge,
+        "BGP neighbor details, always 1.",
+    );
+    let mut enabled = Family::new(
+        "gateway_bgp_neighbor_enabled",
+        Kind::Gauge,
+        "Whether the BGP neighbor is enabled.",
+    );
+    let mut state = Family::new(
+        "gateway_bgp_neighbor_session_state",
+        Kind::Gauge,
+        "BGP session state, 1 for the current state.",
+    );
+    let mut transitions = Family::new(
+        "gateway_bgp_neighbor_established_transitions_total",
+        Kind::Counter,
+        "Number of times the BGP session was established.",
+    );
+    let mut dropped = Family::new(
+        "gateway_bgp_neighbor_connections_dropped_total",
+        Kind::Counter,
+        "Number of dropped BGP connections.",
+    );
+    let mut received = Family::new(
+        "gateway_bgp_neighbor_messages_received_total",
+        Kind::Counter,
+        "BGP messages received by type.",
+    );
+    let mut sent = Family::new(
+        "gateway_bgp_neighbor_messages_sent_total",
+        Kind::Counter,
+        "BGP messages sent by type.",
+    );
+    for (vrf, addr, neighbor) in bgp_neighbors(status) {
+        let labels = [("vrf", vrf), ("neighbor", addr)];
+        let local_as = neighbor.local_as.to_string();
+        let peer_as = neighbor.peer_as.to_string();
+        info.add(
+            &[
+                ("vrf", vrf),
+                ("neighbor", addr),
+                ("local_as", &local_as),
+                ("peer_as", &peer_as),
+                ("peer_group", &neighbor.peer_group),
+                ("remote_router_id", &neighbor.remote_router_id),
+            ],
+            1u32,
+        );
+        enabled.add(&labels, neighbor.enabled);
+        state_set(
+            &mut state,
+            &labels,
+            &SESSION_STATES,
+            neighbor.session_state(),
+        );
+        transitions.add(&labels, neighbor.established_transitions);
+        dropped.add(&labels, neighbor.connections_dropped);
+        if let Some(messages) = &neighbor.messages {
+            add_messages(&mut received, &labels, messages.received.as_ref());
+            add_messages(&mut sent, &labels, messages.sent.as_ref());
+        }
+    }
+    vec![info, enabled, state, transitions, dropped, received, sent]
+}
+
+fn bgp_neighbors(
+    status: &GetDataplaneStatusResponse,
+) -> impl Iterator<Item = (&str, &str, &BgpNeighborStatus)> {
+    status
+        .bgp
+        .iter()
+        .flat_map(|bgp| &bgp.vrfs)
+        .flat_map(|(vrf, vrf_status)| {
+            vrf_status
+                .neighbors
+                .iter()
+                .map(move |(addr, neighbor)| (vrf.as_str(), addr.as_str(), neighbor))
+        })
+}
+
+fn bgp_prefix_families(status: &GetDataplaneStatusResponse) -> Vec<Family> {
+    let mut prefixes_received = Family::new(
+        "gateway_bgp_neighbor_prefixes_received",
+        Kind::Gauge,
+        "Prefixes received from the BGP neighbor by address family, after policy.",
+    );
+    let mut prefixes_pre_policy = Family::new(
+        "gateway_bgp_neighbor_prefixes_received_pre_policy",
+        Kind::Gauge,
+        "Prefixes received from the BGP neighbor by address family, before policy.",
+    );
+    let mut prefixes_sent = Family::new(
+        "gateway_bgp_neighbor_prefixes_sent",
+        Kind::Gauge,
+        "Prefixes sent to the BGP neighbor by address family.",
+    );
+    for (vrf, addr, neighbor) in bgp_neighbors(status) {
+        for (afi, prefixes) in [
+            ("ipv4_unicast", &neighbor.ipv4_unicast_prefixes),
+            ("ipv6_unicast", &neighbor.ipv6_unicast_prefixes),
+            ("l2vpn_evpn", &neighbor.l2vpn_evpn_prefixes),
+        ] {
+            let Some(BgpNeighborPrefixes {
+                received,
+                received_pre_policy,
+                sent,
+            }) = prefixes
+            else {
+                continue;
+            };
+            let labels = [("vrf", vrf), ("neighbor", addr), ("afi", afi)];
+            prefixes_received.add(&labels, *received);
+            prefixes_pre_policy.add(&labels, *received_pre_policy);
+            prefixes_sent.add(&labels, *sent);
+        }
+    }
+    vec![prefixes_received, prefixes_pre_policy, prefixes_sent]
+}
+
+fn add_messages(
+    family: &mut Family,
+    labels: &[(&str, &str)],
+    counters: Option<&BgpMessageCounters>,
+) {
+    let Some(c) = counters else {
+        return;
+    };
+    for (kind, value) in [
+        ("capability", c.capability),
+        ("keepalive", c.keepalive),
+        ("notification", c.notification),
+        ("open", c.open),
+        ("route_refresh", c.route_refresh),
+        ("update", c.update),
+    ] {
+        let mut labels = labels.to_vec();
+        labels.push(("type", kind));
+        family.add(&labels, value);
+    }
+}
+
+fn vpc_families(status: &GetDataplaneStatusResponse) -> Vec<Family> {
+    let mut vni = Family::new("gateway_vpc_vni", Kind::Gauge, "VNI of the VPC.");
+    let mut routes = Family::new(
+        "gateway_vpc_routes",
+        Kind::Gauge,
+        "Number of routes in the VPC.",
+    );
+    let mut packets = Family::new(
+        "gateway_vpc_packets_total",
+        Kind::Counter,
+        "Packets forwarded for the VPC.",
+    );
+    let mut bytes = Family::new(
+        "gateway_vpc_bytes_total",
+        Kind::Counter,
+        "Bytes forwarded for the VPC.",
+    );
+    let mut drops = Family::new(
+        "gateway_vpc_drops_total",
+        Kind::Counter,
+        "Packets dropped for the VPC.",
+    );
+    for (name, vpc) in &status.vpcs {
+        let labels = [("vpc", name.as_str())];
+        vni.add(&labels, vpc.vni);
+        routes.add(&labels, vpc.route_count);
+    }
+    for (name, counters) in &status.vpc_counters {
+        let labels = [("vpc", name.as_str())];
+        packets.add(&labels, counters.packets);
+        bytes.add(&labels, counters.bytes);
+        drops.add(&labels, counters.drops);
+    }
+
+    let mut peering_packets = Family::new(
+        "gateway_vpc_peering_packets_total",
+        Kind::Counter,
+        "Packets forwarded over the VPC peering.",
+    );
+    let mut peering_bytes = Family::new(
+        "gateway_vpc_peering_bytes_total",
+        Kind::Counter,
+        "Bytes forwarded over the VPC peering.",
+    );
+    let mut peering_drops = Family::new(
+        "gateway_vpc_peering_drops_total",
+        Kind::Counter,
+        "Packets dropped on the VPC peering.",
+    );
+    let mut peering_packet_rate = Family::new(
+        "gateway_vpc_peering_packets_per_second",
+        Kind::Gauge,
+        "Packet rate of the VPC peering as reported by the dataplane.",
+    );
+    let mut peering_bit_rate = Family::new(
+        "gateway_vpc_peering_bits_per_second",
+        Kind::Gauge,
+        "Bit rate of the VPC peering as reported by the dataplane.",
+    );
+    for (name, counters) in &status.vpc_peering_counters {
+        let labels = [
+            ("peering", name.as_str()),
+            ("src_vpc", counters.src_vpc.as_str()),
+            ("dst_vpc", counters.dst_vpc.as_str()),
+        ];
+        peering_packets.add(&labels, counters.packets);
+        peering_bytes.add(&labels, counters.bytes);
+        peering_drops.add(&labels, counters.drops);
+        peering_packet_rate.add(&labels, counters.pps);
+        peering_bit_rate.add(&labels, counters.bps);
+    }
+    vec![
+        vni,
+        routes,
+        packets,
+        bytes,
+        drops,
+        peering_packets,
+        peering_bytes,
+        peering_drops,
+        peering_packet_rate,
+        peering_bit_rate,
+    ]
+}
+
+fn drop_reason_families(status: &GetDataplaneStatusResponse) -> Vec<Family> {
+    let mut vpc = Family::new(
+        "gateway_vpc_drops_by_reason_total",
+        Kind::Counter,
+        "Packets dropped for the VPC, by reason.",
+    );
+    let mut peering = Family::new(
+        "gateway_vpc_peering_drops_by_reason_total",
+        Kind::Counter,
+        "Packets dropped on the VPC peering, by reason.",
+    );
+    for (name, counters) in &status.vpc_counters {
+        for (reason, count) in counters.drop_breakdown() {
+            vpc.add(&[("vpc", name.as_str()), ("reason", reason)], count);
+        }
+    }
+    for (name, counters) in &status.vpc_peering_counters {
+        for (reason, count) in counters.drop_breakdown() {
+            let labels = [
+                ("peering", name.as_str()),
+                ("src_vpc", counters.src_vpc.as_str()),
+                ("dst_vpc", counters.dst_vpc.as_str()),
+                ("reason", reason),
+            ];
+            peering.add(&labels, count);
+        }
+    }
+    vec![vpc, peering]
+}
+
+fn expose_families(status: &GetDataplaneStatusResponse) -> Vec<Family> {
+    let mut packets = Family::new(
+        "gateway_expose_packets_total",
+        Kind::Counter,
+        "Packets matching the expose rule of the VPC peering.",
+    );
+    let mut bytes = Family::new(
+        "gateway_expose_bytes_total",
+        Kind::Counter,
+        "Bytes matching the expose rule of the VPC peering.",
+    );
+    let mut drops = Family::new(
+        "gateway_expose_drops_total",
+        Kind::Counter,
+        "Packets matching the expose rule that were dropped, by reason.",
+    );
+    let mut translations = Family::new(
+        "gateway_expose_translations_total",
+        Kind::Counter,
+        "Packets translated by the NAT of the expose rule.",
+    );
+    let mut alloc_failures = Family::new(
+        "gateway_expose_nat_alloc_failures_total",
+        Kind::Counter,
+        "Translations of the expose rule that failed for lack of free addresses or ports.",
+    );
+    for counters in &status.expose_counters {
+        let index = counters.expose_index.to_string();
+        let labels = [
+            ("peering", counters.peering.as_str()),
+            ("vpc", counters.vpc.as_str()),
+            ("expose", index.as_str()),
+        ];
+        packets.add(&labels, counters.packets);
+        bytes.add(&labels, counters.bytes);
+        translations.add(&labels, counters.translations);
+        alloc_failures.add(&labels, counters.nat_alloc_failures);
+        for (reason, count) in counters.drop_breakdown() {
+            let mut labels = labels.to_vec();
+            labels.push(("reason", reason));
+            drops.add(&labels, count);
+        }
+    }
+    vec![packets, bytes, drops, translations, alloc_failures]
+}
+
+fn nat_families(status: &GetDataplaneStatusResponse) -> Vec<Family> {
+    let mut active = Family::new(
+        "gateway_nat_sessions",
+        Kind::Gauge,
+        "Active stateful NAT sessions of the VPC peering.",
+    );
+    let mut created = Family::new(
+        "gateway_nat_sessions_created_total",
+        Kind::Counter,
+        "Stateful NAT sessions created for the VPC peering.",
+    );
+    let mut expired = Family::new(
+        "gateway_nat_sessions_expired_total",
+        Kind::Counter,
+        "Stateful NAT sessions of the VPC peering that expired.",
+    );
+    for (name, counters) in &status.nat_sessions {
+        let labels = [("peering", name.as_str())];
+        active.add(&labels, counters.active);
+        created.add(&labels, counters.created);
+        expired.add(&labels, counters.expired);
+    }
+    vec![active, created, expired]
+}
+
+fn evpn_families(status: &GetDataplaneStatusResponse) -> Vec<Family> {
+    let mut reachability = Family::new(
+        "gateway_evpn_remote_vtep_reachability",
+        Kind::Gauge,
+        "Reachability of the remote VTEP, 1 for the current state.",
+    );
+    let mut learned = Family::new(
+        "gateway_evpn_remote_vtep_vnis",
+        Kind::Gauge,
+        "Number of VNIs learned from the remote VTEP.",
+    );
+    let mut macs = Family::new(
+        "gateway_evpn_vni_macs",
+        Kind::Gauge,
+        "MACs learned for the VNI through EVPN.",
+    );
+    let mut routes = Family::new(
+        "gateway_evpn_vni_routes",
+        Kind::Gauge,
+        "EVPN routes of the VNI.",
+    );
+    let mut type5_routes = Family::new(
+        "gateway_evpn_vni_type5_routes",
+        Kind::Gauge,
+        "EVPN Type-5 (IP prefix) routes of the VNI.",
+    );
+    for (address, vtep) in &status.remote_vteps {
+        let labels = [("vtep", address.as_str())];
+        state_set(
+            &mut reachability,
+            &labels,
+            &VTEP_REACHABILITY,
+            vtep.reachability(),
+        );
+        learned.add(&labels, vtep.vnis.len());
+    }
+    for (vni, vni_status) in &status.vnis {
+        let vni = vni.to_string();
+        let vpc = vpc_for_vni(status, vni_status.vni).map_or("", |vpc| vpc.name.as_str());
+        let labels = [("vni", vni.as_str()), ("vpc", vpc)];
+        macs.add(&labels, vni_status.mac_count);
+        routes.add(&labels, vni_status.route_count);
+        type5_routes.add(&labels, vni_status.type5_route_count);
+    }
+    vec![reachability, learned, macs, routes, type5_routes]
+}
+
+/// Maximum size of an HTTP request head, larger requests are rejected.
+const MAX_REQUEST: usize = 8192;
+/// Default time a client gets to send the request head.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Default timeout of the `GetDataplaneStatus` call made for each scrape.
+pub const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// HTTP server exposing the status of one dataplane on `/metrics`.
+///
+/// The status is fetched on every scrape. `gateway_status_up` tells whether
+/// that worked; if it didn't, it's the only metric returned.
+#[derive(Debug, Clone)]
+pub struct StatusExporter {
+    client: ConfigServiceClient<Channel>,
+    request_timeout: Duration,
+    rpc_timeout: Duration,
+}
+
+impl StatusExporter {
+    #[must_use]
+    pub fn new(client: ConfigServiceClient<Channel>) -> Self {
+        Self {
+            client,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            rpc_timeout: DEFAULT_RPC_TIMEOUT,
+        }
+    }
+
+    /// Time a client gets to send the request head before it's answered with
+    /// `408 Request Timeout`, [`DEFAULT_REQUEST_TIMEOUT`] by default.
+    #[must_use]
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Timeout of the `GetDataplaneStatus` call made for each scrape, after
+    /// which `gateway_status_up` is 0. [`DEFAULT_RPC_TIMEOUT`] by default.
+    #[must_use]
+    pub fn with_rpc_timeout(mut self, timeout: Duration) -> Self {
+        self.rpc_timeout = timeout;
+        self
+    }
+
+    /// Fetch the status and encode it, including `gateway_status_up`.
+    pub async fn scrape(&self) -> String {
+        let mut client = self.client.clone();
+        let result = tokio::time::timeout(
+            self.rpc_timeout,
+            client.get_dataplane_status(GetDataplaneStatusRequest {}),
+        )
+        .await
+        .unwrap_or_else(|_| Err(Status::deadline_exceeded("timed out")));
+        let mut up = Family::new(
+            "gateway_status_up",
+            Kind::Gauge,
+            "Whether the dataplane status could be fetched.",
+        );
+        up.add(&[], result.is_ok());
+        let mut out = String::new();
+        up.write(&mut out);
+        if let Ok(response) = result {
+            out.push_str(&encode_status(response.get_ref()));
+        }
+        out
+    }
+
+    /// Serve metrics on `listener` until `shutdown` completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if accepting connections fails.
+    pub async fn serve(
+        self,
+        listener: TcpListener,
+        shutdown: impl Future<Output = ()>,
+    ) -> io::Result<()> {
+        tokio::pin!(shutdown);
+        loop {
+            tokio::select! {
+                () = &mut shutdown => return Ok(()),
+                accepted = listener.accept() => {
+                    let (stream, _) = accepted?;
+                    let exporter = self.clone();
+                    tokio::spawn(async move {
+                        // The scraper sees the failure, nothing else to do
+                        let _ = exporter.handle(stream).await;
+                    });
+                }
+            }
+        }
+    }
+
+    async fn handle(&self, mut stream: TcpStream) -> io::Result<()> {
+        let request = match tokio::time::timeout(self.request_timeout, read_head(&mut stream)).await
+        {
+            Err(_) => return respond(&mut stream, "408 Request Timeout", "").await,
+            Ok(Err(err)) if err.kind() == io::ErrorKind::InvalidData => {
+                return respond(&mut stream, "431 Request Header Fields Too Large", "").await;
+            }
+            Ok(Err(err)) => return Err(err),
+            Ok(Ok(None)) => return Ok(()),
+            Ok(Ok(Some(request))) => request,
+        };
+        let request = String::from_utf8_lossy(&request);
+        let mut parts = request.split_whitespace();
+        let (method, path) = (parts.next(), parts.next());
+        let path = path.map(|p| p.split('?').next().unwrap_or_default());
+        match (method, path) {
+            (Some("GET"), Some("/metrics")) => {
+                let body = self.scrape().await;
+                respond(&mut stream, "200 OK", &body).await
+            }
+            (Some("GET"), _) => respond(&mut stream, "404 Not Found", "Not found\n").await,
+            _ => respond(&mut stream, "405 Method Not Allowed", "").await,
+        }
+    }
+}
+
+/// Read up to the end of the request head, `None` if the client closed the
+/// connection before, an `InvalidData` error if it exceeds [`MAX_REQUEST`].
+async fn read_head(stream: &mut TcpStream) -> io::Result<Option<Vec<u8>>> {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        if request.len() > MAX_REQUEST {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "request head too large",
+            ));
+        }
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+        request.extend_from_slice(&buf[..n]);
+    }
+    Ok(Some(request))
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) -> io::Result<()> {
+    let head = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {CONTENT_TYPE}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        BgpNeighborStatus, BgpStatus, BgpVrfStatus, DataplaneStatusInfo, ExposeCounters,
+        InterfaceStatus, VpcPeeringCounters,
+    };
+
+    #[test]
+    fn test_values() {
+        assert_eq!(u64::MAX.format(), "18446744073709551615");
+        assert_eq!(1.5f64.format(), "1.5");
+        assert_eq!(3.0f64.format(), "3");
+        assert_eq!(f64::NAN.format(), "NaN");
+        assert_eq!(f64::INFINITY.format(), "+Inf");
+        assert_eq!(f64::NEG_INFINITY.format(), "-Inf");
+        assert_eq!(true.format(), "1");
+    }
+
+    #[test]
+    fn test_label_escaping() {
+        let mut family = Family::new("m", Kind::Gauge, "Help.");
+        family.add(&[("a", "x\"y\\z\nw"), ("b", "ü")], 1u32);
+        let mut out = String::new();
+        family.write(&mut out);
+        assert_eq!(
+            out,
+            "# HELP m Help.\n# TYPE m gauge\nm{a=\"x\\\"y\\\\z\\nw\",b=\"ü\"} 1\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_status() {
+        assert_eq!(encode_status(&GetDataplaneStatusResponse::default()), "");
+    }
+
+    #[test]
+    fn test_state_sets() {
+        let status = GetDataplaneStatusResponse {
+            dataplane_status: Some(DataplaneStatusInfo {
+                status: DataplaneStatusType::DataplaneStatusInit.into(),
+            }),
+            bgp: Some(BgpStatus {
+                vrfs: [(
+                    "default".to_string(),
+                    BgpVrfStatus {
+                        neighbors: [(
+                            "10.0.0.1".to_string(),
+                            BgpNeighborStatus {
+                                session_state: BgpNeighborSessionState::BgpStateActive.into(),
+                                ..Default::default()
+                            },
+                        )]
+                        .into(),
+                    },
+                )]
+                .into(),
+            }),
+            ..Default::default()
+        };
+        let out = encode_status(&status);
+        assert!(out.contains("gateway_dataplane_status{state=\"init\"} 1\n"));
+        assert!(out.contains("gateway_dataplane_status{state=\"healthy\"} 0\n"));
+        assert!(out.contains(
+            "gateway_bgp_neighbor_session_state{vrf=\"default\",neighbor=\"10.0.0.1\",state=\"active\"} 1\n"
+        ), "{out}");
+        // No messages or prefixes reported, no series
+        assert!(!out.contains("gateway_bgp_neighbor_messages_received_total"));
+        assert!(!out.contains("gateway_bgp_neighbor_prefixes_sent"));
+    }
+
+    #[test]
+    fn test_interfaces_and_peerings() {
+        let status = GetDataplaneStatusResponse {
+            interface_statuses: vec![
+                InterfaceStatus {
+                    ifname: "eth1".into(),
+                    oper_status: InterfaceOperStatusType::InterfaceStatusOperUp.into(),
+                    admin_status: InterfaceAdminStatusType::InterfaceAdminStatusUp.into(),
+                    ..Default::default()
+                },
+                InterfaceStatus {
+                    ifname: "eth0".into(),
+                    ..Default::default()
+                },
+            ],
+            vpc_peering_counters: [(
+                "a--b".to_string(),
+                VpcPeeringCounters {
+                    name: "a--b".into(),
+                    src_vpc: "a".into(),
+                    dst_vpc: "b".into(),
+                    pps: 2.5,
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            ..Default::default()
+        };
+        let out = encode_status(&status);
+        assert!(
+            out.contains(
+                "# TYPE gateway_interface_oper_up gauge\n\
+             gateway_interface_oper_up{ifname=\"eth0\"} 0\n\
+             gateway_interface_oper_up{ifname=\"eth1\"} 1\n"
+            ),
+            "{out}"
+        );
+        assert!(out.contains(
+            "gateway_vpc_peering_packets_per_second{peering=\"a--b\",src_vpc=\"a\",dst_vpc=\"b\"} 2.5\n"
+        ), "{out}");
+        assert!(
+            out.contains(
+                "# TYPE gateway_vpc_peering_drops_total counter\n\
+             gateway_vpc_peering_drops_total{peering=\"a--b\",src_vpc=\"a\",dst_vpc=\"b\"} 0\n"
+            ),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn test_unattributed_expose_drops() {
+        let status = GetDataplaneStatusResponse {
+            expose_counters: vec![ExposeCounters {
+                peering: "a--b".into(),
+                vpc: "b".into(),
+                expose_index: 1,
+                drops: 5,
+                drop_reasons: [("no_route".to_string(), 2)].into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let out = encode_status(&status);
+        assert!(
+            out.contains(
+                "gateway_expose_drops_total{peering=\"a--b\",vpc=\"b\",expose=\"1\",reason=\"no_route\"} 2\n\
+             gateway_expose_drops_total{peering=\"a--b\",vpc=\"b\",expose=\"1\",reason=\"unspecified\"} 3\n"
+            ),
+            "{out}"
+        );
+    }
+}
@@ -0,0 +1,535 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Client-side rates from consecutive status snapshots.
+//!
+//! The rates the dataplane reports, like `InterfaceCounters.tx_bps`, are
+//! computed over a window the API doesn't specify, and `VpcCounters` has none.
+//! [`compute_rates`] instead derives the rate of every monotonic counter from
+//! two [`StatusSnapshot`]s, over exactly the time between them.
+//!
+//! A counter that went down either wrapped around `u64::MAX` or was reset, for
+//! example because the dataplane restarted. It is taken as wrapped if the
+//! previous value was in the upper half of the range and the wrapped delta is
+//! less than half of it, as reset otherwise, in which case the current value is
+//! the delta. Counters missing from either snapshot have no rate.
+
+use crate::config::{
+    BgpMessageCounters, BgpNeighborStatus, FrrStatus, GetDataplaneStatusResponse,
+    InterfaceCounters, InterfaceQueueCounters, VpcCounters, VpcPeeringCounters,
+};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum RateError {
+    #[error("Current snapshot is not later than the previous one")]
+    NotLater,
+}
+
+/// A status and the time it was taken.
+#[derive(Debug, Clone)]
+pub struct StatusSnapshot {
+    pub at: Instant,
+    pub status: GetDataplaneStatusResponse,
+}
+
+impl StatusSnapshot {
+    /// Snapshot of `status` taken now.
+    #[must_use]
+    pub fn now(status: GetDataplaneStatusResponse) -> Self {
+        Self {
+            at: Instant::now(),
+            status,
+        }
+    }
+}
+
+/// How a counter got from its previous to its current value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Continuity {
+    /// The counter didn't go down.
+    Monotonic,
+    /// The counter wrapped around `u64::MAX`.
+    Wrapped,
+    /// The counter was reset and counted up from 0 again.
+    Reset,
+}
+
+/// Change of one counter between two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CounterRate {
+    pub delta: u64,
+    pub per_second: f64,
+    pub continuity: Continuity,
+}
+
+impl CounterRate {
+    /// Rate of a counter that went from `previous` to `current` in `elapsed`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(previous: u64, current: u64, elapsed: Duration) -> Self {
+        const HALF: u64 = u64::MAX / 2;
+        let (delta, continuity) = if current >= previous {
+            (current - previous, Continuity::Monotonic)
+        } else if previous > HALF && current.wrapping_sub(previous) < HALF {
+            (current.wrapping_sub(previous), Continuity::Wrapped)
+        } else {
+            (current, Continuity::Reset)
+        };
+        Self {
+            delta,
+            per_second: delta as f64 / elapsed.as_secs_f64(),
+            continuity,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterfaceRates {
+    pub rx_bits: CounterRate,
+    pub tx_bits: CounterRate,
+    pub rx_errors: CounterRate,
+    pub tx_errors: CounterRate,
+    pub rx_packets: CounterRate,
+    pub tx_packets: CounterRate,
+    pub rx_drops: CounterRate,
+    pub tx_drops: CounterRate,
+    pub rx_multicast: CounterRate,
+    pub tx_multicast: CounterRate,
+    pub rx_broadcast: CounterRate,
+    pub tx_broadcast: CounterRate,
+    /// By queue number, for queues in both snapshots.
+    pub rx_queues: BTreeMap<u32, TrafficRates>,
+    /// By queue number, for queues in both snapshots.
+    pub tx_queues: BTreeMap<u32, TrafficRates>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BgpMessageRates {
+    pub capability: CounterRate,
+    pub keepalive: CounterRate,
+    pub notification: CounterRate,
+    pub open: CounterRate,
+    pub route_refresh: CounterRate,
+    pub update: CounterRate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BgpNeighborRates {
+    pub established_transitions: CounterRate,
+    pub connections_dropped: CounterRate,
+    /// Only if both snapshots have received message counters.
+    pub messages_received: Option<BgpMessageRates>,
+    /// Only if both snapshots have sent message counters.
+    pub messages_sent: Option<BgpMessageRates>,
+}
+
+/// Rates of [`VpcCounters`], [`VpcPeeringCounters`] and
+/// [`InterfaceQueueCounters`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrafficRates {
+    pub packets: CounterRate,
+    pub bytes: CounterRate,
+    pub drops: CounterRate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrrRates {
+    pub restarts: CounterRate,
+    pub applied_configs: CounterRate,
+    pub failed_configs: CounterRate,
+}
+
+/// Rates of all counters present in both snapshots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusRates {
+    pub elapsed: Duration,
+    /// By interface name, from `interface_runtime`.
+    pub interfaces: BTreeMap<String, InterfaceRates>,
+    /// By VRF and neighbor address.
+    pub bgp_neighbors: BTreeMap<(String, String), BgpNeighborRates>,
+    /// By VPC name.
+    pub vpcs: BTreeMap<String, TrafficRates>,
+    /// By peering name.
+    pub vpc_peerings: BTreeMap<String, TrafficRates>,
+    pub frr: Option<FrrRates>,
+}
+
+impl StatusRates {
+    /// Whether any counter was reset, which usually means the dataplane
+    /// restarted between the snapshots.
+    #[must_use]
+    pub fn any_reset(&self) -> bool {
+        let interfaces = self.interfaces.values().flat_map(|r| {
+            let queues = r
+                .rx_queues
+                .values()
+                .chain(r.tx_queues.values())
+                .flat_map(|q| [q.packets, q.bytes, q.drops]);
+            [
+                r.rx_bits,
+                r.tx_bits,
+                r.rx_errors,
+                r.tx_errors,
+                r.rx_packets,
+                r.tx_packets,
+                r.rx_drops,
+                r.tx_drops,
+                r.rx_multicast,
+                r.tx_multicast,
+                r.rx_broadcast,
+                r.tx_broadcast,
+            ]
+            .into_iter()
+            .chain(queues)
+        });
+        let bgp = self.bgp_neighbors.values().flat_map(|r| {
+            let messages = [&r.messages_received, &r.messages_sent]
+                .into_iter()
+                .flatten()
+                .flat_map(|m| {
+                    [
+                        m.capability,
+                        m.keepalive,
+                        m.notification,
+                        m.open,
+                        m.route_refresh,
+                        m.update,
+                    ]
+                });
+            [r.established_transitions, r.connections_dropped]
+                .into_iter()
+                .chain(messages)
+        });
+        let traffic = self
+            .vpcs
+            .values()
+            .chain(self.vpc_peerings.values())
+            .flat_map(|r| [r.packets, r.bytes, r.drops]);
+        let frr = self
+            .frr
+            .iter()
+            .flat_map(|r| [r.restarts, r.applied_configs, r.failed_configs]);
+        interfaces
+            .chain(bgp)
+            .chain(traffic)
+            .chain(frr)
+            .any(|rate| rate.continuity == Continuity::Reset)
+    }
+}
+
+/// Compute the rates of all counters between `previous` and `current`.
+///
+/// # Errors
+///
+/// Returns an error if `current` wasn't taken after `previous`.
+pub fn compute_rates(
+    previous: &StatusSnapshot,
+    current: &StatusSnapshot,
+) -> Result<StatusRates, RateError> {
+    let elapsed = current
+        .at
+        .checked_duration_since(previous.at)
+        .filter(|elapsed| !elapsed.is_zero())
+        .ok_or(RateError::NotLater)?;
+    let (prev, cur) = (&previous.status, &current.status);
+
+    let interfaces = join(
+        prev.interface_runtime
+            .iter()
+            .filter_map(|(name, r)| Some((name.clone(), r.counters.as_ref()?))),
+        cur.interface_runtime
+            .iter()
+            .filter_map(|(name, r)| Some((name.clone(), r.counters.as_ref()?))),
+        |p, c| interface_rates(p, c, elapsed),
+    );
+    let bgp_neighbors = join(bgp_neighbors(prev), bgp_neighbors(cur), |p, c| {
+        bgp_neighbor_rates(p, c, elapsed)
+    });
+    let vpcs = join(
+        prev.vpc_counters.iter().map(|(k, v)| (k.clone(), v)),
+        cur.vpc_counters.iter().map(|(k, v)| (k.clone(), v)),
+        |p, c| vpc_rates(p, c, elapsed),
+    );
+    let vpc_peerings = join(
+        prev.vpc_peering_counters
+            .iter()
+            .map(|(k, v)| (k.clone(), v)),
+        cur.vpc_peering_counters.iter().map(|(k, v)| (k.clone(), v)),
+        |p, c| peering_rates(p, c, elapsed),
+    );
+    let frr = match (&prev.frr_status, &cur.frr_status) {
+        (Some(p), Some(c)) => Some(frr_rates(p, c, elapsed)),
+        _ => None,
+    };
+    Ok(StatusRates {
+        elapsed,
+        interfaces,
+        bgp_neighbors,
+        vpcs,
+        vpc_peerings,
+        frr,
+    })
+}
+
+/// Apply `rates` to the entries with the same key in both `previous` and
+/// `current`.
+fn join<'a, K: Ord, V: 'a, R>(
+    previous: impl Iterator<Item = (K, &'a V)>,
+    current: impl Iterator<Item = (K, &'a V)>,
+    rates: impl Fn(&V, &V) -> R,
+) -> BTreeMap<K, R> {
+    let mut previous: BTreeMap<K, &V> = previous.collect();
+    current
+        .filter_map(|(key, cur)| {
+            let prev = previous.remove(&key)?;
+            Some((key, rates(prev, cur)))
+        })
+        .collect()
+}
+
+fn bgp_neighbors(
+    status: &GetDataplaneStatusResponse,
+) -> impl Iterator<Item = ((String, String), &BgpNeighborStatus)> {
+    status
+        .bgp
+        .iter()
+        .flat_map(|bgp| &bgp.vrfs)
+        .flat_map(|(vrf, vrf_status)| {
+            vrf_status
+                .neighbors
+                .iter()
+                .map(move |(addr, neighbor)| ((vrf.clone(), addr.clone()), neighbor))
+        })
+}
+
+fn interface_rates(
+    prev: &InterfaceCounters,
+    cur: &InterfaceCounters,
+    elapsed: Duration,
+) -> InterfaceRates {
+    InterfaceRates {
+        rx_bits: CounterRate::new(prev.rx_bits, cur.rx_bits, elapsed),
+        tx_bits: CounterRate::new(prev.tx_bits, cur.tx_bits, elapsed),
+        rx_errors: CounterRate::new(prev.rx_errors, cur.rx_errors, elapsed),
+        tx_errors: CounterRate::new(prev.tx_errors, cur.tx_errors, elapsed),
+        rx_packets: CounterRate::new(prev.rx_packets, cur.rx_packets, elapsed),
+        tx_packets: CounterRate::new(prev.tx_packets, cur.tx_packets, elapsed),
+        rx_drops: CounterRate::new(prev.rx_drops, cur.rx_drops, elapsed),
+        tx_drops: CounterRate::new(prev.tx_drops, cur.tx_drops, elapsed),
+        rx_multicast: CounterRate::new(prev.rx_multicast, cur.rx_multicast, elapsed),
+        tx_multicast: CounterRate::new(prev.tx_multicast, cur.tx_multicast, elapsed),
+        rx_broadcast: CounterRate::new(prev.rx_broadcast, cur.rx_broadcast, elapsed),
+        tx_broadcast: CounterRate::new(prev.tx_broadcast, cur.tx_broadcast, elapsed),
+        rx_queues: queue_rates(&prev.rx_queues, &cur.rx_queues, elapsed),
+        tx_queues: queue_rates(&prev.tx_queues, &cur.tx_queues, elapsed),
+    }
+}
+
+fn queue_rates(
+    prev: &[InterfaceQueueCounters],
+    cur: &[InterfaceQueueCounters],
+    elapsed: Duration,
+) -> BTreeMap<u32, TrafficRates> {
+    join(
+        prev.iter().map(|q| (q.queue, q)),
+        cur.iter().map(|q| (q.queue, q)),
+        |p, c| TrafficRates {
+            packets: CounterRate::new(p.packets, c.packets, elapsed),
+            bytes: CounterRate::new(p.bytes, c.bytes, elapsed),
+            drops: CounterRate::new(p.drops, c.drops, elapsed),
+        },
+    )
+}
+
+fn message_rates(
+    prev: Option<&BgpMessageCounters>,
+    cur: Option<&BgpMessageCounters>,
+    elapsed: Duration,
+) -> Option<BgpMessageRates> {
+    let (prev, cur) = (prev?, cur?);
+    Some(BgpMessageRates {
+        capability: CounterRate::new(prev.capability, cur.capability, elapsed),
+        keepalive: CounterRate::new(prev.keepalive, cur.keepalive, elapsed),
+        notification: CounterRate::new(prev.notification, cur.notification, elapsed),
+        open: CounterRate::new(prev.open, cur.open, elapsed),
+        route_refresh: CounterRate::new(prev.route_refresh, cur.route_refresh, elapsed),
+        update: CounterRate::new(prev.update, cur.update, elapsed),
+    })
+}
+
+fn bgp_neighbor_rates(
+    prev: &BgpNeighborStatus,
+    cur: &BgpNeighborStatus,
+    elapsed: Duration,
+) -> BgpNeighborRates {
+    let (prev_messages, cur_messages) = (prev.messages.as_ref(), cur.messages.as_ref());
+    BgpNeighborRates {
+        established_transitions: CounterRate::new(
+            prev.established_transitions,
+            cur.established_transitions,
+            elapsed,
+        ),
+        connections_dropped: CounterRate::new(
+            prev.connections_dropped,
+            cur.connections_dropped,
+            elapsed,
+        ),
+        messages_received: message_rates(
+            prev_messages.and_then(|m| m.received.as_ref()),
+            cur_messages.and_then(|m| m.received.as_ref()),
+            elapsed,
+        ),
+        messages_sent: message_rates(
+            prev_messages.and_then(|m| m.sent.as_ref()),
+            cur_messages.and_then(|m| m.sent.as_ref()),
+            elapsed,
+        ),
+    }
+}
+
+fn vpc_rates(prev: &VpcCounters, cur: &VpcCounters, elapsed: Duration) -> TrafficRates {
+    TrafficRates {
+        packets: CounterRate::new(prev.packets, cur.packets, elapsed),
+        bytes: CounterRate::new(prev.bytes, cur.bytes, elapsed),
+        drops: CounterRate::new(prev.drops, cur.drops, elapsed),
+    }
+}
+
+fn peering_rates(
+    prev: &VpcPeeringCounters,
+    cur: &VpcPeeringCounters,
+    elapsed: Duration,
+) -> TrafficRates {
+    TrafficRates {
+        packets: CounterRate::new(prev.packets, cur.packets, elapsed),
+        bytes: CounterRate::new(prev.bytes, cur.bytes, elapsed),
+        drops: CounterRate::new(prev.drops, cur.drops, elapsed),
+    }
+}
+
+fn frr_rates(prev: &FrrStatus, cur: &FrrStatus, elapsed: Duration) -> FrrRates {
+    FrrRates {
+        restarts: CounterRate::new(prev.restarts.into(), cur.restarts.into(), elapsed),
+        applied_configs: CounterRate::new(
+            prev.applied_configs.into(),
+            cur.applied_configs.into(),
+            elapsed,
+        ),
+        failed_configs: CounterRate::new(
+            prev.failed_configs.into(),
+            cur.failed_configs.into(),
+            elapsed,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{InterfaceRuntimeStatus, VpcCounters};
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn test_counter_rate() {
+        let rate = CounterRate::new(100, 350, Duration::from_millis(500));
+        assert_eq!(rate.delta, 250);
+        assert!((rate.per_second - 500.0).abs() < f64::EPSILON);
+        assert_eq!(rate.continuity, Continuity::Monotonic);
+
+        let rate = CounterRate::new(7, 7, SECOND);
+        assert_eq!((rate.delta, rate.continuity), (0, Continuity::Monotonic));
+
+        let rate = CounterRate::new(u64::MAX - 9, 5, SECOND);
+        assert_eq!((rate.delta, rate.continuity), (15, Continuity::Wrapped));
+
+        let rate = CounterRate::new(1000, 10, SECOND);
+        assert_eq!((rate.delta, rate.continuity), (10, Continuity::Reset));
+
+        // Too far for a wrap
+        let rate = CounterRate::new(u64::MAX / 2 + 10, u64::MAX / 2, SECOND);
+        assert_eq!(
+            (rate.delta, rate.continuity),
+            (u64::MAX / 2, Continuity::Reset)
+        );
+    }
+
+    fn snapshot(at: Instant, packets: &[(&str, u64)]) -> StatusSnapshot {
+        StatusSnapshot {
+            at,
+            status: GetDataplaneStatusResponse {
+                vpc_counters: packets
+                    .iter()
+                    .map(|(name, packets)| {
+                        (
+                            (*name).to_string(),
+                            VpcCounters {
+                                name: (*name).to_string(),
+                                packets: *packets,
+                                ..Default::default()
+                            },
+                        )
+                    })
+                    .collect(),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_missing_keys() {
+        let now = Instant::now();
+        let prev = snapshot(now, &[("a", 10), ("b", 10)]);
+        let cur = snapshot(now + 2 * SECOND, &[("a", 30), ("c", 10)]);
+        let rates = compute_rates(&prev, &cur).unwrap();
+        assert_eq!(rates.elapsed, 2 * SECOND);
+        assert_eq!(rates.vpcs.keys().collect::<Vec<_>>(), ["a"]);
+        assert!((rates.vpcs["a"].packets.per_second - 10.0).abs() < f64::EPSILON);
+        assert!(rates.frr.is_none());
+        assert!(!rates.any_reset());
+    }
+
+    #[test]
+    fn test_reset() {
+        let now = Instant::now();
+        let prev = snapshot(now, &[("a", 1000)]);
+        let cur = snapshot(now + SECOND, &[("a", 10)]);
+        let rates = compute_rates(&prev, &cur).unwrap();
+        assert_eq!(rates.vpcs["a"].packets.continuity, Continuity::Reset);
+        assert!(rates.any_reset());
+    }
+
+    #[test]
+    fn test_not_later() {
+        let now = Instant::now();
+        let prev = snapshot(now + SECOND, &[]);
+        assert_eq!(
+            compute_rates(&prev, &snapshot(now, &[])).unwrap_err(),
+            RateError::NotLater
+        );
+        assert_eq!(
+            compute_rates(&prev, &prev.clone()).unwrap_err(),
+            RateError::NotLater
+        );
+    }
+
+    #[test]
+    fn test_interfaces_without_counters() {
+        let now = Instant::now();
+        let mut prev = snapshot(now, &[]);
+        prev.status.interface_runtime.insert(
+            "eth0".into(),
+            InterfaceRuntimeStatus {
+                counters: Some(InterfaceCounters::default()),
+                ..Default::default()
+            },
+        );
+        let mut cur = snapshot(now + SECOND, &[]);
+        cur.status
+            .interface_runtime
+            .insert("eth0".into(), InterfaceRuntimeStatus::default());
+        assert!(compute_rates(&prev, &cur).unwrap().interfaces.is_empty());
+    }
+}
@@ -0,0 +1,385 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Desired-state reconciliation of a dataplane config.
+//!
+//! [`Reconciler`] keeps pushing the desired [`GatewayConfig`] to a dataplane until
+//! `GetConfigGeneration` reports the desired generation and FRR reports it in
+//! `FrrStatus.applied_config_gen`, then keeps watching for drift.
+
+use crate::config::{
+    GatewayConfig, GetConfigGenerationRequest, GetDataplaneStatusRequest, UpdateConfigRequest,
+};
+use crate::error::UpdateConfigError;
+use crate::{ConfigServiceClient, Error};
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+use tonic::transport::Channel;
+
+#[derive(Debug, Clone)]
+pub struct ReconcilerConfig {
+    /// Interval between checks once the dataplane is in sync, and while waiting for FRR.
+    pub poll_interval: Duration,
+    /// Delay before the first retry after a failure, doubled on each consecutive failure.
+    pub initial_backoff: Duration,
+    /// Upper bound for the retry delay.
+    pub max_backoff: Duration,
+    /// Timeout for each individual RPC.
+    pub rpc_timeout: Duration,
+    /// Whether to wait for `FrrStatus.applied_config_gen` to reach the desired generation.
+    pub wait_for_frr: bool,
+}
+
+impl Default for ReconcilerConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(5),
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            rpc_timeout: Duration::from_secs(5),
+            wait_for_frr: true,
+        }
+    }
+}
+
+/// Where the reconciler is in driving the dataplane to the desired config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconcileState {
+    /// Nothing observed yet.
+    Unknown,
+    /// Dataplane reports a different generation, an update is being pushed.
+    Updating,
+    /// Dataplane accepted the desired generation but FRR has not applied it yet.
+    WaitingForFrr,
+    /// Dataplane and FRR are both at the desired generation.
+    InSync,
+    /// Last attempt failed, waiting before the next one.
+    BackingOff,
+    /// Dataplane rejected the desired config as invalid, waiting for a new one.
+    Rejected,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReconcileEvent {
+    /// State changed.
+    Transition {
+        from: ReconcileState,
+        to: ReconcileState,
+    },
+    /// Generation reported by `GetConfigGeneration` differs from the desired one.
+    GenerationMismatch { current: i64, desired: i64 },
+    /// `UpdateConfig` sent with the desired generation.
+    UpdateSent { generation: i64 },
+    /// `UpdateConfig` accepted by the dataplane.
+    UpdateAccepted { generation: i64 },
+    /// `UpdateConfig` rejected by the dataplane.
+    UpdateFailed {
+        generation: i64,
+        error: Error,
+        message: String,
+    },
+    /// An RPC failed on the transport level or timed out.
+    RpcFailed { rpc: &'static str, message: String },
+    /// FRR has not applied the desired generation yet.
+    FrrPending { applied: i64, desired: i64 },
+    /// Waiting before retrying.
+    BackingOff { delay: Duration },
+}
+
+#[derive(thiserror::Error, Debug)]
+enum StepError {
+    #[error("{0}")]
+    Update(#[from] UpdateConfigError),
+    #[error("{rpc}: {message}")]
+    Rpc { rpc: &'static str, message: String },
+}
+
+/// What to do after a reconciliation step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Next {
+    Poll,
+    Backoff,
+    WaitForNewConfig,
+}
+
+/// Drives a dataplane to the desired config received through a [`watch`] channel.
+///
+/// Events describing every action and state transition are published through a
+/// [`broadcast`] channel, see [`Reconciler::subscribe`].
+pub struct Reconciler {
+    client: ConfigServiceClient<Channel>,
+    desired: watch::Receiver<GatewayConfig>,
+    config: ReconcilerConfig,
+    events: broadcast::Sender<ReconcileEvent>,
+    state: ReconcileState,
+    failures: u32,
+}
+
+impl Reconciler {
+    #[must_use]
+    pub fn new(
+        client: ConfigServiceClient<Channel>,
+        desired: watch::Receiver<GatewayConfig>,
+        config: ReconcilerConfig,
+    ) -> Self {
+        let (events, _) = broadcast::channel(128);
+        Self {
+            client,
+            desired,
+            config,
+            events,
+            state: ReconcileState::Unknown,
+            failures: 0,
+        }
+    }
+
+    /// Subscribe to events emitted from now on.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<ReconcileEvent> {
+        self.events.subscribe()
+    }
+
+    #[must_use]
+    pub fn state(&self) -> ReconcileState {
+        self.state
+    }
+
+    fn emit(&self, event: ReconcileEvent) {
+        // No subscribers is fine
+        let _ = self.events.send(event);
+    }
+
+    fn transition(&mut self, to: ReconcileState) {
+        if self.state != to {
+            self.emit(ReconcileEvent::Transition {
+                from: self.state,
+                to,
+            });
+            self.state = to;
+        }
+    }
+
+    fn backoff_delay(&self) -> Duration {
+        let exp = self.failures.saturating_sub(1).min(16);
+        self.config
+            .initial_backoff
+            .saturating_mul(1 << exp)
+            .min(self.config.max_backoff)
+    }
+
+    async fn call<T, F>(&self, rpc: &'static str, fut: F) -> Result<T, StepError>
+    where
+        F: Future<Output = Result<tonic::Response<T>, tonic::Status>>,
+    {
+        match tokio::time::timeout(self.config.rpc_timeout, fut).await {
+            Ok(Ok(resp)) => Ok(resp.into_inner()),
+            Ok(Err(status)) => Err(StepError::Rpc {
+                rpc,
+                message: status.to_string(),
+            }),
+            Err(_) => Err(StepError::Rpc {
+                rpc,
+                message: "timed out".to_string(),
+            }),
+        }
+    }
+
+    async fn step(&mut self, desired: &GatewayConfig) -> Result<ReconcileState, StepError> {
+        let mut client = self.client.clone();
+
+        let current = self
+            .call(
+                "GetConfigGeneration",
+                client.get_config_generation(GetConfigGenerationRequest {}),
+            )
+            .await?
+            .generation;
+
+        if current != desired.generation {
+            self.emit(ReconcileEvent::GenerationMismatch {
+                current,
+                desired: desired.generation,
+            });
+            self.transition(ReconcileState::Updating);
+            self.emit(ReconcileEvent::UpdateSent {
+                generation: desired.generation,
+            });
+            let result = tokio::time::timeout(
+                self.config.rpc_timeout,
+                client.update_config(UpdateConfigRequest {
+                    config: Some(desired.clone()),
+                }),
+            )
+            .await
+            .map_err(|_| StepError::Rpc {
+                rpc: "UpdateConfig",
+                message: "timed out".to_string(),
+            })?;
+            match UpdateConfigError::from_result(result) {
+                // The dataplane never saw the config or its answer got lost
+                Err(UpdateConfigError::Rpc(status)) => {
+                    return Err(StepError::Rpc {
+                        rpc: "UpdateConfig",
+                        message: status.to_string(),
+                    });
+                }
+                result => result?,
+            };
+            self.emit(ReconcileEvent::UpdateAccepted {
+                generation: desired.generation,
+            });
+        }
+
+        if !self.config.wait_for_frr {
+            return Ok(ReconcileState::InSync);
+        }
+
+        let status = self
+            .call(
+                "GetDataplaneStatus",
+                client.get_dataplane_status(GetDataplaneStatusRequest {}),
+            )
+            .await?;
+        let applied = status.frr_status.map_or(0, |frr| frr.applied_config_gen);
+        if applied == desired.generation {
+            Ok(ReconcileState::InSync)
+        } else {
+            self.emit(ReconcileEvent::FrrPending {
+                applied,
+                desired: desired.generation,
+            });
+            Ok(ReconcileState::WaitingForFrr)
+        }
+    }
+
+    /// Run a single reconciliation step against the current desired config.
+    ///
+    /// Returns the resulting state.
+    pub async fn reconcile_once(&mut self) -> ReconcileState {
+        self.reconcile_step().await;
+        self.state
+    }
+
+    async fn reconcile_step(&mut self) -> Next {
+        let desired = self.desired.borrow_and_update().clone();
+        match self.step(&desired).await {
+            Ok(state) => {
+                self.failures = 0;
+                self.transition(state);
+                Next::Poll
+            }
+            Err(StepError::Update(err)) => {
+                self.emit(ReconcileEvent::UpdateFailed {
+                    generation: desired.generation,
+                    error: err.error(),
+                    message: err.message().to_string(),
+                });
+                if let UpdateConfigError::ValidationFailed(_) = err {
+                    // Retrying the same config is pointless
+                    self.transition(ReconcileState::Rejected);
+                    Next::WaitForNewConfig
+                } else {
+                    self.failures = self.failures.saturating_add(1);
+                    self.transition(ReconcileState::BackingOff);
+                    Next::Backoff
+                }
+            }
+            Err(StepError::Rpc { rpc, message }) => {
+                self.emit(ReconcileEvent::RpcFailed { rpc, message });
+                self.failures = self.failures.saturating_add(1);
+                self.transition(ReconcileState::BackingOff);
+                Next::Backoff
+            }
+        }
+    }
+
+    /// Keep reconciling until the sending side of the desired config channel is dropped.
+    ///
+    /// A change of the desired config interrupts any wait and triggers a new step
+    /// right away.
+    pub async fn run(mut self) {
+        loop {
+            let delay = match self.reconcile_step().await {
+                Next::Poll => Some(self.config.poll_interval),
+                Next::Backoff => {
+                    let delay = self.backoff_delay();
+                    self.emit(ReconcileEvent::BackingOff { delay });
+                    Some(delay)
+                }
+                Next::WaitForNewConfig => None,
+            };
+
+            let changed = match delay {
+                Some(delay) => {
+                    tokio::select! {
+                        changed = self.desired.changed() => changed,
+                        () = tokio::time::sleep(delay) => Ok(()),
+                    }
+                }
+                None => self.desired.changed().await,
+            };
+            if changed.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reconciler(initial_backoff_ms: u64, max_backoff_ms: u64) -> Reconciler {
+        let channel = Channel::from_static("http://[::1]:1").connect_lazy();
+        let (_tx, rx) = watch::channel(GatewayConfig::default());
+        Reconciler::new(
+            ConfigServiceClient::new(channel),
+            rx,
+            ReconcilerConfig {
+                initial_backoff: Duration::from_millis(initial_backoff_ms),
+                max_backoff: Duration::from_millis(max_backoff_ms),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_backoff_delay() {
+        let mut r = reconciler(100, 1000);
+        let delays: Vec<_> = (1..=6)
+            .map(|failures| {
+                r.failures = failures;
+                r.backoff_delay().as_millis()
+            })
+            .collect();
+        assert_eq!(delays, vec![100, 200, 400, 800, 1000, 1000]);
+
+        r.failures = u32::MAX;
+        assert_eq!(r.backoff_delay(), Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_transition_emits_once() {
+        let mut r = reconciler(100, 1000);
+        let mut events = r.subscribe();
+        r.transition(ReconcileState::Updating);
+        r.transition(ReconcileState::Updating);
+        r.transition(ReconcileState::InSync);
+
+        assert_eq!(
+            events.try_recv().unwrap(),
+            ReconcileEvent::Transition {
+                from: ReconcileState::Unknown,
+                to: ReconcileState::Updating
+            }
+        );
+        assert_eq!(
+            events.try_recv().unwrap(),
+            ReconcileEvent::Transition {
+                from: ReconcileState::Updating,
+                to: ReconcileState::InSync
+            }
+        );
+        assert!(events.try_recv().is_err());
+    }
+}
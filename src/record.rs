@@ -0,0 +1,547 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Recording and deterministic replay of config service sessions.
+//!
+//! [`Recorder`] wraps any [`ConfigService`] and appends every call to a recording
+//! as a length-delimited, protobuf-encoded [`RecordedCall`]. [`ReplayService`]
+//! serves the recorded responses back, in the recorded order per RPC, so that an
+//! incident can be reproduced in tests without the original dataplane.
+
+use crate::ConfigService;
+use crate::config::{
+    GatewayConfig, GetConfigGenerationRequest, GetConfigGenerationResponse, GetConfigRequest,
+    GetDataplaneStatusRequest, GetDataplaneStatusResponse, GetRoutesRequest, GetRoutesResponse,
+    ListNatSessionsRequest, ListNatSessionsResponse, LookupRouteRequest, LookupRouteResponse,
+    UpdateConfigRequest, UpdateConfigResponse,
+};
+use crate::fault::Rpc;
+use prost::Message;
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufReader, Read, Write};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime};
+use tonic::{Code, Request, Response, Status};
+
+/// A single recorded call.
+#[derive(Clone, PartialEq, Message)]
+pub struct RecordedCall {
+    /// Wall clock time the call was received, in microseconds since the Unix epoch.
+    #[prost(uint64, tag = "1")]
+    pub timestamp_micros: u64,
+    /// Method name, e.g. `UpdateConfig`.
+    #[prost(string, tag = "2")]
+    pub rpc: String,
+    /// Encoded request message.
+    #[prost(bytes = "vec", tag = "3")]
+    pub request: Vec<u8>,
+    /// Encoded response message, if the call succeeded.
+    #[prost(bytes = "vec", optional, tag = "4")]
+    pub response: Option<Vec<u8>>,
+    /// gRPC status code, if the call failed.
+    #[prost(int32, tag = "5")]
+    pub status_code: i32,
+    #[prost(string, tag = "6")]
+    pub status_message: String,
+    /// Time the inner service took to answer, in microseconds.
+    #[prost(uint64, tag = "7")]
+    pub elapsed_micros: u64,
+}
+
+impl RecordedCall {
+    #[must_use]
+    pub fn rpc(&self) -> Option<Rpc> {
+        Rpc::from_str_name(&self.rpc)
+    }
+
+    /// Decode the recorded request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request doesn't decode as `M`.
+    pub fn decode_request<M: Message + Default>(&self) -> Result<M, prost::DecodeError> {
+        M::decode(self.request.as_slice())
+    }
+
+    /// Recorded outcome, with the response decoded as `M`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the recorded status if the call failed, or an internal error if the
+    /// response doesn't decode as `M`.
+    pub fn decode_response<M: Message + Default>(&self) -> Result<M, Status> {
+        match &self.response {
+            Some(response) => M::decode(response.as_slice())
+                .map_err(|e| Status::internal(format!("invalid recorded response: {e}"))),
+            None => Err(Status::new(
+                Code::from(self.status_code),
+                self.status_message.clone(),
+            )),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum RecordingError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid record #{0}: {1}")]
+    Decode(usize, prost::DecodeError),
+    #[error("Unknown RPC {1:?} in record #{0}")]
+    UnknownRpc(usize, String),
+}
+
+/// Read all calls from a recording.
+///
+/// # Errors
+///
+/// Returns an error if reading fails or the recording is corrupted.
+pub fn read_recording<R: Read>(reader: R) -> Result<Vec<RecordedCall>, RecordingError> {
+    let mut data = Vec::new();
+    BufReader::new(reader).read_to_end(&mut data)?;
+
+    let mut buf = data.as_slice();
+    let mut calls = Vec::new();
+    while !buf.is_empty() {
+        let call = RecordedCall::decode_length_delimited(&mut buf)
+            .map_err(|e| RecordingError::Decode(calls.len(), e))?;
+        calls.push(call);
+    }
+    Ok(calls)
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+fn duration_micros(duration: Duration) -> u64 {
+    u64::try_from(duration.as_micros()).unwrap_or(u64::MAX)
+}
+
+/// [`ConfigService`] wrapper recording every call to a writer.
+///
+/// Recording failures are not reported to clients, the first one is kept and
+/// available through [`Recorder::error`].
+pub struct Recorder<S, W> {
+    inner: S,
+    writer: Mutex<W>,
+    error: Mutex<Option<std::io::Error>>,
+}
+
+impl<S: ConfigService> Recorder<S, std::fs::File> {
+    /// Record to a newly created file at `path`, truncating it if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be created.
+    pub fn create(inner: S, path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::new(inner, std::fs::File::create(path)?))
+    }
+}
+
+impl<S: ConfigService, W: Write + Send + 'static> Recorder<S, W> {
+    pub fn new(inner: S, writer: W) -> Self {
+        Self {
+            inner,
+            writer: Mutex::new(writer),
+            error: Mutex::new(None),
+        }
+    }
+
+    /// First error encountered while writing the recording, if any.
+    #[must_use]
+    pub fn error(&self) -> Option<String> {
+        lock(&self.error).as_ref().map(ToString::to_string)
+    }
+
+    pub fn into_parts(self) -> (S, W) {
+        (
+            self.inner,
+            self.writer
+                .into_inner()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        )
+    }
+
+    fn write(&self, call: &RecordedCall) {
+        let mut writer = lock(&self.writer);
+        let result = writer
+            .write_all(&call.encode_length_delimited_to_vec())
+            .and_then(|()| writer.flush());
+        if let Err(err) = result {
+            lock(&self.error).get_or_insert(err);
+        }
+    }
+
+    async fn record<Req, Resp, F>(
+        &self,
+        rpc: Rpc,
+        request: Request<Req>,
+        call: impl FnOnce(Request<Req>) -> F,
+    ) -> Result<Response<Resp>, Status>
+    where
+        Req: Message,
+        Resp: Message,
+        F: Future<Output = Result<Response<Resp>, Status>>,
+    {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        let encoded_request = request.get_ref().encode_to_vec();
+        let start = Instant::now();
+        let result = call(request).await;
+        let elapsed = start.elapsed();
+
+        let (response, status_code, status_message) = match &result {
+            Ok(response) => (Some(response.get_ref().encode_to_vec()), 0, String::new()),
+            Err(status) => (None, status.code().into(), status.message().to_string()),
+        };
+        self.write(&RecordedCall {
+            timestamp_micros: duration_micros(timestamp),
+            rpc: rpc.as_str().to_string(),
+            request: encoded_request,
+            response,
+            status_code,
+            status_message,
+            elapsed_micros: duration_micros(elapsed),
+        });
+
+        result
+    }
+}
+
+#[tonic::async_trait]
+impl<S: ConfigService, W: Write + Send + 'static> ConfigService for Recorder<S, W> {
+    async fn get_config(
+        &self,
+        request: Request<GetConfigRequest>,
+    ) -> Result<Response<GatewayConfig>, Status> {
+        self.record(Rpc::GetConfig, request, |r| self.inner.get_config(r))
+            .await
+    }
+
+    async fn get_config_generation(
+        &self,
+        request: Request<GetConfigGenerationRequest>,
+    ) -> Result<Response<GetConfigGenerationResponse>, Status> {
+        self.record(Rpc::GetConfigGeneration, request, |r| {
+            self.inner.get_config_generation(r)
+        })
+        .await
+    }
+
+    async fn update_config(
+        &self,
+        request: Request<UpdateConfigRequest>,
+    ) -> Result<Response<UpdateConfigResponse>, Status> {
+        self.record(Rpc::UpdateConfig, request, |r| self.inner.update_config(r))
+            .await
+    }
+
+    async fn get_dataplane_status(
+        &self,
+        request: Request<GetDataplaneStatusRequest>,
+    ) -> Result<Response<GetDataplaneStatusResponse>, Status> {
+        self.record(Rpc::GetDataplaneStatus, request, |r| {
+            self.inner.get_dataplane_status(r)
+        })
+        .await
+    }
+
+    async fn list_nat_sessions(
+        &self,
+        request: Request<ListNatSessionsRequest>,
+    ) -> Result<Response<ListNatSessionsResponse>, Status> {
+        self.record(Rpc::ListNatSessions, request, |r| {
+            self.inner.list_nat_sessions(r)
+        })
+        .await
+    }
+
+    async fn get_routes(
+        &self,
+        request: Request<GetRoutesRequest>,
+    ) -> Result<Response<GetRoutesResponse>, Status> {
+        self.record(Rpc::GetRoutes, request, |r| self.inner.get_routes(r))
+            .await
+    }
+
+    async fn lookup_route(
+        &self,
+        request: Request<LookupRouteRequest>,
+    ) -> Result<Response<LookupRouteResponse>, Status> {
+        self.record(Rpc::LookupRoute, request, |r| self.inner.lookup_route(r))
+            .await
+    }
+}
+
+/// [`ConfigService`] answering with recorded responses.
+///
+/// Calls of each RPC get the recorded responses of that RPC in the recorded
+/// order; once they are exhausted, calls fail with `OUT_OF_RANGE`.
+#[derive(Debug)]
+pub struct ReplayService {
+    calls: Mutex<HashMap<Rpc, VecDeque<RecordedCall>>>,
+    strict: bool,
+    timing: bool,
+}
+
+impl ReplayService {
+    #[must_use]
+    pub fn new(calls: Vec<RecordedCall>) -> Self {
+        let mut queues: HashMap<Rpc, VecDeque<RecordedCall>> = HashMap::new();
+        for call in calls {
+            if let Some(rpc) = call.rpc() {
+                queues.entry(rpc).or_default().push_back(call);
+            }
+        }
+        Self {
+            calls: Mutex::new(queues),
+            strict: false,
+            timing: false,
+        }
+    }
+
+    /// Load a recording written by [`Recorder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or the recording is corrupted.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, RecordingError> {
+        let calls = read_recording(std::fs::File::open(path)?)?;
+        for (i, call) in calls.iter().enumerate() {
+            if call.rpc().is_none() {
+                return Err(RecordingError::UnknownRpc(i, call.rpc.clone()));
+            }
+        }
+        Ok(Self::new(calls))
+    }
+
+    /// Fail calls with `FAILED_PRECONDITION` if the request differs from the recorded one.
+    ///
+    /// A failed call doesn't consume the recorded one, so the next call can
+    /// still match it.
+    #[must_use]
+    pub fn with_strict_requests(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Delay responses by the time the recorded calls took.
+    #[must_use]
+    pub fn with_timing(mut self, timing: bool) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// Number of recorded calls not replayed yet.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        lock(&self.calls).values().map(VecDeque::len).sum()
+    }
+
+    async fn replay<Req: Message + Default + PartialEq, Resp: Message + Default>(
+        &self,
+        rpc: Rpc,
+        request: &Request<Req>,
+    ) -> Result<Response<Resp>, Status> {
+        let call = {
+            let mut calls = lock(&self.calls);
+            let queue = calls.get_mut(&rpc).filter(|queue| !queue.is_empty());
+            let Some(queue) = queue else {
+                return Err(Status::out_of_range(format!(
+                    "no more recorded {} calls",
+                    rpc.as_str()
+                )));
+            };
+            // Compared decoded, maps encode in a different order every time
+            if self.strict
+                && !queue[0]
+                    .decode_request::<Req>()
+                    .is_ok_and(|recorded| recorded == *request.get_ref())
+            {
+                return Err(Status::failed_precondition(format!(
+                    "{} request does not match the recorded one",
+                    rpc.as_str()
+                )));
+            }
+            queue.pop_front().expect("queue is not empty")
+        };
+
+        if self.timing {
+            tokio::time::sleep(Duration::from_micros(call.elapsed_micros)).await;
+        }
+        call.decode_response().map(Response::new)
+    }
+}
+
+#[tonic::async_trait]
+impl ConfigService for ReplayService {
+    async fn get_config(
+        &self,
+        request: Request<GetConfigRequest>,
+    ) -> Result<Response<GatewayConfig>, Status> {
+        self.replay(Rpc::GetConfig, &request).await
+    }
+
+    async fn get_config_generation(
+        &self,
+        request: Request<GetConfigGenerationRequest>,
+    ) -> Result<Response<GetConfigGenerationResponse>, Status> {
+        self.replay(Rpc::GetConfigGeneration, &request).await
+    }
+
+    async fn update_config(
+        &self,
+        request: Request<UpdateConfigRequest>,
+    ) -> Result<Response<UpdateConfigResponse>, Status> {
+        self.replay(Rpc::UpdateConfig, &request).await
+    }
+
+    async fn get_dataplane_status(
+        &self,
+        request: Request<GetDataplaneStatusRequest>,
+    ) -> Result<Response<GetDataplaneStatusResponse>, Status> {
+        self.replay(Rpc::GetDataplaneStatus, &request).await
+    }
+
+    async fn list_nat_sessions(
+        &self,
+        request: Request<ListNatSessionsRequest>,
+    ) -> Result<Response<ListNatSessionsResponse>, Status> {
+        self.replay(Rpc::ListNatSessions, &request).await
+    }
+
+    async fn get_routes(
+        &self,
+        request: Request<GetRoutesRequest>,
+    ) -> Result<Response<GetRoutesResponse>, Status> {
+        self.replay(Rpc::GetRoutes, &request).await
+    }
+
+    async fn lookup_route(
+        &self,
+        request: Request<LookupRouteRequest>,
+    ) -> Result<Response<LookupRouteResponse>, Status> {
+        self.replay(Rpc::LookupRoute, &request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::InMemoryConfigService;
+
+    fn update(generation: i64) -> Request<UpdateConfigRequest> {
+        Request::new(UpdateConfigRequest {
+            config: Some(GatewayConfig {
+                generation,
+                ..Default::default()
+            }),
+        })
+    }
+
+    async fn record_session() -> Vec<u8> {
+        let recorder = Recorder::new(InMemoryConfigService::new(), Vec::new());
+        recorder.update_config(update(3)).await.unwrap();
+        recorder
+            .get_config_generation(Request::new(GetConfigGenerationRequest {}))
+            .await
+            .unwrap();
+        recorder
+            .update_config(Request::new(UpdateConfigRequest { config: None }))
+            .await
+            .unwrap_err();
+        assert!(recorder.error().is_none());
+        recorder.into_parts().1
+    }
+
+    #[tokio::test]
+    async fn test_record() {
+        let calls = read_recording(record_session().await.as_slice()).unwrap();
+        assert_eq!(calls.len(), 3);
+
+        assert_eq!(calls[0].rpc(), Some(Rpc::UpdateConfig));
+        let request: UpdateConfigRequest = calls[0].decode_request().unwrap();
+        assert_eq!(request.config.unwrap().generation, 3);
+        assert!(calls[0].decode_response::<UpdateConfigResponse>().is_ok());
+        assert!(calls[0].timestamp_micros > 0);
+
+        assert_eq!(calls[1].rpc(), Some(Rpc::GetConfigGeneration));
+        let response: GetConfigGenerationResponse = calls[1].decode_response().unwrap();
+        assert_eq!(response.generation, 3);
+
+        let status = calls[2]
+            .decode_response::<UpdateConfigResponse>()
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(status.message(), "missing config");
+    }
+
+    #[tokio::test]
+    async fn test_replay() {
+        let calls = read_recording(record_session().await.as_slice()).unwrap();
+        let replay = ReplayService::new(calls);
+        assert_eq!(replay.remaining(), 3);
+
+        // Other RPCs don't affect the order within an RPC
+        let generation = replay
+            .get_config_generation(Request::new(GetConfigGenerationRequest {}))
+            .await
+            .unwrap()
+            .into_inner()
+            .generation;
+        assert_eq!(generation, 3);
+
+        replay.update_config(update(3)).await.unwrap();
+        let status = replay.update_config(update(3)).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        let status = replay.update_config(update(3)).await.unwrap_err();
+        assert_eq!(status.code(), Code::OutOfRange);
+        assert_eq!(replay.remaining(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_replay_strict() {
+        let calls = read_recording(record_session().await.as_slice()).unwrap();
+        let replay = ReplayService::new(calls).with_strict_requests(true);
+
+        let status = replay.update_config(update(4)).await.unwrap_err();
+        assert_eq!(status.code(), Code::FailedPrecondition);
+        assert_eq!(replay.remaining(), 3);
+
+        replay.update_config(update(3)).await.unwrap();
+        assert_eq!(replay.remaining(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_replay_strict_maps() {
+        // Every map has its own iteration order, so equal configs encode differently
+        let update = || {
+            Request::new(UpdateConfigRequest {
+                config: Some(GatewayConfig {
+                    generation: 1,
+                    communities: (0..32).map(|i| (i, format!("65000:{i}"))).collect(),
+                    ..Default::default()
+                }),
+            })
+        };
+        let recorder = Recorder::new(InMemoryConfigService::new(), Vec::new());
+        recorder.update_config(update()).await.unwrap();
+        let calls = read_recording(recorder.into_parts().1.as_slice()).unwrap();
+        let replay = ReplayService::new(calls).with_strict_requests(true);
+
+        replay.update_config(update()).await.unwrap();
+        assert_eq!(replay.remaining(), 0);
+    }
+
+    #[test]
+    fn test_corrupted_recording() {
+        let err = read_recording([0x05_u8, 0x01].as_slice()).unwrap_err();
+        assert!(matches!(err, RecordingError::Decode(0, _)));
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Reflection over the messages defined in `dataplane.proto`.
+//!
+//! The descriptor set is generated together with the Rust code (see `build.rs`)
+//! and includes the proto source comments.
+
+use prost::{Message, Name};
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor};
+use std::sync::LazyLock;
+
+static DESCRIPTOR_POOL: LazyLock<DescriptorPool> = LazyLock::new(|| {
+    DescriptorPool::decode(include_bytes!("generated/descriptor.bin").as_ref())
+        .expect("Failed to decode embedded descriptor set (should never happen)")
+});
+
+#[derive(thiserror::Error, Debug)]
+pub enum ReflectError {
+    #[error("Message {0} not found in descriptor pool")]
+    UnknownMessage(String),
+    #[error("Failed to transcode message: {0}")]
+    Transcode(#[from] prost::DecodeError),
+}
+
+/// Descriptor pool with all messages of `dataplane.proto` and its imports.
+#[must_use]
+pub fn descriptor_pool() -> &'static DescriptorPool {
+    &DESCRIPTOR_POOL
+}
+
+/// Descriptor of the message `M`.
+///
+/// # Errors
+///
+/// Returns an error if `M` is not defined in `dataplane.proto` or its imports.
+pub fn message_descriptor<M: Name>() -> Result<MessageDescriptor, ReflectError> {
+    let name = M::full_name();
+    DESCRIPTOR_POOL
+        .get_message_by_name(&name)
+        .ok_or(ReflectError::UnknownMessage(name))
+}
+
+/// Convert `msg` into a [`DynamicMessage`].
+///
+/// # Errors
+///
+/// Returns an error if `M` is not defined in `dataplane.proto` or its imports.
+pub fn to_dynamic<M: Message + Name>(msg: &M) -> Result<DynamicMessage, ReflectError> {
+    let mut dynamic = DynamicMessage::new(message_descriptor::<M>()?);
+    dynamic.transcode_from(msg)?;
+    Ok(dynamic)
+}
+
+/// Convert a [`DynamicMessage`] back into `M`.
+///
+/// # Errors
+///
+/// Returns an error if `dynamic` is not compatible with `M`.
+pub fn from_dynamic<M: Message + Default>(dynamic: &DynamicMessage) -> Result<M, ReflectError> {
+    Ok(dynamic.transcode_to::<M>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GatewayConfig;
+    use crate::google::protobuf::Duration;
+
+    #[test]
+    fn test_descriptors() {
+        let desc = message_descriptor::<GatewayConfig>().unwrap();
+        assert_eq!(desc.full_name(), "config.GatewayConfig");
+        let desc = message_descriptor::<Duration>().unwrap();
+        assert_eq!(desc.full_name(), "google.protobuf.Duration");
+    }
+
+    #[test]
+    fn test_dynamic_round_trip() {
+        let config = GatewayConfig {
+            generation: 5,
+            communities: [(1, "65000:1".to_string())].into(),
+            ..Default::default()
+        };
+        let dynamic = to_dynamic(&config).unwrap();
+        assert_eq!(
+            dynamic.get_field_by_name("generation").unwrap().as_i64(),
+            Some(5)
+        );
+        assert_eq!(from_dynamic::<GatewayConfig>(&dynamic).unwrap(), config);
+    }
+}
@@ -0,0 +1,336 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Querying the route tables of underlay VRFs and VPCs with `GetRoutes` and
+//! `LookupRoute`.
+//!
+//! Servers can use [`page_routes`] and [`lookup_route`] on the routes of the
+//! table selected by [`GetRoutesRequest::route_table`] or
+//! [`LookupRouteRequest::route_table`],
+//! clients can use [`ConfigServiceClient::get_all_routes`] to follow the pages.
+
+use crate::ConfigServiceClient;
+use crate::config::{
+    GetRoutesRequest, GetRoutesResponse, LookupRouteRequest, LookupRouteResponse, Route,
+    RouteProtocol, get_routes_request, lookup_route_request,
+};
+use crate::page::{cut_page, follow_pages};
+use std::fmt;
+use std::net::IpAddr;
+use tonic::Status;
+use tonic::transport::Channel;
+
+/// Route table selected by a request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RouteTable {
+    /// Underlay VRF, e.g. `default`.
+    Vrf(String),
+    /// VPC, by name.
+    Vpc(String),
+}
+
+impl From<get_routes_request::Table> for RouteTable {
+    fn from(table: get_routes_request::Table) -> Self {
+        match table {
+            get_routes_request::Table::Vrf(name) => Self::Vrf(name),
+            get_routes_request::Table::Vpc(name) => Self::Vpc(name),
+        }
+    }
+}
+
+impl From<RouteTable> for get_routes_request::Table {
+    fn from(table: RouteTable) -> Self {
+        match table {
+            RouteTable::Vrf(name) => Self::Vrf(name),
+            RouteTable::Vpc(name) => Self::Vpc(name),
+        }
+    }
+}
+
+impl From<lookup_route_request::Table> for RouteTable {
+    fn from(table: lookup_route_request::Table) -> Self {
+        match table {
+            lookup_route_request::Table::Vrf(name) => Self::Vrf(name),
+            lookup_route_request::Table::Vpc(name) => Self::Vpc(name),
+        }
+    }
+}
+
+impl From<RouteTable> for lookup_route_request::Table {
+    fn from(table: RouteTable) -> Self {
+        match table {
+            RouteTable::Vrf(name) => Self::Vrf(name),
+            RouteTable::Vpc(name) => Self::Vpc(name),
+        }
+    }
+}
+
+impl fmt::Display for RouteTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Vrf(name) => write!(f, "VRF {name}"),
+            Self::Vpc(name) => write!(f, "VPC {name}"),
+        }
+    }
+}
+
+/// Error for a request that doesn't select a route table.
+fn no_table() -> Status {
+    Status::invalid_argument("one of vrf and vpc must be set")
+}
+
+impl GetRoutesRequest {
+    /// Route table the request is for.
+    ///
+    /// # Errors
+    ///
+    /// Returns `INVALID_ARGUMENT` if neither `vrf` nor `vpc` is set.
+    pub fn route_table(&self) -> Result<RouteTable, Status> {
+        self.table
+            .clone()
+            .map(RouteTable::from)
+            .ok_or_else(no_table)
+    }
+
+    /// Whether `route` passes the protocol filter of the request.
+    #[must_use]
+    pub fn matches(&self, route: &Route) -> bool {
+        self.protocol() == RouteProtocol::Unspecified || route.protocol == self.protocol
+    }
+}
+
+impl LookupRouteRequest {
+    /// Route table the request is for.
+    ///
+    /// # Errors
+    ///
+    /// Returns `INVALID_ARGUMENT` if neither `vrf` nor `vpc` is set.
+    pub fn route_table(&self) -> Result<RouteTable, Status> {
+        self.table
+            .clone()
+            .map(RouteTable::from)
+            .ok_or_else(no_table)
+    }
+}
+
+/// Parse `prefix` as an address and a prefix length, e.g. `10.1.0.0/24`.
+///
+/// A bare address is a host route.
+fn parse_prefix(prefix: &str) -> Option<(IpAddr, u8)> {
+    let (address, len) = prefix.split_once('/').unwrap_or((prefix, ""));
+    let address = address.parse::<IpAddr>().ok()?;
+    let max = if address.is_ipv4() { 32 } else { 128 };
+    let len = if len.is_empty() {
+        max
+    } else {
+        len.parse::<u8>().ok()?
+    };
+    (len <= max).then_some((address, len))
+}
+
+/// Whether `address` is inside the `network/len` prefix.
+fn contains(network: IpAddr, len: u8, address: IpAddr) -> bool {
+    fn mask(bits: u128, width: u8, len: u8) -> u128 {
+        if len == 0 {
+            0
+        } else {
+            bits >> (width - len) << (width - len)
+        }
+    }
+    match (network, address) {
+        (IpAddr::V4(network), IpAddr::V4(address)) => {
+            mask(u32::from(network).into(), 32, len) == mask(u32::from(address).into(), 32, len)
+        }
+        (IpAddr::V6(network), IpAddr::V6(address)) => {
+            mask(network.into(), 128, len) == mask(address.into(), 128, len)
+        }
+        _ => false,
+    }
+}
+
+/// Longest installed route of `routes` whose prefix contains `address`.
+///
+/// Routes with an unparsable prefix never match, ties are resolved in favor
+/// of the first route.
+#[must_use]
+pub fn longest_match<'a>(
+    routes: impl IntoIterator<Item = &'a Route>,
+    address: IpAddr,
+) -> Option<&'a Route> {
+    let mut best: Option<(&Route, u8)> = None;
+    for route in routes.into_iter().filter(|route| route.installed) {
+        let Some((network, len)) = parse_prefix(&route.prefix) else {
+            continue;
+        };
+        if contains(network, len, address) && best.is_none_or(|(_, best_len)| len > best_len) {
+            best = Some((route, len));
+        }
+    }
+    best.map(|(route, _)| route)
+}
+
+/// Build the response to `request` from the whole route table it selects.
+///
+/// # Errors
+///
+/// Returns `INVALID_ARGUMENT` if the page token wasn't produced by this function.
+pub fn page_routes<'a>(
+    routes: impl IntoIterator<Item = &'a Route>,
+    request: &GetRoutesRequest,
+) -> Result<GetRoutesResponse, Status> {
+    let (routes, next_page_token) = cut_page(
+        routes.into_iter().filter(|route| request.matches(route)),
+        request.page_size,
+        &request.page_token,
+    )?;
+    Ok(GetRoutesResponse {
+        routes,
+        next_page_token,
+    })
+}
+
+/// Answer `request` with the [`longest_match`] in the whole route table it selects.
+///
+/// # Errors
+///
+/// Returns `INVALID_ARGUMENT` if the address of the request isn't an IP address.
+pub fn lookup_route<'a>(
+    routes: impl IntoIterator<Item = &'a Route>,
+    request: &LookupRouteRequest,
+) -> Result<LookupRouteResponse, Status> {
+    let address = request
+        .address
+        .parse::<IpAddr>()
+        .map_err(|_| Status::invalid_argument(format!("invalid address {:?}", request.address)))?;
+    Ok(LookupRouteResponse {
+        route: longest_match(routes, address).cloned(),
+    })
+}
+
+impl ConfigServiceClient<Channel> {
+    /// Call `GetRoutes` until the last page and collect all routes.
+    ///
+    /// The page token of `request` is used for the first call, so a listing
+    /// can be resumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the status of the first failed call, or `INTERNAL` if the
+    /// server returns the same page token twice in a row.
+    pub async fn get_all_routes(&self, request: GetRoutesRequest) -> Result<Vec<Route>, Status> {
+        follow_pages(request.page_token.clone(), |page_token| {
+            let mut client = self.clone();
+            let request = GetRoutesRequest {
+                page_token,
+                ..request.clone()
+            };
+            async move {
+                let page = client.get_routes(request).await?.into_inner();
+                Ok((page.routes, page.next_page_token))
+            }
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(prefix: &str, protocol: RouteProtocol, installed: bool) -> Route {
+        Route {
+            prefix: prefix.into(),
+            protocol: protocol.into(),
+            installed,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_route_table() {
+        let request = GetRoutesRequest {
+            table: Some(get_routes_request::Table::Vrf("default".into())),
+            ..Default::default()
+        };
+        assert_eq!(
+            request.route_table().unwrap(),
+            RouteTable::Vrf("default".into())
+        );
+
+        let request = LookupRouteRequest {
+            table: Some(RouteTable::Vpc("vpc-1".into()).into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            request.route_table().unwrap(),
+            RouteTable::Vpc("vpc-1".into())
+        );
+
+        let err = GetRoutesRequest::default().route_table().unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        let err = LookupRouteRequest::default().route_table().unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[test]
+    fn test_longest_match() {
+        let routes = [
+            route("0.0.0.0/0", RouteProtocol::Static, true),
+            route("10.1.0.0/16", RouteProtocol::Bgp, true),
+            route("10.1.2.0/24", RouteProtocol::Bgp, false),
+            route("10.1.2.0/23", RouteProtocol::Connected, true),
+            route("10.1.2.5", RouteProtocol::Static, true),
+            route("fd00::/64", RouteProtocol::Connected, true),
+            route("bogus", RouteProtocol::Static, true),
+        ];
+        let lookup = |address: &str| {
+            longest_match(&routes, address.parse().unwrap()).map(|route| route.prefix.as_str())
+        };
+
+        // The /24 isn't installed, so it doesn't count
+        assert_eq!(lookup("10.1.2.1"), Some("10.1.2.0/23"));
+        assert_eq!(lookup("10.1.2.5"), Some("10.1.2.5"));
+        assert_eq!(lookup("10.1.9.1"), Some("10.1.0.0/16"));
+        assert_eq!(lookup("192.0.2.1"), Some("0.0.0.0/0"));
+        assert_eq!(lookup("fd00::1"), Some("fd00::/64"));
+        assert_eq!(lookup("fd01::1"), None);
+
+        let err = lookup_route(
+            &routes,
+            &LookupRouteRequest {
+                table: Some(RouteTable::Vrf("default".into()).into()),
+                address: "10.1.2".into(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[test]
+    fn test_pages() {
+        let routes: Vec<_> = (0_u32..5)
+            .map(|i| {
+                let protocol = if i.is_multiple_of(2) {
+                    RouteProtocol::Bgp
+                } else {
+                    RouteProtocol::Connected
+                };
+                route(&format!("10.{i}.0.0/16"), protocol, true)
+            })
+            .collect();
+        let mut request = GetRoutesRequest {
+            table: Some(RouteTable::Vrf("default".into()).into()),
+            protocol: RouteProtocol::Bgp.into(),
+            page_size: 2,
+            ..Default::default()
+        };
+
+        let page = page_routes(&routes, &request).unwrap();
+        assert_eq!(page.routes, vec![routes[0].clone(), routes[2].clone()]);
+
+        request.page_token = page.next_page_token;
+        let page = page_routes(&routes, &request).unwrap();
+        assert_eq!(page.routes, vec![routes[4].clone()]);
+        assert_eq!(page.next_page_token, "");
+    }
+}
@@ -0,0 +1,463 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! [JSON Schema](https://json-schema.org/draft/2020-12) (draft 2020-12) of the
+//! protojson mapping of messages, for validating configuration in editors and
+//! CI before it reaches the controller.
+//!
+//! The schema is derived from the embedded descriptors: each message and enum
+//! becomes an entry of `$defs`, enums list their value names, oneofs allow at
+//! most one of their fields and proto comments become descriptions. Fields
+//! holding addresses, which the proto only types as strings, are checked
+//! against CIDR, IP and MAC address patterns.
+//!
+//! Only the camelCase field names written by [`to_json`](crate::json::to_json)
+//! and [`to_yaml`](crate::yaml::to_yaml) are part of the schema, although
+//! [`from_json`](crate::json::from_json) also accepts the original proto names.
+
+use crate::reflect::{self, ReflectError};
+use prost::Name;
+use prost_reflect::{
+    EnumDescriptor, FieldDescriptor, FileDescriptor, Kind, MessageDescriptor, OneofDescriptor,
+};
+use serde_json::{Map, Value, json};
+
+pub const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+const IPV4: &str = r"(25[0-5]|2[0-4][0-9]|1[0-9][0-9]|[1-9]?[0-9])(\.(25[0-5]|2[0-4][0-9]|1[0-9][0-9]|[1-9]?[0-9])){3}";
+const IPV6: &str = r"[0-9A-Fa-f]{0,4}(:[0-9A-Fa-f]{0,4}){2,7}";
+
+/// Shape of string fields holding addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StringFormat {
+    /// IPv4 address, e.g. a router ID.
+    Ipv4,
+    /// IPv4 or IPv6 address.
+    Ip,
+    /// IPv4 or IPv6 prefix, e.g. `10.0.0.0/24`.
+    Cidr,
+    /// Ethernet address, e.g. `02:00:00:00:00:01`.
+    Mac,
+}
+
+const STRING_FORMATS: &[(&str, StringFormat)] = &[
+    ("config.BgpAddressFamilyIPv4.networks", StringFormat::Cidr),
+    ("config.BgpAddressFamilyIPv6.networks", StringFormat::Cidr),
+    ("config.BgpNeighbor.address", StringFormat::Ip),
+    ("config.BgpNeighborUpdateSource.address", StringFormat::Ip),
+    ("config.GatewayGroupMember.ipaddress", StringFormat::Ip),
+    ("config.Interface.ipaddrs", StringFormat::Cidr),
+    ("config.Interface.macaddr", StringFormat::Mac),
+    ("config.OspfConfig.router_id", StringFormat::Ipv4),
+    ("config.PeeringAs.cidr", StringFormat::Cidr),
+    ("config.PeeringAs.not", StringFormat::Cidr),
+    ("config.PeeringIPs.cidr", StringFormat::Cidr),
+    ("config.PeeringIPs.not", StringFormat::Cidr),
+    ("config.RouterConfig.router_id", StringFormat::Ipv4),
+];
+
+impl StringFormat {
+    fn of(field: &FieldDescriptor) -> Option<Self> {
+        STRING_FORMATS
+            .iter()
+            .find(|(name, _)| *name == field.full_name())
+            .map(|(_, format)| *format)
+    }
+
+    fn schema(self) -> Value {
+        match self {
+            Self::Ipv4 => json!({
+                "type": "string",
+                "format": "ipv4",
+                "pattern": format!("^{IPV4}$"),
+            }),
+            Self::Ip => json!({
+                "type": "string",
+                "anyOf": [
+                    {"format": "ipv4", "pattern": format!("^{IPV4}$")},
+                    {"format": "ipv6", "pattern": format!("^{IPV6}$")},
+                ],
+            }),
+            Self::Cidr => json!({
+                "type": "string",
+                "pattern": format!(
+                    "^({IPV4}/(3[0-2]|[12]?[0-9])|{IPV6}/(12[0-8]|1[01][0-9]|[1-9]?[0-9]))$"
+                ),
+            }),
+            Self::Mac => json!({
+                "type": "string",
+                "pattern": "^[0-9A-Fa-f]{2}(:[0-9A-Fa-f]{2}){5}$",
+            }),
+        }
+    }
+}
+
+/// JSON Schema of the protojson encoding of `M`.
+///
+/// # Errors
+///
+/// Returns an error if `M` is not defined in `dataplane.proto` or its imports.
+pub fn json_schema<M: Name>() -> Result<Value, ReflectError> {
+    let desc = reflect::message_descriptor::<M>()?;
+    let mut builder = Builder::default();
+    let root = builder.message(&desc);
+    let mut schema = Map::new();
+    schema.insert("$schema".into(), DRAFT.into());
+    schema.insert("title".into(), desc.name().into());
+    if let Value::Object(root) = root {
+        schema.extend(root);
+    }
+    schema.insert("$defs".into(), Value::Object(builder.defs));
+    Ok(Value::Object(schema))
+}
+
+#[derive(Default)]
+struct Builder {
+    defs: Map<String, Value>,
+}
+
+impl Builder {
+    /// Schema of a message field: a reference to the `$defs` entry, or an
+    /// inline schema for well-known types with a special JSON mapping.
+    fn message(&mut self, desc: &MessageDescriptor) -> Value {
+        match desc.full_name() {
+            "google.protobuf.Duration" => {
+                return json!({
+                    "type": "string",
+                    "pattern": r"^-?[0-9]+(\.[0-9]{1,9})?s$",
+                });
+            }
+            "google.protobuf.Empty" => return json!({"type": "object", "maxProperties": 0}),
+            _ => {}
+        }
+        let name = desc.full_name();
+        if !self.defs.contains_key(name) {
+            // Insert first so recursive messages terminate
+            self.defs.insert(name.into(), Value::Null);
+            let def = self.message_def(desc);
+            self.defs.insert(name.into(), def);
+        }
+        json!({"$ref": format!("#/$defs/{name}")})
+    }
+
+    fn message_def(&mut self, desc: &MessageDescriptor) -> Value {
+        let mut properties = Map::new();
+        for field in desc.fields() {
+            let mut schema = self.field(&field);
+            if let (Value::Object(schema), Some(description)) = (&mut schema, comments(&field)) {
+                schema.insert("description".into(), description.into());
+            }
+            properties.insert(field.json_name().into(), schema);
+        }
+
+        let mut def = Map::new();
+        def.insert("type".into(), "object".into());
+        if let Some(description) = comments(desc) {
+            def.insert("description".into(), description.into());
+        }
+        def.insert("properties".into(), Value::Object(properties));
+        def.insert("additionalProperties".into(), false.into());
+
+        let mut oneofs: Vec<Value> = desc
+            .oneofs()
+            .filter(|oneof| !oneof.is_synthetic())
+            .map(|oneof| at_most_one(&oneof))
+            .collect();
+        match oneofs.len() {
+            0 => {}
+            1 => {
+                if let Some(Value::Object(oneof)) = oneofs.pop() {
+                    def.extend(oneof);
+                }
+            }
+            _ => {
+                def.insert("allOf".into(), Value::Array(oneofs));
+            }
+        }
+        Value::Object(def)
+    }
+
+    fn field(&mut self, field: &FieldDescriptor) -> Value {
+        if field.is_map() {
+            let Kind::Message(entry) = field.kind() else {
+                unreachable!("map field {} without entry type", field.full_name());
+            };
+            let key = entry.map_entry_key_field();
+            let mut schema = json!({
+                "type": "object",
+                "additionalProperties": self.single(&entry.map_entry_value_field()),
+            });
+            if let Some(names) = map_key_names(&key.kind()) {
+                schema["propertyNames"] = names;
+            }
+            return schema;
+        }
+        let single = self.single(field);
+        if field.is_list() {
+            json!({"type": "array", "items": single})
+        } else {
+            single
+        }
+    }
+
+    /// Schema of one value of `field`, ignoring whether it's repeated.
+    fn single(&mut self, field: &FieldDescriptor) -> Value {
+        match field.kind() {
+            Kind::Message(desc) => self.message(&desc),
+            Kind::Enum(desc) => self.enumeration(&desc),
+            Kind::String => StringFormat::of(field)
+                .map_or_else(|| json!({"type": "string"}), StringFormat::schema),
+            kind => scalar(&kind),
+        }
+    }
+
+    fn enumeration(&mut self, desc: &EnumDescriptor) -> Value {
+        let name = desc.full_name();
+        if !self.defs.contains_key(name) {
+            let mut def = Map::new();
+            def.insert("type".into(), "string".into());
+            if let Some(description) = comments(desc) {
+                def.insert("description".into(), description.into());
+            }
+            def.insert(
+                "enum".into(),
+                desc.values().map(|v| Value::from(v.name())).collect(),
+            );
+            self.defs.insert(name.into(), Value::Object(def));
+        }
+        json!({"$ref": format!("#/$defs/{name}")})
+    }
+}
+
+/// Scalar types, following the protojson mapping: 64-bit integers may be
+/// strings, and floating point numbers may be `NaN` or infinite.
+fn scalar(kind: &Kind) -> Value {
+    match kind {
+        Kind::Bool => json!({"type": "boolean"}),
+        Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => json!({
+            "type": "integer",
+            "minimum": i32::MIN,
+            "maximum": i32::MAX,
+        }),
+        Kind::Uint32 | Kind::Fixed32 => json!({
+            "type": "integer",
+            "minimum": 0,
+            "maximum": u32::MAX,
+        }),
+        Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => json!({
+            "type": ["integer", "string"],
+            "pattern": "^-?[0-9]+$",
+        }),
+        Kind::Uint64 | Kind::Fixed64 => json!({
+            "type": ["integer", "string"],
+            "minimum": 0,
+            "pattern": "^[0-9]+$",
+        }),
+        Kind::Float | Kind::Double => json!({
+            "anyOf": [
+                {"type": "number"},
+                {"enum": ["NaN", "Infinity", "-Infinity"]},
+            ],
+        }),
+        Kind::Bytes => json!({"type": "string", "contentEncoding": "base64"}),
+        Kind::String | Kind::Message(_) | Kind::Enum(_) => {
+            unreachable!("{kind:?} is not a scalar")
+        }
+    }
+}
+
+fn map_key_names(kind: &Kind) -> Option<Value> {
+    match kind {
+        Kind::Bool => Some(json!({"enum": ["true", "false"]})),
+        Kind::Int32
+        | Kind::Sint32
+        | Kind::Sfixed32
+        | Kind::Int64
+        | Kind::Sint64
+        | Kind::Sfixed64 => Some(json!({"pattern": "^-?[0-9]+$"})),
+        Kind::Uint32 | Kind::Fixed32 | Kind::Uint64 | Kind::Fixed64 => {
+            Some(json!({"pattern": "^[0-9]+$"}))
+        }
+        _ => None,
+    }
+}
+
+/// Either exactly one of the fields of `oneof` is present, or none is.
+fn at_most_one(oneof: &OneofDescriptor) -> Value {
+    let present: Vec<Value> = oneof
+        .fields()
+        .map(|field| json!({"required": [field.json_name()]}))
+        .collect();
+    let mut choices = present.clone();
+    choices.push(json!({"not": {"anyOf": present}}));
+    json!({"oneOf": choices})
+}
+
+/// Descriptors with a location in their proto file.
+trait Located {
+    fn file(&self) -> FileDescriptor;
+    fn path(&self) -> &[i32];
+}
+
+macro_rules! located {
+    ($($t:ty),*) => {$(
+        impl Located for $t {
+            fn file(&self) -> FileDescriptor {
+                self.parent_file()
+            }
+
+            fn path(&self) -> &[i32] {
+                <$t>::path(self)
+            }
+        }
+    )*};
+}
+
+located!(MessageDescriptor, FieldDescriptor, EnumDescriptor);
+
+/// Leading comments of `desc`, or its trailing comments if there are none,
+/// with surrounding whitespace removed from each line.
+fn comments(desc: &impl Located) -> Option<String> {
+    let file = desc.file();
+    let location = file
+        .file_descriptor_proto()
+        .source_code_info
+        .as_ref()?
+        .location
+        .iter()
+        .find(|location| location.path == desc.path())?;
+    let text = location
+        .leading_comments
+        .as_deref()
+        .or(location.trailing_comments.as_deref())?;
+    let text = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{GatewayConfig, GetDataplaneStatusResponse};
+
+    fn def<'a>(schema: &'a Value, name: &str) -> &'a Value {
+        &schema["$defs"][name]
+    }
+
+    #[test]
+    fn test_root() {
+        let schema = json_schema::<GatewayConfig>().unwrap();
+        assert_eq!(schema["$schema"], DRAFT);
+        assert_eq!(schema["title"], "GatewayConfig");
+        assert_eq!(schema["$ref"], "#/$defs/config.GatewayConfig");
+        assert!(def(&schema, "config.GatewayConfig").is_object());
+        // Only messages reachable from GatewayConfig
+        assert!(def(&schema, "config.BgpStatus").is_null());
+    }
+
+    #[test]
+    fn test_fields() {
+        let schema = json_schema::<GatewayConfig>().unwrap();
+        let config = def(&schema, "config.GatewayConfig");
+        assert_eq!(config["additionalProperties"], false);
+        assert_eq!(config["description"], "Complete Gateway config options");
+        let properties = &config["properties"];
+        assert_eq!(
+            properties["generation"]["type"],
+            json!(["integer", "string"])
+        );
+        assert_eq!(
+            properties["gwGroups"],
+            json!({"type": "array", "items": {"$ref": "#/$defs/config.GatewayGroup"}})
+        );
+        assert_eq!(
+            properties["communities"],
+            json!({
+                "type": "object",
+                "additionalProperties": {"type": "string"},
+                "propertyNames": {"pattern": "^[0-9]+$"},
+                "description": "priority to community map",
+            })
+        );
+        assert!(properties.get("gw_groups").is_none());
+
+        let iface = &def(&schema, "config.Interface")["properties"];
+        assert_eq!(iface["vlan"]["description"], "only if VLAN role is defined");
+        assert_eq!(iface["mtu"]["maximum"], u32::MAX);
+        assert_eq!(iface["type"]["$ref"], "#/$defs/config.IfType");
+    }
+
+    #[test]
+    fn test_enums() {
+        let schema = json_schema::<GatewayConfig>().unwrap();
+        assert_eq!(
+            def(&schema, "config.IfType"),
+            &json!({
+                "type": "string",
+                "description": "Defines interface representation on the Gateway",
+                "enum": ["IF_TYPE_ETHERNET", "IF_TYPE_VLAN", "IF_TYPE_LOOPBACK", "IF_TYPE_VTEP"],
+            })
+        );
+        let tracing = &def(&schema, "config.TracingConfig")["properties"];
+        assert_eq!(
+            tracing["taglevel"]["additionalProperties"]["$ref"],
+            "#/$defs/config.LogLevel"
+        );
+    }
+
+    #[test]
+    fn test_oneofs() {
+        let schema = json_schema::<GatewayConfig>().unwrap();
+        let ips = def(&schema, "config.PeeringIPs");
+        assert_eq!(
+            ips["oneOf"],
+            json!([
+                {"required": ["cidr"]},
+                {"required": ["not"]},
+                {"not": {"anyOf": [{"required": ["cidr"]}, {"required": ["not"]}]}},
+            ])
+        );
+        // proto3 optional fields are not oneofs
+        assert!(def(&schema, "config.Interface").get("oneOf").is_none());
+        assert_eq!(
+            def(&schema, "config.PeeringStatefulNAT")["properties"]["idleTimeout"]["type"],
+            "string"
+        );
+    }
+
+    #[test]
+    fn test_string_formats() {
+        let schema = json_schema::<GatewayConfig>().unwrap();
+        for (name, _) in STRING_FORMATS {
+            let (message, field) = name.rsplit_once('.').unwrap();
+            assert!(
+                reflect::descriptor_pool()
+                    .get_message_by_name(message)
+                    .and_then(|desc| desc.get_field_by_name(field))
+                    .is_some(),
+                "{name} does not exist"
+            );
+        }
+        let iface = &def(&schema, "config.Interface")["properties"];
+        assert_eq!(iface["ipaddrs"]["items"], StringFormat::Cidr.schema());
+        assert_eq!(
+            iface["macaddr"]["pattern"],
+            StringFormat::Mac.schema()["pattern"]
+        );
+    }
+
+    #[test]
+    fn test_status_schema() {
+        let schema = json_schema::<GetDataplaneStatusResponse>().unwrap();
+        let counters = &def(&schema, "config.VpcPeeringCounters")["properties"];
+        assert!(counters["pps"]["anyOf"].is_array());
+        assert_eq!(
+            def(&schema, "config.BgpVrfStatus")["properties"]["neighbors"]["description"],
+            "key: neighbor address (IP string)"
+        );
+    }
+}
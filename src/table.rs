@@ -0,0 +1,833 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! Aligned text tables of [`GetDataplaneStatusResponse`], for people rather
+//! than programs.
+//!
+//! [`render_status`] prints a one line summary followed by tables of
+//! interfaces, BGP neighbors, VPCs, VNIs, remote VTEPs and VPC peerings, each
+//! sorted by name or number.
+//! Empty tables are left out. With [`TableOptions::color`], states are
+//! highlighted with ANSI colors: green when healthy, yellow while in
+//! transition or unknown and red when down or failed.
+
+use crate::config::{
+    BgpMessageCounters, BgpNeighborPrefixes, BgpNeighborSessionState, DataplaneStatusInfo,
+    DataplaneStatusType, FrrAgentStatusType, FrrLifecycleState, GetDataplaneStatusResponse,
+    InterfaceAdminStatusType, InterfaceCounters, InterfaceOperStatusType, InterfaceRuntimeStatus,
+    InterfaceStatus, VtepReachability, ZebraStatusType,
+};
+use crate::evpn::{remote_vteps_for_vni, vpc_for_vni};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TableOptions {
+    /// Highlight states with ANSI colors.
+    pub color: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Health {
+    Good,
+    Pending,
+    Bad,
+}
+
+impl Health {
+    fn color(self) -> &'static str {
+        match self {
+            Self::Good => "\x1b[32m",
+            Self::Pending => "\x1b[33m",
+            Self::Bad => "\x1b[31m",
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+struct Cell {
+    text: String,
+    health: Option<Health>,
+}
+
+impl From<String> for Cell {
+    fn from(text: String) -> Self {
+        Self { text, health: None }
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(text: &str) -> Self {
+        text.to_string().into()
+    }
+}
+
+fn state(text: &str, health: Health) -> Cell {
+    Cell {
+        text: text.to_string(),
+        health: Some(health),
+    }
+}
+
+struct Table {
+    title: &'static str,
+    columns: Vec<(&'static str, Align)>,
+    rows: Vec<Vec<Cell>>,
+}
+
+impl Table {
+    fn new(title: &'static str, columns: &[(&'static str, Align)]) -> Self {
+        Self {
+            title,
+            columns: columns.to_vec(),
+            rows: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, row: Vec<Cell>) {
+        debug_assert_eq!(row.len(), self.columns.len());
+        self.rows.push(row);
+    }
+
+    fn render(&self, out: &mut String, options: TableOptions) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let mut widths: Vec<usize> = self.columns.iter().map(|(h, _)| h.len()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.text.chars().count());
+            }
+        }
+
+        let _ = writeln!(out, "\n{}", self.title);
+        let header: Vec<Cell> = self.columns.iter().map(|(h, _)| (*h).into()).collect();
+        self.line(out, &header, &widths, TableOptions::default());
+        for row in &self.rows {
+            self.line(out, row, &widths, options);
+        }
+    }
+
+    fn line(&self, out: &mut String, row: &[Cell], widths: &[usize], options: TableOptions) {
+        let mut line = String::new();
+        for (i, (cell, width)) in row.iter().zip(widths).enumerate() {
+            if i > 0 {
+                line.push_str("  ");
+            }
+            let pad = " ".repeat(width - cell.text.chars().count());
+            let text = match cell.health {
+                Some(health) if options.color => {
+                    format!("{}{}{RESET}", health.color(), cell.text)
+                }
+                _ => cell.text.clone(),
+            };
+            match self.columns[i].1 {
+                Align::Left => {
+                    line.push_str(&text);
+                    line.push_str(&pad);
+                }
+                Align::Right => {
+                    line.push_str(&pad);
+                    line.push_str(&text);
+                }
+            }
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+}
+
+/// Render `status` as a summary line followed by aligned tables.
+#[must_use]
+pub fn render_status(status: &GetDataplaneStatusResponse, options: TableOptions) -> String {
+    let mut out = String::new();
+    summary(&mut out, status, options);
+    for table in [
+        interfaces(status),
+        interface_packets(status),
+        interface_queues(status),
+        bgp_neighbors(status),
+        vpcs(status),
+        vnis(status),
+        remote_vteps(status),
+        peerings(status),
+    ] {
+        table.render(&mut out, options);
+    }
+    out
+}
+
+fn summary(out: &mut String, status: &GetDataplaneStatusResponse, options: TableOptions) {
+    let paint = |cell: Cell| match cell.health {
+        Some(health) if options.color => format!("{}{}{RESET}", health.color(), cell.text),
+        _ => cell.text,
+    };
+    let dataplane = status.dataplane_status.as_ref().map_or(
+        DataplaneStatusType::DataplaneStatusUnknown,
+        DataplaneStatusInfo::status,
+    );
+    let _ = write!(out, "Dataplane: {}", paint(dataplane_state(dataplane)));
+    match &status.frr_status {
+        Some(frr) => {
+            let pending = if frr.pending_config_gen == 0 {
+                String::new()
+            } else {
+                format!(", pending {}", frr.pending_config_gen)
+            };
+            let _ = writeln!(
+                out,
+                "  FRR: {}  FRR agent: {}  Zebra: {}  Applied config: {}{pending} ({} applied, {} failed, {} restarts)",
+                paint(frr_state(frr.state())),
+                paint(frr_agent_state(frr.frr_agent_status())),
+                paint(zebra_state(frr.zebra_status())),
+                frr.applied_config_gen,
+                frr.applied_configs,
+                frr.failed_configs,
+                frr.restarts,
+            );
+            if !frr.last_apply_error.is_empty() {
+                let _ = writeln!(out, "Last config error: {}", frr.last_apply_error);
+            }
+        }
+        None => {
+            let _ = writeln!(out, "  FRR: {}", paint(state("UNKNOWN", Health::Pending)));
+        }
+    }
+}
+
+fn dataplane_state(status: DataplaneStatusType) -> Cell {
+    match status {
+        DataplaneStatusType::DataplaneStatusHealthy => state("HEALTHY", Health::Good),
+        DataplaneStatusType::DataplaneStatusInit => state("INIT", Health::Pending),
+        DataplaneStatusType::DataplaneStatusUnknown => state("UNKNOWN", Health::Pending),
+        DataplaneStatusType::DataplaneStatusError => state("ERROR", Health::Bad),
+    }
+}
+
+fn frr_state(status: FrrLifecycleState) -> Cell {
+    match status {
+        FrrLifecycleState::FrrStateRunning => state("RUNNING", Health::Good),
+        FrrLifecycleState::FrrStateStarting => state("STARTING", Health::Pending),
+        FrrLifecycleState::FrrStateReloading => state("RELOADING", Health::Pending),
+        FrrLifecycleState::FrrStateUnknown => state("UNKNOWN", Health::Pending),
+        FrrLifecycleState::FrrStateStopping => state("STOPPING", Health::Bad),
+        FrrLifecycleState::FrrStateCrashed => state("CRASHED", Health::Bad),
+    }
+}
+
+fn reachability(status: VtepReachability) -> Cell {
+    match status {
+        VtepReachability::Reachable => state("reachable", Health::Good),
+        VtepReachability::Unreachable => state("unreachable", Health::Bad),
+        VtepReachability::Unknown => state("unknown", Health::Pending),
+    }
+}
+
+fn frr_agent_state(status: FrrAgentStatusType) -> Cell {
+    match status {
+        FrrAgentStatusType::FrrAgentStatusConnected => state("CONNECTED", Health::Good),
+        FrrAgentStatusType::FrrAgentStatusNotConnected => state("NOT CONNECTED", Health::Bad),
+    }
+}
+
+fn zebra_state(status: ZebraStatusType) -> Cell {
+    match status {
+        ZebraStatusType::ZebraStatusConnected => state("CONNECTED", Health::Good),
+        ZebraStatusType::ZebraStatusNotConnected => state("NOT CONNECTED", Health::Bad),
+    }
+}
+
+fn admin_state(status: InterfaceAdminStatusType) -> Cell {
+    match status {
+        InterfaceAdminStatusType::InterfaceAdminStatusUp => state("up", Health::Good),
+        // Administratively down is intended, not a failure
+        InterfaceAdminStatusType::InterfaceAdminStatusDown => state("down", Health::Pending),
+        InterfaceAdminStatusType::InterfaceAdminStatusUnknown => state("unknown", Health::Pending),
+    }
+}
+
+fn oper_state(status: InterfaceOperStatusType) -> Cell {
+    match status {
+        InterfaceOperStatusType::InterfaceStatusOperUp => state("up", Health::Good),
+        InterfaceOperStatusType::InterfaceStatusOperDown => state("down", Health::Bad),
+        InterfaceOperStatusType::InterfaceStatusError => state("error", Health::Bad),
+        InterfaceOperStatusType::InterfaceStatusUnknown => state("unknown", Health::Pending),
+    }
+}
+
+fn session_state(status: BgpNeighborSessionState) -> Cell {
+    match status {
+        BgpNeighborSessionState::BgpStateEstablished => state("Established", Health::Good),
+        BgpNeighborSessionState::BgpStateConnect => state("Connect", Health::Pending),
+        BgpNeighborSessionState::BgpStateOpen => state("Open", Health::Pending),
+        BgpNeighborSessionState::BgpStateActive => state("Active", Health::Bad),
+        BgpNeighborSessionState::BgpStateIdle => state("Idle", Health::Bad),
+        BgpNeighborSessionState::BgpStateUnset => state("unknown", Health::Pending),
+    }
+}
+
+/// Rate in bits per second with an SI prefix, e.g. `1.50 Mbps`.
+fn format_bps(bps: f64) -> String {
+    const UNITS: [&str; 5] = ["bps", "Kbps", "Mbps", "Gbps", "Tbps"];
+    let mut value = bps;
+    let mut unit = 0;
+    while value.abs() >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{value:.0} {}", UNITS[0])
+    } else {
+        format!("{value:.2} {}", UNITS[unit])
+    }
+}
+
+fn dash() -> Cell {
+    "-".into()
+}
+
+fn prefixes(prefixes: Option<&BgpNeighborPrefixes>) -> Cell {
+    prefixes.map_or_else(dash, |p| format!("{}/{}", p.received, p.sent).into())
+}
+
+fn interfaces(status: &GetDataplaneStatusResponse) -> Table {
+    use Align::{Left, Right};
+    let mut table = Table::new(
+        "INTERFACES",
+        &[
+            ("NAME", Left),
+            ("ADMIN", Left),
+            ("OPER", Left),
+            ("MAC", Left),
+            ("MTU", Right),
+            ("RX RATE", Right),
+            ("TX RATE", Right),
+            ("RX ERRORS", Right),
+            ("TX ERRORS", Right),
+            ("RX DROPS", Right),
+            ("TX DROPS", Right),
+            ("ERROR", Left),
+        ],
+    );
+    // Interfaces may only be listed in one of the two places
+    let names: BTreeSet<&str> = status
+        .interface_statuses
+        .iter()
+        .map(|s| s.ifname.as_str())
+        .chain(status.interface_runtime.keys().map(String::as_str))
+        .collect();
+    for name in names {
+        let basic = status.interface_statuses.iter().find(|s| s.ifname == name);
+        let runtime = status.interface_runtime.get(name);
+        let admin = runtime
+            .map(InterfaceRuntimeStatus::admin_status)
+            .or(basic.map(InterfaceStatus::admin_status))
+            .unwrap_or_default();
+        let oper = runtime
+            .map(InterfaceRuntimeStatus::oper_status)
+            .or(basic.map(InterfaceStatus::oper_status))
+            .unwrap_or_default();
+        let counters = runtime.and_then(|r| r.counters.as_ref());
+        let error_reason = runtime
+            .map(|r| r.error_reason.as_str())
+            .filter(|reason| !reason.is_empty())
+            .or(basic.map(|s| s.error_reason.as_str()))
+            .filter(|reason| !reason.is_empty());
+        table.push(vec![
+            name.into(),
+            admin_state(admin),
+            oper_state(oper),
+            runtime
+                .filter(|r| !r.mac.is_empty())
+                .map_or_else(dash, |r| r.mac.as_str().into()),
+            runtime
+                .filter(|r| r.mtu != 0)
+                .map_or_else(dash, |r| r.mtu.to_string().into()),
+            counters.map_or_else(dash, |c| format_bps(c.rx_bps).into()),
+            counters.map_or_else(dash, |c| format_bps(c.tx_bps).into()),
+            counters.map_or_else(dash, |c| c.rx_errors.to_string().into()),
+            counters.map_or_else(dash, |c| c.tx_errors.to_string().into()),
+            counters.map_or_else(dash, |c| c.rx_drops.to_string().into()),
+            counters.map_or_else(dash, |c| c.tx_drops.to_string().into()),
+            error_reason.map_or_else(dash, Into::into),
+        ]);
+    }
+    table
+}
+
+/// Interfaces with counters, sorted by name.
+fn interface_counters(
+    status: &GetDataplaneStatusResponse,
+) -> impl Iterator<Item = (&str, &InterfaceCounters)> {
+    let counters: BTreeMap<&str, &InterfaceCounters> = status
+        .interface_runtime
+        .iter()
+        .filter_map(|(name, runtime)| Some((name.as_str(), runtime.counters.as_ref()?)))
+        .collect();
+    counters.into_iter()
+}
+
+fn interface_packets(status: &GetDataplaneStatusResponse) -> Table {
+    use Align::{Left, Right};
+    let mut table = Table::new(
+        "INTERFACE PACKETS",
+        &[
+            ("NAME", Left),
+            ("RX PACKETS", Right),
+            ("TX PACKETS", Right),
+            ("RX MCAST", Right),
+            ("TX MCAST", Right),
+            ("RX BCAST", Right),
+            ("TX BCAST", Right),
+        ],
+    );
+    for (name, c) in interface_counters(status) {
+        table.push(vec![
+            name.into(),
+            c.rx_packets.to_string().into(),
+            c.tx_packets.to_string().into(),
+            c.rx_multicast.to_string().into(),
+            c.tx_multicast.to_string().into(),
+            c.rx_broadcast.to_string().into(),
+            c.tx_broadcast.to_string().into(),
+        ]);
+    }
+    table
+}
+
+fn interface_queues(status: &GetDataplaneStatusResponse) -> Table {
+    use Align::{Left, Right};
+    let mut table = Table::new(
+        "INTERFACE QUEUES",
+        &[
+            ("NAME", Left),
+            ("DIRECTION", Left),
+            ("QUEUE", Right),
+            ("PACKETS", Right),
+            ("BYTES", Right),
+            ("DROPS", Right),
+        ],
+    );
+    for (name, c) in interface_counters(status) {
+        for (direction, queues) in [("rx", &c.rx_queues), ("tx", &c.tx_queues)] {
+            let mut queues: Vec<_> = queues.iter().collect();
+            queues.sort_by_key(|q| q.queue);
+            for q in queues {
+                table.push(vec![
+                    name.into(),
+                    direction.into(),
+                    q.queue.to_string().into(),
+                    q.packets.to_string().into(),
+                    q.bytes.to_string().into(),
+                    q.drops.to_string().into(),
+                ]);
+            }
+        }
+    }
+    table
+}
+
+fn bgp_neighbors(status: &GetDataplaneStatusResponse) -> Table {
+    use Align::{Left, Right};
+    let mut table = Table::new(
+        "BGP NEIGHBORS",
+        &[
+            ("VRF", Left),
+            ("NEIGHBOR", Left),
+            ("PEER AS", Right),
+            ("STATE", Left),
+            ("ESTABLISHED", Right),
+            ("DROPPED", Right),
+            ("MSG RX", Right),
+            ("MSG TX", Right),
+            ("IPV4 RX/TX", Right),
+            ("IPV6 RX/TX", Right),
+            ("EVPN RX/TX", Right),
+            ("LAST RESET", Left),
+        ],
+    );
+    let Some(bgp) = &status.bgp else {
+        return table;
+    };
+    let mut vrfs: Vec<_> = bgp.vrfs.iter().collect();
+    vrfs.sort_by_key(|(name, _)| *name);
+    for (vrf, vrf_status) in vrfs {
+        let mut neighbors: Vec<_> = vrf_status.neighbors.iter().collect();
+        neighbors.sort_by_key(|(addr, _)| *addr);
+        for (addr, neighbor) in neighbors {
+            let messages = neighbor.messages.as_ref();
+            let total = |counters: Option<&BgpMessageCounters>| {
+                counters.map_or(0, |c| {
+                    [
+                        c.capability,
+                        c.keepalive,
+                        c.notification,
+                        c.open,
+                        c.route_refresh,
+                        c.update,
+                    ]
+                    .into_iter()
+                    .fold(0, u64::saturating_add)
+                })
+            };
+            let state = if neighbor.enabled {
+                session_state(neighbor.session_state())
+            } else {
+                state("disabled", Health::Pending)
+            };
+            table.push(vec![
+                vrf.as_str().into(),
+                addr.as_str().into(),
+                neighbor.peer_as.to_string().into(),
+                state,
+                neighbor.established_transitions.to_string().into(),
+                neighbor.connections_dropped.to_string().into(),
+                total(messages.and_then(|m| m.received.as_ref()))
+                    .to_string()
+                    .into(),
+                total(messages.and_then(|m| m.sent.as_ref()))
+                    .to_string()
+                    .into(),
+                prefixes(neighbor.ipv4_unicast_prefixes.as_ref()),
+                prefixes(neighbor.ipv6_unicast_prefixes.as_ref()),
+                prefixes(neighbor.l2vpn_evpn_prefixes.as_ref()),
+                if neighbor.last_reset_reason.is_empty() {
+                    dash()
+                } else {
+                    neighbor.last_reset_reason.as_str().into()
+                },
+            ]);
+        }
+    }
+    table
+}
+
+fn vpcs(status: &GetDataplaneStatusResponse) -> Table {
+    use Align::{Left, Right};
+    let mut table = Table::new(
+        "VPCS",
+        &[
+            ("NAME", Left),
+            ("ID", Left),
+            ("VNI", Right),
+            ("ROUTES", Right),
+            ("INTERFACES UP", Right),
+            ("PACKETS", Right),
+            ("BYTES", Right),
+            ("DROPS", Right),
+        ],
+    );
+    let names: BTreeSet<&str> = status
+        .vpcs
+        .keys()
+        .chain(status.vpc_counters.keys())
+        .map(String::as_str)
+        .collect();
+    for name in names {
+        let vpc = status.vpcs.get(name);
+        let counters = status.vpc_counters.get(name);
+        let interfaces = vpc.map_or_else(dash, |vpc| {
+            let total = vpc.interfaces.len();
+            let up = vpc
+                .interfaces
+                .values()
+                .filter(|i| i.oper_status() == InterfaceOperStatusType::InterfaceStatusOperUp)
+                .count();
+            let health = if up == total {
+                Health::Good
+            } else if up == 0 {
+                Health::Bad
+            } else {
+                Health::Pending
+            };
+            state(&format!("{up}/{total}"), health)
+        });
+        table.push(vec![
+            name.into(),
+            vpc.map_or_else(dash, |v| v.id.as_str().into()),
+            vpc.map_or_else(dash, |v| v.vni.to_string().into()),
+            vpc.map_or_else(dash, |v| v.route_count.to_string().into()),
+            interfaces,
+            counters.map_or_else(dash, |c| c.packets.to_string().into()),
+            counters.map_or_else(dash, |c| c.bytes.to_string().into()),
+            counters.map_or_else(dash, |c| c.drops.to_string().into()),
+        ]);
+    }
+    table
+}
+
+fn vnis(status: &GetDataplaneStatusResponse) -> Table {
+    use Align::{Left, Right};
+    let mut table = Table::new(
+        "VNIS",
+        &[
+            ("VNI", Right),
+            ("VPC", Left),
+            ("MACS", Right),
+            ("ROUTES", Right),
+            ("TYPE-5 ROUTES", Right),
+            ("REMOTE VTEPS", Right),
+        ],
+    );
+    let vnis: BTreeMap<_, _> = status.vnis.iter().collect();
+    for (vni, vni_status) in vnis {
+        let vteps = remote_vteps_for_vni(status, *vni);
+        let reachable = vteps
+            .iter()
+            .filter(|vtep| vtep.reachability() == VtepReachability::Reachable)
+            .count();
+        let health = if reachable == vteps.len() {
+            Health::Good
+        } else if reachable == 0 {
+            Health::Bad
+        } else {
+            Health::Pending
+        };
+        table.push(vec![
+            vni.to_string().into(),
+            vpc_for_vni(status, *vni).map_or_else(dash, |vpc| vpc.name.as_str().into()),
+            vni_status.mac_count.to_string().into(),
+            vni_status.route_count.to_string().into(),
+            vni_status.type5_route_count.to_string().into(),
+            state(&format!("{reachable}/{}", vteps.len()), health),
+        ]);
+    }
+    table
+}
+
+fn remote_vteps(status: &GetDataplaneStatusResponse) -> Table {
+    use Align::Left;
+    let mut table = Table::new(
+        "REMOTE VTEPS",
+        &[("ADDRESS", Left), ("REACHABILITY", Left), ("VNIS", Left)],
+    );
+    let vteps: BTreeMap<_, _> = status.remote_vteps.iter().collect();
+    for (address, vtep) in vteps {
+        let vnis = if vtep.vnis.is_empty() {
+            dash()
+        } else {
+            let vnis: Vec<_> = vtep.vnis.iter().map(u32::to_string).collect();
+            vnis.join(",").into()
+        };
+        table.push(vec![
+            address.as_str().into(),
+            reachability(vtep.reachability()),
+            vnis,
+        ]);
+    }
+    table
+}
+
+fn peerings(status: &GetDataplaneStatusResponse) -> Table {
+    use Align::{Left, Right};
+    let mut table = Table::new(
+        "VPC PEERINGS",
+        &[
+            ("NAME", Left),
+            ("SRC VPC", Left),
+            ("DST VPC", Left),
+            ("PACKETS", Right),
+            ("BYTES", Right),
+            ("DROPS", Right),
+            ("PPS", Right),
+            ("RATE", Right),
+        ],
+    );
+    let mut peerings: Vec<_> = status.vpc_peering_counters.iter().collect();
+    peerings.sort_by_key(|(name, _)| *name);
+    for (name, counters) in peerings {
+        table.push(vec![
+            name.as_str().into(),
+            counters.src_vpc.as_str().into(),
+            counters.dst_vpc.as_str().into(),
+            counters.packets.to_string().into(),
+            counters.bytes.to_string().into(),
+            counters.drops.to_string().into(),
+            format!("{:.2}", counters.pps).into(),
+            format_bps(counters.bps).into(),
+        ]);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        BgpMessages, BgpNeighborStatus, BgpStatus, BgpVrfStatus, DataplaneStatusInfo, FrrStatus,
+        InterfaceStatus,
+    };
+
+    #[test]
+    fn test_format_bps() {
+        assert_eq!(format_bps(0.0), "0 bps");
+        assert_eq!(format_bps(999.4), "999 bps");
+        assert_eq!(format_bps(1500.5), "1.50 Kbps");
+        assert_eq!(format_bps(25e9), "25.00 Gbps");
+        assert_eq!(format_bps(4e15), "4000.00 Tbps");
+    }
+
+    #[test]
+    fn test_alignment() {
+        let mut table = Table::new("T", &[("NAME", Align::Left), ("N", Align::Right)]);
+        table.push(vec!["a".into(), "1000".into()]);
+        table.push(vec!["longer".into(), "7".into()]);
+        let mut out = String::new();
+        table.render(&mut out, TableOptions::default());
+        assert_eq!(out, "\nT\nNAME       N\na       1000\nlonger     7\n");
+
+        // Colors don't count towards the width
+        let mut table = Table::new("T", &[("STATE", Align::Left), ("X", Align::Left)]);
+        table.push(vec![state("up", Health::Good), "x".into()]);
+        let mut out = String::new();
+        table.render(&mut out, TableOptions { color: true });
+        assert_eq!(out, "\nT\nSTATE  X\n\x1b[32mup\x1b[0m     x\n");
+
+        let mut out = String::new();
+        Table::new("EMPTY", &[("A", Align::Left)]).render(&mut out, TableOptions::default());
+        assert_eq!(out, "");
+    }
+
+    #[test]
+    fn test_empty_status() {
+        assert_eq!(
+            render_status(
+                &GetDataplaneStatusResponse::default(),
+                TableOptions::default()
+            ),
+            "Dataplane: UNKNOWN  FRR: UNKNOWN\n"
+        );
+    }
+
+    #[test]
+    fn test_merged_interfaces() {
+        let status = GetDataplaneStatusResponse {
+            interface_statuses: vec![InterfaceStatus {
+                ifname: "eth1".into(),
+                oper_status: InterfaceOperStatusType::InterfaceStatusOperDown.into(),
+                admin_status: InterfaceAdminStatusType::InterfaceAdminStatusUp.into(),
+                error_reason: "no carrier".into(),
+            }],
+            dataplane_status: Some(DataplaneStatusInfo {
+                status: DataplaneStatusType::DataplaneStatusError.into(),
+            }),
+            frr_status: Some(FrrStatus::default()),
+            ..Default::default()
+        };
+        let out = render_status(&status, TableOptions::default());
+        assert_eq!(
+            out,
+            "Dataplane: ERROR  FRR: UNKNOWN  FRR agent: NOT CONNECTED  Zebra: NOT CONNECTED  \
+             Applied config: 0 (0 applied, 0 failed, 0 restarts)\n\
+             \n\
+             INTERFACES\n\
+             NAME  ADMIN  OPER  MAC  MTU  RX RATE  TX RATE  RX ERRORS  TX ERRORS  RX DROPS  TX DROPS  ERROR\n\
+             eth1  up     down  -      -        -        -          -          -         -         -  no carrier\n"
+        );
+    }
+
+    #[test]
+    fn test_frr_lifecycle() {
+        let status = GetDataplaneStatusResponse {
+            frr_status: Some(FrrStatus {
+                state: FrrLifecycleState::FrrStateReloading.into(),
+                applied_config_gen: 6,
+                pending_config_gen: 7,
+                failed_configs: 1,
+                last_apply_error: "line 12: Unknown command".into(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            render_status(&status, TableOptions::default()),
+            "Dataplane: UNKNOWN  FRR: RELOADING  FRR agent: NOT CONNECTED  Zebra: NOT CONNECTED  \
+             Applied config: 6, pending 7 (0 applied, 1 failed, 0 restarts)\n\
+             Last config error: line 12: Unknown command\n"
+        );
+    }
+
+    #[test]
+    fn test_bgp_colors() {
+        let neighbor = |state: BgpNeighborSessionState| BgpNeighborStatus {
+            enabled: true,
+            session_state: state.into(),
+            ..Default::default()
+        };
+        let status = GetDataplaneStatusResponse {
+            bgp: Some(BgpStatus {
+                vrfs: [(
+                    "default".to_string(),
+                    BgpVrfStatus {
+                        neighbors: [
+                            (
+                                "10.0.0.2".to_string(),
+                                neighbor(BgpNeighborSessionState::BgpStateIdle),
+                            ),
+                            (
+                                "10.0.0.1".to_string(),
+                                neighbor(BgpNeighborSessionState::BgpStateEstablished),
+                            ),
+                        ]
+                        .into(),
+                    },
+                )]
+                .into(),
+            }),
+            ..Default::default()
+        };
+        let out = render_status(&status, TableOptions { color: true });
+        let rows: Vec<&str> = out.lines().skip(4).collect();
+        assert!(rows[0].starts_with("default  10.0.0.1"), "{out}");
+        assert!(rows[0].contains("\x1b[32mEstablished\x1b[0m"), "{out}");
+        assert!(rows[1].contains("\x1b[31mIdle\x1b[0m"), "{out}");
+        assert!(!render_status(&status, TableOptions::default()).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_bgp_message_totals_saturate() {
+        let counters = BgpMessageCounters {
+            keepalive: u64::MAX - 1,
+            update: 5,
+            ..Default::default()
+        };
+        let neighbor = BgpNeighborStatus {
+            messages: Some(BgpMessages {
+                received: Some(counters),
+                sent: Some(BgpMessageCounters {
+                    open: 1,
+                    update: 2,
+                    ..Default::default()
+                }),
+            }),
+            ..Default::default()
+        };
+        let status = GetDataplaneStatusResponse {
+            bgp: Some(BgpStatus {
+                vrfs: [(
+                    "default".to_string(),
+                    BgpVrfStatus {
+                        neighbors: [("10.0.0.1".to_string(), neighbor)].into(),
+                    },
+                )]
+                .into(),
+            }),
+            ..Default::default()
+        };
+        let out = render_status(&status, TableOptions::default());
+        let row = out
+            .lines()
+            .find(|line| line.starts_with("default"))
+            .unwrap();
+        let columns: Vec<&str> = row.split_whitespace().collect();
+        assert_eq!(columns[6..8], [u64::MAX.to_string().as_str(), "3"], "{out}");
+    }
+}
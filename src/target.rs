@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! gRPC targets given as `tcp://host:port` or `unix://name`, as accepted by
+//! `gwtestctl`.
+//!
+//! An empty host means all interfaces when listening and the local host when
+//! connecting, so `tcp://:5123` works for both sides, and port 0 (or an empty
+//! port, as in `tcp://:`) listens on a random port.
+//!
+//! Like the Go `gwtestctl` client, `unix://name` is the Linux abstract socket
+//! `name` (gRPC's `unix-abstract:name`), which is where the dataplane listens.
+//! The name is taken verbatim after the scheme, so `unix:///tmp/gateway.sock`
+//! is the abstract socket `/tmp/gateway.sock`, not a file.
+
+use crate::{ConfigService, ConfigServiceClient, ConfigServiceServer};
+use hyper_util::rt::TokioIo;
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::net::{TcpListener, UnixListener, UnixStream};
+use tokio_stream::wrappers::{TcpListenerStream, UnixListenerStream};
+use tonic::transport::{Channel, Endpoint, Server, Uri};
+
+const TCP_SCHEME: &str = "tcp://";
+const UNIX_SCHEME: &str = "unix://";
+
+#[derive(thiserror::Error, Debug)]
+pub enum TargetError {
+    #[error("Invalid target {0:?}, expected tcp://host:port or unix://name")]
+    InvalidTarget(String),
+    #[error("Invalid endpoint for {0}: {1}")]
+    InvalidEndpoint(Target, #[source] tonic::transport::Error),
+    #[error("Failed to connect to {0}: {1}")]
+    Connect(Target, #[source] tonic::transport::Error),
+    #[error("Timed out connecting to {0}")]
+    Timeout(Target),
+    #[error("Failed to listen on {0}: {1}")]
+    Listen(Target, #[source] io::Error),
+}
+
+/// Where a config service is served.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// `host:port`, the host may be empty.
+    Tcp(String),
+    /// Name in the Linux abstract socket namespace, without the leading NUL.
+    Unix(String),
+}
+
+impl FromStr for Target {
+    type Err = TargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(addr) = s.strip_prefix(TCP_SCHEME) {
+            match addr.rsplit_once(':') {
+                // Like Go's net.Listen, an empty port is a random one
+                Some((host, "")) => return Ok(Self::Tcp(format!("{host}:0"))),
+                Some((_, port)) if port.parse::<u16>().is_ok() => {
+                    return Ok(Self::Tcp(addr.to_string()));
+                }
+                _ => {}
+            }
+        } else if let Some(name) = s.strip_prefix(UNIX_SCHEME)
+            && !name.is_empty()
+        {
+            return Ok(Self::Unix(name.to_string()));
+        }
+        Err(TargetError::InvalidTarget(s.to_string()))
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp(addr) => write!(f, "{TCP_SCHEME}{addr}"),
+            Self::Unix(name) => write!(f, "{UNIX_SCHEME}{name}"),
+        }
+    }
+}
+
+impl Target {
+    /// Path to bind or connect to for Unix sockets.
+    fn unix_path(&self) -> Option<String> {
+        match self {
+            Self::Tcp(_) => None,
+            // tokio maps a leading NUL to the abstract namespace
+            Self::Unix(name) => Some(format!("\0{name}")),
+        }
+    }
+
+    /// Connect a client to the target. `timeout` applies to establishing the
+    /// connection and to every RPC made with the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection can't be established in time.
+    pub async fn connect(
+        &self,
+        timeout: Duration,
+    ) -> Result<ConfigServiceClient<Channel>, TargetError> {
+        let uri = match self {
+            Self::Tcp(addr) if addr.starts_with(':') => format!("http://127.0.0.1{addr}"),
+            Self::Tcp(addr) => format!("http://{addr}"),
+            // Not used to connect, but the URI has to be valid
+            Self::Unix(_) => "http://localhost".to_string(),
+        };
+        let endpoint = Endpoint::from_shared(uri)
+            .map_err(|e| TargetError::InvalidEndpoint(self.clone(), e))?
+            .connect_timeout(timeout)
+            .timeout(timeout);
+        let connect = async {
+            match self.unix_path() {
+                None => endpoint.connect().await,
+                Some(path) => {
+                    endpoint
+                        .connect_with_connector(tower::service_fn(move |_: Uri| {
+                            let path = path.clone();
+                            async move {
+                                Ok::<_, io::Error>(TokioIo::new(UnixStream::connect(path).await?))
+                            }
+                        }))
+                        .await
+                }
+            }
+        };
+        let channel = tokio::time::timeout(timeout, connect)
+            .await
+            .map_err(|_| TargetError::Timeout(self.clone()))?
+            .map_err(|e| TargetError::Connect(self.clone(), e))?;
+        Ok(ConfigServiceClient::new(channel))
+    }
+
+    /// Start listening on the target.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address can't be bound.
+    pub async fn listen(&self) -> Result<Listener, TargetError> {
+        let err = |e| TargetError::Listen(self.clone(), e);
+        match self {
+            Self::Tcp(addr) => {
+                let addr = if addr.starts_with(':') {
+                    format!("0.0.0.0{addr}")
+                } else {
+                    addr.clone()
+                };
+                let listener = TcpListener::bind(addr).await.map_err(err)?;
+                Ok(Listener::Tcp(listener))
+            }
+            Self::Unix(name) => {
+                let listener = UnixListener::bind(format!("\0{name}")).map_err(err)?;
+                Ok(Listener::Unix(listener, self.clone()))
+            }
+        }
+    }
+}
+
+/// Bound listener for a [`Target`], ready to serve a [`ConfigService`].
+#[derive(Debug)]
+pub enum Listener {
+    Tcp(TcpListener),
+    /// Listener on a [`Target::Unix`].
+    Unix(UnixListener, Target),
+}
+
+impl Listener {
+    /// Target clients can connect to, with the actual port if a random one was
+    /// requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local address of the socket can't be read.
+    pub fn local_target(&self) -> io::Result<Target> {
+        Ok(match self {
+            Self::Tcp(listener) => Target::Tcp(listener.local_addr()?.to_string()),
+            Self::Unix(_, target) => target.clone(),
+        })
+    }
+
+    /// Serve `service` until `shutdown` completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server fails.
+    pub async fn serve<S: ConfigService>(
+        self,
+        service: S,
+        shutdown: impl Future<Output = ()>,
+    ) -> Result<(), tonic::transport::Error> {
+        let router = Server::builder().add_service(ConfigServiceServer::new(service));
+        match self {
+            Self::Tcp(listener) => {
+                router
+                    .serve_with_incoming_shutdown(TcpListenerStream::new(listener), shutdown)
+                    .await
+            }
+            Self::Unix(listener, _) => {
+                router
+                    .serve_with_incoming_shutdown(UnixListenerStream::new(listener), shutdown)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for (s, target) in [
+            ("tcp://localhost:5123", Target::Tcp("localhost:5123".into())),
+            ("tcp://:5123", Target::Tcp(":5123".into())),
+            ("tcp://:0", Target::Tcp(":0".into())),
+            ("tcp://[::1]:5123", Target::Tcp("[::1]:5123".into())),
+            (
+                "unix:///tmp/gateway.sock",
+                Target::Unix("/tmp/gateway.sock".into()),
+            ),
+            (
+                "unix://tmp/gateway.sock",
+                Target::Unix("tmp/gateway.sock".into()),
+            ),
+        ] {
+            assert_eq!(s.parse::<Target>().unwrap(), target);
+            assert_eq!(target.to_string(), s);
+        }
+        // An empty port is a random one
+        for (s, target) in [
+            ("tcp://:", Target::Tcp(":0".into())),
+            ("tcp://localhost:", Target::Tcp("localhost:0".into())),
+        ] {
+            assert_eq!(s.parse::<Target>().unwrap(), target);
+        }
+        for s in [
+            "",
+            "localhost:5123",
+            "http://localhost:5123",
+            "tcp://localhost",
+            "tcp://localhost:http",
+            "tcp://:70000",
+            "unix://",
+            "unix-abstract:gateway.sock",
+        ] {
+            assert!(
+                matches!(s.parse::<Target>(), Err(TargetError::InvalidTarget(_))),
+                "{s}"
+            );
+        }
+    }
+}
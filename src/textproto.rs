@@ -0,0 +1,469 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! [Text format](https://protobuf.dev/reference/protobuf/textformat-spec/)
+//! encoding of messages, for test fixtures and human-readable diffs.
+//!
+//! [`to_textproto`] prints fields in field number order, map entries sorted by
+//! key and one field per line, so the output of equal messages is always the
+//! same and small changes give small diffs. Fields use their proto names,
+//! enums their value names and `google.protobuf.Duration` and `Timestamp` are
+//! printed as messages with `seconds` and `nanos`, like the Go and C++ printers
+//! do.
+//!
+//! [`from_textproto`] accepts any valid text format, including the `[..]`
+//! list syntax, and reports the line and column of parse errors.
+
+use crate::reflect::{self, ReflectError};
+use crate::yaml::Position;
+use prost::{Message, Name};
+use prost_reflect::{DynamicMessage, FieldDescriptor, Kind, MapKey, Value};
+use std::fmt::Write;
+
+#[derive(thiserror::Error, Debug)]
+pub enum TextprotoError {
+    #[error("{0}")]
+    Reflect(#[from] ReflectError),
+    #[error("Invalid textproto at {position}: {message}")]
+    Parse { position: Position, message: String },
+}
+
+impl TextprotoError {
+    /// Where in the input the error was found, if known.
+    #[must_use]
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            Self::Reflect(_) => None,
+            Self::Parse { position, .. } => Some(*position),
+        }
+    }
+}
+
+/// Encode `msg` in the text format with a stable field order.
+///
+/// # Errors
+///
+/// Returns an error if the message can't be encoded.
+pub fn to_textproto<M: Message + Name>(msg: &M) -> Result<String, TextprotoError> {
+    let dynamic = reflect::to_dynamic(msg)?;
+    let mut printer = Printer::default();
+    printer.fields(&dynamic);
+    Ok(printer.out)
+}
+
+/// Decode the text format into `M`. Unknown fields are rejected.
+///
+/// # Errors
+///
+/// Returns an error if `text` is not a valid text format encoding of `M`.
+pub fn from_textproto<M: Message + Name + Default>(text: &str) -> Result<M, TextprotoError> {
+    let dynamic = DynamicMessage::parse_text_format(reflect::message_descriptor::<M>()?, text)
+        .map_err(|err| TextprotoError::Parse {
+            position: error_position(&err, text),
+            message: err.to_string(),
+        })?;
+    Ok(reflect::from_dynamic(&dynamic)?)
+}
+
+/// Position of the first label of `err`, or the end of the input if the
+/// error has none (which is the case for unexpected end of input).
+fn error_position(err: &prost_reflect::text_format::ParseError, text: &str) -> Position {
+    let offset = miette::Diagnostic::labels(err)
+        .and_then(|mut labels| labels.next())
+        .map_or(text.len(), |label| label.offset());
+    position_at(text, offset)
+}
+
+fn position_at(text: &str, offset: usize) -> Position {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+    }
+}
+
+#[derive(Default)]
+struct Printer {
+    out: String,
+    depth: usize,
+}
+
+impl Printer {
+    fn indent(&mut self) {
+        for _ in 0..self.depth {
+            self.out.push_str("  ");
+        }
+    }
+
+    fn fields(&mut self, msg: &DynamicMessage) {
+        let mut fields: Vec<_> = msg.fields().collect();
+        fields.sort_by_key(|(field, _)| field.number());
+        for (field, value) in fields {
+            match value {
+                Value::List(items) => {
+                    for item in items {
+                        self.field(&field, item);
+                    }
+                }
+                Value::Map(entries) => {
+                    let Kind::Message(entry) = field.kind() else {
+                        unreachable!("map field {} without entry type", field.full_name());
+                    };
+                    let key_field = entry.map_entry_key_field();
+                    let value_field = entry.map_entry_value_field();
+                    let mut entries: Vec<_> = entries.iter().collect();
+                    entries.sort_by_key(|(key, _)| *key);
+                    for (key, value) in entries {
+                        self.open(field.name());
+                        self.field(&key_field, &map_key_value(key));
+                        self.field(&value_field, value);
+                        self.close();
+                    }
+                }
+                value => self.field(&field, value),
+            }
+        }
+    }
+
+    fn open(&mut self, name: &str) {
+        self.indent();
+        let _ = writeln!(self.out, "{name} {{");
+        self.depth += 1;
+    }
+
+    fn close(&mut self) {
+        self.depth -= 1;
+        self.indent();
+        self.out.push_str("}\n");
+    }
+
+    fn field(&mut self, field: &FieldDescriptor, value: &Value) {
+        if let Value::Message(msg) = value {
+            if msg.fields().next().is_none() {
+                self.indent();
+                let _ = writeln!(self.out, "{} {{}}", field.name());
+            } else {
+                self.open(field.name());
+                self.fields(msg);
+                self.close();
+            }
+            return;
+        }
+        self.indent();
+        let _ = write!(self.out, "{}: ", field.name());
+        match value {
+            Value::Bool(v) => {
+                let _ = write!(self.out, "{v}");
+            }
+            Value::I32(v) => {
+                let _ = write!(self.out, "{v}");
+            }
+            Value::I64(v) => {
+                let _ = write!(self.out, "{v}");
+            }
+            Value::U32(v) => {
+                let _ = write!(self.out, "{v}");
+            }
+            Value::U64(v) => {
+                let _ = write!(self.out, "{v}");
+            }
+            Value::F32(v) => write_float(&mut self.out, f64::from(*v)),
+            Value::F64(v) => write_float(&mut self.out, *v),
+            Value::String(v) => write_string(&mut self.out, v.as_bytes()),
+            Value::Bytes(v) => write_string(&mut self.out, v),
+            Value::EnumNumber(number) => {
+                let name = match field.kind() {
+                    Kind::Enum(desc) => desc.get_value(*number).map(|v| v.name().to_string()),
+                    _ => None,
+                };
+                let _ = write!(self.out, "{}", name.unwrap_or_else(|| number.to_string()));
+            }
+            Value::Message(_) | Value::List(_) | Value::Map(_) => {
+                unreachable!("nested value for field {}", field.full_name())
+            }
+        }
+        self.out.push('\n');
+    }
+}
+
+fn map_key_value(key: &MapKey) -> Value {
+    match key {
+        MapKey::Bool(v) => Value::Bool(*v),
+        MapKey::I32(v) => Value::I32(*v),
+        MapKey::I64(v) => Value::I64(*v),
+        MapKey::U32(v) => Value::U32(*v),
+        MapKey::U64(v) => Value::U64(*v),
+        MapKey::String(v) => Value::String(v.clone()),
+    }
+}
+
+fn write_float(out: &mut String, v: f64) {
+    if v.is_nan() {
+        out.push_str("nan");
+    } else if v.is_infinite() {
+        out.push_str(if v > 0.0 { "inf" } else { "-inf" });
+    } else {
+        let _ = write!(out, "{v:?}");
+    }
+}
+
+/// Quote `bytes`, keeping valid UTF-8 as is and escaping everything else.
+fn write_string(out: &mut String, bytes: &[u8]) {
+    out.push('"');
+    for chunk in bytes.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            match c {
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                '"' => out.push_str("\\\""),
+                '\'' => out.push_str("\\'"),
+                '\\' => out.push_str("\\\\"),
+                c if c.is_ascii_control() => {
+                    let _ = write!(out, "\\{:03o}", u32::from(c));
+                }
+                c => out.push(c),
+            }
+        }
+        for b in chunk.invalid() {
+            let _ = write!(out, "\\{b:03o}");
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        Expose, GatewayConfig, GetDataplaneStatusResponse, IfType, Interface, PeeringIPs,
+        PeeringStatefulNat, VpcCounters, expose, peering_i_ps,
+    };
+    use crate::google::protobuf::Duration;
+
+    #[test]
+    fn test_scalars() {
+        let iface = Interface {
+            name: "eth0".into(),
+            r#type: IfType::Vtep.into(),
+            system_name: Some(String::new()),
+            mtu: Some(9000),
+            ..Default::default()
+        };
+        assert_eq!(
+            to_textproto(&iface).unwrap(),
+            "name: \"eth0\"\ntype: IF_TYPE_VTEP\nsystem_name: \"\"\nmtu: 9000\n"
+        );
+
+        let iface = Interface {
+            r#type: 42,
+            ..Default::default()
+        };
+        assert_eq!(to_textproto(&iface).unwrap(), "type: 42\n");
+        assert_eq!(from_textproto::<Interface>("type: 42").unwrap(), iface);
+    }
+
+    #[test]
+    fn test_strings() {
+        let mut out = String::new();
+        write_string(&mut out, "a\"b'c\\d\n\t\r\u{1}é".as_bytes());
+        assert_eq!(out, r#""a\"b\'c\\d\n\t\r\001é""#);
+
+        let mut out = String::new();
+        write_string(&mut out, b"\xff\x00x");
+        assert_eq!(out, r#""\377\000x""#);
+
+        let iface = Interface {
+            name: "tab\there \"quoted\" \u{7f} ünïcode".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            from_textproto::<Interface>(&to_textproto(&iface).unwrap()).unwrap(),
+            iface
+        );
+    }
+
+    #[test]
+    fn test_floats() {
+        let status = GetDataplaneStatusResponse {
+            vpc_peering_counters: [(
+                "p".to_string(),
+                crate::config::VpcPeeringCounters {
+                    pps: 1.0,
+                    bps: f64::INFINITY,
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            ..Default::default()
+        };
+        let text = to_textproto(&status).unwrap();
+        assert!(text.contains("    pps: 1.0\n"), "{text}");
+        assert!(text.contains("    bps: inf\n"), "{text}");
+        assert_eq!(
+            from_textproto::<GetDataplaneStatusResponse>(&text).unwrap(),
+            status
+        );
+
+        let mut out = String::new();
+        write_float(&mut out, f64::NAN);
+        write_float(&mut out, f64::NEG_INFINITY);
+        write_float(&mut out, 1e300);
+        assert_eq!(out, "nan-inf1e300");
+    }
+
+    #[test]
+    fn test_maps_sorted() {
+        let config = GatewayConfig {
+            communities: [
+                (10, "65000:10".to_string()),
+                (2, "65000:2".to_string()),
+                (0, "65000:0".to_string()),
+            ]
+            .into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            to_textproto(&config).unwrap(),
+            "communities {\n  key: 0\n  value: \"65000:0\"\n}\n\
+             communities {\n  key: 2\n  value: \"65000:2\"\n}\n\
+             communities {\n  key: 10\n  value: \"65000:10\"\n}\n"
+        );
+
+        let status = GetDataplaneStatusResponse {
+            vpc_counters: [
+                ("b".to_string(), VpcCounters::default()),
+                ("a".to_string(), VpcCounters::default()),
+            ]
+            .into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            to_textproto(&status).unwrap(),
+            "vpc_counters {\n  key: \"a\"\n  value {}\n}\n\
+             vpc_counters {\n  key: \"b\"\n  value {}\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_oneofs_and_duration() {
+        let expose = Expose {
+            ips: vec![
+                PeeringIPs {
+                    rule: Some(peering_i_ps::Rule::Cidr("10.0.0.0/16".into())),
+                },
+                PeeringIPs {
+                    rule: Some(peering_i_ps::Rule::Not("10.0.0.0/24".into())),
+                },
+            ],
+            r#as: vec![],
+            nat: Some(expose::Nat::Stateful(PeeringStatefulNat {
+                idle_timeout: Some(Duration {
+                    seconds: 30,
+                    nanos: 500_000_000,
+                }),
+            })),
+        };
+        let text = to_textproto(&expose).unwrap();
+        assert_eq!(
+            text,
+            "ips {\n  cidr: \"10.0.0.0/16\"\n}\n\
+             ips {\n  not: \"10.0.0.0/24\"\n}\n\
+             stateful {\n  idleTimeout {\n    seconds: 30\n    nanos: 500000000\n  }\n}\n"
+        );
+        assert_eq!(from_textproto::<Expose>(&text).unwrap(), expose);
+
+        let err = from_textproto::<Expose>("stateful {}\nstateless {}\n").unwrap_err();
+        assert_eq!(
+            err.position(),
+            Some(Position {
+                line: 2,
+                column: 11
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let config: GatewayConfig = from_textproto(
+            "# comment\ngeneration: 7\ngw_groups: [{name: 'g'}, {name: \"h\"}]\n\
+             communities: [{key: 1 value: \"65000:1\"}]",
+        )
+        .unwrap();
+        assert_eq!(config.generation, 7);
+        assert_eq!(config.gw_groups.len(), 2);
+        assert_eq!(config.gw_groups[1].name, "h");
+        assert_eq!(config.communities[&1], "65000:1");
+    }
+
+    #[test]
+    fn test_error_positions() {
+        let err = from_textproto::<GatewayConfig>("generation: 1\n  bogus: 2\n").unwrap_err();
+        assert_eq!(err.position(), Some(Position { line: 2, column: 3 }));
+        assert!(err.to_string().contains("bogus"), "{err}");
+        assert!(
+            err.to_string()
+                .starts_with("Invalid textproto at line 2, column 3")
+        );
+
+        let err = from_textproto::<GatewayConfig>("generation: 1\ngeneration: 2").unwrap_err();
+        assert_eq!(
+            err.position(),
+            Some(Position {
+                line: 2,
+                column: 13
+            })
+        );
+
+        let err = from_textproto::<Interface>("name: \"é\" type: NOPE").unwrap_err();
+        assert_eq!(
+            err.position(),
+            Some(Position {
+                line: 1,
+                column: 17
+            })
+        );
+
+        let err = from_textproto::<GatewayConfig>("overlay {\n  vpcs {\n").unwrap_err();
+        assert_eq!(err.position(), Some(Position { line: 3, column: 1 }));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let config = GatewayConfig {
+            generation: i64::MIN,
+            communities: [(1, "65000:1".to_string()), (2, "65000:2".to_string())].into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            from_textproto::<GatewayConfig>(&to_textproto(&config).unwrap()).unwrap(),
+            config
+        );
+        assert_eq!(to_textproto(&GatewayConfig::default()).unwrap(), "");
+        assert_eq!(
+            from_textproto::<GatewayConfig>("").unwrap(),
+            GatewayConfig::default()
+        );
+    }
+
+    #[cfg(feature = "bolero")]
+    #[test]
+    fn test_round_trip_bolero() {
+        bolero::check!()
+            .with_type::<GatewayConfig>()
+            .for_each(|config| {
+                let text = to_textproto(config).unwrap();
+                assert_eq!(&from_textproto::<GatewayConfig>(&text).unwrap(), config);
+            });
+        bolero::check!()
+            .with_type::<GetDataplaneStatusResponse>()
+            .for_each(|status| {
+                let text = to_textproto(status).unwrap();
+                assert_eq!(
+                    &from_textproto::<GetDataplaneStatusResponse>(&text).unwrap(),
+                    status
+                );
+            });
+    }
+}
@@ -0,0 +1,1557 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+//! YAML encoding of messages, compatible with Go `pkg/protoyaml`.
+//!
+//! The Go tools (e.g. `gwtestctl`) read and write YAML by converting
+//! [protojson](crate::json) with `sigs.k8s.io/yaml`, so documents use the
+//! protojson mapping. [`to_yaml`] produces the same bytes as Go
+//! `protoyaml.MarshalYAML` (sorted keys, YAML 1.1 quoting, 80 column folding)
+//! and [`from_yaml`] accepts the same documents as `protoyaml.UnmarshalYAML`.
+//! Like the Go version, decoding is strict: duplicate keys and unknown fields
+//! are rejected, and the error reports where they are in the document.
+//!
+//! The golden files in `tests/testdata` are also round-tripped through the Go
+//! version by the `pkg/protoyaml` tests, so both sides are held to the same
+//! bytes.
+
+use crate::json::{self, JsonError};
+use crate::reflect::{self, ReflectError};
+use prost::{Message, Name};
+use prost_reflect::{FieldDescriptor, Kind, MessageDescriptor};
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser, Tag};
+use yaml_rust2::scanner::{Marker, TScalarStyle};
+
+/// Location in a YAML document. Both line and column start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl From<Marker> for Position {
+    fn from(mark: Marker) -> Self {
+        Self {
+            line: mark.line(),
+            column: mark.col() + 1,
+        }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum YamlError {
+    #[error("{0}")]
+    Json(#[from] JsonError),
+    #[error("{0}")]
+    Reflect(#[from] ReflectError),
+    #[error("Invalid YAML at {position}: {message}")]
+    Syntax { position: Position, message: String },
+    #[error("Duplicate key {key:?} at {position}")]
+    DuplicateKey { position: Position, key: String },
+    #[error("Unknown field {field:?} for {message} at {position}")]
+    UnknownField {
+        position: Position,
+        field: String,
+        message: String,
+    },
+    #[error("Invalid value at {position}: {message}")]
+    InvalidValue { position: Position, message: String },
+}
+
+impl YamlError {
+    /// Where in the document the error was found, if known.
+    #[must_use]
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            Self::Json(_) | Self::Reflect(_) => None,
+            Self::Syntax { position, .. }
+            | Self::DuplicateKey { position, .. }
+            | Self::UnknownField { position, .. }
+            | Self::InvalidValue { position, .. } => Some(*position),
+        }
+    }
+
+    fn invalid(mark: Marker, message: impl Into<String>) -> Self {
+        Self::InvalidValue {
+            position: mark.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Encode `msg` as YAML, byte-for-byte like Go `protoyaml.MarshalYAML`.
+///
+/// # Errors
+///
+/// Returns an error if the message can't be encoded.
+pub fn to_yaml<M: Message + Name>(msg: &M) -> Result<String, YamlError> {
+    let value = json::to_json_value(msg)?;
+    let mut emitter = Emitter::default();
+    emitter.node(&value, None, Context::default());
+    emitter.write_indent(0);
+    Ok(emitter.out)
+}
+
+/// Decode YAML into `M` like Go `protoyaml.UnmarshalYAML`.
+///
+/// Only the first document is read. Duplicate keys and unknown fields are
+/// rejected.
+///
+/// # Errors
+///
+/// Returns an error if `yaml` is not a valid YAML encoding of `M`.
+pub fn from_yaml<M: Message + Name + Default>(yaml: &str) -> Result<M, YamlError> {
+    let value = match load(yaml)? {
+        Some(root) => convert(&root, &Target::message(reflect::message_descriptor::<M>()?))?,
+        None => Value::Null,
+    };
+    Ok(json::from_json_value(value)?)
+}
+
+// ---------------------------------------------------------------------------
+// Loading
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+enum Node {
+    Scalar {
+        value: String,
+        style: TScalarStyle,
+        tag: Option<Tag>,
+        mark: Marker,
+    },
+    Sequence {
+        items: Vec<Node>,
+        mark: Marker,
+    },
+    Mapping {
+        entries: Vec<(Node, Node)>,
+        mark: Marker,
+    },
+}
+
+impl Node {
+    fn mark(&self) -> Marker {
+        match self {
+            Self::Scalar { mark, .. }
+            | Self::Sequence { mark, .. }
+            | Self::Mapping { mark, .. } => *mark,
+        }
+    }
+
+    fn is_merge_key(&self) -> bool {
+        match self {
+            Self::Scalar {
+                value, style, tag, ..
+            } => match tag {
+                Some(tag) => tag.handle == YAML_TAG_PREFIX && tag.suffix == "merge",
+                None => *style == TScalarStyle::Plain && value == "<<",
+            },
+            _ => false,
+        }
+    }
+}
+
+enum Partial {
+    Sequence(Vec<Node>, Marker, usize),
+    Mapping(Vec<(Node, Node)>, Option<Node>, Marker, usize),
+}
+
+/// Builds a [`Node`] tree of the first document, resolving aliases.
+#[derive(Default)]
+struct Loader {
+    stack: Vec<Partial>,
+    anchors: HashMap<usize, Node>,
+    root: Option<Node>,
+    error: Option<YamlError>,
+}
+
+impl Loader {
+    fn complete(&mut self, node: Node, anchor: usize) {
+        if anchor != 0 {
+            self.anchors.insert(anchor, node.clone());
+        }
+        match self.stack.last_mut() {
+            Some(Partial::Sequence(items, ..)) => items.push(node),
+            Some(Partial::Mapping(entries, key, ..)) => match key.take() {
+                Some(key) => entries.push((key, node)),
+                None => *key = Some(node),
+            },
+            None => {
+                if self.root.is_none() {
+                    self.root = Some(node);
+                }
+            }
+        }
+    }
+}
+
+impl MarkedEventReceiver for Loader {
+    fn on_event(&mut self, ev: Event, mark: Marker) {
+        if self.error.is_some() {
+            return;
+        }
+        match ev {
+            Event::Scalar(value, style, anchor, tag) => {
+                let node = Node::Scalar {
+                    value,
+                    style,
+                    tag,
+                    mark,
+                };
+                self.complete(node, anchor);
+            }
+            Event::Alias(anchor) => match self.anchors.get(&anchor) {
+                Some(node) => self.complete(node.clone(), 0),
+                None => {
+                    self.error = Some(YamlError::invalid(mark, "anchor value contains itself"));
+                }
+            },
+            Event::SequenceStart(anchor, _) => {
+                self.stack.push(Partial::Sequence(Vec::new(), mark, anchor));
+            }
+            Event::MappingStart(anchor, _) => {
+                self.stack
+                    .push(Partial::Mapping(Vec::new(), None, mark, anchor));
+            }
+            Event::SequenceEnd | Event::MappingEnd => match self.stack.pop() {
+                Some(Partial::Sequence(items, mark, anchor)) => {
+                    self.complete(Node::Sequence { items, mark }, anchor);
+                }
+                Some(Partial::Mapping(entries, _, mark, anchor)) => {
+                    self.complete(Node::Mapping { entries, mark }, anchor);
+                }
+                None => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+fn load(yaml: &str) -> Result<Option<Node>, YamlError> {
+    let mut loader = Loader::default();
+    Parser::new_from_str(yaml)
+        .load(&mut loader, false)
+        .map_err(|e| YamlError::Syntax {
+            position: (*e.marker()).into(),
+            message: e.info().to_string(),
+        })?;
+    match loader.error {
+        Some(err) => Err(err),
+        None => Ok(loader.root),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// YAML to JSON
+// ---------------------------------------------------------------------------
+
+const YAML_TAG_PREFIX: &str = "tag:yaml.org,2002:";
+
+/// Scalar value as resolved by go-yaml v2 (YAML 1.1 core schema).
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Scalar {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Str,
+}
+
+impl Scalar {
+    fn tag(&self) -> &'static str {
+        match self {
+            Self::Null => "!!null",
+            Self::Bool(_) => "!!bool",
+            Self::Int(_) | Self::Uint(_) => "!!int",
+            Self::Float(_) => "!!float",
+            Self::Str => "!!str",
+        }
+    }
+}
+
+/// Resolve an untagged plain scalar.
+fn resolve(value: &str) -> Scalar {
+    match value {
+        "y" | "Y" | "yes" | "Yes" | "YES" | "true" | "True" | "TRUE" | "on" | "On" | "ON" => {
+            return Scalar::Bool(true);
+        }
+        "n" | "N" | "no" | "No" | "NO" | "false" | "False" | "FALSE" | "off" | "Off" | "OFF" => {
+            return Scalar::Bool(false);
+        }
+        "" | "~" | "null" | "Null" | "NULL" => return Scalar::Null,
+        ".nan" | ".NaN" | ".NAN" => return Scalar::Float(f64::NAN),
+        ".inf" | ".Inf" | ".INF" | "+.inf" | "+.Inf" | "+.INF" => {
+            return Scalar::Float(f64::INFINITY);
+        }
+        "-.inf" | "-.Inf" | "-.INF" => return Scalar::Float(f64::NEG_INFINITY),
+        _ => {}
+    }
+    match value.as_bytes()[0] {
+        b'.' => {
+            if let Some(f) = parse_float(value) {
+                return Scalar::Float(f);
+            }
+        }
+        b'+' | b'-' | b'0'..=b'9' => {
+            let plain = value.replace('_', "");
+            if let Some(int) = parse_int(&plain) {
+                return int;
+            }
+            if is_yaml_float(&plain) {
+                if let Some(f) = parse_float(&plain) {
+                    return Scalar::Float(f);
+                }
+            }
+        }
+        _ => {}
+    }
+    Scalar::Str
+}
+
+fn resolve_node(value: &str, style: TScalarStyle, tag: Option<&Tag>) -> Result<Scalar, String> {
+    let Some(tag) = tag else {
+        return Ok(if style == TScalarStyle::Plain {
+            resolve(value)
+        } else {
+            Scalar::Str
+        });
+    };
+    if tag.handle != YAML_TAG_PREFIX {
+        return Ok(Scalar::Str);
+    }
+    let resolved = resolve(value);
+    match (tag.suffix.as_str(), &resolved) {
+        ("int", Scalar::Int(_) | Scalar::Uint(_))
+        | ("float", Scalar::Float(_))
+        | ("bool", Scalar::Bool(_))
+        | ("null", Scalar::Null) => Ok(resolved),
+        #[allow(clippy::cast_precision_loss)]
+        ("float", Scalar::Int(i)) => Ok(Scalar::Float(*i as f64)),
+        #[allow(clippy::cast_precision_loss)]
+        ("float", Scalar::Uint(u)) => Ok(Scalar::Float(*u as f64)),
+        ("int" | "float" | "bool" | "null" | "binary", _) => Err(format!(
+            "cannot decode {} `{value}` as a !!{}",
+            resolved.tag(),
+            tag.suffix
+        )),
+        _ => Ok(Scalar::Str),
+    }
+}
+
+/// Go `strconv.ParseInt(s, 0, 64)`, falling back to `ParseUint`.
+fn parse_int(s: &str) -> Option<Scalar> {
+    let (sign, unsigned) = match s.as_bytes().first()? {
+        b'-' => (Some(true), &s[1..]),
+        b'+' => (Some(false), &s[1..]),
+        _ => (None, s),
+    };
+    let prefixed = |p: &str| {
+        unsigned
+            .strip_prefix(p)
+            .or_else(|| unsigned.strip_prefix(&p.to_uppercase()))
+    };
+    let (radix, digits) = if let Some(digits) = prefixed("0x") {
+        (16, digits)
+    } else if let Some(digits) = prefixed("0b") {
+        (2, digits)
+    } else if let Some(digits) = prefixed("0o") {
+        (8, digits)
+    } else if unsigned.len() > 1 && unsigned.starts_with('0') {
+        (8, &unsigned[1..])
+    } else {
+        (10, unsigned)
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    let magnitude = u128::from_str_radix(digits, radix).ok()?;
+    if sign == Some(true) {
+        let value = 0i128.checked_sub(i128::try_from(magnitude).ok()?)?;
+        return i64::try_from(value).ok().map(Scalar::Int);
+    }
+    if let Ok(value) = i64::try_from(magnitude) {
+        return Some(Scalar::Int(value));
+    }
+    if sign.is_none() {
+        return u64::try_from(magnitude).ok().map(Scalar::Uint);
+    }
+    None
+}
+
+/// Go `strconv.ParseFloat(s, 64)` for strings that look like YAML floats.
+fn parse_float(s: &str) -> Option<f64> {
+    s.parse::<f64>().ok().filter(|f| f.is_finite())
+}
+
+/// `^[-+]?(\.[0-9]+|[0-9]+(\.[0-9]*)?)([eE][-+]?[0-9]+)?$`
+fn is_yaml_float(s: &str) -> bool {
+    fn digits(s: &[u8]) -> usize {
+        s.iter().take_while(|c| c.is_ascii_digit()).count()
+    }
+    let mut s = s.as_bytes();
+    if let [b'-' | b'+', rest @ ..] = s {
+        s = rest;
+    }
+    if let [b'.', rest @ ..] = s {
+        let n = digits(rest);
+        if n == 0 {
+            return false;
+        }
+        s = &rest[n..];
+    } else {
+        let n = digits(s);
+        if n == 0 {
+            return false;
+        }
+        s = &s[n..];
+        if let [b'.', rest @ ..] = s {
+            s = &rest[digits(rest)..];
+        }
+    }
+    if let [b'e' | b'E', rest @ ..] = s {
+        s = rest;
+        if let [b'-' | b'+', rest @ ..] = s {
+            s = rest;
+        }
+        let n = digits(s);
+        if n == 0 {
+            return false;
+        }
+        s = &s[n..];
+    }
+    s.is_empty()
+}
+
+/// `^[-+]?[0-9][0-9_]*(?::[0-5]?[0-9])+(?:\.[0-9_]*)?$`
+///
+/// Base 60 floats are not resolved, but go-yaml quotes them on output.
+fn is_base60_float(s: &str) -> bool {
+    let mut s = s.as_bytes();
+    if let [b'-' | b'+', rest @ ..] = s {
+        s = rest;
+    }
+    let [b'0'..=b'9', rest @ ..] = s else {
+        return false;
+    };
+    s = rest;
+    s = &s[s
+        .iter()
+        .take_while(|c| c.is_ascii_digit() || **c == b'_')
+        .count()..];
+    let mut groups = 0;
+    while let [b':', rest @ ..] = s {
+        s = match rest {
+            [b'0'..=b'5', b'0'..=b'9', rest @ ..] | [b'0'..=b'9', rest @ ..] => rest,
+            _ => return false,
+        };
+        groups += 1;
+    }
+    if let [b'.', rest @ ..] = s {
+        s = &rest[rest
+            .iter()
+            .take_while(|c| c.is_ascii_digit() || **c == b'_')
+            .count()..];
+    }
+    groups > 0 && s.is_empty()
+}
+
+/// Whether go-yaml would decode `s` as a timestamp if left unquoted.
+fn is_timestamp(s: &str) -> bool {
+    fn number(s: &mut &[u8], min: usize, max: usize) -> Option<u32> {
+        let n = s.iter().take_while(|c| c.is_ascii_digit()).count();
+        if n < min || n > max {
+            return None;
+        }
+        let value = std::str::from_utf8(&s[..n]).ok()?.parse().ok()?;
+        *s = &s[n..];
+        Some(value)
+    }
+    fn expect(s: &mut &[u8], c: u8) -> Option<()> {
+        let (first, rest) = s.split_first()?;
+        (*first == c).then(|| *s = rest)
+    }
+    fn parse(mut s: &[u8]) -> Option<()> {
+        let s = &mut s;
+        let year = number(s, 4, 4)?;
+        expect(s, b'-')?;
+        let month = number(s, 1, 2)?;
+        expect(s, b'-')?;
+        let day = number(s, 1, 2)?;
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if day == 0 || day > days {
+            return None;
+        }
+        let Some((separator, rest)) = s.split_first() else {
+            return Some(());
+        };
+        *s = rest;
+        if number(s, 1, 2)? > 23 {
+            return None;
+        }
+        expect(s, b':')?;
+        if number(s, 1, 2)? > 59 {
+            return None;
+        }
+        expect(s, b':')?;
+        if number(s, 1, 2)? > 59 {
+            return None;
+        }
+        if expect(s, b'.').is_some() {
+            number(s, 1, 9)?;
+        }
+        match separator {
+            b' ' => s.is_empty().then_some(()),
+            b'T' | b't' => {
+                if expect(s, b'Z').is_none() {
+                    let (sign, rest) = s.split_first()?;
+                    if *sign != b'+' && *sign != b'-' {
+                        return None;
+                    }
+                    *s = rest;
+                    number(s, 2, 2)?;
+                    expect(s, b':')?;
+                    number(s, 2, 2)?;
+                }
+                s.is_empty().then_some(())
+            }
+            _ => None,
+        }
+    }
+    parse(s.as_bytes()).is_some()
+}
+
+/// What a YAML node is decoded into, used to find unknown fields.
+#[derive(Clone)]
+enum Target {
+    Any,
+    Message(MessageDescriptor),
+    List(Box<Target>),
+    Map(Box<Target>),
+}
+
+impl Target {
+    fn message(desc: MessageDescriptor) -> Self {
+        // Well-known types have special JSON representations.
+        if desc.package_name() == "google.protobuf" {
+            Self::Any
+        } else {
+            Self::Message(desc)
+        }
+    }
+
+    fn kind(kind: Kind) -> Self {
+        match kind {
+            Kind::Message(desc) => Self::message(desc),
+            _ => Self::Any,
+        }
+    }
+
+    fn field(field: &FieldDescriptor) -> Self {
+        match field.kind() {
+            Kind::Message(entry) if field.is_map() => {
+                Self::Map(Box::new(Self::kind(entry.map_entry_value_field().kind())))
+            }
+            kind if field.is_list() => Self::List(Box::new(Self::kind(kind))),
+            kind => Self::kind(kind),
+        }
+    }
+}
+
+fn convert(node: &Node, target: &Target) -> Result<Value, YamlError> {
+    match node {
+        Node::Scalar {
+            value,
+            style,
+            tag,
+            mark,
+        } => {
+            let scalar = resolve_node(value, *style, tag.as_ref())
+                .map_err(|e| YamlError::invalid(*mark, e))?;
+            scalar_to_json(scalar, value, *mark)
+        }
+        Node::Sequence { items, .. } => {
+            let target = match target {
+                Target::List(target) => target,
+                _ => &Target::Any,
+            };
+            items
+                .iter()
+                .map(|item| convert(item, target))
+                .collect::<Result<_, _>>()
+                .map(Value::Array)
+        }
+        Node::Mapping { entries, .. } => {
+            let mut map = Map::new();
+            convert_entries(entries, target, &mut map)?;
+            Ok(Value::Object(map))
+        }
+    }
+}
+
+fn convert_entries(
+    entries: &[(Node, Node)],
+    target: &Target,
+    map: &mut Map<String, Value>,
+) -> Result<(), YamlError> {
+    for (key, value) in entries {
+        if key.is_merge_key() {
+            merge(value, target, map)?;
+            continue;
+        }
+        let name = key_to_string(key)?;
+        let value_target = match target {
+            Target::Message(desc) => {
+                let field = desc
+                    .get_field_by_json_name(&name)
+                    .or_else(|| desc.get_field_by_name(&name))
+                    .ok_or_else(|| YamlError::UnknownField {
+                        position: key.mark().into(),
+                        field: name.clone(),
+                        message: desc.full_name().to_string(),
+                    })?;
+                Target::field(&field)
+            }
+            Target::Map(target) => (**target).clone(),
+            Target::Any | Target::List(_) => Target::Any,
+        };
+        let value = convert(value, &value_target)?;
+        if map.contains_key(&name) {
+            return Err(YamlError::DuplicateKey {
+                position: key.mark().into(),
+                key: name,
+            });
+        }
+        map.insert(name, value);
+    }
+    Ok(())
+}
+
+fn merge(node: &Node, target: &Target, map: &mut Map<String, Value>) -> Result<(), YamlError> {
+    let error = || {
+        YamlError::invalid(
+            node.mark(),
+            "map merge requires map or sequence of maps as the value",
+        )
+    };
+    match node {
+        Node::Mapping { entries, .. } => convert_entries(entries, target, map),
+        Node::Sequence { items, .. } => {
+            // Earlier maps take precedence, which go-yaml implements by
+            // merging them last.
+            for item in items.iter().rev() {
+                let Node::Mapping { entries, .. } = item else {
+                    return Err(error());
+                };
+                convert_entries(entries, target, map)?;
+            }
+            Ok(())
+        }
+        Node::Scalar { .. } => Err(error()),
+    }
+}
+
+fn key_to_string(key: &Node) -> Result<String, YamlError> {
+    let Node::Scalar {
+        value, style, tag, ..
+    } = key
+    else {
+        return Err(YamlError::invalid(key.mark(), "invalid map key"));
+    };
+    match resolve_node(value, *style, tag.as_ref())
+        .map_err(|e| YamlError::invalid(key.mark(), e))?
+    {
+        Scalar::Str => Ok(value.clone()),
+        Scalar::Int(i) => Ok(i.to_string()),
+        Scalar::Bool(b) => Ok(b.to_string()),
+        #[allow(clippy::cast_possible_truncation)]
+        Scalar::Float(f) => {
+            // Go formats float keys with 32-bit precision.
+            let f = f as f32;
+            Ok(format_float(&format!("{f:e}"), f64::from(f)))
+        }
+        scalar @ (Scalar::Null | Scalar::Uint(_)) => Err(YamlError::invalid(
+            key.mark(),
+            format!("unsupported map key of type {}: {value:?}", scalar.tag()),
+        )),
+    }
+}
+
+fn scalar_to_json(scalar: Scalar, value: &str, mark: Marker) -> Result<Value, YamlError> {
+    Ok(match scalar {
+        Scalar::Null => Value::Null,
+        Scalar::Bool(b) => Value::Bool(b),
+        Scalar::Int(i) => Value::from(i),
+        Scalar::Uint(u) => Value::from(u),
+        Scalar::Float(f) if !f.is_finite() => {
+            return Err(YamlError::invalid(
+                mark,
+                format!("unsupported value {value:?}"),
+            ));
+        }
+        Scalar::Float(f) => Value::Number(float_to_json(f)),
+        Scalar::Str => Value::String(value.to_string()),
+    })
+}
+
+/// JSON number for `f` as written by Go `encoding/json`, which prints whole
+/// numbers below 1e21 without a fraction (and thus reads back as integers).
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn float_to_json(f: f64) -> Number {
+    if f.fract() == 0.0 && f.abs() < 1e21 {
+        if (i64::MIN as f64..-(i64::MIN as f64)).contains(&f) {
+            return Number::from(f as i64);
+        }
+        if (0.0..u64::MAX as f64).contains(&f) {
+            return Number::from(f as u64);
+        }
+    }
+    Number::from_f64(f).expect("finite float")
+}
+
+// ---------------------------------------------------------------------------
+// Emitting
+// ---------------------------------------------------------------------------
+
+const BEST_INDENT: usize = 2;
+const BEST_WIDTH: usize = 80;
+const MAX_SIMPLE_KEY_LENGTH: usize = 128;
+
+/// Go `strconv.FormatFloat(f, 'g', -1, _)` given the shortest representation
+/// of `f` in Rust `{:e}` format.
+fn format_float(shortest: &str, f: f64) -> String {
+    if f.is_nan() {
+        return ".nan".into();
+    }
+    if f.is_infinite() {
+        return if f > 0.0 { ".inf" } else { "-.inf" }.into();
+    }
+    let (negative, shortest) = match shortest.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, shortest),
+    };
+    let (mantissa, exp) = shortest.split_once('e').expect("float in {:e} format");
+    let exp: i32 = exp.parse().expect("float exponent");
+    let digits: Vec<char> = mantissa.chars().filter(char::is_ascii_digit).collect();
+    let mut out = String::new();
+    if negative {
+        out.push('-');
+    }
+    if !(-4..6).contains(&exp) {
+        out.push(digits[0]);
+        if digits.len() > 1 {
+            out.push('.');
+            out.extend(&digits[1..]);
+        }
+        out.push('e');
+        out.push(if exp < 0 { '-' } else { '+' });
+        let _ = write!(out, "{:02}", exp.abs());
+        return out;
+    }
+    let digit = |i: i32| {
+        usize::try_from(i)
+            .ok()
+            .and_then(|i| digits.get(i))
+            .copied()
+            .unwrap_or('0')
+    };
+    let point = exp + 1;
+    if point > 0 {
+        out.extend((0..point).map(digit));
+    } else {
+        out.push('0');
+    }
+    let fraction = i32::try_from(digits.len()).expect("few digits") - point;
+    if fraction > 0 {
+        out.push('.');
+        out.extend((point..point + fraction).map(digit));
+    }
+    out
+}
+
+/// A JSON number as go-yaml prints it after reading it back from Go protojson.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_number(n: &Number) -> String {
+    if let Some(i) = n.as_i64() {
+        return i.to_string();
+    }
+    if let Some(u) = n.as_u64() {
+        return u.to_string();
+    }
+    let f = n.as_f64().expect("JSON number");
+    match float_to_json(f) {
+        n if n.is_f64() => format_float(&format!("{f:e}"), f),
+        n => n.to_string(),
+    }
+}
+
+/// Go `keyList.Less` from go-yaml, which orders digit runs numerically.
+fn key_less(a: &str, b: &str) -> bool {
+    let ar: Vec<char> = a.chars().collect();
+    let br: Vec<char> = b.chars().collect();
+    for i in 0..ar.len().min(br.len()) {
+        if ar[i] == br[i] {
+            continue;
+        }
+        let al = ar[i].is_alphabetic();
+        let bl = br[i].is_alphabetic();
+        if al && bl {
+            return ar[i] < br[i];
+        }
+        if al || bl {
+            return bl;
+        }
+        let (mut an, mut bn) = (0i64, 0i64);
+        if ar[i] == '0' || br[i] == '0' {
+            for j in (0..i).rev() {
+                if !ar[j].is_ascii_digit() {
+                    break;
+                }
+                if ar[j] != '0' {
+                    an = 1;
+                    bn = 1;
+                    break;
+                }
+            }
+        }
+        let number = |r: &[char], n: &mut i64| {
+            let mut end = i;
+            while end < r.len() && r[end].is_ascii_digit() {
+                *n = n
+                    .wrapping_mul(10)
+                    .wrapping_add(i64::from(u32::from(r[end]) - u32::from('0')));
+                end += 1;
+            }
+            end
+        };
+        let ai = number(&ar, &mut an);
+        let bi = number(&br, &mut bn);
+        if an != bn {
+            return an < bn;
+        }
+        if ai != bi {
+            return ai < bi;
+        }
+        return ar[i] < br[i];
+    }
+    ar.len() < br.len()
+}
+
+fn is_break(c: char) -> bool {
+    matches!(c, '\r' | '\n' | '\u{85}' | '\u{2028}' | '\u{2029}')
+}
+
+fn is_blank(c: char) -> bool {
+    c == ' ' || c == '\t'
+}
+
+fn is_printable(c: char) -> bool {
+    matches!(c, '\n' | '\u{20}'..='\u{7e}' | '\u{a0}'..='\u{d7ff}' | '\u{e000}'..='\u{fffd}')
+        && c != '\u{feff}'
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Plain,
+    SingleQuoted,
+    DoubleQuoted,
+    Literal,
+}
+
+/// Style go-yaml asks for when encoding the string `s`.
+fn string_style(s: &str) -> Style {
+    if s.contains('\n') {
+        Style::Literal
+    } else if resolve_str(s) {
+        Style::Plain
+    } else {
+        Style::DoubleQuoted
+    }
+}
+
+/// Whether `s` can be written unquoted and still decode as a string.
+fn resolve_str(s: &str) -> bool {
+    resolve(s) == Scalar::Str && !is_timestamp(s) && !is_base60_float(s)
+}
+
+/// Which styles can represent a scalar, as in libyaml `analyze_scalar`.
+#[allow(clippy::struct_excessive_bools)]
+struct Analysis {
+    multiline: bool,
+    plain_allowed: bool,
+    single_quoted_allowed: bool,
+    block_allowed: bool,
+}
+
+impl Analysis {
+    fn new(value: &str) -> Self {
+        if value.is_empty() {
+            return Self {
+                multiline: false,
+                plain_allowed: true,
+                single_quoted_allowed: true,
+                block_allowed: false,
+            };
+        }
+        let mut indicators = value.starts_with("---") || value.starts_with("...");
+        let mut line_breaks = false;
+        let mut special_characters = false;
+        let mut leading = false;
+        let mut trailing_space = false;
+        let mut trailing_break = false;
+        let mut break_space = false;
+        let mut space_break = false;
+        let mut preceded_by_whitespace = true;
+        let mut previous_space = false;
+        let mut previous_break = false;
+
+        let mut chars = value.chars().peekable();
+        let mut first = true;
+        while let Some(c) = chars.next() {
+            let next = chars.peek().copied();
+            let followed_by_whitespace = next.is_none_or(is_blank);
+            if first {
+                match c {
+                    '#' | ',' | '[' | ']' | '{' | '}' | '&' | '*' | '!' | '|' | '>' | '\''
+                    | '"' | '%' | '@' | '`' => indicators = true,
+                    '?' | ':' | '-' if followed_by_whitespace => indicators = true,
+                    _ => {}
+                }
+            } else {
+                match c {
+                    ':' if followed_by_whitespace => indicators = true,
+                    '#' if preceded_by_whitespace => indicators = true,
+                    _ => {}
+                }
+            }
+            if !is_printable(c) {
+                special_characters = true;
+            }
+            if c == ' ' {
+                leading |= first;
+                trailing_space |= next.is_none();
+                break_space |= previous_break;
+                previous_space = true;
+                previous_break = false;
+            } else if is_break(c) {
+                line_breaks = true;
+                leading |= first;
+                trailing_break |= next.is_none();
+                space_break |= previous_space;
+                previous_space = false;
+                previous_break = true;
+            } else {
+                previous_space = false;
+                previous_break = false;
+            }
+            preceded_by_whitespace = is_blank(c) || is_break(c) || c == '\0';
+            first = false;
+        }
+
+        Self {
+            multiline: line_breaks,
+            plain_allowed: !(leading
+                || trailing_space
+                || trailing_break
+                || break_space
+                || space_break
+                || special_characters
+                || line_breaks
+                || indicators),
+            single_quoted_allowed: !(break_space || space_break || special_characters),
+            block_allowed: !(trailing_space || space_break || special_characters),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Context {
+    mapping: bool,
+    simple_key: bool,
+}
+
+/// Block-style YAML emitter following libyaml as configured by go-yaml v2.
+struct Emitter {
+    out: String,
+    column: usize,
+    whitespace: bool,
+    indention: bool,
+}
+
+impl Default for Emitter {
+    fn default() -> Self {
+        Self {
+            out: String::new(),
+            column: 0,
+            whitespace: true,
+            indention: true,
+        }
+    }
+}
+
+impl Emitter {
+    fn node(&mut self, value: &Value, indent: Option<usize>, context: Context) {
+        match value {
+            Value::Object(map) if map.is_empty() => self.empty("{", "}"),
+            Value::Array(items) if items.is_empty() => self.empty("[", "]"),
+            Value::Object(map) => self.mapping(map, indent),
+            Value::Array(items) => self.sequence(items, indent, context),
+            Value::String(s) => self.scalar(s, string_style(s), indent, context),
+            Value::Number(n) => self.scalar(&format_number(n), Style::Plain, indent, context),
+            Value::Bool(b) => self.scalar(&b.to_string(), Style::Plain, indent, context),
+            Value::Null => self.scalar("null", Style::Plain, indent, context),
+        }
+    }
+
+    fn empty(&mut self, open: &str, close: &str) {
+        self.write_indicator(open, true, true, false);
+        self.write_indicator(close, false, false, false);
+    }
+
+    fn mapping(&mut self, map: &Map<String, Value>, indent: Option<usize>) {
+        let indent = indent.map_or(0, |indent| indent + BEST_INDENT);
+        let mut keys: Vec<&String> = map.keys().collect();
+        keys.sort_by(|a, b| {
+            if key_less(a, b) {
+                std::cmp::Ordering::Less
+            } else if key_less(b, a) {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        });
+        for key in keys {
+            self.write_indent(indent);
+            let style = string_style(key);
+            if !Analysis::new(key).multiline && key.len() <= MAX_SIMPLE_KEY_LENGTH {
+                let context = Context {
+                    mapping: true,
+                    simple_key: true,
+                };
+                self.scalar(key, style, Some(indent), context);
+                self.write_indicator(":", false, false, false);
+            } else {
+                self.write_indicator("?", true, false, true);
+                let context = Context {
+                    mapping: true,
+                    simple_key: false,
+                };
+                self.scalar(key, style, Some(indent), context);
+                self.write_indent(indent);
+                self.write_indicator(":", true, false, true);
+            }
+            let context = Context {
+                mapping: true,
+                simple_key: false,
+            };
+            self.node(&map[key.as_str()], Some(indent), context);
+        }
+    }
+
+    fn sequence(&mut self, items: &[Value], indent: Option<usize>, context: Context) {
+        let indentless = context.mapping && !self.indention;
+        let indent = match indent {
+            None => 0,
+            Some(indent) if indentless => indent,
+            Some(indent) => indent + BEST_INDENT,
+        };
+        for item in items {
+            self.write_indent(indent);
+            self.write_indicator("-", true, false, true);
+            self.node(item, Some(indent), Context::default());
+        }
+    }
+
+    fn scalar(&mut self, value: &str, style: Style, indent: Option<usize>, context: Context) {
+        let analysis = Analysis::new(value);
+        let mut style = style;
+        if context.simple_key && analysis.multiline {
+            style = Style::DoubleQuoted;
+        }
+        if style == Style::Plain
+            && (!analysis.plain_allowed || (value.is_empty() && context.simple_key))
+        {
+            style = Style::SingleQuoted;
+        }
+        if style == Style::SingleQuoted && !analysis.single_quoted_allowed {
+            style = Style::DoubleQuoted;
+        }
+        if style == Style::Literal && (!analysis.block_allowed || context.simple_key) {
+            style = Style::DoubleQuoted;
+        }
+
+        let indent = indent.map_or(BEST_INDENT, |indent| indent + BEST_INDENT);
+        let allow_breaks = !context.simple_key;
+        match style {
+            Style::Plain => self.write_plain(value, indent, allow_breaks),
+            Style::SingleQuoted => self.write_single_quoted(value, indent, allow_breaks),
+            Style::DoubleQuoted => self.write_double_quoted(value, indent, allow_breaks),
+            Style::Literal => self.write_literal(value, indent),
+        }
+    }
+
+    fn put(&mut self, c: char) {
+        self.out.push(c);
+        self.column += 1;
+    }
+
+    fn put_break(&mut self) {
+        self.out.push('\n');
+        self.column = 0;
+    }
+
+    fn write_break(&mut self, c: char) {
+        if c == '\n' {
+            self.put_break();
+        } else {
+            self.out.push(c);
+            self.column = 0;
+        }
+    }
+
+    fn write_indent(&mut self, indent: usize) {
+        if !self.indention || self.column > indent || (self.column == indent && !self.whitespace) {
+            self.put_break();
+        }
+        while self.column < indent {
+            self.put(' ');
+        }
+        self.whitespace = true;
+        self.indention = true;
+    }
+
+    fn write_indicator(
+        &mut self,
+        indicator: &str,
+        need_whitespace: bool,
+        is_whitespace: bool,
+        is_indention: bool,
+    ) {
+        if need_whitespace && !self.whitespace {
+            self.put(' ');
+        }
+        indicator.chars().for_each(|c| self.put(c));
+        self.whitespace = is_whitespace;
+        self.indention = self.indention && is_indention;
+    }
+
+    fn write_plain(&mut self, value: &str, indent: usize, allow_breaks: bool) {
+        if !self.whitespace {
+            self.put(' ');
+        }
+        let mut spaces = false;
+        let mut breaks = false;
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == ' ' {
+                if allow_breaks && !spaces && self.column > BEST_WIDTH && chars.peek() != Some(&' ')
+                {
+                    self.write_indent(indent);
+                } else {
+                    self.put(c);
+                }
+                spaces = true;
+            } else if is_break(c) {
+                if !breaks && c == '\n' {
+                    self.put_break();
+                }
+                self.write_break(c);
+                self.indention = true;
+                breaks = true;
+            } else {
+                if breaks {
+                    self.write_indent(indent);
+                }
+                self.put(c);
+                self.indention = false;
+                spaces = false;
+                breaks = false;
+            }
+        }
+        self.whitespace = false;
+        self.indention = false;
+    }
+
+    fn write_single_quoted(&mut self, value: &str, indent: usize, allow_breaks: bool) {
+        self.write_indicator("'", true, false, false);
+        let mut spaces = false;
+        let mut breaks = false;
+        let mut chars = value.chars().enumerate().peekable();
+        let last = value.chars().count().saturating_sub(1);
+        while let Some((i, c)) = chars.next() {
+            if c == ' ' {
+                if allow_breaks
+                    && !spaces
+                    && self.column > BEST_WIDTH
+                    && i > 0
+                    && i < last
+                    && chars.peek().map(|(_, c)| *c) != Some(' ')
+                {
+                    self.write_indent(indent);
+                } else {
+                    self.put(c);
+                }
+                spaces = true;
+            } else if is_break(c) {
+                if !breaks && c == '\n' {
+                    self.put_break();
+                }
+                self.write_break(c);
+                self.indention = true;
+                breaks = true;
+            } else {
+                if breaks {
+                    self.write_indent(indent);
+                }
+                if c == '\'' {
+                    self.put('\'');
+                }
+                self.put(c);
+                self.indention = false;
+                spaces = false;
+                breaks = false;
+            }
+        }
+        self.write_indicator("'", false, false, false);
+        self.whitespace = false;
+        self.indention = false;
+    }
+
+    fn write_double_quoted(&mut self, value: &str, indent: usize, allow_breaks: bool) {
+        self.write_indicator("\"", true, false, false);
+        // libyaml checks for a BOM at the start of the value, not at each
+        // character, so a leading BOM escapes everything.
+        let bom = value.starts_with('\u{feff}');
+        let mut spaces = false;
+        let mut chars = value.chars().enumerate().peekable();
+        let last = value.chars().count().saturating_sub(1);
+        while let Some((i, c)) = chars.next() {
+            if bom || !is_printable(c) || is_break(c) || c == '"' || c == '\\' {
+                self.put('\\');
+                match c {
+                    '\0' => self.put('0'),
+                    '\u{07}' => self.put('a'),
+                    '\u{08}' => self.put('b'),
+                    '\t' => self.put('t'),
+                    '\n' => self.put('n'),
+                    '\u{0b}' => self.put('v'),
+                    '\u{0c}' => self.put('f'),
+                    '\r' => self.put('r'),
+                    '\u{1b}' => self.put('e'),
+                    '"' => self.put('"'),
+                    '\\' => self.put('\\'),
+                    '\u{85}' => self.put('N'),
+                    '\u{a0}' => self.put('_'),
+                    '\u{2028}' => self.put('L'),
+                    '\u{2029}' => self.put('P'),
+                    c => {
+                        let code = u32::from(c);
+                        let escape = match code {
+                            0..=0xff => format!("x{code:02X}"),
+                            0x100..=0xffff => format!("u{code:04X}"),
+                            _ => format!("U{code:08X}"),
+                        };
+                        escape.chars().for_each(|c| self.put(c));
+                    }
+                }
+                spaces = false;
+            } else if c == ' ' {
+                if allow_breaks && !spaces && self.column > BEST_WIDTH && i > 0 && i < last {
+                    self.write_indent(indent);
+                    if chars.peek().map(|(_, c)| *c) == Some(' ') {
+                        self.put('\\');
+                    }
+                } else {
+                    self.put(c);
+                }
+                spaces = true;
+            } else {
+                self.put(c);
+                spaces = false;
+            }
+        }
+        self.write_indicator("\"", false, false, false);
+        self.whitespace = false;
+        self.indention = false;
+    }
+
+    fn write_literal(&mut self, value: &str, indent: usize) {
+        self.write_indicator("|", true, false, false);
+        if value.starts_with(|c| c == ' ' || is_break(c)) {
+            self.write_indicator(&BEST_INDENT.to_string(), false, false, false);
+        }
+        let mut tail = value.chars().rev();
+        let chomp = match (tail.next(), tail.next()) {
+            (Some(last), _) if !is_break(last) => Some("-"),
+            (Some(_), None) => Some("+"),
+            (Some(_), Some(previous)) if is_break(previous) => Some("+"),
+            (None, _) => Some("-"),
+            _ => None,
+        };
+        if let Some(chomp) = chomp {
+            self.write_indicator(chomp, false, false, false);
+        }
+        self.put_break();
+        self.indention = true;
+        self.whitespace = true;
+        let mut breaks = true;
+        for c in value.chars() {
+            if is_break(c) {
+                self.write_break(c);
+                self.indention = true;
+                breaks = true;
+            } else {
+                if breaks {
+                    self.write_indent(indent);
+                }
+                self.put(c);
+                self.indention = false;
+                breaks = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{GatewayConfig, GatewayGroup, GatewayGroupMember};
+    use serde_json::json;
+
+    fn emit(value: &Value) -> String {
+        let mut emitter = Emitter::default();
+        emitter.node(value, None, Context::default());
+        emitter.write_indent(0);
+        emitter.out
+    }
+
+    #[test]
+    fn test_string_quoting() {
+        for (s, yaml) in [
+            ("eth0", "eth0"),
+            ("10.0.0.1/32", "10.0.0.1/32"),
+            ("65000:100", "65000:100"),
+            ("", r#""""#),
+            ("yes", r#""yes""#),
+            ("Off", r#""Off""#),
+            ("~", r#""~""#),
+            ("42", r#""42""#),
+            ("0x1F", r#""0x1F""#),
+            ("1e3", r#""1e3""#),
+            (".5", r#"".5""#),
+            ("12:30", r#""12:30""#),
+            ("02:00:00:00:00:01", r#""02:00:00:00:00:01""#),
+            ("2024-01-02", r#""2024-01-02""#),
+            ("2024-01-02T03:04:05Z", r#""2024-01-02T03:04:05Z""#),
+            ("2024-13-02", "2024-13-02"),
+            ("#tag", "'#tag'"),
+            ("- item", "'- item'"),
+            ("a: b", "'a: b'"),
+            ("it's", "it's"),
+            ("'quoted'", "'''quoted'''"),
+            (" leading", "' leading'"),
+            ("tab\there", r#""tab\there""#),
+            ("caf\u{e9}", "caf\u{e9}"),
+            ("\u{1f600}", r#""\U0001F600""#),
+        ] {
+            assert_eq!(emit(&json!({ "k": s })), format!("k: {yaml}\n"), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_block_strings() {
+        assert_eq!(emit(&json!({"k": "a\nb"})), "k: |-\n  a\n  b\n");
+        assert_eq!(emit(&json!({"k": "a\n"})), "k: |\n  a\n");
+        assert_eq!(emit(&json!({"k": "a\n\n"})), "k: |+\n  a\n\n");
+        assert_eq!(emit(&json!({"k": " a\nb"})), "k: |2-\n   a\n  b\n");
+        assert_eq!(emit(&json!({"k": "a \nb"})), "k: \"a \\nb\"\n");
+        assert_eq!(emit(&json!({"a\nb": 1})), "? |-\n  a\n  b\n: 1\n");
+    }
+
+    #[test]
+    fn test_long_strings_fold() {
+        let words = ["word"; 20].join(" ");
+        let yaml = emit(&json!({ "k": words }));
+        let lines: Vec<&str> = yaml.lines().collect();
+        assert_eq!(lines.len(), 2, "{yaml}");
+        assert_eq!(lines[0], format!("k: {}", ["word"; 16].join(" ")));
+        assert_eq!(lines[1], format!("  {}", ["word"; 4].join(" ")));
+
+        let quoted = format!("{words}: x");
+        let yaml = emit(&json!({ "k": quoted }));
+        assert_eq!(yaml.lines().count(), 2, "{yaml}");
+        let value: Value = convert(&load(&yaml).unwrap().unwrap(), &Target::Any).unwrap();
+        assert_eq!(value, json!({ "k": quoted }));
+
+        let key = "k".repeat(MAX_SIMPLE_KEY_LENGTH + 1);
+        assert_eq!(emit(&json!({ &key: 1 })), format!("? {key}\n: 1\n"));
+    }
+
+    #[test]
+    fn test_collections() {
+        assert_eq!(emit(&json!({})), "{}\n");
+        assert_eq!(emit(&json!({"a": {}, "b": []})), "a: {}\nb: []\n");
+        assert_eq!(
+            emit(&json!({"a": [{"b": 1, "c": [[1, 2]]}]})),
+            "a:\n- b: 1\n  c:\n  - - 1\n    - 2\n"
+        );
+        assert_eq!(emit(&json!({"a": [null, true]})), "a:\n- null\n- true\n");
+    }
+
+    #[test]
+    fn test_numbers() {
+        for (n, yaml) in [
+            (json!(0), "0"),
+            (json!(-7), "-7"),
+            (json!(u64::MAX), "18446744073709551615"),
+            (json!(2.0), "2"),
+            (json!(-0.0), "0"),
+            (json!(1500.5), "1500.5"),
+            (json!(0.0001), "0.0001"),
+            (json!(0.000_01), "1e-05"),
+            (json!(1_234_567.5), "1.2345675e+06"),
+            (json!(3e20), "3e+20"),
+            (json!(1e21), "1e+21"),
+            (json!(1.5e-300), "1.5e-300"),
+        ] {
+            assert_eq!(emit(&json!({ "k": n })), format!("k: {yaml}\n"), "{n}");
+        }
+    }
+
+    #[test]
+    fn test_key_order() {
+        let yaml = emit(&json!({"a10": 1, "a2": 1, "b": 1, "A": 1, "1": 1, "_x": 1}));
+        assert_eq!(yaml, "_x: 1\n\"1\": 1\nA: 1\na2: 1\na10: 1\nb: 1\n");
+    }
+
+    #[test]
+    fn test_resolve() {
+        for (s, scalar) in [
+            ("", Scalar::Null),
+            ("null", Scalar::Null),
+            ("on", Scalar::Bool(true)),
+            ("N", Scalar::Bool(false)),
+            ("12", Scalar::Int(12)),
+            ("-0x10", Scalar::Int(-16)),
+            ("0o17", Scalar::Int(15)),
+            ("017", Scalar::Int(15)),
+            ("0b101", Scalar::Int(5)),
+            ("1_000", Scalar::Int(1000)),
+            ("18446744073709551615", Scalar::Uint(u64::MAX)),
+            ("09", Scalar::Float(9.0)),
+            ("1.5", Scalar::Float(1.5)),
+            ("-.5e1", Scalar::Float(-5.0)),
+            ("1e999", Scalar::Str),
+            ("0x", Scalar::Str),
+            ("1.2.3", Scalar::Str),
+            ("true!", Scalar::Str),
+        ] {
+            assert_eq!(resolve(s), scalar, "{s:?}");
+        }
+        assert!(resolve(".nan") != Scalar::Str);
+    }
+
+    fn to_value(yaml: &str) -> Result<Value, YamlError> {
+        convert(&load(yaml)?.unwrap(), &Target::Any)
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(
+            to_value("a: yes\nb: '1'\nc: !!str 2\nd: !!float 3\n1: x\n1.5: y\n2.5: v\ntrue: z\n")
+                .unwrap(),
+            json!({"a": true, "b": "1", "c": "2", "d": 3, "1": "x", "1.5": true, "2.5": "v", "true": "z"})
+        );
+        assert_eq!(
+            to_value("base: &base {a: 1, b: 2}\nderived:\n  <<: *base\n  c: 3\n").unwrap(),
+            json!({"base": {"a": 1, "b": 2}, "derived": {"a": 1, "b": 2, "c": 3}})
+        );
+        assert_eq!(to_value("a: 1\n---\nb: 2\n").unwrap(), json!({"a": 1}));
+
+        let err = to_value("a: !!int x\n").unwrap_err();
+        assert!(err.to_string().contains("!!int"), "{err}");
+        assert!(to_value("a: .inf\n").is_err());
+        assert!(to_value("~: 1\n").is_err());
+        assert!(to_value("[1]: 1\n").is_err());
+    }
+
+    #[test]
+    fn test_strict_errors() {
+        let err = from_yaml::<GatewayConfig>("generation: 1\n\ngeneration: 2\n").unwrap_err();
+        assert!(matches!(err, YamlError::DuplicateKey { .. }), "{err}");
+        assert_eq!(err.position(), Some(Position { line: 3, column: 1 }));
+
+        let err = from_yaml::<GatewayConfig>("gwGroups:\n- name: g\n  bogus: 1\n").unwrap_err();
+        assert!(matches!(err, YamlError::UnknownField { .. }), "{err}");
+        assert_eq!(err.position(), Some(Position { line: 3, column: 3 }));
+
+        let err = from_yaml::<GatewayConfig>("generation: [1\n").unwrap_err();
+        assert!(matches!(err, YamlError::Syntax { .. }), "{err}");
+        assert_eq!(err.position().unwrap().line, 2);
+
+        let err = from_yaml::<GatewayConfig>("generation: yes\n").unwrap_err();
+        assert!(matches!(err, YamlError::Json(_)), "{err}");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let config = GatewayConfig {
+            generation: 3,
+            gw_groups: vec![GatewayGroup {
+                name: "yes".into(),
+                members: vec![GatewayGroupMember {
+                    name: "gw: 1\nsecond line".into(),
+                    priority: 10,
+                    ipaddress: "10.0.0.1".into(),
+                }],
+            }],
+            communities: [(0, "65000:1".into()), (10, "no".into())].into(),
+            ..Default::default()
+        };
+        let yaml = to_yaml(&config).unwrap();
+        assert_eq!(from_yaml::<GatewayConfig>(&yaml).unwrap(), config);
+    }
+
+    #[cfg(feature = "bolero")]
+    #[test]
+    fn test_round_trip_bolero() {
+        use crate::config::GetDataplaneStatusResponse;
+
+        bolero::check!()
+            .with_type::<GatewayConfig>()
+            .for_each(|config| {
+                let yaml = to_yaml(config).unwrap();
+                assert_eq!(&from_yaml::<GatewayConfig>(&yaml).unwrap(), config);
+            });
+        bolero::check!()
+            .with_type::<GetDataplaneStatusResponse>()
+            .for_each(|status| {
+                let yaml = to_yaml(status).unwrap();
+                assert_eq!(
+                    &from_yaml::<GetDataplaneStatusResponse>(&yaml).unwrap(),
+                    status
+                );
+            });
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+// Not every test uses every helper
+#![allow(dead_code)]
+
+use gateway_config::{ConfigService, ConfigServiceClient, ConfigServiceServer};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use tonic::transport::Channel;
+
+/// Path of the test data file `name`.
+pub fn testdata(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/testdata")
+        .join(name)
+}
+
+/// Contents of the golden file `name` in the test data.
+pub fn golden(name: &str) -> String {
+    std::fs::read_to_string(testdata(name)).unwrap()
+}
+
+/// Serve `service` on a random local port and return a client connected to it.
+pub async fn serve<S: ConfigService>(service: S) -> ConfigServiceClient<Channel> {
+    let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
+    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    let server_addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        tonic::transport::Server::builder()
+            .add_service(ConfigServiceServer::new(service))
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+            .await
+            .unwrap();
+    });
+
+    let channel = tonic::transport::Channel::from_shared(format!("http://{server_addr}"))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+
+    ConfigServiceClient::new(channel)
+}
@@ -0,0 +1,31 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "encoding")]
+
+mod common;
+
+use common::golden;
+use gateway_config::consistency::check_consistency;
+use gateway_config::json::from_json;
+use gateway_config::yaml::from_yaml;
+use gateway_config::{GatewayConfig, GetDataplaneStatusResponse};
+
+#[test]
+fn test_golden_config_and_status() {
+    let config: GatewayConfig = from_yaml(&golden("gateway_config.yaml")).unwrap();
+    let status: GetDataplaneStatusResponse = from_json(&golden("dataplane_status.json")).unwrap();
+    let mismatches: Vec<_> = check_consistency(&config, &status)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        mismatches,
+        [
+            "VPC vpc-2 is configured but not in status",
+            "Interface eth0.100 is configured but not in status",
+            "Interface lo is configured but not in status",
+            "Interface vtep is configured but not in status",
+        ]
+    );
+}
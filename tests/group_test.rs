@@ -0,0 +1,174 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use std::time::Duration;
+use tonic::{Request, Response, Status};
+
+use gateway_config::group::GroupClient;
+use gateway_config::memory::InMemoryConfigService;
+use gateway_config::{
+    ConfigService, ConfigServiceClient, Error, GatewayConfig, GatewayGroupMember,
+    GetConfigGenerationRequest, GetConfigGenerationResponse, GetConfigRequest,
+    GetDataplaneStatusRequest, GetDataplaneStatusResponse, GetRoutesRequest, GetRoutesResponse,
+    ListNatSessionsRequest, ListNatSessionsResponse, LookupRouteRequest, LookupRouteResponse,
+    UpdateConfigRequest, UpdateConfigResponse,
+};
+
+/// Rejects every update with `ERROR_APPLY_FAILED`.
+struct ApplyFailingService(InMemoryConfigService);
+
+#[tonic::async_trait]
+impl ConfigService for ApplyFailingService {
+    async fn get_config(
+        &self,
+        request: Request<GetConfigRequest>,
+    ) -> Result<Response<GatewayConfig>, Status> {
+        self.0.get_config(request).await
+    }
+
+    async fn get_config_generation(
+        &self,
+        request: Request<GetConfigGenerationRequest>,
+    ) -> Result<Response<GetConfigGenerationResponse>, Status> {
+        self.0.get_config_generation(request).await
+    }
+
+    async fn update_config(
+        &self,
+        _request: Request<UpdateConfigRequest>,
+    ) -> Result<Response<UpdateConfigResponse>, Status> {
+        Ok(Response::new(UpdateConfigResponse {
+            error: Error::ApplyFailed.into(),
+            message: "frr reload failed".into(),
+        }))
+    }
+
+    async fn get_dataplane_status(
+        &self,
+        request: Request<GetDataplaneStatusRequest>,
+    ) -> Result<Response<GetDataplaneStatusResponse>, Status> {
+        self.0.get_dataplane_status(request).await
+    }
+
+    async fn list_nat_sessions(
+        &self,
+        request: Request<ListNatSessionsRequest>,
+    ) -> Result<Response<ListNatSessionsResponse>, Status> {
+        self.0.list_nat_sessions(request).await
+    }
+
+    async fn get_routes(
+        &self,
+        request: Request<GetRoutesRequest>,
+    ) -> Result<Response<GetRoutesResponse>, Status> {
+        self.0.get_routes(request).await
+    }
+
+    async fn lookup_route(
+        &self,
+        request: Request<LookupRouteRequest>,
+    ) -> Result<Response<LookupRouteResponse>, Status> {
+        self.0.lookup_route(request).await
+    }
+}
+
+fn member(name: &str, priority: u32) -> GatewayGroupMember {
+    GatewayGroupMember {
+        name: name.into(),
+        priority,
+        ipaddress: "127.0.0.1".into(),
+    }
+}
+
+fn config(generation: i64) -> GatewayConfig {
+    GatewayConfig {
+        generation,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_group_update_all_members() {
+    let services = [
+        InMemoryConfigService::new(),
+        InMemoryConfigService::new(),
+        InMemoryConfigService::new(),
+    ];
+    let mut members = Vec::new();
+    for (i, service) in services.iter().enumerate() {
+        let client = common::serve(service.clone()).await;
+        members.push((
+            member(&format!("gw{i}"), 10 * u32::try_from(i).unwrap()),
+            client,
+        ));
+    }
+    let group = GroupClient::from_clients("gw-group", members, Duration::from_secs(2));
+
+    let report = group.update_config(&config(7)).await;
+    assert_eq!(report.group, "gw-group");
+    assert_eq!(report.members.len(), 3);
+    assert_eq!(report.succeeded().count(), 3);
+    assert!(report.is_complete());
+    assert!(!report.is_partial_failure());
+    assert!(!report.skew.is_skewed());
+    for service in &services {
+        assert_eq!(service.config().generation, 7);
+    }
+}
+
+#[tokio::test]
+async fn test_group_update_partial_failure() {
+    let good = InMemoryConfigService::new();
+    let failing = InMemoryConfigService::new();
+    let unreachable = ConfigServiceClient::new(
+        tonic::transport::Channel::from_static("http://127.0.0.1:1").connect_lazy(),
+    );
+
+    let group = GroupClient::from_clients(
+        "gw-group",
+        vec![
+            (member("good", 1), common::serve(good.clone()).await),
+            (
+                member("failing", 2),
+                common::serve(ApplyFailingService(failing.clone())).await,
+            ),
+            (member("unreachable", 3), unreachable),
+        ],
+        Duration::from_secs(2),
+    );
+
+    let report = group.update_config(&config(3)).await;
+    assert!(!report.is_complete());
+    assert!(report.is_partial_failure());
+    assert_eq!(
+        report
+            .succeeded()
+            .map(|m| m.member.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["good"]
+    );
+
+    let failed: Vec<_> = report
+        .failed()
+        .map(|m| (m.member.name.as_str(), m.error().unwrap().error()))
+        .collect();
+    assert_eq!(
+        failed,
+        vec![
+            ("failing", Error::ApplyFailed),
+            ("unreachable", Error::UnknownError)
+        ]
+    );
+
+    // Failing member is left behind at the old generation
+    assert!(report.skew.is_skewed());
+    assert_eq!(
+        report.skew.lagging(3).collect::<Vec<_>>(),
+        vec![("failing", 0)]
+    );
+    assert!(report.skew.unreachable.contains_key("unreachable"));
+    assert_eq!(good.config().generation, 3);
+    assert_eq!(failing.config().generation, 0);
+}
@@ -0,0 +1,234 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::testdata;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+fn gwtestctl(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_gwtestctl"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8(output.stderr.clone()).unwrap()
+}
+
+/// Scratch file removed when dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str, contents: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("gwtestctl-{}-{name}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        Self(path)
+    }
+
+    fn path(&self) -> &str {
+        self.0.to_str().unwrap()
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn test_validate() {
+    for name in [
+        "gateway_config.yaml",
+        "gateway_config.json",
+        "gateway_config.txtpb",
+    ] {
+        let output = gwtestctl(&["validate", "-f", testdata(name).to_str().unwrap()]);
+        assert!(output.status.success(), "{name}: {}", stderr(&output));
+    }
+
+    let invalid = TempFile::new(
+        "invalid.yaml",
+        "underlay:\n  vrfs:\n  - interfaces:\n    - name: eth0\n      ipaddrs: [10.0.0.1]\n",
+    );
+    let output = gwtestctl(&["validate", "-f", invalid.path()]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("/underlay/vrfs/0/interfaces/0/ipaddrs/0"),
+        "{}",
+        stderr(&output)
+    );
+
+    let unknown = TempFile::new("unknown.yaml", "generation: 1\nbogus: 2\n");
+    let output = gwtestctl(&["validate", "-f", unknown.path()]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("line 2, column 1"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn test_diff() {
+    let old = testdata("gateway_config.yaml");
+    let output = gwtestctl(&["diff", old.to_str().unwrap(), old.to_str().unwrap()]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "");
+
+    let new = TempFile::new(
+        "new.yaml",
+        &std::fs::read_to_string(&old)
+            .unwrap()
+            .replace("generation: \"42\"", "generation: \"43\""),
+    );
+    let output = gwtestctl(&["-b", "diff", old.to_str().unwrap(), new.path()]);
+    assert_eq!(output.status.code(), Some(1));
+    let diff = stdout(&output);
+    assert!(
+        diff.contains("-generation: \"42\"\n+generation: \"43\"\n"),
+        "{diff}"
+    );
+
+    // Files in different formats compare by content
+    let json = testdata("gateway_config.json");
+    let output = gwtestctl(&[
+        "diff",
+        "-o",
+        "textproto",
+        old.to_str().unwrap(),
+        json.to_str().unwrap(),
+    ]);
+    assert!(output.status.success(), "{}", stderr(&output));
+}
+
+#[test]
+fn test_client_and_fake_server() {
+    // The Go client dials unix://name as an abstract socket, so do we
+    let target = format!("unix://gwtestctl-{}.sock", std::process::id());
+    let mut server = Command::new(env!("CARGO_BIN_EXE_gwtestctl"))
+        .args(["fake-server", "-t", &target])
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    for _ in 0..100 {
+        if gwtestctl(&["get-config-gen", "-t", &target, "--timeout", "1"])
+            .status
+            .success()
+        {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+
+    let config = testdata("gateway_config.yaml");
+    let output = gwtestctl(&[
+        "update-config",
+        "-t",
+        &target,
+        "-f",
+        config.to_str().unwrap(),
+    ]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let output = gwtestctl(&["get-config-gen", "-t", &target]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "42\n");
+
+    let output = gwtestctl(&["get-config", "-t", &target]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), std::fs::read_to_string(&config).unwrap());
+
+    let output = gwtestctl(&["get-status", "-t", &target, "-o", "json"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("\"appliedConfigGen\": \"42\""));
+
+    let output = gwtestctl(&["get-status", "-t", &target, "-o", "table"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(
+        stdout(&output).starts_with("Dataplane: HEALTHY  FRR: RUNNING  FRR agent: CONNECTED"),
+        "{}",
+        stdout(&output)
+    );
+
+    let output = gwtestctl(&["health", "-t", &target, "--expected-generation", "42"]);
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert_eq!(stdout(&output), "HEALTHY\n");
+    let output = gwtestctl(&["health", "-t", &target, "--expected-generation", "43"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "DEGRADED\n  DEGRADED frr: applied config generation 42, expected 43\n"
+    );
+
+    let output = gwtestctl(&["get-status", "-t", &target, "-o", "prometheus"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(
+        stdout(&output).contains("\ngateway_frr_applied_config_generation 42\n"),
+        "{}",
+        stdout(&output)
+    );
+
+    let output = gwtestctl(&[
+        "nat-sessions",
+        "-t",
+        &target,
+        "--protocol",
+        "tcp",
+        "-o",
+        "json",
+    ]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "{}\n");
+
+    // The fake server has no route tables
+    let output = gwtestctl(&["routes", "-t", &target, "--vrf", "default"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("no routes for VRF default"),
+        "{}",
+        stderr(&output)
+    );
+    let output = gwtestctl(&[
+        "lookup-route",
+        "-t",
+        &target,
+        "--vrf",
+        "default",
+        "--vpc",
+        "vpc-1",
+        "10.1.0.5",
+    ]);
+    assert_eq!(output.status.code(), Some(2));
+
+    server.kill().unwrap();
+    server.wait().unwrap();
+
+    let output = gwtestctl(&["get-config", "-t", &target, "--timeout", "1"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("Failed to connect"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn test_invalid_target() {
+    let output = gwtestctl(&["get-config", "-t", "localhost:5123"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        stderr(&output).contains("expected tcp://host:port"),
+        "{}",
+        stderr(&output)
+    );
+}
@@ -0,0 +1,124 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "encoding")]
+
+mod common;
+
+use common::golden;
+use gateway_config::config::{expose, peering_i_ps};
+use gateway_config::json::{from_json, to_json_pretty, to_json_value};
+use gateway_config::{
+    BgpNeighborSessionState, GatewayConfig, GetDataplaneStatusResponse, IfType, LogLevel,
+};
+use prost::{Message, Name};
+use serde_json::Value;
+
+/// Numbers compare by value, e.g. `1500` and `1500.0` are the same double.
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::Number(n) => n
+            .as_f64()
+            .and_then(serde_json::Number::from_f64)
+            .map_or(Value::Number(n), Value::Number),
+        Value::Array(items) => Value::Array(items.into_iter().map(normalize).collect()),
+        Value::Object(fields) => {
+            Value::Object(fields.into_iter().map(|(k, v)| (k, normalize(v))).collect())
+        }
+        other => other,
+    }
+}
+
+/// Golden file decodes and encodes back to the same JSON, also after a trip
+/// through the protobuf wire format.
+fn check_golden<M: Message + Name + Default + PartialEq + std::fmt::Debug>(name: &str) -> M {
+    let json = golden(name);
+    let msg: M = from_json(&json).unwrap();
+
+    let expected: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        normalize(to_json_value(&msg).unwrap()),
+        normalize(expected),
+        "{name} does not round trip"
+    );
+
+    let wire = M::decode(msg.encode_to_vec().as_slice()).unwrap();
+    assert_eq!(wire, msg);
+    assert_eq!(
+        from_json::<M>(&to_json_pretty(&wire).unwrap()).unwrap(),
+        msg
+    );
+
+    msg
+}
+
+#[test]
+fn test_gateway_config_golden() {
+    let config: GatewayConfig = check_golden("gateway_config.json");
+
+    assert_eq!(config.generation, 42);
+    let tracing = config.device.unwrap().tracing.unwrap();
+    assert_eq!(tracing.default(), LogLevel::Info);
+    assert_eq!(tracing.taglevel["vxlan"], i32::from(LogLevel::Trace));
+
+    let vrf = &config.underlay.unwrap().vrfs[0];
+    assert_eq!(vrf.interfaces[0].r#type(), IfType::Ethernet);
+    assert_eq!(vrf.interfaces[2].vlan, Some(100));
+
+    let peering = &config.overlay.unwrap().peerings[0];
+    let expose = &peering.r#for[0].expose[0];
+    assert_eq!(
+        expose.ips[1].rule,
+        Some(peering_i_ps::Rule::Not("10.1.1.0/24".into()))
+    );
+    let Some(expose::Nat::Stateful(nat)) = &expose.nat else {
+        panic!("expected stateful NAT, got {:?}", expose.nat);
+    };
+    assert_eq!(nat.idle_timeout.unwrap().seconds, 300);
+    assert_eq!(
+        peering.r#for[1].expose[0].nat,
+        Some(expose::Nat::Stateless(
+            gateway_config::config::PeeringStatelessNat {}
+        ))
+    );
+    assert_eq!(peering.r#for[1].expose[1].nat, None);
+
+    assert_eq!(config.communities[&1], "65000:101");
+}
+
+#[test]
+fn test_dataplane_status_golden() {
+    let status: GetDataplaneStatusResponse = check_golden("dataplane_status.json");
+
+    assert_eq!(status.frr_status.unwrap().applied_config_gen, 42);
+    let counters = status.interface_runtime["eth0"].counters.as_ref().unwrap();
+    assert_eq!(counters.rx_bits, 987_654_321);
+    assert!((counters.tx_bps - 1500.5).abs() < f64::EPSILON);
+    assert_eq!(counters.rx_errors, 0);
+    assert_eq!(counters.rx_packets, 1_200_000);
+    assert_eq!(counters.rx_queues[1].drops, 3);
+
+    let neighbor = &status.bgp.unwrap().vrfs["default"].neighbors["192.168.1.2"];
+    assert_eq!(
+        neighbor.session_state(),
+        BgpNeighborSessionState::BgpStateEstablished
+    );
+    assert_eq!(neighbor.messages.unwrap().sent.unwrap().notification, 1);
+    assert!(neighbor.ipv6_unicast_prefixes.is_none());
+}
+
+#[test]
+fn test_proto_field_names_accepted() {
+    let config: GatewayConfig =
+        from_json(r#"{"generation": "1", "gw_groups": [{"name": "g", "members": []}]}"#).unwrap();
+    assert_eq!(config.gw_groups[0].name, "g");
+}
+
+#[test]
+fn test_enum_numbers_accepted() {
+    let config: GatewayConfig = from_json(r#"{"device": {"tracing": {"default": 4}}}"#).unwrap();
+    assert_eq!(
+        config.device.unwrap().tracing.unwrap().default(),
+        LogLevel::Debug
+    );
+}
@@ -0,0 +1,107 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use gateway_config::google::protobuf::Duration;
+use gateway_config::memory::InMemoryConfigService;
+use gateway_config::{FiveTuple, IpProtocol, ListNatSessionsRequest, NatSession};
+use tonic::Code;
+
+fn session(i: u32) -> NatSession {
+    let protocol = if i.is_multiple_of(3) {
+        IpProtocol::Udp
+    } else {
+        IpProtocol::Tcp
+    };
+    let peering = if i.is_multiple_of(2) {
+        ("vpc-1--vpc-2", "vpc-1", "vpc-2")
+    } else {
+        ("vpc-1--vpc-3", "vpc-1", "vpc-3")
+    };
+    NatSession {
+        peering: peering.0.into(),
+        src_vpc: peering.1.into(),
+        dst_vpc: peering.2.into(),
+        original: Some(FiveTuple {
+            protocol: protocol.into(),
+            src_ip: format!("10.1.0.{i}"),
+            src_port: 40000 + i,
+            dst_ip: "10.2.0.1".into(),
+            dst_port: 443,
+        }),
+        translated: Some(FiveTuple {
+            protocol: protocol.into(),
+            src_ip: "192.0.2.1".into(),
+            src_port: 1024 + i,
+            dst_ip: "10.2.0.1".into(),
+            dst_port: 443,
+        }),
+        idle_time: Some(Duration {
+            seconds: i.into(),
+            nanos: 0,
+        }),
+        remaining_timeout: Some(Duration {
+            seconds: 300 - i64::from(i),
+            nanos: 0,
+        }),
+    }
+}
+
+#[tokio::test]
+async fn test_list_nat_sessions_pages() {
+    let table: Vec<_> = (0..10).map(session).collect();
+    let service = InMemoryConfigService::new();
+    service.set_nat_sessions(table.clone());
+    let mut client = common::serve(service).await;
+
+    let first = client
+        .list_nat_sessions(ListNatSessionsRequest {
+            page_size: 4,
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(first.sessions, table[..4]);
+    assert!(!first.next_page_token.is_empty());
+
+    let all = client
+        .list_all_nat_sessions(ListNatSessionsRequest {
+            page_size: 4,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(all, table);
+
+    let tcp_to_vpc_2 = client
+        .list_all_nat_sessions(ListNatSessionsRequest {
+            vpc: "vpc-2".into(),
+            protocol: IpProtocol::Tcp.into(),
+            page_size: 1,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let expected: Vec<_> = [2, 4, 8].into_iter().map(session).collect();
+    assert_eq!(tcp_to_vpc_2, expected);
+
+    let by_address = client
+        .list_all_nat_sessions(ListNatSessionsRequest {
+            address: "10.1.0.7".into(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(by_address, vec![session(7)]);
+
+    let err = client
+        .list_nat_sessions(ListNatSessionsRequest {
+            page_token: "not a token".into(),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::InvalidArgument);
+}
@@ -0,0 +1,239 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(all(feature = "otel", feature = "encoding"))]
+
+mod common;
+
+use common::golden;
+use gateway_config::GetDataplaneStatusResponse;
+use gateway_config::fault::{Fault, FaultInjector, Rpc, ScriptedFaults};
+use gateway_config::json::from_json;
+use gateway_config::memory::InMemoryConfigService;
+use gateway_config::otel::StatusMetrics;
+use opentelemetry::KeyValue;
+use opentelemetry::metrics::MeterProvider;
+use opentelemetry_sdk::metrics::data::{AggregatedMetrics, MetricData};
+use opentelemetry_sdk::metrics::{InMemoryMetricExporter, PeriodicReader, SdkMeterProvider};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+fn setup() -> (SdkMeterProvider, InMemoryMetricExporter, StatusMetrics) {
+    let exporter = InMemoryMetricExporter::default();
+    let provider = SdkMeterProvider::builder()
+        .with_reader(PeriodicReader::builder(exporter.clone()).build())
+        .build();
+    let metrics = StatusMetrics::register(&provider.meter("gateway"));
+    (provider, exporter, metrics)
+}
+
+fn series<'a>(name: &str, attributes: impl Iterator<Item = &'a KeyValue>) -> String {
+    let mut attributes: Vec<_> = attributes
+        .map(|kv| format!("{}={}", kv.key, kv.value))
+        .collect();
+    attributes.sort();
+    format!("{name}{{{}}}", attributes.join(","))
+}
+
+fn collect_data<T: Copy>(
+    name: &str,
+    data: &MetricData<T>,
+    to_f64: fn(T) -> f64,
+    out: &mut BTreeMap<String, f64>,
+) {
+    match data {
+        MetricData::Gauge(gauge) => {
+            for point in gauge.data_points() {
+                out.insert(series(name, point.attributes()), to_f64(point.value()));
+            }
+        }
+        MetricData::Sum(sum) => {
+            assert!(sum.is_monotonic(), "{name}");
+            for point in sum.data_points() {
+                out.insert(series(name, point.attributes()), to_f64(point.value()));
+            }
+        }
+        _ => panic!("unexpected data for {name}"),
+    }
+}
+
+/// Flush and return the last value of every series.
+#[allow(clippy::cast_precision_loss)]
+fn collect(
+    provider: &SdkMeterProvider,
+    exporter: &InMemoryMetricExporter,
+) -> BTreeMap<String, f64> {
+    exporter.reset();
+    provider.force_flush().unwrap();
+    let mut out = BTreeMap::new();
+    for resource in exporter.get_finished_metrics().unwrap() {
+        for scope in resource.scope_metrics() {
+            for metric in scope.metrics() {
+                match metric.data() {
+                    AggregatedMetrics::F64(data) => {
+                        collect_data(metric.name(), data, |v| v, &mut out);
+                    }
+                    AggregatedMetrics::U64(data) => {
+                        collect_data(metric.name(), data, |v| v as f64, &mut out);
+                    }
+                    AggregatedMetrics::I64(data) => panic!("unexpected i64 {data:?}"),
+                }
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn test_snapshot() {
+    let (provider, exporter, metrics) = setup();
+    let status: GetDataplaneStatusResponse = from_json(&golden("dataplane_status.json")).unwrap();
+
+    let values = collect(&provider, &exporter);
+    assert_eq!(values.len(), 1, "{values:?}");
+    assert_eq!(values["gateway.status.up{}"], 0.0);
+
+    metrics.update(status);
+    let values = collect(&provider, &exporter);
+    for (series, value) in [
+        ("gateway.status.up{}", 1.0),
+        (
+            "gateway.interface.io{direction=receive,ifname=eth0}",
+            987_654_321.0,
+        ),
+        (
+            "gateway.interface.io{direction=transmit,ifname=eth0}",
+            123_456_789.0,
+        ),
+        (
+            "gateway.interface.errors{direction=transmit,ifname=eth0}",
+            1.0,
+        ),
+        (
+            "gateway.interface.packets{direction=receive,ifname=eth0}",
+            1_200_000.0,
+        ),
+        (
+            "gateway.interface.drops{direction=receive,ifname=eth0}",
+            5.0,
+        ),
+        (
+            "gateway.interface.multicast{direction=receive,ifname=eth0}",
+            350.0,
+        ),
+        (
+            "gateway.interface.broadcast{direction=transmit,ifname=eth0}",
+            4.0,
+        ),
+        (
+            "gateway.interface.queue.drops{direction=receive,ifname=eth0,queue=1}",
+            3.0,
+        ),
+        (
+            "gateway.interface.queue.bytes{direction=receive,ifname=eth0,queue=0}",
+            63_000_000.0,
+        ),
+        (
+            "gateway.interface.bit_rate{direction=receive,ifname=eth0}",
+            2500.25,
+        ),
+        (
+            "gateway.bgp.neighbor.session_state{neighbor=192.168.1.2,state=established,vrf=default}",
+            1.0,
+        ),
+        (
+            "gateway.bgp.neighbor.session_state{neighbor=192.168.1.2,state=idle,vrf=default}",
+            0.0,
+        ),
+        ("gateway.vpc.packets{vpc=vpc-1}", 2000.0),
+        ("gateway.vpc.bytes{vpc=vpc-1}", 128_000.0),
+        ("gateway.vpc.drops{vpc=vpc-1}", 4.0),
+        (
+            "gateway.vpc_peering.packets{dst_vpc=vpc-2,peering=vpc-1--vpc-2,src_vpc=vpc-1}",
+            1000.0,
+        ),
+        (
+            "gateway.vpc_peering.drops{dst_vpc=vpc-2,peering=vpc-1--vpc-2,src_vpc=vpc-1}",
+            3.0,
+        ),
+        (
+            "gateway.vpc_peering.packet_rate{dst_vpc=vpc-2,peering=vpc-1--vpc-2,src_vpc=vpc-1}",
+            10.5,
+        ),
+        (
+            "gateway.vpc_peering.bit_rate{dst_vpc=vpc-2,peering=vpc-1--vpc-2,src_vpc=vpc-1}",
+            5376.75,
+        ),
+    ] {
+        assert_eq!(values.get(series), Some(&value), "{series} in {values:#?}");
+    }
+
+    metrics.clear();
+    let values = collect(&provider, &exporter);
+    assert_eq!(values["gateway.status.up{}"], 0.0);
+}
+
+#[tokio::test]
+async fn test_poll() {
+    let (provider, exporter, metrics) = setup();
+    let service = InMemoryConfigService::new();
+    service.set_status(from_json(&golden("dataplane_status.json")).unwrap());
+    let client = common::serve(service).await;
+
+    let poller = metrics.clone();
+    let task = tokio::spawn(async move {
+        poller
+            .poll(client, Duration::ZERO, Duration::from_secs(5))
+            .await;
+    });
+    for _ in 0..100 {
+        if collect(&provider, &exporter)["gateway.status.up{}"] == 1.0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let values = collect(&provider, &exporter);
+    assert_eq!(values["gateway.status.up{}"], 1.0);
+    assert_eq!(values["gateway.vpc.packets{vpc=vpc-1}"], 2000.0);
+    task.abort();
+}
+
+#[tokio::test]
+async fn test_poll_timeout() {
+    let (provider, exporter, metrics) = setup();
+    let service = InMemoryConfigService::new();
+    service.set_status(from_json(&golden("dataplane_status.json")).unwrap());
+    let schedule = ScriptedFaults::new()
+        .then(Rpc::GetDataplaneStatus, [])
+        .times(
+            Rpc::GetDataplaneStatus,
+            100,
+            &[Fault::Delay(Duration::from_secs(60))],
+        );
+    let client = common::serve(FaultInjector::new(service, schedule)).await;
+
+    let poller = metrics.clone();
+    let task = tokio::spawn(async move {
+        poller
+            .poll(
+                client,
+                Duration::from_millis(10),
+                Duration::from_millis(100),
+            )
+            .await;
+    });
+    let mut seen = Vec::new();
+    for _ in 0..200 {
+        let up = collect(&provider, &exporter)["gateway.status.up{}"];
+        if seen.last() != Some(&up) {
+            seen.push(up);
+        }
+        if seen.ends_with(&[1.0, 0.0]) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    // The hung call cleared the snapshot
+    assert!(seen.ends_with(&[1.0, 0.0]), "{seen:?}");
+    task.abort();
+}
@@ -0,0 +1,151 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "encoding")]
+
+mod common;
+
+use common::golden;
+use std::time::Duration;
+use tonic::Code;
+
+use gateway_config::GetDataplaneStatusResponse;
+use gateway_config::fault::{Fault, FaultInjector, Rpc, ScriptedFaults};
+use gateway_config::json::from_json;
+use gateway_config::memory::InMemoryConfigService;
+use gateway_config::poller::{StatusPoller, StatusPollerConfig};
+
+fn status() -> GetDataplaneStatusResponse {
+    from_json(&golden("dataplane_status.json")).unwrap()
+}
+
+fn config() -> StatusPollerConfig {
+    StatusPollerConfig {
+        interval: Duration::from_millis(20),
+        jitter: 0.1,
+        rpc_timeout: Duration::from_millis(200),
+    }
+}
+
+#[tokio::test]
+async fn test_keeps_last_good_snapshot() {
+    let service = InMemoryConfigService::new();
+    service.set_status(status());
+    let schedule = ScriptedFaults::new()
+        .then(Rpc::GetDataplaneStatus, [])
+        .times(
+            Rpc::GetDataplaneStatus,
+            2,
+            &[Fault::Error(Code::Unavailable)],
+        )
+        .then(
+            Rpc::GetDataplaneStatus,
+            [Fault::Delay(Duration::from_secs(5))],
+        );
+    let client = common::serve(FaultInjector::new(service, schedule)).await;
+    let mut poller = StatusPoller::new(client, config());
+    let rx = poller.subscribe();
+
+    poller.poll_once().await.unwrap();
+    let first_at = {
+        let polled = rx.borrow();
+        assert_eq!(polled.consecutive_failures, 0);
+        assert_eq!(polled.snapshot.as_ref().unwrap().status, status());
+        polled.snapshot.as_ref().unwrap().at
+    };
+
+    for failures in 1..=2 {
+        let err = poller.poll_once().await.unwrap_err();
+        assert_eq!(err.code(), Code::Unavailable);
+        let polled = rx.borrow();
+        assert_eq!(polled.consecutive_failures, failures);
+        assert!(polled.last_error.is_some());
+        assert_eq!(polled.snapshot.as_ref().unwrap().at, first_at);
+    }
+
+    let err = poller.poll_once().await.unwrap_err();
+    assert_eq!(err.code(), Code::DeadlineExceeded);
+    assert_eq!(rx.borrow().consecutive_failures, 3);
+    assert!(!rx.borrow().is_stale(Duration::from_secs(60)));
+
+    poller.poll_once().await.unwrap();
+    let polled = rx.borrow();
+    assert_eq!(polled.consecutive_failures, 0);
+    assert_eq!(polled.last_error, None);
+    assert!(polled.snapshot.as_ref().unwrap().at > first_at);
+}
+
+#[tokio::test]
+async fn test_run_fans_out_until_receivers_dropped() {
+    let service = InMemoryConfigService::new();
+    service.set_status(status());
+    let client = common::serve(service).await;
+    let poller = StatusPoller::new(client, config());
+    let mut first = poller.subscribe();
+    let mut second = poller.subscribe();
+    let task = tokio::spawn(poller.run());
+
+    for rx in [&mut first, &mut second] {
+        let polled = tokio::time::timeout(
+            Duration::from_secs(5),
+            rx.wait_for(|polled| polled.snapshot.is_some()),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(polled.snapshot.as_ref().unwrap().status, status());
+    }
+
+    // Several polls happen within a few intervals
+    first.mark_unchanged();
+    tokio::time::timeout(Duration::from_secs(5), first.changed())
+        .await
+        .unwrap()
+        .unwrap();
+
+    drop(first);
+    drop(second);
+    tokio::time::timeout(Duration::from_secs(5), task)
+        .await
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_interval_is_start_to_start() {
+    let service = InMemoryConfigService::new();
+    service.set_status(status());
+    let schedule = ScriptedFaults::new().times(
+        Rpc::GetDataplaneStatus,
+        10,
+        &[Fault::Delay(Duration::from_millis(60))],
+    );
+    let client = common::serve(FaultInjector::new(service, schedule)).await;
+    let config = StatusPollerConfig {
+        interval: Duration::from_millis(100),
+        jitter: 0.0,
+        ..config()
+    };
+    let poller = StatusPoller::new(client, config);
+    let mut rx = poller.subscribe();
+    let task = tokio::spawn(poller.run());
+
+    let mut polled_at = Vec::new();
+    while polled_at.len() < 4 {
+        tokio::time::timeout(Duration::from_secs(5), rx.changed())
+            .await
+            .unwrap()
+            .unwrap();
+        polled_at.push(rx.borrow_and_update().snapshot.as_ref().unwrap().at);
+    }
+
+    // Sleeping after each poll would take 160ms per poll
+    let elapsed = polled_at[3] - polled_at[0];
+    assert!(elapsed < Duration::from_millis(420), "{elapsed:?}");
+
+    drop(rx);
+    tokio::time::timeout(Duration::from_secs(5), task)
+        .await
+        .unwrap()
+        .unwrap();
+}
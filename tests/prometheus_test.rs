@@ -0,0 +1,124 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "encoding")]
+
+mod common;
+
+use common::golden;
+use gateway_config::fault::{Fault, FaultInjector, Rpc, ScriptedFaults};
+use gateway_config::json::from_json;
+use gateway_config::memory::InMemoryConfigService;
+use gateway_config::prometheus::{StatusExporter, encode_status};
+use gateway_config::{ConfigServiceClient, GetDataplaneStatusResponse};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tonic::transport::Channel;
+
+/// Serve `exporter` on a random port, send `request` and return the response.
+async fn http(exporter: StatusExporter, request: &str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(exporter.serve(listener, std::future::pending()));
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+}
+
+#[test]
+fn test_dataplane_status_golden() {
+    let status: GetDataplaneStatusResponse = from_json(&golden("dataplane_status.json")).unwrap();
+    assert_eq!(encode_status(&status), golden("dataplane_status.prom"));
+}
+
+#[tokio::test]
+async fn test_exporter() {
+    let service = InMemoryConfigService::new();
+    let status: GetDataplaneStatusResponse = from_json(&golden("dataplane_status.json")).unwrap();
+    service.set_status(status);
+    let exporter = StatusExporter::new(common::serve(service).await);
+
+    let response = http(
+        exporter.clone(),
+        "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n",
+    )
+    .await;
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    assert!(head.starts_with("HTTP/1.1 200 OK\r\n"), "{head}");
+    assert!(head.contains("Content-Type: text/plain; version=0.0.4; charset=utf-8\r\n"));
+    assert!(head.contains(&format!("Content-Length: {}\r\n", body.len())));
+    assert_eq!(
+        body,
+        "# HELP gateway_status_up Whether the dataplane status could be fetched.\n\
+         # TYPE gateway_status_up gauge\n\
+         gateway_status_up 1\n"
+            .to_string()
+            + &golden("dataplane_status.prom")
+    );
+
+    let response = http(exporter.clone(), "GET / HTTP/1.1\r\n\r\n").await;
+    assert!(
+        response.starts_with("HTTP/1.1 404 Not Found\r\n"),
+        "{response}"
+    );
+    let response = http(exporter, "POST /metrics HTTP/1.1\r\n\r\n").await;
+    assert!(
+        response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"),
+        "{response}"
+    );
+}
+
+#[tokio::test]
+async fn test_exporter_bad_clients() {
+    let channel = Channel::from_static("http://127.0.0.1:1").connect_lazy();
+    let exporter = StatusExporter::new(ConfigServiceClient::new(channel))
+        .with_request_timeout(Duration::from_millis(100));
+
+    // The head never ends
+    let response = http(exporter.clone(), "GET /metrics HTTP/1.1\r\n").await;
+    assert!(
+        response.starts_with("HTTP/1.1 408 Request Timeout\r\n"),
+        "{response}"
+    );
+
+    let huge = format!(
+        "GET /metrics HTTP/1.1\r\nX-Padding: {}\r\n",
+        "a".repeat(10_000)
+    );
+    let response = http(exporter, &huge).await;
+    assert!(
+        response.starts_with("HTTP/1.1 431 Request Header Fields Too Large\r\n"),
+        "{response}"
+    );
+}
+
+#[tokio::test]
+async fn test_exporter_down() {
+    // Nothing listens on port 1
+    let channel = Channel::from_static("http://127.0.0.1:1").connect_lazy();
+    let exporter = StatusExporter::new(ConfigServiceClient::new(channel));
+    assert_eq!(
+        exporter.scrape().await,
+        "# HELP gateway_status_up Whether the dataplane status could be fetched.\n\
+         # TYPE gateway_status_up gauge\n\
+         gateway_status_up 0\n"
+    );
+}
+
+#[tokio::test]
+async fn test_exporter_hung_dataplane() {
+    let service = InMemoryConfigService::new();
+    let schedule = ScriptedFaults::new().then(
+        Rpc::GetDataplaneStatus,
+        [Fault::Delay(Duration::from_secs(60))],
+    );
+    let client = common::serve(FaultInjector::new(service, schedule)).await;
+    let exporter = StatusExporter::new(client).with_rpc_timeout(Duration::from_millis(100));
+    let scrape = tokio::time::timeout(Duration::from_secs(5), exporter.scrape())
+        .await
+        .unwrap();
+    assert!(scrape.ends_with("gateway_status_up 0\n"), "{scrape}");
+}
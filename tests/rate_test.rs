@@ -0,0 +1,77 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "encoding")]
+
+mod common;
+
+use common::golden;
+use gateway_config::GetDataplaneStatusResponse;
+use gateway_config::json::from_json;
+use gateway_config::rate::{Continuity, StatusSnapshot, compute_rates};
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_dataplane_status_rates() {
+    let status: GetDataplaneStatusResponse = from_json(&golden("dataplane_status.json")).unwrap();
+    let mut next = status.clone();
+    let counters = next
+        .interface_runtime
+        .get_mut("eth0")
+        .unwrap()
+        .counters
+        .as_mut()
+        .unwrap();
+    counters.rx_bits += 10_000;
+    counters.rx_multicast += 50;
+    counters.rx_queues[1].drops += 20;
+    next.vpc_peering_counters
+        .get_mut("vpc-1--vpc-2")
+        .unwrap()
+        .bytes += 6400;
+    let neighbor = next.bgp.as_mut().unwrap().vrfs.get_mut("default").unwrap();
+    let neighbor = neighbor.neighbors.get_mut("192.168.1.2").unwrap();
+    neighbor
+        .messages
+        .as_mut()
+        .unwrap()
+        .received
+        .as_mut()
+        .unwrap()
+        .keepalive += 3;
+    next.frr_status.as_mut().unwrap().restarts = 0;
+
+    let now = Instant::now();
+    let rates = compute_rates(
+        &StatusSnapshot { at: now, status },
+        &StatusSnapshot {
+            at: now + Duration::from_secs(10),
+            status: next,
+        },
+    )
+    .unwrap();
+
+    let eth0 = &rates.interfaces["eth0"];
+    assert_eq!(eth0.rx_bits.delta, 10_000);
+    assert!((eth0.rx_bits.per_second - 1000.0).abs() < f64::EPSILON);
+    assert_eq!(eth0.tx_bits.delta, 0);
+    assert_eq!(eth0.rx_multicast.delta, 50);
+    assert_eq!(eth0.rx_broadcast.delta, 0);
+    let queue = &eth0.rx_queues[&1];
+    assert!((queue.drops.per_second - 2.0).abs() < f64::EPSILON);
+
+    let peering = &rates.vpc_peerings["vpc-1--vpc-2"];
+    assert!((peering.bytes.per_second - 640.0).abs() < f64::EPSILON);
+    assert_eq!(rates.vpcs["vpc-1"].packets.delta, 0);
+
+    let neighbor = &rates.bgp_neighbors[&("default".to_string(), "192.168.1.2".to_string())];
+    assert_eq!(
+        neighbor.messages_received.as_ref().unwrap().keepalive.delta,
+        3
+    );
+    assert_eq!(neighbor.messages_sent.as_ref().unwrap().update.delta, 0);
+
+    let frr = rates.frr.as_ref().unwrap();
+    assert_eq!(frr.restarts.continuity, Continuity::Reset);
+    assert!(rates.any_reset());
+}
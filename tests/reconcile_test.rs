@@ -0,0 +1,305 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+use tonic::{Code, Request, Response, Status};
+
+use gateway_config::fault::{Fault, FaultInjector, Rpc, ScriptedFaults};
+use gateway_config::memory::InMemoryConfigService;
+use gateway_config::reconcile::{ReconcileEvent, ReconcileState, Reconciler, ReconcilerConfig};
+use gateway_config::{
+    ConfigService, Error, GatewayConfig, GetConfigGenerationRequest, GetConfigGenerationResponse,
+    GetConfigRequest, GetDataplaneStatusRequest, GetDataplaneStatusResponse, GetRoutesRequest,
+    GetRoutesResponse, ListNatSessionsRequest, ListNatSessionsResponse, LookupRouteRequest,
+    LookupRouteResponse, UpdateConfigRequest, UpdateConfigResponse,
+};
+
+/// Rejects the first `failures` updates with the given error, then delegates.
+struct RejectingService {
+    inner: InMemoryConfigService,
+    failures: Arc<AtomicU32>,
+    error: Error,
+}
+
+#[tonic::async_trait]
+impl ConfigService for RejectingService {
+    async fn get_config(
+        &self,
+        request: Request<GetConfigRequest>,
+    ) -> Result<Response<GatewayConfig>, Status> {
+        self.inner.get_config(request).await
+    }
+
+    async fn get_config_generation(
+        &self,
+        request: Request<GetConfigGenerationRequest>,
+    ) -> Result<Response<GetConfigGenerationResponse>, Status> {
+        self.inner.get_config_generation(request).await
+    }
+
+    async fn update_config(
+        &self,
+        request: Request<UpdateConfigRequest>,
+    ) -> Result<Response<UpdateConfigResponse>, Status> {
+        let left = self.failures.load(Ordering::SeqCst);
+        if left > 0 {
+            self.failures.store(left - 1, Ordering::SeqCst);
+            return Ok(Response::new(UpdateConfigResponse {
+                error: self.error.into(),
+                message: "nope".into(),
+            }));
+        }
+        self.inner.update_config(request).await
+    }
+
+    async fn get_dataplane_status(
+        &self,
+        request: Request<GetDataplaneStatusRequest>,
+    ) -> Result<Response<GetDataplaneStatusResponse>, Status> {
+        self.inner.get_dataplane_status(request).await
+    }
+
+    async fn list_nat_sessions(
+        &self,
+        request: Request<ListNatSessionsRequest>,
+    ) -> Result<Response<ListNatSessionsResponse>, Status> {
+        self.inner.list_nat_sessions(request).await
+    }
+
+    async fn get_routes(
+        &self,
+        request: Request<GetRoutesRequest>,
+    ) -> Result<Response<GetRoutesResponse>, Status> {
+        self.inner.get_routes(request).await
+    }
+
+    async fn lookup_route(
+        &self,
+        request: Request<LookupRouteRequest>,
+    ) -> Result<Response<LookupRouteResponse>, Status> {
+        self.inner.lookup_route(request).await
+    }
+}
+
+fn test_config() -> ReconcilerConfig {
+    ReconcilerConfig {
+        poll_interval: Duration::from_millis(20),
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(50),
+        rpc_timeout: Duration::from_secs(2),
+        wait_for_frr: true,
+    }
+}
+
+fn generation(generation: i64) -> GatewayConfig {
+    GatewayConfig {
+        generation,
+        ..Default::default()
+    }
+}
+
+/// Collect events until the reconciler transitions to `state`.
+async fn wait_for_state(
+    events: &mut broadcast::Receiver<ReconcileEvent>,
+    state: ReconcileState,
+) -> Vec<ReconcileEvent> {
+    let mut seen = Vec::new();
+    tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let event = events.recv().await.unwrap();
+            let done = matches!(event, ReconcileEvent::Transition { to, .. } if to == state);
+            seen.push(event);
+            if done {
+                return;
+            }
+        }
+    })
+    .await
+    .unwrap_or_else(|_| panic!("timed out waiting for {state:?}, seen {seen:?}"));
+    seen
+}
+
+#[tokio::test]
+async fn test_reconcile_to_desired_generation() {
+    let service = InMemoryConfigService::new();
+    let client = common::serve(service.clone()).await;
+
+    let (tx, rx) = watch::channel(generation(3));
+    let reconciler = Reconciler::new(client, rx, test_config());
+    let mut events = reconciler.subscribe();
+    let handle = tokio::spawn(reconciler.run());
+
+    let seen = wait_for_state(&mut events, ReconcileState::InSync).await;
+    assert!(seen.contains(&ReconcileEvent::GenerationMismatch {
+        current: 0,
+        desired: 3
+    }));
+    assert!(seen.contains(&ReconcileEvent::UpdateAccepted { generation: 3 }));
+    assert_eq!(service.config().generation, 3);
+
+    // New desired config is picked up right away
+    tx.send(generation(4)).unwrap();
+    wait_for_state(&mut events, ReconcileState::Updating).await;
+    wait_for_state(&mut events, ReconcileState::InSync).await;
+    assert_eq!(service.config().generation, 4);
+
+    drop(tx);
+    tokio::time::timeout(Duration::from_secs(5), handle)
+        .await
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_reconcile_waits_for_frr() {
+    let service = InMemoryConfigService::new();
+    service.set_frr_follows_config(false);
+    let client = common::serve(service.clone()).await;
+
+    let (_tx, rx) = watch::channel(generation(5));
+    let reconciler = Reconciler::new(client, rx, test_config());
+    let mut events = reconciler.subscribe();
+    tokio::spawn(reconciler.run());
+
+    let seen = wait_for_state(&mut events, ReconcileState::WaitingForFrr).await;
+    assert!(seen.contains(&ReconcileEvent::FrrPending {
+        applied: 0,
+        desired: 5
+    }));
+    assert_eq!(service.config().generation, 5);
+
+    service.update_status(|status| {
+        status.frr_status.as_mut().unwrap().applied_config_gen = 5;
+    });
+    wait_for_state(&mut events, ReconcileState::InSync).await;
+}
+
+#[tokio::test]
+async fn test_reconcile_backs_off_on_apply_failure() {
+    let inner = InMemoryConfigService::new();
+    let client = common::serve(RejectingService {
+        inner: inner.clone(),
+        failures: Arc::new(AtomicU32::new(2)),
+        error: Error::ApplyFailed,
+    })
+    .await;
+
+    let (_tx, rx) = watch::channel(generation(1));
+    let reconciler = Reconciler::new(client, rx, test_config());
+    let mut events = reconciler.subscribe();
+    tokio::spawn(reconciler.run());
+
+    let seen = wait_for_state(&mut events, ReconcileState::InSync).await;
+    let failures = seen
+        .iter()
+        .filter(|e| matches!(e, ReconcileEvent::UpdateFailed { error, .. } if *error == Error::ApplyFailed))
+        .count();
+    assert_eq!(failures, 2);
+    let delays: Vec<_> = seen
+        .iter()
+        .filter_map(|e| match e {
+            ReconcileEvent::BackingOff { delay } => Some(*delay),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        delays,
+        vec![Duration::from_millis(10), Duration::from_millis(20)]
+    );
+    assert_eq!(inner.config().generation, 1);
+}
+
+#[tokio::test]
+async fn test_reconcile_waits_for_new_config_after_validation_failure() {
+    let inner = InMemoryConfigService::new();
+    let client = common::serve(RejectingService {
+        inner: inner.clone(),
+        failures: Arc::new(AtomicU32::new(1)),
+        error: Error::ValidationFailed,
+    })
+    .await;
+
+    let (tx, rx) = watch::channel(generation(1));
+    let reconciler = Reconciler::new(client, rx, test_config());
+    let mut events = reconciler.subscribe();
+    tokio::spawn(reconciler.run());
+
+    wait_for_state(&mut events, ReconcileState::Rejected).await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(
+        events.try_recv().is_err(),
+        "rejected config must not be retried"
+    );
+
+    tx.send(generation(2)).unwrap();
+    wait_for_state(&mut events, ReconcileState::InSync).await;
+    assert_eq!(inner.config().generation, 2);
+}
+
+#[tokio::test]
+async fn test_reconcile_update_transport_failure() {
+    let inner = InMemoryConfigService::new();
+    let schedule = ScriptedFaults::new().then(Rpc::UpdateConfig, [Fault::Error(Code::Unavailable)]);
+    let client = common::serve(FaultInjector::new(inner.clone(), schedule)).await;
+
+    let (_tx, rx) = watch::channel(generation(1));
+    let mut reconciler = Reconciler::new(client, rx, test_config());
+    let mut events = reconciler.subscribe();
+
+    assert_eq!(
+        reconciler.reconcile_once().await,
+        ReconcileState::BackingOff
+    );
+    let mut seen = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        seen.push(event);
+    }
+    assert!(
+        seen.iter().any(|e| matches!(
+            e,
+            ReconcileEvent::RpcFailed {
+                rpc: "UpdateConfig",
+                ..
+            }
+        )),
+        "{seen:?}"
+    );
+    assert!(
+        !seen
+            .iter()
+            .any(|e| matches!(e, ReconcileEvent::UpdateFailed { .. })),
+        "{seen:?}"
+    );
+
+    assert_eq!(reconciler.reconcile_once().await, ReconcileState::InSync);
+    assert_eq!(inner.config().generation, 1);
+}
+
+#[tokio::test]
+async fn test_reconcile_once_unreachable() {
+    let channel = tonic::transport::Channel::from_static("http://127.0.0.1:1").connect_lazy();
+    let (_tx, rx) = watch::channel(generation(1));
+    let mut reconciler = Reconciler::new(
+        gateway_config::ConfigServiceClient::new(channel),
+        rx,
+        test_config(),
+    );
+    let mut events = reconciler.subscribe();
+
+    assert_eq!(
+        reconciler.reconcile_once().await,
+        ReconcileState::BackingOff
+    );
+    assert!(matches!(
+        events.recv().await.unwrap(),
+        ReconcileEvent::RpcFailed {
+            rpc: "GetConfigGeneration",
+            ..
+        }
+    ));
+}
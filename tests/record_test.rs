@@ -0,0 +1,127 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use std::sync::Arc;
+
+use gateway_config::memory::InMemoryConfigService;
+use gateway_config::record::{Recorder, ReplayService};
+use gateway_config::{
+    ConfigService, GatewayConfig, GetConfigGenerationRequest, GetConfigRequest,
+    GetDataplaneStatusRequest, GetDataplaneStatusResponse, GetRoutesRequest, GetRoutesResponse,
+    ListNatSessionsRequest, ListNatSessionsResponse, LookupRouteRequest, LookupRouteResponse,
+    UpdateConfigRequest,
+};
+use tonic::{Request, Response, Status};
+
+/// Forwards to a shared recorder so it can be inspected after serving.
+struct Shared<S>(Arc<S>);
+
+#[tonic::async_trait]
+impl<S: ConfigService> ConfigService for Shared<S> {
+    async fn get_config(
+        &self,
+        request: Request<GetConfigRequest>,
+    ) -> Result<Response<GatewayConfig>, Status> {
+        self.0.get_config(request).await
+    }
+
+    async fn get_config_generation(
+        &self,
+        request: Request<GetConfigGenerationRequest>,
+    ) -> Result<Response<gateway_config::GetConfigGenerationResponse>, Status> {
+        self.0.get_config_generation(request).await
+    }
+
+    async fn update_config(
+        &self,
+        request: Request<UpdateConfigRequest>,
+    ) -> Result<Response<gateway_config::UpdateConfigResponse>, Status> {
+        self.0.update_config(request).await
+    }
+
+    async fn get_dataplane_status(
+        &self,
+        request: Request<GetDataplaneStatusRequest>,
+    ) -> Result<Response<GetDataplaneStatusResponse>, Status> {
+        self.0.get_dataplane_status(request).await
+    }
+
+    async fn list_nat_sessions(
+        &self,
+        request: Request<ListNatSessionsRequest>,
+    ) -> Result<Response<ListNatSessionsResponse>, Status> {
+        self.0.list_nat_sessions(request).await
+    }
+
+    async fn get_routes(
+        &self,
+        request: Request<GetRoutesRequest>,
+    ) -> Result<Response<GetRoutesResponse>, Status> {
+        self.0.get_routes(request).await
+    }
+
+    async fn lookup_route(
+        &self,
+        request: Request<LookupRouteRequest>,
+    ) -> Result<Response<LookupRouteResponse>, Status> {
+        self.0.lookup_route(request).await
+    }
+}
+
+#[tokio::test]
+async fn test_record_and_replay_over_grpc() {
+    let path = std::env::temp_dir().join(format!(
+        "gateway-config-record-test-{}.bin",
+        std::process::id()
+    ));
+
+    let recorder = Arc::new(Recorder::create(InMemoryConfigService::new(), &path).unwrap());
+    let mut client = common::serve(Shared(recorder.clone())).await;
+
+    let config = GatewayConfig {
+        generation: 11,
+        ..Default::default()
+    };
+    client.apply_config(config.clone()).await.unwrap();
+    let recorded_status = client
+        .get_dataplane_status(GetDataplaneStatusRequest {})
+        .await
+        .unwrap()
+        .into_inner();
+    let recorded_config = client
+        .get_config(GetConfigRequest {})
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(recorder.error().is_none());
+
+    let mut replay = common::serve(
+        ReplayService::from_file(&path)
+            .unwrap()
+            .with_strict_requests(true),
+    )
+    .await;
+    replay.apply_config(config).await.unwrap();
+    assert_eq!(
+        replay
+            .get_dataplane_status(GetDataplaneStatusRequest {})
+            .await
+            .unwrap()
+            .into_inner(),
+        recorded_status
+    );
+    assert_eq!(
+        replay
+            .get_config(GetConfigRequest {})
+            .await
+            .unwrap()
+            .into_inner(),
+        recorded_config
+    );
+    let status = replay.get_config(GetConfigRequest {}).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::OutOfRange);
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,137 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use gateway_config::memory::InMemoryConfigService;
+use gateway_config::routes::RouteTable;
+use gateway_config::{GetRoutesRequest, LookupRouteRequest, Route, RouteNextHop, RouteProtocol};
+use tonic::Code;
+
+fn route(i: u8) -> Route {
+    let protocol = if i.is_multiple_of(2) {
+        RouteProtocol::Bgp
+    } else {
+        RouteProtocol::Static
+    };
+    Route {
+        prefix: format!("10.{i}.0.0/16"),
+        next_hops: vec![RouteNextHop {
+            address: "10.255.0.2".into(),
+            ifname: "vtep".into(),
+            vni: 1001,
+            vtep: "10.255.0.2".into(),
+        }],
+        protocol: protocol.into(),
+        metric: 20,
+        installed: true,
+    }
+}
+
+fn vpc_routes() -> Vec<Route> {
+    let mut routes: Vec<_> = (0..10).map(route).collect();
+    routes.push(Route {
+        prefix: "10.1.2.0/24".into(),
+        next_hops: vec![RouteNextHop {
+            ifname: "vpc-1".into(),
+            ..Default::default()
+        }],
+        protocol: RouteProtocol::Connected.into(),
+        installed: true,
+        ..Default::default()
+    });
+    routes
+}
+
+#[tokio::test]
+async fn test_get_routes_pages() {
+    let routes = vpc_routes();
+    let service = InMemoryConfigService::new();
+    service.set_routes(RouteTable::Vpc("vpc-1".into()), routes.clone());
+    let mut client = common::serve(service).await;
+
+    let first = client
+        .get_routes(GetRoutesRequest {
+            table: Some(RouteTable::Vpc("vpc-1".into()).into()),
+            page_size: 4,
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(first.routes, routes[..4]);
+    assert!(!first.next_page_token.is_empty());
+
+    let all = client
+        .get_all_routes(GetRoutesRequest {
+            table: Some(RouteTable::Vpc("vpc-1".into()).into()),
+            page_size: 4,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(all, routes);
+
+    let bgp = client
+        .get_all_routes(GetRoutesRequest {
+            table: Some(RouteTable::Vpc("vpc-1".into()).into()),
+            protocol: RouteProtocol::Bgp.into(),
+            page_size: 2,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let expected: Vec<_> = [0, 2, 4, 6, 8].into_iter().map(route).collect();
+    assert_eq!(bgp, expected);
+
+    let err = client
+        .get_routes(GetRoutesRequest {
+            table: Some(RouteTable::Vrf("default".into()).into()),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::NotFound);
+
+    let err = client
+        .get_routes(GetRoutesRequest::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn test_lookup_route() {
+    let service = InMemoryConfigService::new();
+    service.set_routes(RouteTable::Vpc("vpc-1".into()), vpc_routes());
+    let mut client = common::serve(service).await;
+
+    let lookup = |address: &str| LookupRouteRequest {
+        table: Some(RouteTable::Vpc("vpc-1".into()).into()),
+        address: address.into(),
+    };
+
+    let found = client
+        .lookup_route(lookup("10.1.2.3"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(found.route.unwrap().prefix, "10.1.2.0/24");
+
+    let found = client
+        .lookup_route(lookup("10.1.3.3"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(found.route, Some(route(1)));
+
+    let missing = client
+        .lookup_route(lookup("192.0.2.1"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(missing.route, None);
+
+    let err = client.lookup_route(lookup("vpc-1")).await.unwrap_err();
+    assert_eq!(err.code(), Code::InvalidArgument);
+}
@@ -0,0 +1,75 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "encoding")]
+
+mod common;
+
+use common::golden;
+use gateway_config::GatewayConfig;
+use gateway_config::schema::json_schema;
+use gateway_config::yaml::from_yaml;
+use serde_json::{Value, json};
+
+fn validator() -> jsonschema::Validator {
+    jsonschema::options()
+        .should_validate_formats(true)
+        .build(&json_schema::<GatewayConfig>().unwrap())
+        .unwrap()
+}
+
+#[test]
+fn test_schema_golden() {
+    let schema: Value = serde_json::from_str(&golden("gateway_config.schema.json")).unwrap();
+    assert_eq!(
+        json_schema::<GatewayConfig>().unwrap(),
+        schema,
+        "gateway_config.schema.json does not match"
+    );
+    assert!(jsonschema::meta::is_valid(&schema));
+}
+
+#[test]
+fn test_valid_documents() {
+    let validator = validator();
+    let config: Value = serde_json::from_str(&golden("gateway_config.json")).unwrap();
+    assert!(validator.is_valid(&config), "gateway_config.json");
+
+    let config: GatewayConfig = from_yaml(&golden("gateway_config.yaml")).unwrap();
+    let config = gateway_config::json::to_json_value(&config).unwrap();
+    assert!(validator.is_valid(&config), "gateway_config.yaml");
+
+    for config in [
+        json!({}),
+        json!({"generation": 42}),
+        json!({"generation": "42", "communities": {"1": "65000:1"}}),
+        json!({"underlay": {"vrfs": [{"interfaces": [{"name": "eth0", "ipaddrs": ["2001:db8::1/64"]}]}]}}),
+        json!({"underlay": {"vrfs": [{"router": {"neighbors": [{"address": "fd00::2"}]}}]}}),
+        json!({"overlay": {"peerings": [{"for": [{"expose": [{"stateful": {"idleTimeout": "1.5s"}}]}]}]}}),
+    ] {
+        assert!(validator.is_valid(&config), "{config}");
+    }
+}
+
+#[test]
+fn test_invalid_documents() {
+    let validator = validator();
+    for config in [
+        json!({"bogus": 1}),
+        json!({"gw_groups": []}),
+        json!({"generation": "forty-two"}),
+        json!({"communities": {"first": "65000:1"}}),
+        json!({"device": {"tracing": {"default": "VERBOSE"}}}),
+        json!({"underlay": {"vrfs": [{"interfaces": [{"name": "eth0", "ipaddrs": ["10.0.0.1"]}]}]}}),
+        json!({"underlay": {"vrfs": [{"interfaces": [{"name": "eth0", "ipaddrs": ["10.0.0.256/24"]}]}]}}),
+        json!({"underlay": {"vrfs": [{"interfaces": [{"name": "vtep", "macaddr": "02:00:00:00:01"}]}]}}),
+        json!({"underlay": {"vrfs": [{"interfaces": [{"name": "eth0", "mtu": -1}]}]}}),
+        json!({"underlay": {"vrfs": [{"router": {"routerId": "router-1"}}]}}),
+        json!({"underlay": {"vrfs": [{"router": {"neighbors": [{"address": "peer"}]}}]}}),
+        json!({"overlay": {"peerings": [{"for": [{"expose": [{"ips": [{"cidr": "10.0.0.0/8", "not": "10.0.0.0/24"}]}]}]}]}}),
+        json!({"overlay": {"peerings": [{"for": [{"expose": [{"stateless": {}, "stateful": {}}]}]}]}}),
+        json!({"overlay": {"peerings": [{"for": [{"expose": [{"stateful": {"idleTimeout": "5m"}}]}]}]}}),
+    ] {
+        assert!(!validator.is_valid(&config), "{config}");
+    }
+}
@@ -7,9 +7,9 @@ use tonic::{Request, Response, Status};
 
 use gateway_config::{
     BgpStatus, ConfigService, ConfigServiceClient, ConfigServiceServer, DataplaneStatusInfo,
-    DataplaneStatusType, FrrAgentStatusType, FrrStatus, GetConfigGenerationRequest,
-    GetConfigGenerationResponse, GetDataplaneStatusRequest, GetDataplaneStatusResponse,
-    ZebraStatusType,
+    DataplaneStatusType, FrrAgentStatusType, FrrLifecycleState, FrrStatus,
+    GetConfigGenerationRequest, GetConfigGenerationResponse, GetDataplaneStatusRequest,
+    GetDataplaneStatusResponse, ZebraStatusType,
 };
 
 struct SimpleConfigService {
@@ -47,6 +47,10 @@ impl ConfigService for SimpleConfigService {
             vpcs: HashMap::new(),
             vpc_counters: HashMap::new(),
             vpc_peering_counters: HashMap::new(),
+            nat_sessions: HashMap::new(),
+            expose_counters: vec![],
+            remote_vteps: HashMap::new(),
+            vnis: HashMap::new(),
             interface_statuses: vec![],
             frr_status: Some(FrrStatus {
                 zebra_status: ZebraStatusType::ZebraStatusConnected as i32,
@@ -55,6 +59,12 @@ impl ConfigService for SimpleConfigService {
                 applied_config_gen: 1,
                 applied_configs: 1,
                 failed_configs: 0,
+                state: FrrLifecycleState::FrrStateRunning as i32,
+                state_since: None,
+                zebra_status_since: None,
+                frr_agent_status_since: None,
+                last_apply_error: String::new(),
+                pending_config_gen: 0,
             }),
             dataplane_status: Some(DataplaneStatusInfo {
                 status: DataplaneStatusType::DataplaneStatusHealthy as i32,
@@ -129,3 +139,85 @@ async fn test_simple_generation_request() {
     println!("Received response with generation: {}", result.generation);
     assert_eq!(result.generation, 228);
 }
+
+#[tokio::test]
+async fn test_simple_generation_request_with_faults() {
+    use gateway_config::fault::{Fault, FaultInjector, Rpc, ScriptedFaults};
+
+    let schedule = ScriptedFaults::new()
+        .then(
+            Rpc::GetConfigGeneration,
+            [Fault::Error(tonic::Code::Unavailable)],
+        )
+        .then(
+            Rpc::GetConfigGeneration,
+            [Fault::Delay(std::time::Duration::from_millis(10))],
+        );
+    let service = FaultInjector::new(SimpleConfigService::new(228), schedule);
+    let server = ConfigServiceServer::new(service);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let server_addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        tonic::transport::Server::builder()
+            .add_service(server)
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+            .await
+            .unwrap();
+    });
+
+    let channel = tonic::transport::Channel::from_shared(format!("http://{server_addr}"))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = ConfigServiceClient::new(channel);
+
+    let err = client
+        .get_config_generation(Request::new(GetConfigGenerationRequest {}))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Unavailable);
+
+    for _ in 0..2 {
+        let result = client
+            .get_config_generation(Request::new(GetConfigGenerationRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(result.generation, 228);
+    }
+}
+
+#[tokio::test]
+async fn test_unimplemented_rpc_default() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let server_addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        tonic::transport::Server::builder()
+            .add_service(ConfigServiceServer::new(SimpleConfigService::new(228)))
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+            .await
+            .unwrap();
+    });
+
+    let channel = tonic::transport::Channel::from_shared(format!("http://{server_addr}"))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = ConfigServiceClient::new(channel);
+
+    let err = client
+        .list_nat_sessions(gateway_config::ListNatSessionsRequest::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Unimplemented);
+
+    let err = client
+        .get_routes(gateway_config::GetRoutesRequest::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Unimplemented);
+}
@@ -0,0 +1,20 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "encoding")]
+
+mod common;
+
+use common::golden;
+use gateway_config::GetDataplaneStatusResponse;
+use gateway_config::json::from_json;
+use gateway_config::table::{TableOptions, render_status};
+
+#[test]
+fn test_dataplane_status_golden() {
+    let status: GetDataplaneStatusResponse = from_json(&golden("dataplane_status.json")).unwrap();
+    assert_eq!(
+        render_status(&status, TableOptions::default()),
+        golden("dataplane_status.txt")
+    );
+}
@@ -0,0 +1,82 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "connect")]
+
+use gateway_config::GatewayConfig;
+use gateway_config::config::GetConfigGenerationRequest;
+use gateway_config::memory::InMemoryConfigService;
+use gateway_config::target::{Target, TargetError};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Serve a fresh in-memory service on `target`, push a config through one
+/// client and read it back through another.
+async fn check_round_trip(target: Target) -> Target {
+    let service = InMemoryConfigService::new();
+    let listener = target.listen().await.unwrap();
+    let local = listener.local_target().unwrap();
+    let (stop, stopped) = oneshot::channel::<()>();
+    let server = tokio::spawn(listener.serve(service.clone(), async {
+        let _ = stopped.await;
+    }));
+
+    let config = GatewayConfig {
+        generation: 7,
+        ..Default::default()
+    };
+    local
+        .connect(TIMEOUT)
+        .await
+        .unwrap()
+        .apply_config(config.clone())
+        .await
+        .unwrap();
+    let generation = local
+        .connect(TIMEOUT)
+        .await
+        .unwrap()
+        .get_config_generation(GetConfigGenerationRequest {})
+        .await
+        .unwrap()
+        .into_inner()
+        .generation;
+    assert_eq!(generation, 7);
+    assert_eq!(service.config(), config);
+
+    stop.send(()).unwrap();
+    server.await.unwrap().unwrap();
+    local
+}
+
+#[tokio::test]
+async fn test_tcp_random_port() {
+    let local = check_round_trip("tcp://127.0.0.1:0".parse().unwrap()).await;
+    let Target::Tcp(addr) = local else {
+        panic!("unexpected target {local}");
+    };
+    assert!(!addr.ends_with(":0"), "{addr}");
+}
+
+/// The Go client dials `unix://name` as the same abstract socket.
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_unix_socket() {
+    let target: Target = format!("unix://gwtestctl-{}", std::process::id())
+        .parse()
+        .unwrap();
+    assert_eq!(check_round_trip(target.clone()).await, target);
+    // Nothing is left on the filesystem to clean up, the name is free again
+    check_round_trip(target).await;
+}
+
+#[tokio::test]
+async fn test_connect_failure() {
+    let err = Target::Unix(format!("gwtestctl-{}.none", std::process::id()))
+        .connect(TIMEOUT)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TargetError::Connect(..)), "{err}");
+}
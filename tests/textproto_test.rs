@@ -0,0 +1,62 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "encoding")]
+
+mod common;
+
+use common::golden;
+use gateway_config::json::from_json;
+use gateway_config::textproto::{TextprotoError, from_textproto, to_textproto};
+use gateway_config::yaml::Position;
+use gateway_config::{GatewayConfig, GetDataplaneStatusResponse};
+use prost::{Message, Name};
+
+/// Textproto golden file matches the JSON golden file of the same message,
+/// both ways.
+fn check_golden<M: Message + Name + Default + PartialEq + std::fmt::Debug>(name: &str) {
+    let msg: M = from_json(&golden(&format!("{name}.json"))).unwrap();
+    let text = golden(&format!("{name}.txtpb"));
+    assert_eq!(
+        to_textproto(&msg).unwrap(),
+        text,
+        "{name}.txtpb does not match"
+    );
+    assert_eq!(from_textproto::<M>(&text).unwrap(), msg);
+}
+
+#[test]
+fn test_gateway_config_golden() {
+    check_golden::<GatewayConfig>("gateway_config");
+}
+
+#[test]
+fn test_dataplane_status_golden() {
+    check_golden::<GetDataplaneStatusResponse>("dataplane_status");
+}
+
+#[test]
+fn test_error_location() {
+    let text = golden("dataplane_status.txtpb").replacen(
+        "session_state: BGP_STATE_ESTABLISHED",
+        "session_state: BGP_STATE_UNKNOWN_TO_US",
+        1,
+    );
+    let err = from_textproto::<GetDataplaneStatusResponse>(&text).unwrap_err();
+    let TextprotoError::Parse { position, message } = &err else {
+        panic!("unexpected error {err:?}");
+    };
+    let line = text
+        .lines()
+        .position(|l| l.contains("BGP_STATE_UNKNOWN_TO_US"))
+        .unwrap();
+    let column = text.lines().nth(line).unwrap().find("BGP_STATE").unwrap();
+    assert_eq!(
+        *position,
+        Position {
+            line: line + 1,
+            column: column + 1
+        }
+    );
+    assert!(message.contains("BGP_STATE_UNKNOWN_TO_US"), "{message}");
+}
@@ -0,0 +1,129 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "encoding")]
+
+mod common;
+
+use common::golden;
+use gateway_config::json::from_json;
+use gateway_config::yaml::{Position, YamlError, from_yaml, to_yaml};
+use gateway_config::{GatewayConfig, GetDataplaneStatusResponse, IfType};
+use prost::{Message, Name};
+
+/// YAML golden file matches the JSON golden file of the same message, both
+/// ways.
+fn check_golden<M: Message + Name + Default + PartialEq + std::fmt::Debug>(name: &str) {
+    let msg: M = from_json(&golden(&format!("{name}.json"))).unwrap();
+    let yaml = golden(&format!("{name}.yaml"));
+    assert_eq!(to_yaml(&msg).unwrap(), yaml, "{name}.yaml does not match");
+    assert_eq!(from_yaml::<M>(&yaml).unwrap(), msg);
+}
+
+#[test]
+fn test_gateway_config_golden() {
+    check_golden::<GatewayConfig>("gateway_config");
+}
+
+#[test]
+fn test_dataplane_status_golden() {
+    check_golden::<GetDataplaneStatusResponse>("dataplane_status");
+}
+
+#[test]
+fn test_gwtestctl_documents() {
+    // Same inputs as pkg/protoyaml tests.
+    for (input, if_type) in [
+        (
+            "
+generation: 42
+underlay:
+  vrfs:
+  - interfaces:
+    - name: eth0
+      type: IF_TYPE_ETHERNET
+    name: vrf1
+",
+            IfType::Ethernet,
+        ),
+        (
+            "
+generation: 42
+underlay:
+  vrfs:
+  - interfaces:
+    - name: eth0
+    name: vrf1
+",
+            IfType::Ethernet,
+        ),
+        (
+            "
+generation: 42
+underlay:
+  vrfs:
+  - interfaces:
+    - name: eth0
+      type: IF_TYPE_VLAN
+    name: vrf1
+",
+            IfType::Vlan,
+        ),
+    ] {
+        let config: GatewayConfig = from_yaml(input).unwrap();
+        assert_eq!(config.generation, 42);
+        let vrf = &config.underlay.as_ref().unwrap().vrfs[0];
+        assert_eq!(vrf.name, "vrf1");
+        assert_eq!(vrf.interfaces[0].name, "eth0");
+        assert_eq!(vrf.interfaces[0].r#type(), if_type);
+        assert_eq!(
+            from_yaml::<GatewayConfig>(&to_yaml(&config).unwrap()).unwrap(),
+            config
+        );
+    }
+
+    let config: GatewayConfig = from_yaml("generation: \"42\"\n").unwrap();
+    assert_eq!(config.generation, 42);
+}
+
+#[test]
+fn test_unknown_field() {
+    let err = from_yaml::<GatewayConfig>(
+        "
+generation: 1
+underlay:
+  vrfs:
+  - name: default
+    interfaces:
+    - name: eth0
+      mtuu: 9000
+",
+    )
+    .unwrap_err();
+    let YamlError::UnknownField { field, message, .. } = &err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(field, "mtuu");
+    assert_eq!(message, "config.Interface");
+    assert_eq!(err.position(), Some(Position { line: 8, column: 7 }));
+}
+
+#[test]
+fn test_map_keys_are_not_fields() {
+    let config: GatewayConfig = from_yaml(
+        "
+device:
+  tracing:
+    taglevel:
+      whatever: 3
+communities:
+  1: 65000:1
+",
+    )
+    .unwrap();
+    assert_eq!(config.communities[&1], "65000:1");
+    assert_eq!(
+        config.device.unwrap().tracing.unwrap().taglevel["whatever"],
+        3
+    );
+}